@@ -23,7 +23,7 @@ use ibc_relayer::config::filter::{FilterPattern, PacketFilter};
 use ibc_relayer::config::gas_multiplier::GasMultiplier;
 use ibc_relayer::config::types::{MaxMsgNum, MaxTxSize, Memo, TrustThreshold};
 use ibc_relayer::config::{default, AddressType, ChainConfig, EventSourceMode, GasPrice};
-use ibc_relayer::keyring::Store;
+use ibc_relayer::keyring::{PassphraseSource, Store};
 use ibc_relayer::util::excluded_sequences::ExcludedSequences;
 
 const MAX_HEALTHY_QUERY_RETRIES: u8 = 5;
@@ -138,6 +138,8 @@ where
         key_name: String::new(),
         key_store_type: Store::default(),
         key_store_folder: None,
+        key_store_passphrase: PassphraseSource::default(),
+        remote_signer: None,
         store_prefix: "ibc".to_string(),
         default_gas: Some(100000),
         max_gas: Some(400000),
@@ -164,8 +166,15 @@ where
         },
         packet_filter: packet_filter.unwrap_or_default(),
         address_type: AddressType::default(),
+        key_pool: Default::default(),
+        fee_bump: Default::default(),
+        fee_priority: Default::default(),
+        rate_limit: Default::default(),
         sequential_batch_tx: false,
+        light_block_cache: false,
+        light_block_cache_folder: None,
         extension_options: Vec::new(),
+        backup_endpoints: Vec::new(),
         compat_mode: None,
         clear_interval: None,
         excluded_sequences: ExcludedSequences::new(BTreeMap::new()),
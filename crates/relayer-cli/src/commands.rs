@@ -12,6 +12,8 @@ mod listen;
 mod logs;
 mod misbehaviour;
 mod query;
+mod recover;
+mod replay;
 mod start;
 mod tx;
 mod update;
@@ -21,8 +23,9 @@ mod version;
 use self::{
     clear::ClearCmds, completions::CompletionsCmd, config::ConfigCmd, create::CreateCmds,
     evidence::EvidenceCmd, fee::FeeCmd, health::HealthCheckCmd, keys::KeysCmd, listen::ListenCmd,
-    logs::LogsCmd, misbehaviour::MisbehaviourCmd, query::QueryCmd, start::StartCmd, tx::TxCmd,
-    update::UpdateCmds, upgrade::UpgradeCmds, version::VersionCmd,
+    logs::LogsCmd, misbehaviour::MisbehaviourCmd, query::QueryCmd, recover::RecoverCmds,
+    replay::ReplayCmd, start::StartCmd, tx::TxCmd, update::UpdateCmds, upgrade::UpgradeCmds,
+    version::VersionCmd,
 };
 
 use core::time::Duration;
@@ -63,6 +66,10 @@ pub enum CliCmd {
     #[clap(subcommand)]
     Upgrade(UpgradeCmds),
 
+    /// Recover objects (expired or frozen clients) through governance
+    #[clap(subcommand)]
+    Recover(RecoverCmds),
+
     /// Clear objects, such as outstanding packets on a channel.
     #[clap(subcommand)]
     Clear(ClearCmds),
@@ -72,6 +79,11 @@ pub enum CliCmd {
     /// Relays packets and open handshake messages between all chains in the config.
     Start(StartCmd),
 
+    /// Replay the events of a chain from a past height in the running instance.
+    ///
+    /// Requires the REST API to be enabled.
+    Replay(ReplayCmd),
+
     /// Query objects from the chain
     #[clap(subcommand)]
     Query(QueryCmd),
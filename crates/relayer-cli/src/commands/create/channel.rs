@@ -1,3 +1,5 @@
+use core::iter;
+
 use abscissa_core::clap::Parser;
 
 use console::style;
@@ -11,6 +13,7 @@ use ibc_relayer::channel::Channel;
 use ibc_relayer::config::default::connection_delay;
 use ibc_relayer::connection::Connection;
 use ibc_relayer::foreign_client::ForeignClient;
+use ibc_relayer::multihop::{self, find_hop_chain};
 use ibc_relayer_types::core::ics03_connection::connection::IdentifiedConnectionEnd;
 use ibc_relayer_types::core::ics04_channel::channel::Ordering;
 use ibc_relayer_types::core::ics04_channel::version::Version;
@@ -42,6 +45,17 @@ static HINT: &str = "Consider using the default invocation\n\nhermes create chan
 /// Note that `Connection-ID`s have to be considered based off of the chain's perspective. Although
 /// chain A and chain B might refer to the connection with different names, they are actually referring
 /// to the same connection.
+///
+/// Reusing the `connection-localhost` connection of chain A creates a channel between two ports
+/// of chain A, which is relayed over the localhost client of the chain.
+///
+/// When reusing a connection, the `--resume` flag resumes the handshake of a channel between
+/// the two ports which was left half-open, eg. by an interrupted invocation of this command,
+/// instead of creating a new channel.
+///
+/// When reusing a connection, the `--connection-hops` option creates a multi-hop channel (ICS 033)
+/// over the connection on chain A followed by the given connections on the intermediate chains,
+/// the last of which leads to chain B.
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
 #[clap(
     override_usage = "hermes create channel [OPTIONS] --a-chain <A_CHAIN_ID> --a-connection <A_CONNECTION_ID> --a-port <A_PORT_ID> --b-port <B_PORT_ID>
@@ -129,6 +143,26 @@ pub struct CreateChannelCommand {
         help = "Skip new_client_connection confirmation"
     )]
     yes: bool,
+
+    #[clap(
+        long = "resume",
+        conflicts_with = "new-client-connection",
+        help = "Resume the handshake of a channel between the ports which was left half-open, \
+                instead of creating a new channel"
+    )]
+    resume: bool,
+
+    #[clap(
+        long = "connection-hops",
+        value_name = "CONNECTION_ID",
+        multiple_values = true,
+        value_delimiter = ',',
+        conflicts_with_all = &["new-client-connection", "resume"],
+        help = "Identifiers of the connections on the intermediate chains of a multi-hop channel, \
+                following the connection on chain `a`, from the chain next to chain `a` \
+                to the chain next to chain `b`"
+    )]
+    connection_hops: Vec<ConnectionId>,
 }
 
 impl Runnable for CreateChannelCommand {
@@ -209,6 +243,10 @@ impl CreateChannelCommand {
 
     /// Creates a new channel, reusing an already existing connection and its clients.
     fn run_reusing_connection(&self, connection_a: &ConnectionId) {
+        if !self.connection_hops.is_empty() {
+            return self.run_multihop(connection_a);
+        }
+
         let config = app_config();
 
         // Validate & spawn runtime for side a.
@@ -238,9 +276,13 @@ impl CreateChannelCommand {
             .map(|(cs, _)| cs.chain_id())
             .unwrap_or_else(exit_with_unrecoverable_error);
 
-        // Spawn the runtime for side b.
-        let chain_b =
-            spawn_chain_runtime(&config, &chain_b).unwrap_or_else(exit_with_unrecoverable_error);
+        // Spawn the runtime for side b, unless the connection is a localhost
+        // connection, whose both ends are on chain a.
+        let chain_b = if chain_b == chain_a.id() {
+            chain_a.clone()
+        } else {
+            spawn_chain_runtime(&config, &chain_b).unwrap_or_else(exit_with_unrecoverable_error)
+        };
 
         // Create the foreign client handles.
         let client_a = ForeignClient::find(chain_b.clone(), chain_a.clone(), conn_end.client_id())
@@ -253,12 +295,63 @@ impl CreateChannelCommand {
         let connection = Connection::find(client_a, client_b, &identified_end)
             .unwrap_or_else(exit_with_unrecoverable_error);
 
-        let channel = Channel::new(
-            connection,
+        let channel = if self.resume {
+            Channel::resume(
+                connection,
+                self.order,
+                self.port_a.clone(),
+                self.port_b.clone(),
+                self.version.clone(),
+            )
+        } else {
+            Channel::new(
+                connection,
+                self.order,
+                self.port_a.clone(),
+                self.port_b.clone(),
+                self.version.clone(),
+            )
+        }
+        .unwrap_or_else(exit_with_unrecoverable_error);
+
+        Output::success(channel).exit();
+    }
+
+    /// Creates a new multi-hop channel, over the given connection on chain a
+    /// followed by the connections on the intermediate chains.
+    fn run_multihop(&self, connection_a: &ConnectionId) {
+        let config = app_config();
+
+        let chain_a = spawn_chain_runtime(&config, &self.chain_a)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let connection_hops: Vec<ConnectionId> = iter::once(connection_a.clone())
+            .chain(self.connection_hops.iter().cloned())
+            .collect();
+
+        // Spawn the runtimes of the intermediate chains, obtain the identifier of chain b.
+        let (hop_chains, chain_b) = multihop::hop_chains(&chain_a, &connection_hops, |chain_id| {
+            spawn_chain_runtime(&config, chain_id)
+                .map_err(|e| error!("failed to spawn the runtime of chain {chain_id}: {e}"))
+                .ok()
+        })
+        .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let chain_b =
+            spawn_chain_runtime(&config, &chain_b).unwrap_or_else(exit_with_unrecoverable_error);
+
+        let hop_chain = |chain_id: &_| find_hop_chain(&hop_chains, chain_id);
+
+        let channel = Channel::new_multihop(
+            chain_a,
+            chain_b,
+            &connection_hops,
             self.order,
             self.port_a.clone(),
             self.port_b.clone(),
             self.version.clone(),
+            hop_chain,
+            hop_chain,
         )
         .unwrap_or_else(exit_with_unrecoverable_error);
 
@@ -289,7 +382,9 @@ mod tests {
                 order: Ordering::Unordered,
                 version: None,
                 new_client_connection: false,
-                yes: false
+                yes: false,
+                resume: false,
+                connection_hops: vec![]
             },
             CreateChannelCommand::parse_from([
                 "test",
@@ -317,7 +412,9 @@ mod tests {
                 order: Ordering::Unordered,
                 version: Some(Version::new("v1".to_owned())),
                 new_client_connection: false,
-                yes: false
+                yes: false,
+                resume: false,
+                connection_hops: vec![]
             },
             CreateChannelCommand::parse_from([
                 "test",
@@ -347,7 +444,9 @@ mod tests {
                 order: Ordering::Ordered,
                 version: None,
                 new_client_connection: false,
-                yes: false
+                yes: false,
+                resume: false,
+                connection_hops: vec![]
             },
             CreateChannelCommand::parse_from([
                 "test",
@@ -377,7 +476,9 @@ mod tests {
                 order: Ordering::Unordered,
                 version: None,
                 new_client_connection: false,
-                yes: false
+                yes: false,
+                resume: false,
+                connection_hops: vec![]
             },
             CreateChannelCommand::parse_from([
                 "test",
@@ -393,6 +494,130 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_create_channel_a_conn_with_resume() {
+        assert_eq!(
+            CreateChannelCommand {
+                chain_a: ChainId::from_string("chain_a"),
+                chain_b: None,
+                connection_a: Some(ConnectionId::from_str("connection_a").unwrap()),
+                port_a: PortId::from_str("port_id_a").unwrap(),
+                port_b: PortId::from_str("port_id_b").unwrap(),
+                order: Ordering::Unordered,
+                version: None,
+                new_client_connection: false,
+                yes: false,
+                resume: true,
+                connection_hops: vec![]
+            },
+            CreateChannelCommand::parse_from([
+                "test",
+                "--a-chain",
+                "chain_a",
+                "--a-connection",
+                "connection_a",
+                "--a-port",
+                "port_id_a",
+                "--b-port",
+                "port_id_b",
+                "--resume"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_create_channel_a_conn_with_connection_hops() {
+        assert_eq!(
+            CreateChannelCommand {
+                chain_a: ChainId::from_string("chain_a"),
+                chain_b: None,
+                connection_a: Some(ConnectionId::from_str("connection_a").unwrap()),
+                port_a: PortId::from_str("port_id_a").unwrap(),
+                port_b: PortId::from_str("port_id_b").unwrap(),
+                order: Ordering::Unordered,
+                version: None,
+                new_client_connection: false,
+                yes: false,
+                resume: false,
+                connection_hops: vec![
+                    ConnectionId::from_str("connection_b").unwrap(),
+                    ConnectionId::from_str("connection_c").unwrap()
+                ]
+            },
+            CreateChannelCommand::parse_from([
+                "test",
+                "--a-chain",
+                "chain_a",
+                "--a-connection",
+                "connection_a",
+                "--a-port",
+                "port_id_a",
+                "--b-port",
+                "port_id_b",
+                "--connection-hops",
+                "connection_b,connection_c"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_create_channel_connection_hops_with_resume() {
+        assert!(CreateChannelCommand::try_parse_from([
+            "test",
+            "--a-chain",
+            "chain_a",
+            "--a-connection",
+            "connection_a",
+            "--a-port",
+            "port_id_a",
+            "--b-port",
+            "port_id_b",
+            "--connection-hops",
+            "connection_b",
+            "--resume"
+        ])
+        .is_err())
+    }
+
+    #[test]
+    fn test_create_channel_connection_hops_with_new_client_conn() {
+        assert!(CreateChannelCommand::try_parse_from([
+            "test",
+            "--a-chain",
+            "chain_a",
+            "--b-chain",
+            "chain_b",
+            "--a-port",
+            "port_id_a",
+            "--b-port",
+            "port_id_b",
+            "--new-client-connection",
+            "--yes",
+            "--connection-hops",
+            "connection_b"
+        ])
+        .is_err())
+    }
+
+    #[test]
+    fn test_create_channel_b_chain_with_resume() {
+        assert!(CreateChannelCommand::try_parse_from([
+            "test",
+            "--a-chain",
+            "chain_a",
+            "--b-chain",
+            "chain_b",
+            "--a-port",
+            "port_id_a",
+            "--b-port",
+            "port_id_b",
+            "--new-client-connection",
+            "--yes",
+            "--resume"
+        ])
+        .is_err())
+    }
+
     #[test]
     fn test_create_channel_a_conn_with_new_client_conn() {
         assert!(CreateChannelCommand::try_parse_from([
@@ -463,7 +688,9 @@ mod tests {
                 order: Ordering::Unordered,
                 version: None,
                 new_client_connection: true,
-                yes: false
+                yes: false,
+                resume: false,
+                connection_hops: vec![]
             },
             CreateChannelCommand::parse_from([
                 "test",
@@ -492,7 +719,9 @@ mod tests {
                 order: Ordering::Unordered,
                 version: None,
                 new_client_connection: true,
-                yes: true
+                yes: true,
+                resume: false,
+                connection_hops: vec![]
             },
             CreateChannelCommand::parse_from([
                 "test",
@@ -522,7 +751,9 @@ mod tests {
                 order: Ordering::Unordered,
                 version: None,
                 new_client_connection: true,
-                yes: false
+                yes: false,
+                resume: false,
+                connection_hops: vec![]
             },
             CreateChannelCommand::parse_from([
                 "test",
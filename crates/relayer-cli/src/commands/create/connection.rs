@@ -63,11 +63,20 @@ pub struct CreateConnectionCommand {
         default_value = "0"
     )]
     delay: u64,
+
+    #[clap(
+        long = "resume",
+        conflicts_with = "chain-b-id",
+        help = "Resume the handshake of a connection between the clients which was left half-open, \
+                instead of creating a new connection"
+    )]
+    resume: bool,
 }
 
 // cargo run --bin hermes -- create connection --a-chain ibc-0 --b-chain ibc-1
 // cargo run --bin hermes -- create connection --a-chain ibc-0 --b-chain ibc-1 --delay 100
 // cargo run --bin hermes -- create connection --a-chain ibc-0 --a-client 07-tendermint-0 --b-client 07-tendermint-0
+// cargo run --bin hermes -- create connection --a-chain ibc-0 --a-client 07-tendermint-0 --b-client 07-tendermint-0 --resume
 impl Runnable for CreateConnectionCommand {
     fn run(&self) {
         match &self.chain_b_id {
@@ -153,10 +162,17 @@ impl CreateConnectionCommand {
             .exit(),
         };
 
-        info!(
-            "Creating a new connection with pre-existing clients {} and {}",
-            client_a_id, client_b_id
-        );
+        if self.resume {
+            info!(
+                "Resuming a connection handshake between pre-existing clients {} and {}",
+                client_a_id, client_b_id
+            );
+        } else {
+            info!(
+                "Creating a new connection with pre-existing clients {} and {}",
+                client_a_id, client_b_id
+            );
+        }
 
         // Get the two ForeignClient objects.
         let client_a = ForeignClient::find(chain_b.clone(), chain_a.clone(), client_a_id)
@@ -166,7 +182,13 @@ impl CreateConnectionCommand {
 
         // All verification passed. Create the Connection object & do the handshake.
         let delay = Duration::from_secs(self.delay);
-        match Connection::new(client_a, client_b, delay) {
+        let connection = if self.resume {
+            Connection::resume(client_a, client_b, delay)
+        } else {
+            Connection::new(client_a, client_b, delay)
+        };
+
+        match connection {
             Ok(conn) => Output::success(conn).exit(),
             Err(e) => Output::error(e).exit(),
         }
@@ -190,7 +212,8 @@ mod tests {
                 chain_b_id: Some(ChainId::from_string("chain_b")),
                 client_a: None,
                 client_b: None,
-                delay: 0
+                delay: 0,
+                resume: false
             },
             CreateConnectionCommand::parse_from([
                 "test",
@@ -210,7 +233,8 @@ mod tests {
                 chain_b_id: Some(ChainId::from_string("chain_b")),
                 client_a: None,
                 client_b: None,
-                delay: 42
+                delay: 42,
+                resume: false
             },
             CreateConnectionCommand::parse_from([
                 "test",
@@ -232,7 +256,8 @@ mod tests {
                 chain_b_id: None,
                 client_a: Some(ClientId::from_str("07-client_a").unwrap()),
                 client_b: Some(ClientId::from_str("07-client_b").unwrap()),
-                delay: 0
+                delay: 0,
+                resume: false
             },
             CreateConnectionCommand::parse_from([
                 "test",
@@ -254,7 +279,8 @@ mod tests {
                 chain_b_id: None,
                 client_a: Some(ClientId::from_str("07-client_a").unwrap()),
                 client_b: Some(ClientId::from_str("07-client_b").unwrap()),
-                delay: 42
+                delay: 42,
+                resume: false
             },
             CreateConnectionCommand::parse_from([
                 "test",
@@ -270,6 +296,30 @@ mod tests {
         )
     }
 
+    #[test]
+    fn create_connection_a_chain_and_clients_with_resume() {
+        assert_eq!(
+            CreateConnectionCommand {
+                chain_a_id: ChainId::from_string("chain_a"),
+                chain_b_id: None,
+                client_a: Some(ClientId::from_str("07-client_a").unwrap()),
+                client_b: Some(ClientId::from_str("07-client_b").unwrap()),
+                delay: 0,
+                resume: true
+            },
+            CreateConnectionCommand::parse_from([
+                "test",
+                "--a-chain",
+                "chain_a",
+                "--a-client",
+                "07-client_a",
+                "--b-client",
+                "07-client_b",
+                "--resume"
+            ])
+        )
+    }
+
     #[test]
     fn test_create_connection_a_chain_only() {
         assert!(CreateConnectionCommand::try_parse_from(["test", "--a-chain", "chain_a"]).is_err())
@@ -331,4 +381,17 @@ mod tests {
         ])
         .is_err())
     }
+
+    #[test]
+    fn test_create_connection_b_chain_with_resume() {
+        assert!(CreateConnectionCommand::try_parse_from([
+            "test",
+            "--a-chain",
+            "chain_a",
+            "--b-chain",
+            "chain_b",
+            "--resume"
+        ])
+        .is_err())
+    }
 }
@@ -2,9 +2,10 @@ use std::fmt::Write;
 
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
+use serde::Serialize;
 
+use ibc_relayer::account::Balance;
 use ibc_relayer::chain::handle::ChainHandle;
-use ibc_relayer::config::ChainConfig;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
 use crate::application::app_config;
@@ -17,7 +18,8 @@ use crate::conclude::{exit_with_unrecoverable_error, json, Output};
 ///
 /// `keys balance --chain <chain_id> --key-name <KEY_NAME>`
 ///
-/// If no key name is given, it will be taken from the configuration file.
+/// If no key name is given, the balances of the key specified by `key_name` in the configuration
+/// file and of the keys of its `key_pool` are displayed.
 /// If successful the balance and denominator of the account, associated with the key name
 /// on the given chain, will be displayed.
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
@@ -34,7 +36,7 @@ pub struct KeyBalanceCmd {
     #[clap(
         long = "key-name",
         value_name = "KEY_NAME",
-        help = "(optional) name of the key (defaults to the `key_name` defined in the config)"
+        help = "(optional) name of the key (defaults to the `key_name` and `key_pool` keys defined in the config)"
     )]
     key_name: Option<String>,
 
@@ -59,60 +61,108 @@ impl Runnable for KeyBalanceCmd {
         let chain = spawn_chain_runtime(&config, &self.chain_id)
             .unwrap_or_else(exit_with_unrecoverable_error);
 
-        let key_name = self.key_name.clone();
+        // If no key name is given, report the balance of every key used by the chain,
+        // i.e. the key specified by `key_name` and the keys of its `key_pool`.
+        let key_names = match &self.key_name {
+            Some(key_name) => vec![key_name.clone()],
+            None => chain
+                .get_keys()
+                .unwrap_or_else(exit_with_unrecoverable_error)
+                .into_iter()
+                .map(|(key_name, _)| key_name)
+                .collect(),
+        };
 
         if self.all {
-            get_balances(chain, key_name)
+            get_balances(chain, key_names)
         } else {
-            get_balance(chain, key_name, self.denom.clone());
+            get_balance(chain, key_names, self.denom.clone());
         }
     }
 }
 
-fn get_balance(chain: impl ChainHandle, key_name: Option<String>, denom: Option<String>) {
-    match chain.query_balance(key_name.clone(), denom) {
-        Ok(balance) if json() => Output::success(balance).exit(),
-        Ok(balance) => {
-            // Retrieve the key name string to output.
-            let key_name = key_name.unwrap_or_else(|| {
-                let chain_config = chain.config().unwrap_or_else(exit_with_unrecoverable_error);
-                match chain_config {
-                    ChainConfig::CosmosSdk(chain_config) => chain_config.key_name,
-                }
-            });
-
-            Output::success_msg(format!(
-                "balance for key `{}`: {} {}",
-                key_name, balance.amount, balance.denom
-            ))
-            .exit()
+/// The balance of a key, as reported when querying the balance of several keys.
+#[derive(Debug, Serialize)]
+struct KeyBalance {
+    key_name: String,
+    #[serde(flatten)]
+    balance: Balance,
+}
+
+/// The balances of a key, as reported when querying the balances of several keys.
+#[derive(Debug, Serialize)]
+struct KeyBalances {
+    key_name: String,
+    balances: Vec<Balance>,
+}
+
+fn get_balance(chain: impl ChainHandle, key_names: Vec<String>, denom: Option<String>) {
+    let mut key_balances = Vec::with_capacity(key_names.len());
+
+    for key_name in key_names {
+        match chain.query_balance(Some(key_name.clone()), denom.clone()) {
+            Ok(balance) => key_balances.push(KeyBalance { key_name, balance }),
+            Err(e) => {
+                Output::error(format!("there was a problem querying the balance: {e}")).exit()
+            }
+        }
+    }
+
+    if json() {
+        match key_balances.as_slice() {
+            [key_balance] => Output::success(key_balance.balance.clone()).exit(),
+            _ => Output::success(key_balances).exit(),
         }
-        Err(e) => Output::error(format!("there was a problem querying the balance: {e}")).exit(),
     }
+
+    let pretty_output = key_balances
+        .iter()
+        .map(|KeyBalance { key_name, balance }| {
+            format!(
+                "balance for key `{}`: {} {}",
+                key_name, balance.amount, balance.denom
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Output::success_msg(pretty_output).exit()
 }
 
-fn get_balances(chain: impl ChainHandle, key_name: Option<String>) {
-    match chain.query_all_balances(key_name.clone()) {
-        Ok(balances) if json() => Output::success(balances).exit(),
-        Ok(balances) => {
-            // Retrieve the key name string to output.
-            let key_name = key_name.unwrap_or_else(|| {
-                let chain_config = chain.config().unwrap_or_else(exit_with_unrecoverable_error);
-                match chain_config {
-                    ChainConfig::CosmosSdk(chain_config) => chain_config.key_name,
-                }
-            });
-
-            let mut pretty_output = format!("Balances for key `{key_name}`:");
-            for balance in balances {
-                write!(pretty_output, "\n\t{} {}", balance.amount, balance.denom)
-                    .unwrap_or_else(exit_with_unrecoverable_error);
+fn get_balances(chain: impl ChainHandle, key_names: Vec<String>) {
+    let mut key_balances = Vec::with_capacity(key_names.len());
+
+    for key_name in key_names {
+        match chain.query_all_balances(Some(key_name.clone())) {
+            Ok(balances) => key_balances.push(KeyBalances { key_name, balances }),
+            Err(e) => {
+                Output::error(format!("there was a problem querying the balance: {e}")).exit()
             }
+        }
+    }
+
+    if json() {
+        match key_balances.as_mut_slice() {
+            [key_balances] => Output::success(core::mem::take(&mut key_balances.balances)).exit(),
+            _ => Output::success(key_balances).exit(),
+        }
+    }
+
+    let mut pretty_output = String::new();
+    for KeyBalances { key_name, balances } in key_balances {
+        if !pretty_output.is_empty() {
+            pretty_output.push('\n');
+        }
 
-            Output::success_msg(pretty_output).exit()
+        write!(pretty_output, "Balances for key `{key_name}`:")
+            .unwrap_or_else(exit_with_unrecoverable_error);
+        for balance in balances {
+            write!(pretty_output, "\n\t{} {}", balance.amount, balance.denom)
+                .unwrap_or_else(exit_with_unrecoverable_error);
         }
-        Err(e) => Output::error(format!("there was a problem querying the balance: {e}")).exit(),
     }
+
+    Output::success_msg(pretty_output).exit()
 }
 
 #[cfg(test)]
@@ -4,7 +4,7 @@ use abscissa_core::{Command, Runnable};
 use eyre::eyre;
 use ibc_relayer::{
     config::{ChainConfig, Config},
-    keyring::{KeyRing, Store},
+    keyring::KeyRing,
 };
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
@@ -116,10 +116,23 @@ pub fn delete_key(config: &ChainConfig, key_name: &str) -> eyre::Result<()> {
     match config {
         ChainConfig::CosmosSdk(config) => {
             let mut keyring = KeyRing::new_secp256k1(
-                Store::Test,
+                config.key_store_type.persistent(),
                 &config.account_prefix,
                 &config.id,
                 &config.key_store_folder,
+                &config.key_store_passphrase,
+                &config.remote_signer,
+            )?;
+            keyring.remove_key(key_name)?;
+        }
+        ChainConfig::SoloMachine(config) => {
+            let mut keyring = KeyRing::new_secp256k1(
+                config.key_store_type.persistent(),
+                &config.account_prefix,
+                &config.id,
+                &config.key_store_folder,
+                &config.key_store_passphrase,
+                &None,
             )?;
             keyring.remove_key(key_name)?;
         }
@@ -131,10 +144,26 @@ pub fn delete_all_keys(config: &ChainConfig) -> eyre::Result<()> {
     match config {
         ChainConfig::CosmosSdk(config) => {
             let mut keyring = KeyRing::new_secp256k1(
-                Store::Test,
+                config.key_store_type.persistent(),
+                &config.account_prefix,
+                &config.id,
+                &config.key_store_folder,
+                &config.key_store_passphrase,
+                &config.remote_signer,
+            )?;
+            let keys = keyring.keys()?;
+            for (key_name, _) in keys {
+                keyring.remove_key(&key_name)?;
+            }
+        }
+        ChainConfig::SoloMachine(config) => {
+            let mut keyring = KeyRing::new_secp256k1(
+                config.key_store_type.persistent(),
                 &config.account_prefix,
                 &config.id,
                 &config.key_store_folder,
+                &config.key_store_passphrase,
+                &None,
             )?;
             let keys = keyring.keys()?;
             for (key_name, _) in keys {
@@ -14,9 +14,8 @@ use tokio::runtime::Runtime as TokioRuntime;
 use tracing::{error, info, instrument};
 
 use ibc_relayer::{
-    chain::handle::Subscription,
+    chain::{cosmos::failover::build_rpc_client, handle::Subscription},
     config::{ChainConfig, EventSourceMode},
-    error::Error,
     event::source::EventSource,
     util::compat_mode::compat_mode_from_version,
     HERMES_VERSION,
@@ -154,23 +153,43 @@ fn subscribe(
                 EventSourceMode::Push { url, batch_delay } => EventSource::websocket(
                     chain_config.id().clone(),
                     url.clone(),
+                    config.backup_websocket_addrs(),
                     compat_mode,
                     *batch_delay,
                     rt,
                 ),
+                EventSourceMode::Hybrid {
+                    url,
+                    batch_delay,
+                    stall_timeout,
+                    max_retries,
+                } => EventSource::hybrid(
+                    chain_config.id().clone(),
+                    url.clone(),
+                    config.backup_websocket_addrs(),
+                    build_rpc_client(&config.rpc_addr, compat_mode)?,
+                    compat_mode,
+                    *batch_delay,
+                    *stall_timeout,
+                    *max_retries,
+                    rt,
+                ),
                 EventSourceMode::Pull {
                     interval,
                     max_retries,
                 } => {
-                    let mut rpc_client = HttpClient::builder(config.rpc_addr.clone().try_into()?)
-                        .user_agent(format!("hermes/{}", HERMES_VERSION))
-                        .build()
-                        .map_err(|e| Error::rpc(config.rpc_addr.clone(), e))?;
-                    rpc_client.set_compat_mode(compat_mode);
+                    let rpc_client = build_rpc_client(&config.rpc_addr, compat_mode)?;
+
+                    let backup_rpc_clients = config
+                        .backup_endpoints
+                        .iter()
+                        .map(|endpoint| build_rpc_client(&endpoint.rpc_addr, compat_mode))
+                        .collect::<Result<_, _>>()?;
 
                     EventSource::rpc(
                         chain_config.id().clone(),
                         rpc_client,
+                        backup_rpc_clients,
                         *interval,
                         *max_retries,
                         rt,
@@ -183,6 +202,10 @@ fn subscribe(
             let subscription = monitor_tx.subscribe()?;
             Ok(subscription)
         }
+        ChainConfig::SoloMachine(_) => Err(eyre!(
+            "cannot subscribe to events of solo machine '{}', which has no RPC endpoint",
+            chain_config.id()
+        )),
     }
 }
 
@@ -193,6 +216,12 @@ fn detect_compatibility_mode(
     // TODO(erwan): move this to the cosmos sdk endpoint implementation
     let rpc_addr = match config {
         ChainConfig::CosmosSdk(config) => config.rpc_addr.clone(),
+        ChainConfig::SoloMachine(config) => {
+            return Err(eyre!(
+                "cannot listen to events of solo machine '{}', which has no RPC endpoint",
+                config.id
+            ))
+        }
     };
     let client = HttpClient::builder(rpc_addr.try_into()?)
         .user_agent(format!("hermes/{}", HERMES_VERSION))
@@ -203,6 +232,7 @@ fn detect_compatibility_mode(
         ChainConfig::CosmosSdk(config) => {
             compat_mode_from_version(&config.compat_mode, status.node_info.version)?.into()
         }
+        ChainConfig::SoloMachine(_) => unreachable!("solo machines have no RPC endpoint"),
     };
     Ok(compat_mode)
 }
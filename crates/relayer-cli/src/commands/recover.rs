@@ -0,0 +1,12 @@
+//! `recover` subcommand
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use crate::commands::tx::client::TxRecoverClientCmd;
+
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum RecoverCmds {
+    /// Submit a governance proposal to recover an expired or frozen IBC client
+    Client(TxRecoverClientCmd),
+}
@@ -0,0 +1,105 @@
+use abscissa_core::clap::Parser;
+use serde::Deserialize;
+
+use ibc_relayer::config::RestConfig;
+use ibc_relayer::supervisor::replay::ReplayRange;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::conclude::Output;
+use crate::prelude::*;
+
+/// Ask the running Hermes instance to replay the events of a chain
+/// from a past height, through its REST API.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct ReplayCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain whose events to replay"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "from",
+        required = true,
+        value_name = "HEIGHT",
+        help_heading = "REQUIRED",
+        help = "Height from which to replay the events, up to the latest height of the chain"
+    )]
+    from: u64,
+}
+
+/// The reply of the REST API, as serialized by `ibc-relayer-rest`.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "status", content = "result")]
+#[serde(rename_all = "lowercase")]
+enum JsonResult<R> {
+    Success(R),
+    Error(RestError),
+}
+
+#[derive(Debug, Deserialize)]
+struct RestError {
+    msg: String,
+}
+
+impl Runnable for ReplayCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        match replay(&config.rest, &self.chain_id, self.from) {
+            Ok(range) => Output::success(range).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+fn replay(rest: &RestConfig, chain_id: &ChainId, from: u64) -> Result<ReplayRange, String> {
+    if !rest.enabled {
+        return Err(
+            "the REST API must be enabled in the `[rest]` section of the configuration \
+             for the running instance of Hermes to receive the replay request"
+                .to_string(),
+        );
+    }
+
+    let url = format!("http://{}:{}/replay", rest.host, rest.port);
+
+    let response = ureq::post(&url)
+        .query("chain", chain_id.as_str())
+        .query("from", &from.to_string())
+        .call()
+        .map_err(|e| format!("failed to send the replay request to {url}: {e}"))?;
+
+    match response.into_json() {
+        Ok(JsonResult::Success(range)) => Ok(range),
+        Ok(JsonResult::Error(e)) => Err(e.msg),
+        Err(e) => Err(format!("failed to parse the reply of the REST API: {e}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReplayCmd;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    #[test]
+    fn test_replay() {
+        assert_eq!(
+            ReplayCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                from: 42
+            },
+            ReplayCmd::parse_from(["test", "--chain", "chain_id", "--from", "42"])
+        )
+    }
+
+    #[test]
+    fn test_replay_no_height() {
+        assert!(ReplayCmd::try_parse_from(["test", "--chain", "chain_id"]).is_err())
+    }
+}
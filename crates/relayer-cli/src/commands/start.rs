@@ -1,5 +1,6 @@
-use ibc_relayer::supervisor::SupervisorOptions;
+use ibc_relayer::supervisor::{replay::ReplayFrom, SupervisorOptions};
 use ibc_relayer::util::debug_section::DebugSection;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use std::error::Error;
 use std::io;
 
@@ -23,6 +24,24 @@ pub struct StartCmd {
         help = "Force a full scan of the chains for clients, connections and channels"
     )]
     full_scan: bool,
+
+    #[clap(
+        long = "replay-from",
+        value_name = "HEIGHT",
+        group = "replay_from",
+        requires = "replay_chain",
+        help = "Replay the events of the chain given by --replay-chain from this height, before relaying the live events"
+    )]
+    replay_from: Option<u64>,
+
+    #[clap(
+        long = "replay-chain",
+        value_name = "CHAIN_ID",
+        group = "replay_chain",
+        requires = "replay_from",
+        help = "Identifier of the chain whose events to replay. Requires --replay-from if used."
+    )]
+    replay_chain: Option<ChainId>,
 }
 
 impl Runnable for StartCmd {
@@ -57,6 +76,12 @@ impl Runnable for StartCmd {
         let options = SupervisorOptions {
             force_full_scan: self.full_scan,
             health_check: true,
+            config_path: crate::config::config_path(),
+            replay_from: self
+                .replay_chain
+                .clone()
+                .zip(self.replay_from)
+                .map(|(chain_id, height)| ReplayFrom { chain_id, height }),
         };
 
         let supervisor_handle = make_supervisor::<CachingChainHandle>(config, options)
@@ -82,13 +107,13 @@ impl Runnable for StartCmd {
 }
 
 /// Register the SIGHUP and SIGUSR1 signals, and notify the supervisor.
-/// - [DEPRECATED] SIGHUP: Trigger a reload of the configuration.
+/// - SIGHUP: Trigger a reload of the configuration.
 /// - SIGUSR1: Ask the supervisor to dump its state and print it to the console.
 fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
     use signal_hook::{consts::signal::*, iterator::Signals};
 
     let sigs = vec![
-        SIGHUP,  // Reload of configuration (disabled)
+        SIGHUP,  // Reload of configuration
         SIGUSR1, // Dump state
     ];
 
@@ -97,10 +122,20 @@ fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
     std::thread::spawn(move || {
         for signal in &mut signals {
             match signal {
-                SIGHUP => warn!(
-                    "configuration reloading via SIGHUP has been disabled, \
-                     the signal handler will be removed in the future"
-                ),
+                SIGHUP => {
+                    info!("reloading configuration (triggered by SIGHUP)");
+
+                    let (tx, rx) = crossbeam_channel::bounded(1);
+                    tx_cmd.try_send(SupervisorCmd::ReloadConfig(tx)).unwrap();
+
+                    std::thread::spawn(move || match rx.recv() {
+                        Ok(Ok(update)) => info!("configuration reloaded: {:?}", update),
+                        Ok(Err(e)) => error!("failed to reload configuration: {}", e),
+                        Err(_) => {
+                            error!("failed to receive the outcome of the configuration reload")
+                        }
+                    });
+                }
                 SIGUSR1 => {
                     info!("dumping state (triggered by SIGUSR1)");
 
@@ -220,11 +255,16 @@ mod tests {
     use super::StartCmd;
 
     use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
     #[test]
     fn test_start_required_only() {
         assert_eq!(
-            StartCmd { full_scan: false },
+            StartCmd {
+                full_scan: false,
+                replay_from: None,
+                replay_chain: None,
+            },
             StartCmd::parse_from(["test"])
         )
     }
@@ -232,8 +272,29 @@ mod tests {
     #[test]
     fn test_start_full_scan() {
         assert_eq!(
-            StartCmd { full_scan: true },
+            StartCmd {
+                full_scan: true,
+                replay_from: None,
+                replay_chain: None,
+            },
             StartCmd::parse_from(["test", "--full-scan"])
         )
     }
+
+    #[test]
+    fn test_start_replay() {
+        assert_eq!(
+            StartCmd {
+                full_scan: false,
+                replay_from: Some(42),
+                replay_chain: Some(ChainId::from_string("chain_id")),
+            },
+            StartCmd::parse_from(["test", "--replay-from", "42", "--replay-chain", "chain_id"])
+        )
+    }
+
+    #[test]
+    fn test_start_replay_no_chain() {
+        assert!(StartCmd::try_parse_from(["test", "--replay-from", "42"]).is_err())
+    }
 }
@@ -10,6 +10,11 @@ use abscissa_core::{Command, Runnable};
 use ibc_relayer::config::Config;
 use ibc_relayer::event::IbcEventWithHeight;
 use ibc_relayer::foreign_client::{CreateOptions, ForeignClient};
+use ibc_relayer::recover_client::{
+    build_and_send_recover_client_proposal, create_substitute_client, ensure_expired_or_frozen,
+    query_proposal_id, RecoverClientOptions, RecoverClientProposal,
+};
+use ibc_relayer::upgrade_chain::requires_legacy_upgrade_proposal;
 use ibc_relayer::{chain::handle::ChainHandle, config::GenesisRestart};
 use ibc_relayer::{
     chain::requests::{
@@ -20,6 +25,7 @@ use ibc_relayer::{
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
 use ibc_relayer_types::events::IbcEvent;
 use ibc_relayer_types::Height;
+use subtle_encoding::hex;
 use tendermint::block::Height as BlockHeight;
 use tendermint_light_client_verifier::types::TrustThreshold;
 use tendermint_rpc::Url;
@@ -75,6 +81,13 @@ pub struct TxCreateClientCmd {
     /// and trusted validator set is sufficient for a commit to be accepted going forward.
     #[clap(long = "trust-threshold", value_name = "TRUST_THRESHOLD", parse(try_from_str = parse_trust_threshold))]
     trust_threshold: Option<TrustThreshold>,
+
+    /// Create an ICS-08 Wasm client wrapping the light client.
+    ///
+    /// The hex-encoded checksum of the code of the Wasm light client contract,
+    /// which must have been stored on the host chain beforehand.
+    #[clap(long = "wasm-checksum", value_name = "WASM_CHECKSUM")]
+    wasm_checksum: Option<String>,
 }
 
 /// Sample to run this tx:
@@ -92,12 +105,19 @@ impl Runnable for TxCreateClientCmd {
             Err(e) => Output::error(e).exit(),
         };
 
+        let wasm_checksum = match self.wasm_checksum.as_deref().map(hex::decode) {
+            Some(Ok(checksum)) => Some(checksum),
+            Some(Err(e)) => Output::error(format!("invalid Wasm checksum: {e}")).exit(),
+            None => None,
+        };
+
         let client = ForeignClient::restore(ClientId::default(), chains.dst, chains.src);
 
         let options = CreateOptions {
             max_clock_drift: self.clock_drift.map(Into::into),
             trusting_period: self.trusting_period.map(Into::into),
             trust_threshold: self.trust_threshold.map(Into::into),
+            wasm_checksum,
         };
 
         // Trigger client creation via the "build" interface, so that we obtain the resulting event
@@ -209,6 +229,13 @@ impl Runnable for TxUpdateClientCmd {
                     ChainConfig::CosmosSdk(chain_config) => {
                         chain_config.genesis_restart = Some(restart_params)
                     }
+                    ChainConfig::SoloMachine(_) => {
+                        Output::error(format!(
+                            "Chain '{}' is a solo machine, which cannot be restarted from genesis",
+                            reference_chain_id
+                        ))
+                        .exit();
+                    }
                 },
                 None => {
                     Output::error(format!(
@@ -517,6 +544,139 @@ impl TxUpgradeClientsCmd {
     }
 }
 
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxRecoverClientCmd {
+    #[clap(
+        long = "host-chain",
+        required = true,
+        value_name = "HOST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain that hosts the client"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "client",
+        required = true,
+        value_name = "CLIENT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the expired or frozen client to be recovered"
+    )]
+    client_id: ClientId,
+
+    #[clap(
+        long = "amount",
+        required = true,
+        value_name = "AMOUNT",
+        help_heading = "REQUIRED",
+        help = "Amount of the deposit for the recovery proposal"
+    )]
+    amount: u64,
+
+    #[clap(
+        long = "substitute-client",
+        value_name = "SUBSTITUTE_CLIENT_ID",
+        help = "Identifier of an existing client to substitute the recovered client with. If not given, a new substitute client is created"
+    )]
+    substitute_client_id: Option<ClientId>,
+
+    #[clap(
+        long = "gov-account",
+        value_name = "GOV_ACCOUNT",
+        help = "Authority account used to sign the recover client message. Note: This is only used for chains with ibc-go version v8.0.0 or higher"
+    )]
+    gov_account: Option<String>,
+
+    #[clap(
+        long = "denom",
+        value_name = "DENOM",
+        help = "Denomination for the deposit (default: 'stake')"
+    )]
+    denom: Option<String>,
+}
+
+/// Sample to run this tx:
+///     `hermes recover client --host-chain ibc-0 --client 07-tendermint-0 --amount 10000000`
+impl Runnable for TxRecoverClientCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let host_chain = match spawn_chain_runtime(&config, &self.chain_id) {
+            Ok(handle) => handle,
+            Err(e) => Output::error(e).exit(),
+        };
+
+        let reference_chain_id = match host_chain.query_client_state(
+            QueryClientStateRequest {
+                client_id: self.client_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        ) {
+            Ok((cs, _)) => cs.chain_id(),
+            Err(e) => {
+                Output::error(format!(
+                    "Query of client '{}' on chain '{}' failed with error: {}",
+                    self.client_id, self.chain_id, e
+                ))
+                .exit();
+            }
+        };
+
+        let reference_chain = match spawn_chain_runtime(&config, &reference_chain_id) {
+            Ok(handle) => handle,
+            Err(e) => Output::error(e).exit(),
+        };
+
+        let gov_account = if requires_legacy_upgrade_proposal(host_chain.clone()) {
+            "".to_string()
+        } else if let Some(gov_account) = &self.gov_account {
+            gov_account.clone()
+        } else {
+            Output::error("The host chain uses an ibc-go version v8.0.0 or higher, which requires the governance module account to be specified using the flag `--gov-account`".to_owned()).exit();
+        };
+
+        let subject =
+            ForeignClient::restore(self.client_id.clone(), host_chain.clone(), reference_chain);
+
+        // Checked here as well, since no substitute client is created if one is given
+        ensure_expired_or_frozen(&subject)
+            .map_err(Error::recover_client)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let substitute_client_id = match &self.substitute_client_id {
+            Some(substitute_client_id) => substitute_client_id.clone(),
+            None => create_substitute_client(&subject)
+                .map_err(Error::recover_client)
+                .unwrap_or_else(exit_with_unrecoverable_error),
+        };
+
+        let opts = RecoverClientOptions {
+            subject_client_id: self.client_id.clone(),
+            substitute_client_id,
+            amount: self.amount,
+            denom: self.denom.as_deref().unwrap_or("stake").into(),
+            gov_account,
+        };
+
+        let res = build_and_send_recover_client_proposal(host_chain.clone(), &opts)
+            .and_then(|tx_hash| {
+                query_proposal_id(host_chain, tx_hash).map(|proposal_id| RecoverClientProposal {
+                    subject_client_id: opts.subject_client_id.clone(),
+                    substitute_client_id: opts.substitute_client_id.clone(),
+                    tx_hash,
+                    proposal_id,
+                })
+            })
+            .map_err(Error::recover_client);
+
+        match res {
+            Ok(proposal) => Output::success(proposal).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
 fn parse_trust_threshold(input: &str) -> Result<TrustThreshold, Error> {
     let (num_part, denom_part) = input.split_once('/').ok_or_else(|| {
         Error::cli_arg("expected a fractional argument, two numbers separated by '/'".into())
@@ -609,8 +769,8 @@ impl OutputBuffer {
 #[cfg(test)]
 mod tests {
     use super::{
-        parse_trust_threshold, TxCreateClientCmd, TxUpdateClientCmd, TxUpgradeClientCmd,
-        TxUpgradeClientsCmd,
+        parse_trust_threshold, TxCreateClientCmd, TxRecoverClientCmd, TxUpdateClientCmd,
+        TxUpgradeClientCmd, TxUpgradeClientsCmd,
     };
 
     use std::str::FromStr;
@@ -643,7 +803,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: None,
-                trust_threshold: None
+                trust_threshold: None,
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -663,7 +824,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: Some("5s".parse::<Duration>().unwrap()),
                 trusting_period: None,
-                trust_threshold: None
+                trust_threshold: None,
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -681,7 +843,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: Some("3s".parse::<Duration>().unwrap()),
                 trusting_period: None,
-                trust_threshold: None
+                trust_threshold: None,
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -703,7 +866,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: Some("5s".parse::<Duration>().unwrap()),
-                trust_threshold: None
+                trust_threshold: None,
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -721,7 +885,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: Some("3s".parse::<Duration>().unwrap()),
-                trust_threshold: None
+                trust_threshold: None,
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -743,7 +908,8 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: None,
-                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap())
+                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap()),
+                wasm_checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -765,7 +931,10 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: Some("5s".parse::<Duration>().unwrap()),
                 trusting_period: Some("3s".parse::<Duration>().unwrap()),
-                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap())
+                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap()),
+                wasm_checksum: Some(
+                    "abababababababababababababababababababababababababababababababab".to_owned()
+                )
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -778,7 +947,9 @@ mod tests {
                 "--trusting-period",
                 "3s",
                 "--trust-threshold",
-                "1/2"
+                "1/2",
+                "--wasm-checksum",
+                "abababababababababababababababababababababababababababababababab"
             ])
         )
     }
@@ -1114,4 +1285,68 @@ mod tests {
     fn test_upgrade_clients_no_chain() {
         assert!(TxUpgradeClientsCmd::try_parse_from(["test", "--upgrade-height", "42"]).is_err())
     }
+
+    #[test]
+    fn test_recover_client_required_only() {
+        assert_eq!(
+            TxRecoverClientCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                client_id: ClientId::from_str("client_to_recover").unwrap(),
+                amount: 42,
+                substitute_client_id: None,
+                gov_account: None,
+                denom: None,
+            },
+            TxRecoverClientCmd::parse_from([
+                "test",
+                "--host-chain",
+                "chain_id",
+                "--client",
+                "client_to_recover",
+                "--amount",
+                "42"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_recover_client_substitute() {
+        assert_eq!(
+            TxRecoverClientCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                client_id: ClientId::from_str("client_to_recover").unwrap(),
+                amount: 42,
+                substitute_client_id: Some(ClientId::from_str("substitute_client").unwrap()),
+                gov_account: Some("gov_account".to_owned()),
+                denom: Some("my_denom".to_owned()),
+            },
+            TxRecoverClientCmd::parse_from([
+                "test",
+                "--host-chain",
+                "chain_id",
+                "--client",
+                "client_to_recover",
+                "--amount",
+                "42",
+                "--substitute-client",
+                "substitute_client",
+                "--gov-account",
+                "gov_account",
+                "--denom",
+                "my_denom"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_recover_client_no_amount() {
+        assert!(TxRecoverClientCmd::try_parse_from([
+            "test",
+            "--host-chain",
+            "chain_id",
+            "--client",
+            "client_to_recover"
+        ])
+        .is_err())
+    }
 }
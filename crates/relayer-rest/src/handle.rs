@@ -4,7 +4,12 @@ use tracing::error;
 
 use crossbeam_channel as channel;
 
-use ibc_relayer::supervisor::dump_state::SupervisorState;
+use ibc_relayer::supervisor::{
+    dump_state::SupervisorState,
+    reload::ConfigUpdate,
+    replay::{ReplayFrom, ReplayRange},
+};
+use ibc_relayer::worker::client_expiry::ClientExpiry;
 use ibc_relayer::{
     config::ChainConfig,
     rest::{
@@ -64,6 +69,13 @@ pub fn supervisor_state(
     submit_request(sender, |reply_to| Request::State { reply_to })
 }
 
+/// Fetch the expiry forecasts of the clients which have a worker.
+pub fn client_expiries(
+    sender: &channel::Sender<Request>,
+) -> Result<Vec<ClientExpiry>, RestApiError> {
+    submit_request(sender, |reply_to| Request::GetClients { reply_to })
+}
+
 /// Submit a request to clear all packets for the chain with the
 /// specified `chain_id`.
 pub fn trigger_clear_packets(
@@ -76,6 +88,26 @@ pub fn trigger_clear_packets(
     })
 }
 
+/// Submit a request to reload the configuration from disk.
+pub fn trigger_config_reload(
+    sender: &channel::Sender<Request>,
+) -> Result<ConfigUpdate, RestApiError> {
+    submit_request(sender, |reply_to| Request::ReloadConfig { reply_to })
+}
+
+/// Submit a request to replay the events of the chain with the
+/// specified `chain_id` from the given height.
+pub fn trigger_replay(
+    sender: &channel::Sender<Request>,
+    chain_id: ChainId,
+    height: u64,
+) -> Result<ReplayRange, RestApiError> {
+    submit_request(sender, |reply_to| Request::Replay {
+        from: ReplayFrom { chain_id, height },
+        reply_to,
+    })
+}
+
 pub fn assemble_version_info(sender: &channel::Sender<Request>) -> Vec<VersionInfo> {
     // Fetch the relayer library version
     let lib_version = submit_request(sender, |reply_to| Request::Version { reply_to })
@@ -17,7 +17,8 @@ use tokio::task::JoinHandle;
 use ibc_relayer::rest::{request::Request, RestApiError};
 
 use crate::handle::{
-    all_chain_ids, assemble_version_info, chain_config, supervisor_state, trigger_clear_packets,
+    all_chain_ids, assemble_version_info, chain_config, client_expiries, supervisor_state,
+    trigger_clear_packets, trigger_config_reload, trigger_replay,
 };
 
 pub type BoxError = Box<dyn Error + Send + Sync>;
@@ -71,6 +72,11 @@ async fn get_state(Extension(sender): Extension<Sender>) -> impl IntoResponse {
     Json(JsonResult::from(state))
 }
 
+async fn get_clients(Extension(sender): Extension<Sender>) -> impl IntoResponse {
+    let clients = client_expiries(&sender);
+    Json(JsonResult::from(clients))
+}
+
 #[derive(Debug, Deserialize)]
 struct ClearPacketParams {
     chain: Option<ChainId>,
@@ -84,6 +90,25 @@ async fn clear_packets(
     Json(JsonResult::from(result))
 }
 
+async fn reload_config(Extension(sender): Extension<Sender>) -> impl IntoResponse {
+    let result = trigger_config_reload(&sender);
+    Json(JsonResult::from(result))
+}
+
+#[derive(Debug, Deserialize)]
+struct ReplayParams {
+    chain: ChainId,
+    from: u64,
+}
+
+async fn replay(
+    Extension(sender): Extension<Sender>,
+    Query(params): Query<ReplayParams>,
+) -> impl IntoResponse {
+    let result = trigger_replay(&sender, params.chain, params.from);
+    Json(JsonResult::from(result))
+}
+
 type Sender = channel::Sender<Request>;
 
 async fn run(addr: SocketAddr, sender: Sender) {
@@ -92,7 +117,10 @@ async fn run(addr: SocketAddr, sender: Sender) {
         .route("/chains", get(get_chains))
         .route("/chain/:id", get(get_chain))
         .route("/state", get(get_state))
+        .route("/clients", get(get_clients))
         .route("/clear_packets", post(clear_packets))
+        .route("/config/reload", post(reload_config))
+        .route("/replay", post(replay))
         .layer(Extension(sender));
 
     Server::bind(&addr)
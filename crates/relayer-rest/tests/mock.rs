@@ -5,7 +5,12 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use ibc_relayer::{
     config::ChainConfig,
     rest::request::{Request, VersionInfo},
-    supervisor::dump_state::SupervisorState,
+    supervisor::{
+        dump_state::SupervisorState,
+        lease::{LeadershipState, Role},
+        shard::ChannelShard,
+    },
+    worker::client_expiry::ClientExpiry,
 };
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
@@ -131,7 +136,22 @@ async fn get_chain() {
 
 #[tokio::test]
 async fn state() {
-    let state = SupervisorState::new(vec!["mock-0".parse().unwrap()], std::iter::empty());
+    let state = SupervisorState::new(
+        vec!["mock-0".parse().unwrap()],
+        std::iter::empty(),
+        Vec::new(),
+        Some(LeadershipState {
+            instance_id: "hermes-0".to_string(),
+            role: Role::Standby,
+        }),
+        vec![ChannelShard {
+            chain_id: "mock-0".parse().unwrap(),
+            port_id: "transfer".parse().unwrap(),
+            channel_id: "channel-0".parse().unwrap(),
+            shard: Some(1),
+            owned: false,
+        }],
+    );
     let result: JsonResult<_, ()> = JsonResult::Success(state.clone());
 
     run_test(19104, "/state", result, |req| match req {
@@ -143,3 +163,24 @@ async fn state() {
     })
     .await;
 }
+
+#[tokio::test]
+async fn clients() {
+    let clients = vec![ClientExpiry {
+        chain_id: "mock-0".parse().unwrap(),
+        client_id: "07-tendermint-0".parse().unwrap(),
+        counterparty_chain_id: "mock-1".parse().unwrap(),
+        seconds_until_expiry: 3600,
+        warning: true,
+    }];
+    let result: JsonResult<_, ()> = JsonResult::Success(clients.clone());
+
+    run_test(19105, "/clients", result, |req| match req {
+        Request::GetClients { reply_to } => {
+            reply_to.send(Ok(clients)).unwrap();
+            TestResult::Success
+        }
+        req => TestResult::WrongRequest(req),
+    })
+    .await;
+}
@@ -5,6 +5,7 @@ use std::str::FromStr;
 use tendermint::abci;
 
 use super::error::Error;
+use super::packet_fee::Fee;
 use crate::applications::transfer::coin::RawCoin;
 use crate::core::ics04_channel::packet::Sequence;
 use crate::core::ics24_host::identifier::{ChannelId, PortId};
@@ -22,6 +23,17 @@ pub struct IncentivizedPacket {
     pub total_timeout_fee: Vec<RawCoin>,
 }
 
+impl IncentivizedPacket {
+    /// The total fees escrowed for this packet.
+    pub fn total_fee(&self) -> Fee {
+        Fee {
+            recv_fee: self.total_recv_fee.clone(),
+            ack_fee: self.total_ack_fee.clone(),
+            timeout_fee: self.total_timeout_fee.clone(),
+        }
+    }
+}
+
 fn find_value<'a>(key: &str, entries: &'a [abci::EventAttribute]) -> Result<&'a str, Error> {
     entries
         .iter()
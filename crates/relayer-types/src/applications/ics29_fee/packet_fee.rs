@@ -13,7 +13,7 @@ use crate::signer::Signer;
 
 /// The core type that encodes the different fees that are redeemable by relayers for relaying
 /// different types of packets.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Fee {
     /// The amount that the forward relayer redeems for submitting a recv packet.
     /// This fee is refunded to the payer in the case that the recv packet is not successfully relayed, i.e.,
@@ -40,6 +40,24 @@ pub struct IdentifiedPacketFees {
     pub packet_fees: Vec<PacketFee>,
 }
 
+impl IdentifiedPacketFees {
+    /// The fees of all the payers of this packet, which a relayer can redeem for it.
+    pub fn total_fee(&self) -> Fee {
+        let coins = |select: fn(&Fee) -> &Vec<RawCoin>| {
+            self.packet_fees
+                .iter()
+                .flat_map(|packet_fee| select(&packet_fee.fee).iter().cloned())
+                .collect()
+        };
+
+        Fee {
+            recv_fee: coins(|fee| &fee.recv_fee),
+            ack_fee: coins(|fee| &fee.ack_fee),
+            timeout_fee: coins(|fee| &fee.timeout_fee),
+        }
+    }
+}
+
 impl TryFrom<ProtoFee> for Fee {
     type Error = Error;
 
@@ -0,0 +1,163 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::ClientState as RawClientState;
+use ibc_proto::Protobuf;
+
+use crate::clients::ics06_solomachine::consensus_state::ConsensusState;
+use crate::clients::ics06_solomachine::error::Error;
+use crate::core::ics02_client::client_state::ClientState as Ics2ClientState;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics24_host::identifier::ChainId;
+use crate::Height;
+
+pub const SOLOMACHINE_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.ClientState";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientState {
+    /// The sequence of the next signature of the solo machine
+    pub sequence: u64,
+    pub is_frozen: bool,
+    pub consensus_state: ConsensusState,
+}
+
+impl ClientState {
+    pub fn new(sequence: u64, consensus_state: ConsensusState) -> Result<Self, Error> {
+        if sequence == 0 {
+            return Err(Error::zero_sequence());
+        }
+
+        Ok(Self {
+            sequence,
+            is_frozen: false,
+            consensus_state,
+        })
+    }
+}
+
+impl Ics2ClientState for ClientState {
+    /// A solo machine has no chain identifier, its diversifier is used instead.
+    fn chain_id(&self) -> ChainId {
+        ChainId::from_string(&self.consensus_state.diversifier)
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::SoloMachine
+    }
+
+    fn latest_height(&self) -> Height {
+        Height::new(0, self.sequence).expect("the sequence of a solo machine is never zero")
+    }
+
+    fn frozen_height(&self) -> Option<Height> {
+        self.is_frozen.then(|| self.latest_height())
+    }
+
+    /// Solo machine clients do not expire.
+    fn expired(&self, _elapsed: Duration) -> bool {
+        false
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let consensus_state = raw
+            .consensus_state
+            .ok_or_else(Error::missing_consensus_state)?
+            .try_into()?;
+
+        let mut client_state = Self::new(raw.sequence, consensus_state)?;
+        client_state.is_frozen = raw.is_frozen;
+
+        Ok(client_state)
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            sequence: value.sequence,
+            is_frozen: value.is_frozen,
+            consensus_state: Some(value.consensus_state.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL => Protobuf::<RawClientState>::decode_vec(&raw.value)
+                .map_err(Ics02Error::decode_raw_client_state),
+            _ => Err(Ics02Error::unexpected_client_state_type(
+                SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+                raw.type_url,
+            )),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    use crate::clients::ics06_solomachine::consensus_state::PublicKey;
+    use crate::timestamp::Timestamp;
+
+    fn consensus_state() -> ConsensusState {
+        ConsensusState::new(
+            PublicKey::from_bytes(vec![2; 33]),
+            "solo-1".to_string(),
+            Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap(),
+        )
+    }
+
+    #[test]
+    fn solomachine_client_state_roundtrip() {
+        let client_state = ClientState::new(5, consensus_state()).unwrap();
+
+        assert_eq!(client_state.latest_height(), Height::new(0, 5).unwrap());
+        assert_eq!(client_state.chain_id(), ChainId::from_string("solo-1"));
+        assert_eq!(client_state.frozen_height(), None);
+
+        let any = Any::from(client_state.clone());
+        assert_eq!(any.type_url, SOLOMACHINE_CLIENT_STATE_TYPE_URL);
+
+        let decoded = ClientState::try_from(any).unwrap();
+        assert_eq!(decoded, client_state);
+    }
+
+    #[test]
+    fn solomachine_client_state_with_zero_sequence() {
+        assert!(ClientState::new(0, consensus_state()).is_err());
+
+        let raw = RawClientState {
+            sequence: 0,
+            is_frozen: false,
+            consensus_state: Some(consensus_state().into()),
+        };
+
+        assert!(ClientState::try_from(raw).is_err());
+    }
+}
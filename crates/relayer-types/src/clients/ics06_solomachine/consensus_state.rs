@@ -0,0 +1,163 @@
+use core::fmt;
+
+use serde::{Deserialize, Serialize};
+use subtle_encoding::{Encoding, Hex};
+
+use ibc_proto::cosmos::crypto::secp256k1::PubKey as RawSecp256k1PubKey;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::ConsensusState as RawConsensusState;
+use ibc_proto::Protobuf;
+use prost::Message;
+
+use crate::clients::ics06_solomachine::error::Error;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::consensus_state::ConsensusState as Ics2ConsensusState;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
+
+pub const SOLOMACHINE_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.solomachine.v3.ConsensusState";
+
+pub const SECP256K1_PUBLIC_KEY_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PubKey";
+
+/// A solo machine does not commit to a store, its consensus state thus has an empty root.
+static EMPTY_ROOT: CommitmentRoot = CommitmentRoot::empty();
+
+/// The compressed secp256k1 public key of a solo machine.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PublicKey {
+    #[serde(with = "tendermint::serializers::bytes::hexstring")]
+    bytes: Vec<u8>,
+}
+
+impl PublicKey {
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl fmt::Debug for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = Hex::upper_case().encode_to_string(&self.bytes).unwrap();
+        f.debug_tuple("PublicKey").field(&hex).finish()
+    }
+}
+
+impl TryFrom<Any> for PublicKey {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            SECP256K1_PUBLIC_KEY_TYPE_URL => {
+                let key = RawSecp256k1PubKey::decode(raw.value.as_slice())
+                    .map_err(Error::decode_public_key)?;
+
+                Ok(Self::from_bytes(key.key))
+            }
+            _ => Err(Error::unknown_public_key_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<PublicKey> for Any {
+    fn from(value: PublicKey) -> Self {
+        Any {
+            type_url: SECP256K1_PUBLIC_KEY_TYPE_URL.to_string(),
+            value: RawSecp256k1PubKey { key: value.bytes }.encode_to_vec(),
+        }
+    }
+}
+
+/// The consensus state of a solo machine: the public key and the diversifier
+/// with which its signatures are verified, and the time of its latest signature.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsensusState {
+    pub public_key: PublicKey,
+    pub diversifier: String,
+    pub timestamp: Timestamp,
+}
+
+impl ConsensusState {
+    pub fn new(public_key: PublicKey, diversifier: String, timestamp: Timestamp) -> Self {
+        Self {
+            public_key,
+            diversifier,
+            timestamp,
+        }
+    }
+}
+
+impl Ics2ConsensusState for ConsensusState {
+    fn client_type(&self) -> ClientType {
+        ClientType::SoloMachine
+    }
+
+    fn root(&self) -> &CommitmentRoot {
+        &EMPTY_ROOT
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let public_key = raw
+            .public_key
+            .ok_or_else(Error::missing_public_key)?
+            .try_into()?;
+
+        Ok(Self {
+            public_key,
+            diversifier: raw.diversifier,
+            timestamp: Timestamp::from_nanoseconds(raw.timestamp)
+                .map_err(Error::invalid_timestamp)?,
+        })
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            public_key: Some(value.public_key.into()),
+            diversifier: value.diversifier,
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            SOLOMACHINE_CONSENSUS_STATE_TYPE_URL => {
+                Protobuf::<RawConsensusState>::decode_vec(&raw.value)
+                    .map_err(Ics02Error::invalid_raw_consensus_state)
+            }
+            _ => Err(Ics02Error::unknown_consensus_state_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: SOLOMACHINE_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
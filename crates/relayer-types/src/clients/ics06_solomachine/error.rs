@@ -0,0 +1,39 @@
+use flex_error::{define_error, TraceError};
+
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::timestamp::ParseTimestampError;
+
+define_error! {
+    #[derive(Debug, PartialEq, Eq)]
+    Error {
+        MissingConsensusState
+            |_| { "missing consensus state" },
+
+        MissingPublicKey
+            |_| { "missing public key" },
+
+        UnknownPublicKeyType
+            { type_url: String }
+            |e| { format_args!("unknown public key type: {}", e.type_url) },
+
+        DecodePublicKey
+            [ TraceError<prost::DecodeError> ]
+            |_| { "failed to decode the public key" },
+
+        MissingSignatureAndData
+            |_| { "missing signature and data" },
+
+        InvalidTimestamp
+            [ ParseTimestampError ]
+            |_| { "invalid timestamp" },
+
+        ZeroSequence
+            |_| { "the sequence of a solo machine must not be zero" },
+    }
+}
+
+impl From<Error> for Ics02Error {
+    fn from(e: Error) -> Self {
+        Self::client_specific(e.to_string())
+    }
+}
@@ -0,0 +1,13 @@
+//! ICS 06: Solo Machine Client verifies the state of a standalone machine, eg. an off-chain
+//! process, which signs the values of its store with a single secp256k1 key.
+//!
+//! Every signature of the solo machine commits to the sequence of the client, which is
+//! incremented after each signature verified by the client, and to the diversifier of the
+//! machine. The latest height of the client is the height `0-{sequence}`.
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod header;
+pub mod misbehaviour;
+pub mod sign_bytes;
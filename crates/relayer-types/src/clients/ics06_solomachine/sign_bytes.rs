@@ -0,0 +1,147 @@
+//! The data signed by a solo machine, and the encoding of its signatures as proofs.
+
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::data::{Single, Sum};
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::Data as RawSignatureData;
+use ibc_proto::cosmos::tx::signing::v1beta1::SignMode;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::{
+    HeaderData as RawHeaderData, SignBytes as RawSignBytes,
+    TimestampedSignatureData as RawTimestampedSignatureData,
+};
+use prost::Message;
+
+use crate::clients::ics06_solomachine::consensus_state::PublicKey;
+use crate::core::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use crate::proofs::ProofError;
+use crate::timestamp::Timestamp;
+
+/// The path signed by the headers of a solo machine.
+pub const SENTINEL_HEADER_PATH: &str = "solomachine:header";
+
+/// The bytes signed by a solo machine, which commit to the value
+/// stored at the given path of the solo machine.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignBytes {
+    pub sequence: u64,
+    pub timestamp: Timestamp,
+    pub diversifier: String,
+    pub path: Vec<u8>,
+    pub data: Vec<u8>,
+}
+
+impl SignBytes {
+    pub fn encode_to_vec(self) -> Vec<u8> {
+        RawSignBytes {
+            sequence: self.sequence,
+            timestamp: self.timestamp.nanoseconds(),
+            diversifier: self.diversifier,
+            path: self.path,
+            data: self.data,
+        }
+        .encode_to_vec()
+    }
+}
+
+/// The data signed by a header, ie. the new public key and diversifier of the solo machine.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeaderData {
+    pub new_public_key: PublicKey,
+    pub new_diversifier: String,
+}
+
+impl HeaderData {
+    pub fn encode_to_vec(self) -> Vec<u8> {
+        RawHeaderData {
+            new_pub_key: Some(Any::from(self.new_public_key)),
+            new_diversifier: self.new_diversifier,
+        }
+        .encode_to_vec()
+    }
+}
+
+/// A signature of a solo machine, which is submitted as the proof of a value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TimestampedSignatureData {
+    pub signature_data: Vec<u8>,
+    pub timestamp: Timestamp,
+}
+
+impl TimestampedSignatureData {
+    /// Wrap the given secp256k1 signature into the signature data of a single signer.
+    pub fn new(signature: Vec<u8>, timestamp: Timestamp) -> Self {
+        Self {
+            signature_data: encode_signature_data(signature),
+            timestamp,
+        }
+    }
+}
+
+impl TryFrom<TimestampedSignatureData> for CommitmentProofBytes {
+    type Error = ProofError;
+
+    fn try_from(value: TimestampedSignatureData) -> Result<Self, Self::Error> {
+        RawTimestampedSignatureData {
+            signature_data: value.signature_data,
+            timestamp: value.timestamp.nanoseconds(),
+        }
+        .encode_to_vec()
+        .try_into()
+    }
+}
+
+/// Encode the given signature as the signature data of a single signer.
+pub fn encode_signature_data(signature: Vec<u8>) -> Vec<u8> {
+    RawSignatureData {
+        sum: Some(Sum::Single(Single {
+            mode: SignMode::Direct as i32,
+            signature,
+        })),
+    }
+    .encode_to_vec()
+}
+
+/// The path signed along with a value, ie. the path of the value prefixed with the
+/// commitment prefix of the solo machine, formatted as the string of a Merkle path.
+pub fn merkle_path_bytes(prefix: &CommitmentPrefix, path: &str) -> Vec<u8> {
+    let prefix = String::from_utf8_lossy(prefix.as_bytes());
+
+    format!("/{prefix}/{path}").into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    #[test]
+    fn merkle_path_of_connection() {
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+
+        assert_eq!(
+            merkle_path_bytes(&prefix, "connections/connection-0"),
+            b"/ibc/connections/connection-0".to_vec()
+        );
+    }
+
+    #[test]
+    fn timestamped_signature_data_proof() {
+        let proof = CommitmentProofBytes::try_from(TimestampedSignatureData::new(
+            vec![1; 64],
+            Timestamp::from_nanoseconds(10).unwrap(),
+        ))
+        .unwrap();
+
+        let raw = RawTimestampedSignatureData::decode(proof.as_bytes()).unwrap();
+        assert_eq!(raw.timestamp, 10);
+
+        let data = RawSignatureData::decode(raw.signature_data.as_slice()).unwrap();
+        assert_eq!(
+            data.sum,
+            Some(Sum::Single(Single {
+                mode: SignMode::Direct as i32,
+                signature: vec![1; 64],
+            }))
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use serde::{Deserialize, Serialize};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::tendermint::v1::Misbehaviour as RawTmMisbehaviour;
+use ibc_proto::ibc::lightclients::wasm::v1::ClientMessage as RawClientMessage;
+use ibc_proto::Protobuf;
+
+use crate::clients::ics07_tendermint::header::Header as TmHeader;
+use crate::clients::ics07_tendermint::misbehaviour::{
+    Misbehaviour as TmMisbehaviour, TENDERMINT_MISBEHAVIOR_TYPE_URL,
+};
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::{decode_data, encode_data};
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics02_client::header::Header as Ics2Header;
+use crate::core::ics02_client::misbehaviour::Misbehaviour as Ics2Misbehaviour;
+use crate::core::ics24_host::identifier::ClientId;
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+pub const WASM_CLIENT_MESSAGE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ClientMessage";
+
+/// A client message of a Wasm client holding a header of the wrapped light client.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Header {
+    pub inner: TmHeader,
+}
+
+impl Header {
+    pub fn new(inner: TmHeader) -> Self {
+        Self { inner }
+    }
+}
+
+impl Ics2Header for Header {
+    fn client_type(&self) -> ClientType {
+        ClientType::Wasm
+    }
+
+    fn height(&self) -> Height {
+        self.inner.height()
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        Ics2Header::timestamp(&self.inner)
+    }
+}
+
+impl Protobuf<RawClientMessage> for Header {}
+
+impl TryFrom<RawClientMessage> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawClientMessage) -> Result<Self, Self::Error> {
+        let inner = decode_data(&raw.data)?
+            .try_into()
+            .map_err(Error::invalid_data)?;
+
+        Ok(Self { inner })
+    }
+}
+
+impl From<Header> for RawClientMessage {
+    fn from(value: Header) -> Self {
+        Self {
+            data: encode_data(value.inner),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            WASM_CLIENT_MESSAGE_TYPE_URL => Protobuf::<RawClientMessage>::decode_vec(&raw.value)
+                .map_err(Ics02Error::invalid_raw_header),
+            _ => Err(Ics02Error::unknown_header_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: WASM_CLIENT_MESSAGE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientMessage>::encode_vec(header),
+        }
+    }
+}
+
+/// A client message of a Wasm client holding a misbehaviour of the wrapped light client.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Misbehaviour {
+    pub inner: TmMisbehaviour,
+}
+
+impl Misbehaviour {
+    pub fn new(inner: TmMisbehaviour) -> Self {
+        Self { inner }
+    }
+}
+
+impl Ics2Misbehaviour for Misbehaviour {
+    fn client_id(&self) -> &ClientId {
+        self.inner.client_id()
+    }
+
+    fn height(&self) -> Height {
+        self.inner.height()
+    }
+}
+
+impl Protobuf<RawClientMessage> for Misbehaviour {}
+
+impl TryFrom<RawClientMessage> for Misbehaviour {
+    type Error = Ics02Error;
+
+    fn try_from(raw: RawClientMessage) -> Result<Self, Self::Error> {
+        let data = decode_data(&raw.data)?;
+
+        match data.type_url.as_str() {
+            TENDERMINT_MISBEHAVIOR_TYPE_URL => Ok(Self {
+                inner: Protobuf::<RawTmMisbehaviour>::decode_vec(&data.value)
+                    .map_err(Ics02Error::decode_raw_misbehaviour)?,
+            }),
+            _ => Err(Ics02Error::unknown_misbehaviour_type(data.type_url)),
+        }
+    }
+}
+
+impl From<Misbehaviour> for RawClientMessage {
+    fn from(value: Misbehaviour) -> Self {
+        Self {
+            data: encode_data(Any {
+                type_url: TENDERMINT_MISBEHAVIOR_TYPE_URL.to_string(),
+                value: Protobuf::<RawTmMisbehaviour>::encode_vec(value.inner),
+            }),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            WASM_CLIENT_MESSAGE_TYPE_URL => Protobuf::<RawClientMessage>::decode_vec(&raw.value)
+                .map_err(Ics02Error::decode_raw_misbehaviour),
+            _ => Err(Ics02Error::unknown_misbehaviour_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Any {
+            type_url: WASM_CLIENT_MESSAGE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientMessage>::encode_vec(misbehaviour),
+        }
+    }
+}
+
+impl core::fmt::Display for Misbehaviour {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "wasm {}", self.inner)
+    }
+}
@@ -0,0 +1,174 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::wasm::v1::ClientState as RawWasmClientState;
+use ibc_proto::Protobuf;
+
+use crate::clients::ics07_tendermint::client_state::ClientState as TmClientState;
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::{decode_data, encode_data};
+use crate::core::ics02_client::client_state::ClientState as Ics2ClientState;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics24_host::identifier::ChainId;
+use crate::Height;
+
+pub const WASM_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ClientState";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClientState {
+    /// The checksum of the code of the Wasm contract implementing the light client
+    #[serde(with = "tendermint::serializers::bytes::hexstring")]
+    pub checksum: Vec<u8>,
+    pub latest_height: Height,
+    /// The client state of the wrapped light client
+    pub inner: TmClientState,
+}
+
+impl ClientState {
+    pub fn new(checksum: Vec<u8>, inner: TmClientState) -> Self {
+        Self {
+            checksum,
+            latest_height: inner.latest_height(),
+            inner,
+        }
+    }
+}
+
+impl Ics2ClientState for ClientState {
+    fn chain_id(&self) -> ChainId {
+        self.inner.chain_id()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Wasm
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn frozen_height(&self) -> Option<Height> {
+        self.inner.frozen_height()
+    }
+
+    fn expired(&self, elapsed: Duration) -> bool {
+        self.inner.expired(elapsed)
+    }
+}
+
+impl Protobuf<RawWasmClientState> for ClientState {}
+
+impl TryFrom<RawWasmClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawWasmClientState) -> Result<Self, Self::Error> {
+        let latest_height = raw
+            .latest_height
+            .ok_or_else(Error::missing_latest_height)?
+            .try_into()
+            .map_err(Error::invalid_latest_height)?;
+
+        let inner = decode_data(&raw.data)?
+            .try_into()
+            .map_err(Error::invalid_data)?;
+
+        Ok(Self {
+            checksum: raw.checksum,
+            latest_height,
+            inner,
+        })
+    }
+}
+
+impl From<ClientState> for RawWasmClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            data: encode_data(value.inner),
+            checksum: value.checksum,
+            latest_height: Some(value.latest_height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            WASM_CLIENT_STATE_TYPE_URL => Protobuf::<RawWasmClientState>::decode_vec(&raw.value)
+                .map_err(Ics02Error::decode_raw_client_state),
+            _ => Err(Ics02Error::unexpected_client_state_type(
+                WASM_CLIENT_STATE_TYPE_URL.to_string(),
+                raw.type_url,
+            )),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: WASM_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawWasmClientState>::encode_vec(client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    use crate::clients::ics07_tendermint::client_state::AllowUpdate;
+    use crate::core::ics02_client::trust_threshold::TrustThreshold;
+    use crate::core::ics23_commitment::specs::ProofSpecs;
+
+    fn tm_client_state() -> TmClientState {
+        TmClientState::new(
+            ChainId::new("ibc".to_string(), 1),
+            TrustThreshold::TWO_THIRDS,
+            Duration::from_secs(64000),
+            Duration::from_secs(128000),
+            Duration::from_secs(3),
+            Height::new(1, 10).unwrap(),
+            ProofSpecs::default(),
+            vec![],
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn wasm_client_state_roundtrip() {
+        let client_state = ClientState::new(vec![0xab; 32], tm_client_state());
+
+        assert_eq!(client_state.latest_height, Height::new(1, 10).unwrap());
+        assert_eq!(client_state.client_type(), ClientType::Wasm);
+
+        let any = Any::from(client_state.clone());
+        assert_eq!(any.type_url, WASM_CLIENT_STATE_TYPE_URL);
+
+        let decoded = ClientState::try_from(any).unwrap();
+        assert_eq!(decoded, client_state);
+    }
+
+    #[test]
+    fn wasm_client_state_with_unknown_data() {
+        let raw = RawWasmClientState {
+            data: b"not a client state".to_vec(),
+            checksum: vec![0xab; 32],
+            latest_height: Some(Height::new(1, 10).unwrap().into()),
+        };
+
+        assert!(ClientState::try_from(raw).is_err());
+    }
+}
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::wasm::v1::ConsensusState as RawWasmConsensusState;
+use ibc_proto::Protobuf;
+
+use crate::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::{decode_data, encode_data};
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::consensus_state::ConsensusState as Ics2ConsensusState;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
+
+pub const WASM_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ConsensusState";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsensusState {
+    /// The consensus state of the wrapped light client
+    pub inner: TmConsensusState,
+}
+
+impl ConsensusState {
+    pub fn new(inner: TmConsensusState) -> Self {
+        Self { inner }
+    }
+}
+
+impl Ics2ConsensusState for ConsensusState {
+    fn client_type(&self) -> ClientType {
+        ClientType::Wasm
+    }
+
+    fn root(&self) -> &CommitmentRoot {
+        self.inner.root()
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.inner.timestamp()
+    }
+}
+
+impl Protobuf<RawWasmConsensusState> for ConsensusState {}
+
+impl TryFrom<RawWasmConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawWasmConsensusState) -> Result<Self, Self::Error> {
+        let inner = decode_data(&raw.data)?
+            .try_into()
+            .map_err(Error::invalid_data)?;
+
+        Ok(Self { inner })
+    }
+}
+
+impl From<ConsensusState> for RawWasmConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        Self {
+            data: encode_data(value.inner),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            WASM_CONSENSUS_STATE_TYPE_URL => {
+                Protobuf::<RawWasmConsensusState>::decode_vec(&raw.value)
+                    .map_err(Ics02Error::invalid_raw_consensus_state)
+            }
+            _ => Err(Ics02Error::unknown_consensus_state_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: WASM_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawWasmConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use flex_error::{define_error, TraceError};
+
+use crate::core::ics02_client::error::Error as Ics02Error;
+
+define_error! {
+    #[derive(Debug, PartialEq, Eq)]
+    Error {
+        Decode
+            [ TraceError<prost::DecodeError> ]
+            |_| { "failed to decode the data of the Wasm client" },
+
+        InvalidData
+            [ Ics02Error ]
+            |_| { "invalid data of the light client wrapped by the Wasm client" },
+
+        MissingLatestHeight
+            |_| { "missing latest height" },
+
+        InvalidLatestHeight
+            [ Ics02Error ]
+            |_| { "invalid latest height" },
+    }
+}
+
+impl From<Error> for Ics02Error {
+    fn from(e: Error) -> Self {
+        Self::client_specific(e.to_string())
+    }
+}
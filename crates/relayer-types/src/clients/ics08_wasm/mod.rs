@@ -0,0 +1,24 @@
+//! ICS 08: Wasm Client wraps a light client implemented by a Wasm contract stored on the host
+//! chain, which is identified by the checksum of its code.
+//!
+//! The data of the client state, consensus state and client messages of a Wasm client are
+//! the `Any`-encoded values of the wrapped light client. Only contracts wrapping a Tendermint
+//! light client are supported.
+
+use ibc_proto::google::protobuf::Any;
+use prost::Message;
+
+use crate::clients::ics08_wasm::error::Error;
+
+pub mod client_message;
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+
+fn decode_data(data: &[u8]) -> Result<Any, Error> {
+    Any::decode(data).map_err(Error::decode)
+}
+
+fn encode_data(value: impl Into<Any>) -> Vec<u8> {
+    value.into().encode_to_vec()
+}
@@ -0,0 +1,144 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::localhost::v2::ClientState as RawClientState;
+use ibc_proto::Protobuf;
+
+use crate::core::ics02_client::client_state::ClientState as Ics2ClientState;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error;
+use crate::core::ics24_host::identifier::ChainId;
+use crate::Height;
+
+pub const LOCALHOST_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.localhost.v2.ClientState";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientState {
+    /// The identifier of the chain hosting the client, which is not part of
+    /// the encoded client state and must be set by the hosting chain
+    pub chain_id: ChainId,
+    pub latest_height: Height,
+}
+
+impl ClientState {
+    pub fn new(chain_id: ChainId, latest_height: Height) -> Self {
+        Self {
+            chain_id,
+            latest_height,
+        }
+    }
+
+    /// Sets the identifier of the chain hosting the client.
+    pub fn with_chain_id(self, chain_id: ChainId) -> Self {
+        Self { chain_id, ..self }
+    }
+}
+
+impl Ics2ClientState for ClientState {
+    /// The localhost client tracks the chain which hosts it.
+    fn chain_id(&self) -> ChainId {
+        self.chain_id.clone()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Localhost
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn frozen_height(&self) -> Option<Height> {
+        None
+    }
+
+    /// Localhost clients are updated at every block and never expire.
+    fn expired(&self, _elapsed: Duration) -> bool {
+        false
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let latest_height = raw
+            .latest_height
+            .ok_or_else(Error::missing_height)?
+            .try_into()
+            .map_err(|_| Error::invalid_height())?;
+
+        Ok(Self::new(ChainId::default(), latest_height))
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            latest_height: Some(value.latest_height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            LOCALHOST_CLIENT_STATE_TYPE_URL => Protobuf::<RawClientState>::decode_vec(&raw.value)
+                .map_err(Error::decode_raw_client_state),
+            _ => Err(Error::unexpected_client_state_type(
+                LOCALHOST_CLIENT_STATE_TYPE_URL.to_string(),
+                raw.type_url,
+            )),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: LOCALHOST_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    #[test]
+    fn localhost_client_state_roundtrip() {
+        let client_state =
+            ClientState::new(ChainId::from_string("ibc-0"), Height::new(0, 42).unwrap());
+
+        let any = Any::from(client_state.clone());
+        assert_eq!(any.type_url, LOCALHOST_CLIENT_STATE_TYPE_URL);
+
+        // The chain identifier is not encoded, the hosting chain must set it back
+        let decoded = ClientState::try_from(any).unwrap();
+        assert_eq!(decoded.latest_height(), Height::new(0, 42).unwrap());
+        assert_eq!(
+            decoded.with_chain_id(ChainId::from_string("ibc-0")),
+            client_state
+        );
+    }
+
+    #[test]
+    fn localhost_client_state_without_height() {
+        let raw = RawClientState {
+            latest_height: None,
+        };
+
+        assert!(ClientState::try_from(raw).is_err());
+    }
+}
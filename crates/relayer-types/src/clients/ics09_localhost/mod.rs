@@ -0,0 +1,11 @@
+//! ICS 09: Localhost Client lets a chain running ibc-go v7.1+ verify its own state, so that
+//! channels can be opened between two ports of the same chain over `connection-localhost`.
+//!
+//! The client is updated by the chain itself at every block, it therefore never receives
+//! client updates and stores no consensus states. Instead of Merkle proofs, the client only
+//! accepts the [`SENTINEL_PROOF`] and reads the committed values directly from the store.
+
+pub mod client_state;
+
+/// The proof expected by the localhost client in place of any membership proof.
+pub const SENTINEL_PROOF: &[u8] = &[0x01];
@@ -7,15 +7,24 @@ use super::error::Error;
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum ClientType {
     Tendermint = 1,
+    SoloMachine = 6,
+    Wasm = 8,
+    Localhost = 9,
 }
 
 impl ClientType {
     const TENDERMINT_STR: &'static str = "07-tendermint";
+    const SOLO_MACHINE_STR: &'static str = "06-solomachine";
+    const WASM_STR: &'static str = "08-wasm";
+    const LOCALHOST_STR: &'static str = "09-localhost";
 
     /// Yields the identifier of this client type as a string
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Tendermint => Self::TENDERMINT_STR,
+            Self::SoloMachine => Self::SOLO_MACHINE_STR,
+            Self::Wasm => Self::WASM_STR,
+            Self::Localhost => Self::LOCALHOST_STR,
         }
     }
 }
@@ -32,6 +41,9 @@ impl core::str::FromStr for ClientType {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             Self::TENDERMINT_STR => Ok(Self::Tendermint),
+            Self::SOLO_MACHINE_STR => Ok(Self::SoloMachine),
+            Self::WASM_STR => Ok(Self::Wasm),
+            Self::LOCALHOST_STR => Ok(Self::Localhost),
 
             _ => Err(Error::unknown_client_type(s.to_string())),
         }
@@ -56,6 +68,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_solo_machine_client_type() {
+        let client_type = ClientType::from_str("06-solomachine").unwrap();
+        assert_eq!(client_type, ClientType::SoloMachine);
+        assert_eq!(client_type.as_str(), "06-solomachine");
+    }
+
+    #[test]
+    fn parse_wasm_client_type() {
+        let client_type = ClientType::from_str("08-wasm").unwrap();
+        assert_eq!(client_type, ClientType::Wasm);
+        assert_eq!(client_type.as_str(), "08-wasm");
+    }
+
+    #[test]
+    fn parse_localhost_client_type() {
+        let client_type = ClientType::from_str("09-localhost").unwrap();
+        assert_eq!(client_type, ClientType::Localhost);
+        assert_eq!(client_type.as_str(), "09-localhost");
+    }
+
     #[test]
     fn parse_unknown_client_type() {
         let client_type_str = "some-random-client-type";
@@ -5,9 +5,15 @@ use serde::{Deserialize, Serialize};
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::Protobuf;
 
+use crate::clients::ics06_solomachine::header::{
+    Header as SoloMachineHeader, SOLOMACHINE_HEADER_TYPE_URL,
+};
 use crate::clients::ics07_tendermint::header::{
     decode_header as tm_decode_header, Header as TendermintHeader, TENDERMINT_HEADER_TYPE_URL,
 };
+use crate::clients::ics08_wasm::client_message::{
+    Header as WasmHeader, WASM_CLIENT_MESSAGE_TYPE_URL,
+};
 use crate::core::ics02_client::client_type::ClientType;
 use crate::core::ics02_client::error::Error;
 use crate::timestamp::Timestamp;
@@ -28,37 +34,39 @@ pub trait Header: Debug + Send + Sync // Any: From<Self>,
 
 /// Decodes an encoded header into a known `Header` type,
 pub fn decode_header(header_bytes: &[u8]) -> Result<AnyHeader, Error> {
-    // For now, we only have tendermint; however when there is more than one, we
-    // can try decoding into all the known types, and return an error only if
-    // none work
-    let header: TendermintHeader =
-        Protobuf::<Any>::decode(header_bytes).map_err(Error::invalid_raw_header)?;
-
-    Ok(AnyHeader::Tendermint(header))
+    Protobuf::<Any>::decode(header_bytes).map_err(Error::invalid_raw_header)
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[allow(clippy::large_enum_variant)]
 pub enum AnyHeader {
     Tendermint(TendermintHeader),
+    SoloMachine(SoloMachineHeader),
+    Wasm(WasmHeader),
 }
 
 impl Header for AnyHeader {
     fn client_type(&self) -> ClientType {
         match self {
             Self::Tendermint(header) => header.client_type(),
+            Self::SoloMachine(header) => header.client_type(),
+            Self::Wasm(header) => header.client_type(),
         }
     }
 
     fn height(&self) -> Height {
         match self {
             Self::Tendermint(header) => header.height(),
+            Self::SoloMachine(header) => header.height(),
+            Self::Wasm(header) => header.height(),
         }
     }
 
     fn timestamp(&self) -> Timestamp {
         match self {
             Self::Tendermint(header) => header.timestamp(),
+            Self::SoloMachine(header) => header.timestamp(),
+            Self::Wasm(header) => header.timestamp(),
         }
     }
 }
@@ -75,6 +83,10 @@ impl TryFrom<Any> for AnyHeader {
                 Ok(AnyHeader::Tendermint(val))
             }
 
+            SOLOMACHINE_HEADER_TYPE_URL => Ok(AnyHeader::SoloMachine(raw.try_into()?)),
+
+            WASM_CLIENT_MESSAGE_TYPE_URL => Ok(AnyHeader::Wasm(raw.try_into()?)),
+
             _ => Err(Error::unknown_header_type(raw.type_url)),
         }
     }
@@ -89,6 +101,8 @@ impl From<AnyHeader> for Any {
                 type_url: TENDERMINT_HEADER_TYPE_URL.to_string(),
                 value: Protobuf::<RawHeader>::encode_vec(header),
             },
+            AnyHeader::SoloMachine(header) => header.into(),
+            AnyHeader::Wasm(header) => header.into(),
         }
     }
 }
@@ -98,3 +112,15 @@ impl From<TendermintHeader> for AnyHeader {
         Self::Tendermint(header)
     }
 }
+
+impl From<SoloMachineHeader> for AnyHeader {
+    fn from(header: SoloMachineHeader) -> Self {
+        Self::SoloMachine(header)
+    }
+}
+
+impl From<WasmHeader> for AnyHeader {
+    fn from(header: WasmHeader) -> Self {
+        Self::Wasm(header)
+    }
+}
@@ -226,12 +226,20 @@ impl ChannelEnd {
         &self.version
     }
 
+    /// Returns `true` if the connection hops of this `ChannelEnd` traverse
+    /// intermediate chains, ie. if it is a multi-hop (ICS 033) channel.
+    pub fn is_multihop(&self) -> bool {
+        self.connection_hops.len() > 1
+    }
+
+    /// Checks that the channel end has at least one connection hop.
+    ///
+    /// A channel end over a single connection has exactly one hop, but a multi-hop
+    /// (ICS 033) channel end has one connection hop per chain on its path but the
+    /// last, so no upper bound is enforced here.
     pub fn validate_basic(&self) -> Result<(), Error> {
-        if self.connection_hops.len() != 1 {
-            return Err(Error::invalid_connection_hops_length(
-                1,
-                self.connection_hops.len(),
-            ));
+        if self.connection_hops.is_empty() {
+            return Err(Error::invalid_connection_hops_length(1, 0));
         }
         self.counterparty().validate_basic()
     }
@@ -660,6 +668,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn channel_end_validate_basic() {
+        let channel_end = |connection_hops: &[&str]| {
+            ChannelEnd::try_from(RawChannel {
+                connection_hops: connection_hops.iter().map(|id| id.to_string()).collect(),
+                ..get_dummy_raw_channel_end()
+            })
+            .unwrap()
+        };
+
+        assert!(channel_end(&[]).validate_basic().is_err());
+        assert!(channel_end(&["connection-0"]).validate_basic().is_ok());
+
+        // The connection hops of a multi-hop channel end
+        let multihop_channel_end = channel_end(&["connection-0", "connection-1"]);
+        assert!(multihop_channel_end.is_multihop());
+        assert!(multihop_channel_end.validate_basic().is_ok());
+    }
+
     #[test]
     fn parse_channel_ordering_type() {
         use super::Ordering;
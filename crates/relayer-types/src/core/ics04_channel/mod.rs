@@ -6,6 +6,7 @@ pub mod commitment;
 pub mod error;
 pub mod events;
 pub mod msgs;
+pub mod multihop;
 pub mod packet;
 pub mod packet_id;
 pub mod timeout;
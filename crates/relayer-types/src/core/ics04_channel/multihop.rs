@@ -0,0 +1,181 @@
+//! Proofs for multi-hop channels (ICS 033), ie. channels whose connection hops
+//! traverse intermediate chains between the two channel ends.
+//!
+//! A multi-hop proof is submitted in place of the usual Merkle proof of a message,
+//! encoded as a [`RawMsgMultihopProofs`].
+
+use ibc_proto::ibc::core::commitment::v1::MerklePath;
+use serde::Serialize;
+
+use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
+use crate::proofs::ProofError;
+use crate::tx_msg::encode_message;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawMultihopProof {
+    #[prost(bytes = "vec", tag = "1")]
+    pub proof: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub prefixed_key: Option<MerklePath>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct RawMsgMultihopProofs {
+    #[prost(bytes = "vec", tag = "1")]
+    pub key_proof: Vec<u8>,
+    #[prost(message, repeated, tag = "2")]
+    pub connection_proofs: Vec<RawMultihopProof>,
+    #[prost(message, repeated, tag = "3")]
+    pub consensus_proofs: Vec<RawMultihopProof>,
+}
+
+/// Proof of a value stored on an intermediate chain of a multi-hop channel,
+/// ie. the consensus state of the previous chain or a connection end.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct MultihopProof {
+    pub proof: CommitmentProofBytes,
+    #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
+    pub value: Vec<u8>,
+    /// The key of the value in the store of the intermediate chain,
+    /// prefixed with the commitment prefix of that chain.
+    pub prefixed_key: Vec<String>,
+}
+
+/// Proof of a key stored on the source chain of a multi-hop channel, along with
+/// the proofs of the consensus states and connection ends stored on the intermediate
+/// chains, which let the destination chain verify the key proof.
+///
+/// The proofs of the intermediate chains are ordered from the chain next to
+/// the source chain to the chain next to the destination chain.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct MultihopProofs {
+    pub key_proof: CommitmentProofBytes,
+    pub connection_proofs: Vec<MultihopProof>,
+    pub consensus_proofs: Vec<MultihopProof>,
+}
+
+impl From<MultihopProof> for RawMultihopProof {
+    fn from(value: MultihopProof) -> Self {
+        Self {
+            proof: value.proof.into(),
+            value: value.value,
+            prefixed_key: Some(MerklePath {
+                key_path: value.prefixed_key,
+            }),
+        }
+    }
+}
+
+impl TryFrom<RawMultihopProof> for MultihopProof {
+    type Error = ProofError;
+
+    fn try_from(raw: RawMultihopProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proof: raw.proof.try_into()?,
+            value: raw.value,
+            prefixed_key: raw
+                .prefixed_key
+                .map(|path| path.key_path)
+                .unwrap_or_default(),
+        })
+    }
+}
+
+impl From<MultihopProofs> for RawMsgMultihopProofs {
+    fn from(value: MultihopProofs) -> Self {
+        Self {
+            key_proof: value.key_proof.into(),
+            connection_proofs: value
+                .connection_proofs
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            consensus_proofs: value.consensus_proofs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<RawMsgMultihopProofs> for MultihopProofs {
+    type Error = ProofError;
+
+    fn try_from(raw: RawMsgMultihopProofs) -> Result<Self, Self::Error> {
+        Ok(Self {
+            key_proof: raw.key_proof.try_into()?,
+            connection_proofs: raw
+                .connection_proofs
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            consensus_proofs: raw
+                .consensus_proofs
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<MultihopProofs> for CommitmentProofBytes {
+    type Error = ProofError;
+
+    fn try_from(value: MultihopProofs) -> Result<Self, Self::Error> {
+        encode_message(&RawMsgMultihopProofs::from(value))
+            .map_err(ProofError::encode)?
+            .try_into()
+    }
+}
+
+impl TryFrom<&CommitmentProofBytes> for MultihopProofs {
+    type Error = ProofError;
+
+    fn try_from(value: &CommitmentProofBytes) -> Result<Self, Self::Error> {
+        let raw: RawMsgMultihopProofs =
+            prost::Message::decode(value.as_bytes()).map_err(ProofError::decode)?;
+
+        raw.try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    fn proof(bytes: &[u8], key: &str) -> MultihopProof {
+        MultihopProof {
+            proof: bytes.to_vec().try_into().unwrap(),
+            value: b"value".to_vec(),
+            prefixed_key: vec!["ibc".to_string(), key.to_string()],
+        }
+    }
+
+    #[test]
+    fn multihop_proofs_roundtrip() {
+        let proofs = MultihopProofs {
+            key_proof: vec![1, 2, 3].try_into().unwrap(),
+            connection_proofs: vec![proof(&[4], "connections/connection-1")],
+            consensus_proofs: vec![proof(&[5], "clients/07-tendermint-0/consensusStates/0-10")],
+        };
+
+        let bytes = CommitmentProofBytes::try_from(proofs.clone()).unwrap();
+        let decoded = MultihopProofs::try_from(&bytes).unwrap();
+
+        assert_eq!(decoded, proofs);
+    }
+
+    #[test]
+    fn multihop_proofs_without_key_proof() {
+        let raw = RawMsgMultihopProofs {
+            key_proof: vec![],
+            connection_proofs: vec![],
+            consensus_proofs: vec![],
+        };
+
+        assert!(MultihopProofs::try_from(raw).is_err());
+    }
+}
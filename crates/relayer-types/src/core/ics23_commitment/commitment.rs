@@ -25,6 +25,12 @@ impl fmt::Debug for CommitmentRoot {
 }
 
 impl CommitmentRoot {
+    /// The root of a consensus state which does not commit to a store,
+    /// eg. the consensus state of a solo machine.
+    pub const fn empty() -> Self {
+        Self { bytes: Vec::new() }
+    }
+
     pub fn from_bytes(bytes: &[u8]) -> Self {
         Self {
             bytes: Vec::from(bytes),
@@ -1,7 +1,8 @@
 use flex_error::{define_error, TraceError};
-use prost::EncodeError;
+use prost::{DecodeError, EncodeError};
 use serde::Serialize;
 
+use crate::clients::ics09_localhost::SENTINEL_PROOF;
 use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
 use crate::Height;
 
@@ -17,6 +18,10 @@ define_error! {
         Encode
             [ TraceError<EncodeError> ]
             | _ | { "protobuf encode error" },
+
+        Decode
+            [ TraceError<DecodeError> ]
+            | _ | { "protobuf decode error" },
     }
 }
 
@@ -87,6 +92,27 @@ impl Proofs {
     pub fn other_proof(&self) -> Option<&CommitmentProofBytes> {
         self.other_proof.as_ref()
     }
+
+    /// Replaces each of the proofs with the sentinel proof, which is the only
+    /// proof accepted by the localhost client in place of a Merkle proof.
+    pub fn into_localhost(self) -> Self {
+        let sentinel = || {
+            CommitmentProofBytes::try_from(SENTINEL_PROOF.to_vec())
+                .expect("the sentinel proof is not empty")
+        };
+
+        Self {
+            object_proof: sentinel(),
+            client_proof: self.client_proof.map(|_| sentinel()),
+            consensus_proof: self.consensus_proof.map(|proof| ConsensusProof {
+                proof: sentinel(),
+                height: proof.height,
+            }),
+            host_consensus_state_proof: self.host_consensus_state_proof.map(|_| sentinel()),
+            other_proof: self.other_proof.map(|_| sentinel()),
+            height: self.height,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize)]
@@ -5,4 +5,5 @@ pub mod endpoint;
 pub mod handle;
 pub mod requests;
 pub mod runtime;
+pub mod solomachine;
 pub mod tracking;
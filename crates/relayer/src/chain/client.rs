@@ -10,6 +10,9 @@ use crate::foreign_client::CreateOptions;
 #[derive(Clone, Debug)]
 pub enum ClientSettings {
     Tendermint(cosmos::client::Settings),
+    /// Solo machine clients are fully determined by the public key and
+    /// diversifier of the solo machine, so they take no parameters.
+    SoloMachine,
 }
 
 impl ClientSettings {
@@ -27,7 +30,7 @@ impl ClientSettings {
         //
         // TODO: extract Tendermint-related configs into a separate substructure
         // that can be used both by CosmosSdkConfig and configs for nonSDK chains.
-        use ChainConfig::CosmosSdk as Csdk;
+        use ChainConfig::{CosmosSdk as Csdk, SoloMachine as Solo};
         match (src_chain_config, dst_chain_config) {
             (Csdk(src_chain_config), Csdk(dst_chain_config)) => {
                 ClientSettings::Tendermint(cosmos::client::Settings::for_create_command(
@@ -36,6 +39,20 @@ impl ClientSettings {
                     dst_chain_config,
                 ))
             }
+            (Solo(_), _) => ClientSettings::SoloMachine,
+            (Csdk(src_chain_config), Solo(dst_chain_config)) => {
+                let max_clock_drift = options
+                    .max_clock_drift
+                    .unwrap_or(src_chain_config.clock_drift + dst_chain_config.max_block_time);
+
+                ClientSettings::Tendermint(cosmos::client::Settings {
+                    max_clock_drift,
+                    trusting_period: options.trusting_period,
+                    trust_threshold: options
+                        .trust_threshold
+                        .unwrap_or(src_chain_config.trust_threshold),
+                })
+            }
         }
     }
 }
@@ -15,6 +15,7 @@ use ibc_proto::cosmos::base::node::v1beta1::ConfigResponse;
 use ibc_proto::cosmos::base::tendermint::v1beta1::service_client::ServiceClient;
 use ibc_proto::cosmos::base::tendermint::v1beta1::{GetSyncingRequest, GetSyncingResponse};
 use ibc_proto::cosmos::staking::v1beta1::Params as StakingParams;
+use ibc_proto::cosmos::upgrade::v1beta1::Plan;
 use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
@@ -56,6 +57,7 @@ use ibc_relayer_types::core::{
 use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::Height as ICSHeight;
 
+use tendermint::block::Header as TmBlockHeader;
 use tendermint::block::Height as TmHeight;
 use tendermint::node::{self, info::TxIndexStatus};
 use tendermint::time::Time as TmTime;
@@ -72,8 +74,10 @@ use crate::chain::cosmos::batch::{
     sequential_send_batched_messages_and_wait_commit,
 };
 use crate::chain::cosmos::encode::key_pair_to_signer;
+use crate::chain::cosmos::failover::{build_rpc_client, EndpointPool};
 use crate::chain::cosmos::fee::maybe_register_counterparty_payee;
 use crate::chain::cosmos::gas::{calculate_fee, mul_ceil};
+use crate::chain::cosmos::key_pool::{Assignment, KeyPool};
 use crate::chain::cosmos::query::account::get_or_fetch_account;
 use crate::chain::cosmos::query::balance::{query_all_balances, query_balance};
 use crate::chain::cosmos::query::consensus_state::query_consensus_state_heights;
@@ -85,7 +89,6 @@ use crate::chain::cosmos::query::tx::{
     filter_matching_event, query_packets_from_block, query_packets_from_txs, query_txs,
 };
 use crate::chain::cosmos::query::{abci_query, fetch_version_specs, packet_query, QueryResponse};
-use crate::chain::cosmos::types::account::Account;
 use crate::chain::cosmos::types::config::TxConfig;
 use crate::chain::cosmos::types::gas::{
     default_gas_from_config, gas_multiplier_from_config, max_gas_from_config,
@@ -96,13 +99,13 @@ use crate::chain::requests::*;
 use crate::chain::tracking::TrackedMsgs;
 use crate::client_state::{AnyClientState, IdentifiedAnyClientState};
 use crate::config::Error as ConfigError;
-use crate::config::{parse_gas_prices, ChainConfig, GasPrice};
+use crate::config::{parse_gas_prices, ChainConfig, EventSourceMode, GasPrice};
 use crate::consensus_state::AnyConsensusState;
 use crate::denom::DenomTrace;
 use crate::error::Error;
 use crate::event::source::{EventSource, TxEventSourceCmd};
 use crate::event::IbcEventWithHeight;
-use crate::keyring::{KeyRing, Secp256k1KeyPair, SigningKeyPair};
+use crate::keyring::{AnySecp256k1KeyPair, KeyRing, SigningKeyPair};
 use crate::light_client::tendermint::LightClient as TmLightClient;
 use crate::light_client::{LightClient, Verified};
 use crate::misbehaviour::MisbehaviourEvidence;
@@ -124,8 +127,10 @@ pub mod config;
 pub mod eip_base_fee;
 pub mod encode;
 pub mod estimate;
+pub mod failover;
 pub mod fee;
 pub mod gas;
+pub mod key_pool;
 pub mod query;
 pub mod retry;
 pub mod simulate;
@@ -158,10 +163,14 @@ pub struct CosmosSdkChain {
     grpc_addr: Uri,
     light_client: TmLightClient,
     rt: Arc<TokioRuntime>,
-    keybase: KeyRing<Secp256k1KeyPair>,
+    keybase: KeyRing<AnySecp256k1KeyPair>,
 
-    /// A cached copy of the account information
-    account: Option<Account>,
+    /// The full nodes this chain can connect to, and which one is in use
+    endpoints: EndpointPool,
+
+    /// The keys used to sign transactions, with a cached copy
+    /// of the account information of each of them
+    key_pool: KeyPool,
 
     tx_monitor_cmd: Option<TxEventSourceCmd>,
 }
@@ -177,7 +186,7 @@ impl CosmosSdkChain {
         self.config.max_tx_size.into()
     }
 
-    fn key(&self) -> Result<Secp256k1KeyPair, Error> {
+    fn key(&self) -> Result<AnySecp256k1KeyPair, Error> {
         self.keybase()
             .get_key(&self.config.key_name)
             .map_err(Error::key_base)
@@ -342,8 +351,25 @@ impl CosmosSdkChain {
             Mode::Push { url, batch_delay } => EventSource::websocket(
                 self.config.id.clone(),
                 url.clone(),
+                self.config.backup_websocket_addrs(),
+                self.compat_mode,
+                *batch_delay,
+                self.rt.clone(),
+            ),
+            Mode::Hybrid {
+                url,
+                batch_delay,
+                stall_timeout,
+                max_retries,
+            } => EventSource::hybrid(
+                self.config.id.clone(),
+                url.clone(),
+                self.config.backup_websocket_addrs(),
+                self.rpc_client.clone(),
                 self.compat_mode,
                 *batch_delay,
+                *stall_timeout,
+                *max_retries,
                 self.rt.clone(),
             ),
             Mode::Pull {
@@ -352,6 +378,7 @@ impl CosmosSdkChain {
             } => EventSource::rpc(
                 self.config.id.clone(),
                 self.rpc_client.clone(),
+                self.backup_rpc_clients()?,
                 *interval,
                 *max_retries,
                 self.rt.clone(),
@@ -364,6 +391,143 @@ impl CosmosSdkChain {
         Ok(monitor_tx)
     }
 
+    /// RPC clients for the backup endpoints, in order of preference,
+    /// for the pull-based event source to fail over to.
+    fn backup_rpc_clients(&self) -> Result<Vec<HttpClient>, Error> {
+        self.config
+            .backup_endpoints
+            .iter()
+            .map(|endpoint| build_rpc_client(&endpoint.rpc_addr, self.compat_mode))
+            .collect()
+    }
+
+    /// Check that the full node at the given index is reachable and caught up,
+    /// returning an RPC client for that node along with its node identifier.
+    fn probe_endpoint(&self, index: usize) -> Result<(HttpClient, node::Id), Error> {
+        let endpoint = self
+            .endpoints
+            .get(index)
+            .ok_or_else(|| Error::no_healthy_endpoint(self.id().clone()))?;
+
+        let rpc_client = build_rpc_client(&endpoint.rpc_addr, self.compat_mode)?;
+
+        let status = self
+            .block_on(rpc_client.status())
+            .map_err(|e| Error::rpc(endpoint.rpc_addr.clone(), e))?;
+
+        if status.sync_info.catching_up {
+            return Err(Error::chain_not_caught_up(
+                endpoint.rpc_addr.to_string(),
+                self.id().clone(),
+            ));
+        }
+
+        let grpc_addr = Uri::from_str(&endpoint.grpc_addr.to_string())
+            .map_err(|e| Error::invalid_uri(endpoint.grpc_addr.to_string(), e))?;
+
+        let mut client = self
+            .block_on(ServiceClient::connect(grpc_addr))
+            .map_err(Error::grpc_transport)?;
+
+        let sync_info = self
+            .block_on(client.get_syncing(tonic::Request::new(GetSyncingRequest {})))
+            .map_err(|e| Error::grpc_status(e, "get_syncing".to_string()))?
+            .into_inner();
+
+        if sync_info.syncing {
+            return Err(Error::chain_not_caught_up(
+                endpoint.grpc_addr.to_string(),
+                self.id().clone(),
+            ));
+        }
+
+        Ok((rpc_client, status.node_info.id))
+    }
+
+    /// Switch to the full node at the given index, rebuilding every
+    /// client which depends on the address of the node in use.
+    fn use_endpoint(
+        &mut self,
+        index: usize,
+        rpc_client: HttpClient,
+        node_id: node::Id,
+    ) -> Result<(), Error> {
+        let endpoint = self
+            .endpoints
+            .get(index)
+            .ok_or_else(|| Error::no_healthy_endpoint(self.id().clone()))?;
+
+        let grpc_addr = Uri::from_str(&endpoint.grpc_addr.to_string())
+            .map_err(|e| Error::invalid_uri(endpoint.grpc_addr.to_string(), e))?;
+
+        let mut config = self.config.clone();
+        config.rpc_addr = endpoint.rpc_addr.clone();
+        config.grpc_addr = endpoint.grpc_addr.clone();
+
+        if let (
+            EventSourceMode::Push { url, .. } | EventSourceMode::Hybrid { url, .. },
+            Some(websocket_addr),
+        ) = (&mut config.event_source, &endpoint.websocket_addr)
+        {
+            *url = websocket_addr.clone();
+        }
+
+        let light_client = TmLightClient::from_cosmos_sdk_config(&config, node_id)?;
+        let tx_config = TxConfig::try_from(&config)?;
+
+        self.config = config;
+        self.rpc_client = rpc_client;
+        self.grpc_addr = grpc_addr;
+        self.light_client = light_client;
+        self.tx_config = tx_config;
+        self.endpoints.switch_to(index);
+
+        Ok(())
+    }
+
+    /// Fail over to the next healthy full node, in the order
+    /// in which they are specified in the configuration.
+    ///
+    /// The event source is not affected, as it fails over on its own.
+    ///
+    /// If no endpoint is healthy, the next attempt to fail over before
+    /// a request is delayed, with an exponential backoff.
+    fn failover(&mut self) -> Result<(), Error> {
+        let previous_rpc_addr = self.config.rpc_addr.clone();
+
+        for index in self.endpoints.candidates() {
+            match self.probe_endpoint(index) {
+                Ok((rpc_client, node_id)) => {
+                    self.use_endpoint(index, rpc_client, node_id)?;
+
+                    warn!(
+                        "chain '{}' failed over from unhealthy node at {} to node at {}",
+                        self.id(),
+                        previous_rpc_addr,
+                        self.config.rpc_addr
+                    );
+
+                    crate::telemetry!(endpoint_failover, self.id(), "chain");
+
+                    return Ok(());
+                }
+                Err(e) => {
+                    self.endpoints.record_failure_at(index);
+
+                    debug!(
+                        "cannot fail over to endpoint #{index} of chain '{}': {}",
+                        self.id(),
+                        e.detail()
+                    );
+                }
+            }
+        }
+
+        self.endpoints.record_failed_failover();
+
+        Err(Error::no_healthy_endpoint(self.id().clone()))
+    }
+
     /// Performs a gRPC query to fetch CCV Consumer chain staking parameters.
     pub fn query_ccv_consumer_chain_params(&self) -> Result<CcvConsumerParams, Error> {
         crate::time!(
@@ -734,6 +898,24 @@ impl CosmosSdkChain {
         Ok(rpc_status)
     }
 
+    /// Query the latest height committed by the application,
+    /// along with the header of the block at that height.
+    fn query_latest_app_header(&self) -> Result<(TmHeight, TmBlockHeader), Error> {
+        // We cannot rely on `/status` endpoint to provide details about the latest block.
+        // Instead, we need to pull block height via `/abci_info` and then fetch block
+        // metadata at the given height via `/blockchain` endpoint.
+        let abci_info = self
+            .block_on(self.rpc_client.abci_info())
+            .map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
+
+        // Query `/header` endpoint to pull the latest block that the application committed.
+        let response = self
+            .block_on(self.rpc_client.header(abci_info.last_block_height))
+            .map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
+
+        Ok((abci_info.last_block_height, response.header))
+    }
+
     /// Query the chain's latest height
     pub fn query_chain_latest_height(&self) -> Result<ICSHeight, Error> {
         crate::time!(
@@ -773,13 +955,22 @@ impl CosmosSdkChain {
             }
         );
 
-        let proto_msgs = tracked_msgs.msgs;
+        let tx_config = self.tx_config.with_fee_bumps(tracked_msgs.fee_bumps);
+
+        let Assignment {
+            key_name,
+            key_pair,
+            msgs: proto_msgs,
+        } = self.key_pool.assign(&self.keybase, tracked_msgs.msgs)?;
 
-        let key_pair = self.key()?;
         let key_account = key_pair.account();
 
-        let account =
-            get_or_fetch_account(&self.grpc_addr, &key_account, &mut self.account).await?;
+        let account = get_or_fetch_account(
+            &self.grpc_addr,
+            &key_account,
+            self.key_pool.account_mut(&key_name),
+        )
+        .await?;
 
         let memo_prefix = if let Some(memo_overwrite) = &self.config.memo_overwrite {
             memo_overwrite.clone()
@@ -790,7 +981,7 @@ impl CosmosSdkChain {
         if self.config.sequential_batch_tx {
             sequential_send_batched_messages_and_wait_commit(
                 &self.rpc_client,
-                &self.tx_config,
+                &tx_config,
                 &key_pair,
                 account,
                 &memo_prefix,
@@ -800,7 +991,7 @@ impl CosmosSdkChain {
         } else {
             send_batched_messages_and_wait_commit(
                 &self.rpc_client,
-                &self.tx_config,
+                &tx_config,
                 &key_pair,
                 account,
                 &memo_prefix,
@@ -830,13 +1021,22 @@ impl CosmosSdkChain {
             }
         );
 
-        let proto_msgs = tracked_msgs.msgs;
+        let tx_config = self.tx_config.with_fee_bumps(tracked_msgs.fee_bumps);
+
+        let Assignment {
+            key_name,
+            key_pair,
+            msgs: proto_msgs,
+        } = self.key_pool.assign(&self.keybase, tracked_msgs.msgs)?;
 
-        let key_pair = self.key()?;
         let key_account = key_pair.account();
 
-        let account =
-            get_or_fetch_account(&self.grpc_addr, &key_account, &mut self.account).await?;
+        let account = get_or_fetch_account(
+            &self.grpc_addr,
+            &key_account,
+            self.key_pool.account_mut(&key_name),
+        )
+        .await?;
 
         let memo_prefix = if let Some(memo_overwrite) = &self.config.memo_overwrite {
             memo_overwrite.clone()
@@ -846,7 +1046,7 @@ impl CosmosSdkChain {
 
         send_batched_messages_and_wait_check_tx(
             &self.rpc_client,
-            &self.tx_config,
+            &tx_config,
             &key_pair,
             account,
             &memo_prefix,
@@ -978,7 +1178,7 @@ impl ChainEndpoint for CosmosSdkChain {
     type ConsensusState = TmConsensusState;
     type ClientState = TmClientState;
     type Time = TmTime;
-    type SigningKeyPair = Secp256k1KeyPair;
+    type SigningKeyPair = AnySecp256k1KeyPair;
 
     fn id(&self) -> &ChainId {
         &self.config.id
@@ -1004,11 +1204,13 @@ impl ChainEndpoint for CosmosSdkChain {
         let light_client = TmLightClient::from_cosmos_sdk_config(&config, node_info.id)?;
 
         // Initialize key store and load key
-        let keybase = KeyRing::new_secp256k1(
+        let keybase = KeyRing::new(
             config.key_store_type,
             &config.account_prefix,
             &config.id,
             &config.key_store_folder,
+            &config.key_store_passphrase,
+            &config.remote_signer,
         )
         .map_err(Error::key_base)?;
 
@@ -1017,6 +1219,10 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let tx_config = TxConfig::try_from(&config)?;
 
+        let endpoints = EndpointPool::from_config(&config);
+
+        let key_pool = KeyPool::from_config(&config);
+
         // Retrieve the version specification of this chain
 
         let chain = Self {
@@ -1028,7 +1234,8 @@ impl ChainEndpoint for CosmosSdkChain {
             rt,
             keybase,
             tx_config,
-            account: None,
+            endpoints,
+            key_pool,
             tx_monitor_cmd: None,
         };
 
@@ -1061,6 +1268,21 @@ impl ChainEndpoint for CosmosSdkChain {
         Ok(key_pair)
     }
 
+    fn get_keys(&self) -> Result<Vec<(String, Self::SigningKeyPair)>, Error> {
+        self.key_pool
+            .key_names()
+            .iter()
+            .map(|key_name| {
+                let key_pair = self
+                    .keybase()
+                    .get_key(key_name)
+                    .map_err(|e| Error::key_not_found(key_name.clone(), e))?;
+
+                Ok((key_name.clone(), key_pair))
+            })
+            .collect()
+    }
+
     fn subscribe(&mut self) -> Result<Subscription, Error> {
         let tx_monitor_cmd = match &self.tx_monitor_cmd {
             Some(tx_monitor_cmd) => tx_monitor_cmd,
@@ -1086,10 +1308,26 @@ impl ChainEndpoint for CosmosSdkChain {
     /// Emits a log warning in case anything is amiss.
     /// Exits early if any health check fails, without doing any
     /// further checks.
+    ///
+    /// If the check fails and backup endpoints are configured, fails over
+    /// to the next node and checks it in turn, making at most one pass over
+    /// the backup endpoints before giving up.
     fn health_check(&mut self) -> Result<HealthCheck, Error> {
-        if let Err(e) = do_health_check(self) {
+        let mut remaining_backups = self.config.backup_endpoints.len();
+
+        while let Err(e) = do_health_check(self) {
             warn!("health check failed for chain '{}'", self.id());
             warn!("reason: {}", e.detail());
+
+            if remaining_backups > 0 {
+                remaining_backups -= 1;
+                self.endpoints.mark_unhealthy();
+
+                if self.failover().is_ok() {
+                    continue;
+                }
+            }
+
             warn!("some Hermes features may not work in this mode!");
 
             return Ok(HealthCheck::Unhealthy(Box::new(e)));
@@ -1106,6 +1344,14 @@ impl ChainEndpoint for CosmosSdkChain {
         Ok(HealthCheck::Healthy)
     }
 
+    fn maybe_failover(&mut self) -> Result<(), Error> {
+        if self.endpoints.should_failover() {
+            self.failover()?;
+        }
+
+        Ok(())
+    }
+
     /// Fetch a header from the chain at the given height and verify it.
     fn verify_header(
         &mut self,
@@ -1265,25 +1511,24 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_application_status");
 
-        // We cannot rely on `/status` endpoint to provide details about the latest block.
-        // Instead, we need to pull block height via `/abci_info` and then fetch block
-        // metadata at the given height via `/blockchain` endpoint.
-        let abci_info = self
-            .block_on(self.rpc_client.abci_info())
-            .map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
+        // Failures of this query, which is issued regularly by the workers,
+        // count towards deciding whether to fail over to another node.
+        let result = self.query_latest_app_header();
 
-        // Query `/header` endpoint to pull the latest block that the application committed.
-        let response = self
-            .block_on(self.rpc_client.header(abci_info.last_block_height))
-            .map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
+        match &result {
+            Ok(_) => self.endpoints.record_success(),
+            Err(_) => self.endpoints.record_failure(),
+        }
+
+        let (last_block_height, header) = result?;
 
         let height = ICSHeight::new(
-            ChainId::chain_version(response.header.chain_id.as_str()),
-            u64::from(abci_info.last_block_height),
+            ChainId::chain_version(header.chain_id.as_str()),
+            u64::from(last_block_height),
         )
         .map_err(|_| Error::invalid_height_no_source())?;
 
-        let timestamp = response.header.time.into();
+        let timestamp = header.time.into();
         Ok(ChainStatus { height, timestamp })
     }
 
@@ -1324,6 +1569,10 @@ impl ChainEndpoint for CosmosSdkChain {
             .into_iter()
             .filter_map(|cs| {
                 IdentifiedAnyClientState::try_from(cs.clone())
+                    .map(|mut client| {
+                        client.client_state = client.client_state.with_host_chain_id(self.id());
+                        client
+                    })
                     .map_err(|e| {
                         let (client_type, client_id) = (if let Some(client_state) = &cs.client_state { client_state.type_url.clone() } else { "None".to_string() }, &cs.client_id);
                         warn!("encountered unsupported client type `{}` while scanning client `{}`, skipping the client", client_type, client_id);
@@ -1357,7 +1606,9 @@ impl ChainEndpoint for CosmosSdkChain {
             request.height,
             matches!(include_proof, IncludeProof::Yes),
         )?;
-        let client_state = AnyClientState::decode_vec(&res.value).map_err(Error::decode)?;
+        let client_state = AnyClientState::decode_vec(&res.value)
+            .map_err(Error::decode)?
+            .with_host_chain_id(self.id());
 
         match include_proof {
             IncludeProof::Yes => {
@@ -1462,7 +1713,10 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let consensus_state = AnyConsensusState::decode_vec(&res.value).map_err(Error::decode)?;
 
-        if !matches!(consensus_state, AnyConsensusState::Tendermint(_)) {
+        if !matches!(
+            consensus_state,
+            AnyConsensusState::Tendermint(_) | AnyConsensusState::Wasm(_)
+        ) {
             return Err(Error::consensus_state_type_mismatch(
                 ClientType::Tendermint,
                 consensus_state.client_type(),
@@ -1893,7 +2147,11 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let client_state: Option<IdentifiedAnyClientState> = response
             .identified_client_state
-            .map_or_else(|| None, |proto_cs| proto_cs.try_into().ok());
+            .map_or_else(|| None, |proto_cs| proto_cs.try_into().ok())
+            .map(|mut client: IdentifiedAnyClientState| {
+                client.client_state = client.client_state.with_host_chain_id(self.id());
+                client
+            });
 
         Ok(client_state)
     }
@@ -2512,7 +2770,12 @@ impl ChainEndpoint for CosmosSdkChain {
                 "src_chain": self.config().id.to_string(),
             }
         );
-        let ClientSettings::Tendermint(settings) = settings;
+        let ClientSettings::Tendermint(settings) = settings else {
+            return Err(Error::client_type_mismatch(
+                ClientType::Tendermint,
+                ClientType::SoloMachine,
+            ));
+        };
         let unbonding_period = self.unbonding_period()?;
         let trusting_period = settings
             .trusting_period
@@ -2597,7 +2860,7 @@ impl ChainEndpoint for CosmosSdkChain {
             &self.rpc_client,
             &self.tx_config,
             &key_pair,
-            &mut self.account,
+            self.key_pool.account_mut(&self.config.key_name),
             &memo_prefix,
             channel_id,
             port_id,
@@ -2725,6 +2988,34 @@ impl ChainEndpoint for CosmosSdkChain {
             IncludeProof::No => Ok((error_receipt, None)),
         }
     }
+
+    fn query_upgrade_plan(&self) -> Result<Option<Plan>, Error> {
+        crate::time!(
+            "query_upgrade_plan",
+            {
+                "src_chain": self.config().id.to_string(),
+            }
+        );
+        crate::telemetry!(query, self.id(), "query_upgrade_plan");
+
+        let mut client = self
+            .block_on(
+                ibc_proto::cosmos::upgrade::v1beta1::query_client::QueryClient::connect(
+                    self.grpc_addr.clone(),
+                ),
+            )
+            .map_err(Error::grpc_transport)?;
+
+        let request =
+            tonic::Request::new(ibc_proto::cosmos::upgrade::v1beta1::QueryCurrentPlanRequest {});
+
+        let response = self
+            .block_on(client.current_plan(request))
+            .map_err(|e| Error::grpc_status(e, "query_upgrade_plan".to_owned()))?
+            .into_inner();
+
+        Ok(response.plan)
+    }
 }
 
 fn sort_events_by_sequence(events: &mut [IbcEventWithHeight]) {
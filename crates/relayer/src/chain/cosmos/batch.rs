@@ -19,7 +19,7 @@ use crate::chain::cosmos::wait::wait_for_block_commits;
 use crate::config::types::Memo;
 use crate::error::Error;
 use crate::event::IbcEventWithHeight;
-use crate::keyring::Secp256k1KeyPair;
+use crate::keyring::AnySecp256k1KeyPair;
 
 /**
    Broadcast messages as multiple batched transactions to the chain all at once,
@@ -31,7 +31,7 @@ use crate::keyring::Secp256k1KeyPair;
 pub async fn send_batched_messages_and_wait_commit(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &AnySecp256k1KeyPair,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -68,7 +68,7 @@ pub async fn send_batched_messages_and_wait_commit(
 pub async fn sequential_send_batched_messages_and_wait_commit(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &AnySecp256k1KeyPair,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -93,7 +93,7 @@ pub async fn sequential_send_batched_messages_and_wait_commit(
 pub async fn send_batched_messages_and_wait_check_tx(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &AnySecp256k1KeyPair,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -121,7 +121,7 @@ pub async fn send_batched_messages_and_wait_check_tx(
 async fn send_messages_as_batches(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &AnySecp256k1KeyPair,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -162,7 +162,7 @@ async fn send_messages_as_batches(
 async fn sequential_send_messages_as_batches(
     rpc_client: &HttpClient,
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &AnySecp256k1KeyPair,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -240,7 +240,7 @@ fn response_to_tx_sync_result(
 
 async fn batch_messages(
     config: &TxConfig,
-    key_pair: &Secp256k1KeyPair,
+    key_pair: &AnySecp256k1KeyPair,
     account: &Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -325,14 +325,14 @@ mod tests {
     use crate::chain::cosmos::types::config::TxConfig;
     use crate::config;
     use crate::config::types::{MaxMsgNum, MaxTxSize, Memo};
-    use crate::keyring::{self, KeyRing, Secp256k1KeyPair, SigningKeyPair};
+    use crate::keyring::{self, AnySecp256k1KeyPair, KeyRing, SigningKeyPair};
     use ibc_proto::google::protobuf::Any;
     use ibc_relayer_types::core::ics24_host::identifier::ChainId;
     use std::fs;
 
     const COSMOS_HD_PATH: &str = "m/44'/118'/0'/0/0";
 
-    fn test_fixture() -> (TxConfig, Secp256k1KeyPair, Account) {
+    fn test_fixture() -> (TxConfig, AnySecp256k1KeyPair, Account) {
         let path = concat!(
             env!("CARGO_MANIFEST_DIR"),
             "/tests/config/fixtures/relayer_conf_example.toml"
@@ -357,10 +357,12 @@ mod tests {
             "cosmos",
             &chain_id,
             &chain_config.key_store_folder,
+            &chain_config.key_store_passphrase,
+            &chain_config.remote_signer,
         )
         .unwrap();
         let hd_path = COSMOS_HD_PATH.parse().unwrap();
-        let key_pair = Secp256k1KeyPair::from_seed_file(&seed_file_content, &hd_path).unwrap();
+        let key_pair = AnySecp256k1KeyPair::from_seed_file(&seed_file_content, &hd_path).unwrap();
 
         let account = Account {
             address: AccountAddress::new("".to_owned()),
@@ -1,9 +1,10 @@
 use core::time::Duration;
+use std::collections::HashSet;
 use std::path::PathBuf;
 
 use byte_unit::Byte;
 use serde_derive::{Deserialize, Serialize};
-use tendermint_rpc::Url;
+use tendermint_rpc::{Url, WebSocketClientUrl};
 
 use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
@@ -11,13 +12,16 @@ use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use crate::chain::cosmos::config::error::Error as ConfigError;
 use crate::config::compat_mode::CompatMode;
 use crate::config::dynamic_gas::DynamicGasPrice;
+use crate::config::fee_bump::FeeBump;
+use crate::config::fee_priority::FeePriority;
 use crate::config::gas_multiplier::GasMultiplier;
+use crate::config::rate_limit::RateLimitConfig;
 use crate::config::types::{MaxMsgNum, MaxTxSize, Memo, TrustThreshold};
 use crate::config::{
     self, AddressType, EventSourceMode, ExtensionOption, GasPrice, GenesisRestart, PacketFilter,
 };
 use crate::config::{default, RefreshRate};
-use crate::keyring::Store;
+use crate::keyring::{PassphraseSource, RemoteSignerConfig, Store};
 use crate::util::excluded_sequences::ExcludedSequences;
 
 pub mod error;
@@ -50,6 +54,11 @@ pub struct CosmosSdkConfig {
     #[serde(default)]
     pub key_store_type: Store,
     pub key_store_folder: Option<PathBuf>,
+    /// Where to get the passphrase of the encrypted `File` key store from
+    #[serde(default)]
+    pub key_store_passphrase: PassphraseSource,
+    /// The remote signer holding the keys of the `Remote` key store
+    pub remote_signer: Option<RemoteSignerConfig>,
     pub store_prefix: String,
     pub default_gas: Option<u64>,
     pub max_gas: Option<u64>,
@@ -118,6 +127,14 @@ pub struct CosmosSdkConfig {
     #[serde(default)]
     pub sequential_batch_tx: bool,
 
+    /// Whether to keep the light blocks verified for this chain in a cache on disk
+    #[serde(default)]
+    pub light_block_cache: bool,
+
+    /// The folder of the light block cache, by default the `light_blocks` folder of the `data_dir`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub light_block_cache_folder: Option<PathBuf>,
+
     // Note: These last few need to be last otherwise we run into `ValueAfterTable` error when serializing to TOML.
     //       That's because these are all tables and have to come last when serializing.
     #[serde(
@@ -141,10 +158,30 @@ pub struct CosmosSdkConfig {
     #[serde(default)]
     pub dynamic_gas_price: DynamicGasPrice,
 
+    #[serde(default)]
+    pub fee_bump: FeeBump,
+
+    /// Ordering of the packets relayed to this chain by the value of their fees
+    #[serde(default)]
+    pub fee_priority: FeePriority,
+
+    /// Limits on the rate at which packets are relayed to this chain
+    #[serde(default)]
+    pub rate_limit: RateLimitConfig,
+
     #[serde(default)]
     pub address_type: AddressType,
+
+    /// Additional keys used along with `key_name` to sign transactions
+    #[serde(default, skip_serializing_if = "KeyPoolConfig::is_empty")]
+    pub key_pool: KeyPoolConfig,
     #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
     pub extension_options: Vec<ExtensionOption>,
+
+    /// Additional full nodes to fail over to, in order of preference,
+    /// whenever the node at `rpc_addr`/`grpc_addr` becomes unhealthy.
+    #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
+    pub backup_endpoints: Vec<EndpointConfig>,
     pub compat_mode: Option<CompatMode>,
     pub clear_interval: Option<u64>,
     #[serde(default)]
@@ -154,12 +191,79 @@ pub struct CosmosSdkConfig {
     pub allow_ccq: bool,
 }
 
+/// The addresses of a full node that Hermes can fail over to.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct EndpointConfig {
+    /// The RPC URL to connect to
+    pub rpc_addr: Url,
+
+    /// The gRPC URL to connect to
+    pub grpc_addr: Url,
+
+    /// The WebSocket URL to connect to, required if the event source is in `push` mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub websocket_addr: Option<WebSocketClientUrl>,
+}
+
+/// A pool of keys used, along with the key specified by `key_name`,
+/// to sign the transactions submitted to a chain.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct KeyPoolConfig {
+    /// The names of the additional keys
+    #[serde(default)]
+    pub key_names: Vec<String>,
+
+    /// How batches of messages are assigned to the keys of the pool
+    #[serde(default)]
+    pub selection: KeySelection,
+}
+
+impl KeyPoolConfig {
+    pub fn is_empty(&self) -> bool {
+        self.key_names.is_empty()
+    }
+}
+
+/// How batches of messages are assigned to the keys of a pool.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeySelection {
+    /// Use each key in turn
+    #[default]
+    RoundRobin,
+
+    /// Pick the key from a hash of the channel the packets of a batch belong to,
+    /// so that the packets of a given channel are always signed by the same key
+    Hash,
+}
+
 impl CosmosSdkConfig {
     pub fn validate(&self) -> Result<(), Diagnostic<ConfigError>> {
         validate_trust_threshold(&self.id, self.trust_threshold)?;
         validate_gas_settings(&self.id, self.gas_adjustment)?;
+        validate_backup_endpoints(&self.id, &self.event_source, &self.backup_endpoints)?;
+        validate_key_pool(&self.id, &self.key_name, &self.key_pool)?;
         Ok(())
     }
+
+    /// The names of all the keys used to sign transactions,
+    /// starting with the one specified by `key_name`.
+    pub fn key_names(&self) -> Vec<String> {
+        core::iter::once(&self.key_name)
+            .chain(&self.key_pool.key_names)
+            .cloned()
+            .collect()
+    }
+
+    /// The WebSocket URLs of the backup endpoints, in order of preference.
+    pub fn backup_websocket_addrs(&self) -> Vec<WebSocketClientUrl> {
+        self.backup_endpoints
+            .iter()
+            .filter_map(|endpoint| endpoint.websocket_addr.clone())
+            .collect()
+    }
 }
 
 /// Check that the trust threshold is:
@@ -215,6 +319,55 @@ fn validate_gas_settings(
 
     Ok(())
 }
+/// Check that every backup endpoint specifies a WebSocket URL
+/// if the event source is configured in `push` or `hybrid` mode.
+fn validate_backup_endpoints(
+    id: &ChainId,
+    event_source: &EventSourceMode,
+    backup_endpoints: &[EndpointConfig],
+) -> Result<(), Diagnostic<ConfigError>> {
+    if !matches!(
+        event_source,
+        EventSourceMode::Push { .. } | EventSourceMode::Hybrid { .. }
+    ) {
+        return Ok(());
+    }
+
+    if let Some(endpoint) = backup_endpoints
+        .iter()
+        .find(|endpoint| endpoint.websocket_addr.is_none())
+    {
+        return Err(Diagnostic::Error(
+            ConfigError::missing_backup_websocket_addr(id.clone(), endpoint.rpc_addr.to_string()),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Check that the keys of the pool are all distinct from each other,
+/// and from the key specified by `key_name`.
+fn validate_key_pool(
+    id: &ChainId,
+    key_name: &str,
+    key_pool: &KeyPoolConfig,
+) -> Result<(), Diagnostic<ConfigError>> {
+    let mut seen = HashSet::from([key_name]);
+
+    if let Some(duplicate) = key_pool
+        .key_names
+        .iter()
+        .find(|name| !seen.insert(name.as_str()))
+    {
+        return Err(Diagnostic::Error(ConfigError::duplicate_pool_key(
+            id.clone(),
+            duplicate.clone(),
+        )));
+    }
+
+    Ok(())
+}
+
 #[derive(Clone, Debug)]
 pub enum Diagnostic<E> {
     Warning(E),
@@ -31,6 +31,28 @@ define_error! {
             )
         },
 
+        MissingBackupWebsocketAddr
+        {
+            chain_id: ChainId,
+            rpc_addr: String,
+        }
+        |e| {
+            format!("config file specifies a backup endpoint with RPC address `{1}` for the chain '{0}' \
+                without a `websocket_addr`, which is required when the event source is in `push` or `hybrid` mode",
+                e.chain_id, e.rpc_addr)
+        },
+
+        DuplicatePoolKey
+        {
+            chain_id: ChainId,
+            key_name: String,
+        }
+        |e| {
+            format!("config file specifies the key `{1}` more than once for the chain '{0}', \
+                either in its `key_pool` or as its `key_name`",
+                e.chain_id, e.key_name)
+        },
+
         ExpectedExcludedSequencesArray
         |_| { "expected excluded_sequences to be an array of values" },
 
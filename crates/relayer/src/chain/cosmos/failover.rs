@@ -0,0 +1,329 @@
+//! Tracking of the health of the full nodes configured for a Cosmos chain,
+//! used to decide when and where to fail over.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use core::time::Duration;
+use std::time::Instant;
+
+use tendermint_rpc::client::CompatMode;
+use tendermint_rpc::{HttpClient, Url, WebSocketClientUrl};
+
+use crate::chain::cosmos::config::{CosmosSdkConfig, EndpointConfig};
+use crate::config::EventSourceMode;
+use crate::error::Error;
+use crate::HERMES_VERSION;
+
+/// Number of consecutive failed queries after which
+/// an endpoint is considered unhealthy.
+pub const MAX_CONSECUTIVE_FAILURES: u32 = 3;
+
+/// How long to wait before trying to fail over again after a failed attempt,
+/// doubled after each consecutive failed attempt up to [`MAX_FAILOVER_BACKOFF`].
+const MIN_FAILOVER_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Upper bound of the delay between two failed attempts to fail over.
+const MAX_FAILOVER_BACKOFF: Duration = Duration::from_secs(60);
+
+/// A full node Hermes can connect to, along with its health score.
+#[derive(Debug)]
+pub struct Endpoint {
+    pub rpc_addr: Url,
+    pub grpc_addr: Url,
+    pub websocket_addr: Option<WebSocketClientUrl>,
+
+    /// Number of consecutive failures observed for this endpoint
+    consecutive_failures: AtomicU32,
+}
+
+impl Endpoint {
+    fn new(rpc_addr: Url, grpc_addr: Url, websocket_addr: Option<WebSocketClientUrl>) -> Self {
+        Self {
+            rpc_addr,
+            grpc_addr,
+            websocket_addr,
+            consecutive_failures: AtomicU32::new(0),
+        }
+    }
+
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures.load(Ordering::Relaxed)
+    }
+
+    pub fn is_healthy(&self) -> bool {
+        self.consecutive_failures() < MAX_CONSECUTIVE_FAILURES
+    }
+}
+
+impl From<&EndpointConfig> for Endpoint {
+    fn from(config: &EndpointConfig) -> Self {
+        Self::new(
+            config.rpc_addr.clone(),
+            config.grpc_addr.clone(),
+            config.websocket_addr.clone(),
+        )
+    }
+}
+
+/// The ordered list of full nodes configured for a chain, the first
+/// one being the primary node specified by `rpc_addr` and `grpc_addr`.
+///
+/// Failures are recorded through a shared reference so that they can be
+/// tracked from query paths, while switching to another endpoint
+/// requires exclusive access to the chain.
+#[derive(Debug)]
+pub struct EndpointPool {
+    endpoints: Vec<Endpoint>,
+    current: usize,
+
+    /// Number of consecutive attempts to fail over which found no healthy endpoint
+    failed_failovers: u32,
+
+    /// When the last attempt to fail over failed, if it did
+    last_failed_failover: Option<Instant>,
+}
+
+impl EndpointPool {
+    pub fn from_config(config: &CosmosSdkConfig) -> Self {
+        let websocket_addr = match &config.event_source {
+            EventSourceMode::Push { url, .. } | EventSourceMode::Hybrid { url, .. } => {
+                Some(url.clone())
+            }
+            EventSourceMode::Pull { .. } => None,
+        };
+
+        let primary = Endpoint::new(
+            config.rpc_addr.clone(),
+            config.grpc_addr.clone(),
+            websocket_addr,
+        );
+
+        let endpoints = core::iter::once(primary)
+            .chain(config.backup_endpoints.iter().map(Endpoint::from))
+            .collect();
+
+        Self {
+            endpoints,
+            current: 0,
+            failed_failovers: 0,
+            last_failed_failover: None,
+        }
+    }
+
+    /// Whether or not there is any other endpoint to fail over to.
+    pub fn has_backups(&self) -> bool {
+        self.endpoints.len() > 1
+    }
+
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    pub fn current(&self) -> &Endpoint {
+        &self.endpoints[self.current]
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Endpoint> {
+        self.endpoints.get(index)
+    }
+
+    /// Reset the failure count of the endpoint currently in use.
+    pub fn record_success(&self) {
+        self.current()
+            .consecutive_failures
+            .store(0, Ordering::Relaxed);
+    }
+
+    /// Record a failure for the endpoint currently in use.
+    pub fn record_failure(&self) {
+        self.record_failure_at(self.current);
+    }
+
+    /// Record a failure for the endpoint at the given index.
+    pub fn record_failure_at(&self, index: usize) {
+        if let Some(endpoint) = self.endpoints.get(index) {
+            endpoint
+                .consecutive_failures
+                .fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Mark the endpoint currently in use as unhealthy,
+    /// regardless of how many failures were recorded so far.
+    pub fn mark_unhealthy(&self) {
+        self.current()
+            .consecutive_failures
+            .fetch_max(MAX_CONSECUTIVE_FAILURES, Ordering::Relaxed);
+    }
+
+    /// Whether the current endpoint is unhealthy and there is
+    /// another endpoint to fail over to, unless the last attempt
+    /// to fail over failed too recently.
+    pub fn should_failover(&self) -> bool {
+        self.has_backups() && !self.current().is_healthy() && !self.is_backing_off()
+    }
+
+    /// Whether the last attempt to fail over failed less than the backoff ago,
+    /// in which case the endpoints are not probed again yet.
+    fn is_backing_off(&self) -> bool {
+        self.last_failed_failover
+            .is_some_and(|failed_at| failed_at.elapsed() < self.failover_backoff())
+    }
+
+    /// How long to wait after the last failed attempt to fail over.
+    fn failover_backoff(&self) -> Duration {
+        let exponent = self.failed_failovers.saturating_sub(1).min(16);
+
+        MIN_FAILOVER_BACKOFF
+            .saturating_mul(1 << exponent)
+            .min(MAX_FAILOVER_BACKOFF)
+    }
+
+    /// Record that an attempt to fail over found no healthy endpoint,
+    /// delaying the next attempt.
+    pub fn record_failed_failover(&mut self) {
+        self.failed_failovers = self.failed_failovers.saturating_add(1);
+        self.last_failed_failover = Some(Instant::now());
+    }
+
+    /// The indices of the endpoints to try when failing over, in order of preference.
+    ///
+    /// This starts from the endpoint following the current one and wraps around,
+    /// trying endpoints which are deemed healthy before those which are not.
+    pub fn candidates(&self) -> Vec<usize> {
+        let len = self.endpoints.len();
+
+        let (healthy, unhealthy): (Vec<_>, Vec<_>) = (1..len)
+            .map(|offset| (self.current + offset) % len)
+            .partition(|&index| self.endpoints[index].is_healthy());
+
+        healthy.into_iter().chain(unhealthy).collect()
+    }
+
+    /// Switch to the endpoint at the given index and reset its failure count.
+    pub fn switch_to(&mut self, index: usize) {
+        assert!(index < self.endpoints.len(), "endpoint index out of bounds");
+
+        self.current = index;
+        self.failed_failovers = 0;
+        self.last_failed_failover = None;
+        self.record_success();
+    }
+}
+
+/// Build an RPC client for the given address, using the given compatibility mode.
+pub fn build_rpc_client(rpc_addr: &Url, compat_mode: CompatMode) -> Result<HttpClient, Error> {
+    let mut rpc_client = HttpClient::builder(rpc_addr.clone().try_into().unwrap())
+        .user_agent(format!("hermes/{}", HERMES_VERSION))
+        .build()
+        .map_err(|e| Error::rpc(rpc_addr.clone(), e))?;
+
+    rpc_client.set_compat_mode(compat_mode);
+
+    Ok(rpc_client)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoint(n: u16) -> EndpointConfig {
+        EndpointConfig {
+            rpc_addr: format!("http://127.0.0.1:{}", 26657 + n).parse().unwrap(),
+            grpc_addr: format!("http://127.0.0.1:{}", 9090 + n).parse().unwrap(),
+            websocket_addr: None,
+        }
+    }
+
+    fn pool(backups: u16) -> EndpointPool {
+        let primary = endpoint(0);
+
+        let endpoints = core::iter::once(&primary)
+            .chain((1..=backups).map(endpoint).collect::<Vec<_>>().iter())
+            .map(Endpoint::from)
+            .collect();
+
+        EndpointPool {
+            endpoints,
+            current: 0,
+            failed_failovers: 0,
+            last_failed_failover: None,
+        }
+    }
+
+    #[test]
+    fn failover_after_consecutive_failures() {
+        let pool = pool(1);
+
+        for _ in 0..MAX_CONSECUTIVE_FAILURES - 1 {
+            pool.record_failure();
+        }
+        assert!(!pool.should_failover());
+
+        pool.record_success();
+        pool.record_failure();
+        assert!(!pool.should_failover());
+
+        for _ in 0..MAX_CONSECUTIVE_FAILURES {
+            pool.record_failure();
+        }
+        assert!(pool.should_failover());
+    }
+
+    #[test]
+    fn no_failover_without_backups() {
+        let pool = pool(0);
+
+        pool.mark_unhealthy();
+
+        assert!(!pool.current().is_healthy());
+        assert!(!pool.should_failover());
+        assert!(pool.candidates().is_empty());
+    }
+
+    #[test]
+    fn candidates_prefer_healthy_endpoints() {
+        let mut pool = pool(3);
+
+        pool.mark_unhealthy();
+        pool.record_failure_at(2);
+        pool.record_failure_at(2);
+        pool.record_failure_at(2);
+
+        assert_eq!(pool.candidates(), vec![1, 3, 2]);
+
+        pool.switch_to(3);
+
+        assert_eq!(pool.current_index(), 3);
+        assert!(pool.current().is_healthy());
+        assert_eq!(pool.candidates(), vec![1, 0, 2]);
+    }
+
+    #[test]
+    fn failed_failovers_back_off() {
+        let mut pool = pool(1);
+
+        pool.mark_unhealthy();
+        assert!(pool.should_failover());
+
+        pool.record_failed_failover();
+        assert!(!pool.should_failover());
+        assert_eq!(pool.failover_backoff(), MIN_FAILOVER_BACKOFF);
+
+        pool.record_failed_failover();
+        assert_eq!(pool.failover_backoff(), MIN_FAILOVER_BACKOFF * 2);
+
+        for _ in 0..32 {
+            pool.record_failed_failover();
+        }
+        assert_eq!(pool.failover_backoff(), MAX_FAILOVER_BACKOFF);
+
+        // The backoff is over once the last failure is old enough
+        pool.last_failed_failover = Instant::now().checked_sub(MAX_FAILOVER_BACKOFF);
+        assert!(pool.should_failover());
+
+        pool.record_failed_failover();
+        pool.switch_to(1);
+        pool.mark_unhealthy();
+        assert!(pool.should_failover());
+    }
+}
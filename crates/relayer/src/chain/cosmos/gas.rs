@@ -5,7 +5,7 @@ use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use num_bigint::BigInt;
 use num_rational::BigRational;
 use tendermint_rpc::Url;
-use tracing::warn;
+use tracing::{debug, warn};
 
 use crate::chain::cosmos::types::gas::GasConfig;
 use crate::config::GasPrice;
@@ -27,7 +27,8 @@ pub async fn gas_amount_to_fee(
 
     // The fee in coins based on gas amount
     let dynamic_gas_price = dynamic_gas_price(config, chain_id, rpc_address).await;
-    let amount = calculate_fee(adjusted_gas_limit, &dynamic_gas_price);
+    let gas_price = bumped_gas_price(config, dynamic_gas_price);
+    let amount = calculate_fee(adjusted_gas_limit, &gas_price);
 
     Fee {
         amount: vec![amount],
@@ -90,6 +91,23 @@ pub async fn dynamic_gas_price(
     }
 }
 
+/// Escalates the given gas price according to the `fee_bump` policy of the chain,
+/// when resubmitting a transaction which stayed unconfirmed.
+pub fn bumped_gas_price(config: &GasConfig, gas_price: GasPrice) -> GasPrice {
+    if !config.fee_bump.enabled || config.fee_bumps == 0 {
+        return gas_price;
+    }
+
+    let bumped_price = config.fee_bump.bump(gas_price.price, config.fee_bumps);
+
+    debug!(
+        bumps = config.fee_bumps,
+        "bumping gas price of resubmitted transaction from {} to {}", gas_price.price, bumped_price
+    );
+
+    GasPrice::new(bumped_price, gas_price.denom)
+}
+
 pub fn calculate_fee(adjusted_gas_amount: u64, gas_price: &GasPrice) -> Coin {
     let fee_amount = mul_ceil(adjusted_gas_amount, gas_price.price);
 
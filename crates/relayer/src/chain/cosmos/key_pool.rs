@@ -0,0 +1,407 @@
+//! Assignment of the batches of messages submitted to a Cosmos chain
+//! to the keys of its key pool, each with its own account sequence,
+//! so that transactions signed by different keys can land in the same block.
+//!
+//! The messages of a batch are built by the relayer with the address of the key
+//! specified by `key_name` as their signer. When a batch is assigned to another
+//! key of the pool, the signer of its messages is therefore replaced with the
+//! address of that key. This is only done for batches made of IBC core messages,
+//! whose signer is only used to authenticate the relayer: any other message,
+//! e.g. a `MsgTransfer` whose sender is the relayer, is always signed with
+//! the key specified by `key_name`.
+
+use core::hash::{Hash, Hasher};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::channel::v1::{
+    MsgAcknowledgement, MsgRecvPacket, MsgTimeout, MsgTimeoutOnClose, Packet,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::{
+    acknowledgement, recv_packet, timeout, timeout_on_close,
+};
+use prost::Message;
+use tracing::debug;
+
+use crate::chain::cosmos::config::{CosmosSdkConfig, KeySelection};
+use crate::chain::cosmos::types::account::Account;
+use crate::error::Error;
+use crate::keyring::{KeyRing, SigningKeyPairSized};
+
+/// Prefix of the type URL of the IBC core messages.
+const IBC_CORE_TYPE_URL_PREFIX: &str = "/ibc.core.";
+
+/// The keys used to sign the transactions submitted to a chain,
+/// along with the cached account information of each of them.
+#[derive(Debug)]
+pub struct KeyPool {
+    /// The names of the keys, starting with the one specified by `key_name`
+    key_names: Vec<String>,
+    selection: KeySelection,
+    /// Index of the key to use for the next batch, with round-robin selection
+    next: usize,
+    /// A cached copy of the account information of each key
+    accounts: HashMap<String, Option<Account>>,
+}
+
+/// The key a batch of messages is assigned to, along with the messages
+/// to sign with it.
+pub struct Assignment<K> {
+    pub key_name: String,
+    pub key_pair: K,
+    pub msgs: Vec<Any>,
+}
+
+impl KeyPool {
+    pub fn from_config(config: &CosmosSdkConfig) -> Self {
+        Self {
+            key_names: config.key_names(),
+            selection: config.key_pool.selection,
+            next: 0,
+            accounts: HashMap::new(),
+        }
+    }
+
+    /// The names of the keys of the pool, starting with the one specified by `key_name`.
+    pub fn key_names(&self) -> &[String] {
+        &self.key_names
+    }
+
+    /// The name of the key specified by `key_name`.
+    pub fn main_key_name(&self) -> &str {
+        &self.key_names[0]
+    }
+
+    /// The cached account information of the given key, if any.
+    pub fn account_mut(&mut self, key_name: &str) -> &mut Option<Account> {
+        self.accounts.entry(key_name.to_string()).or_default()
+    }
+
+    /// Assign the given batch of messages to a key of the pool, replacing
+    /// the signer of the messages if they are assigned to another key than
+    /// the one specified by `key_name`.
+    pub fn assign<K: SigningKeyPairSized>(
+        &mut self,
+        keybase: &KeyRing<K>,
+        msgs: Vec<Any>,
+    ) -> Result<Assignment<K>, Error> {
+        let main_key = self.get_key(keybase, 0)?;
+
+        let index = self.select(&msgs);
+        if index == 0 {
+            return Ok(self.main_assignment(main_key, msgs));
+        }
+
+        let key_pair = self.get_key(keybase, index)?;
+        let (signer, new_signer) = (main_key.account(), key_pair.account());
+
+        let replaced = msgs
+            .iter()
+            .map(|msg| replace_signer(msg, &signer, &new_signer))
+            .collect::<Option<Vec<_>>>();
+
+        match replaced {
+            Some(replaced) => Ok(Assignment {
+                key_name: self.key_names[index].clone(),
+                key_pair,
+                msgs: replaced,
+            }),
+            None => {
+                debug!(
+                    key_name = %self.key_names[index],
+                    "could not replace the signer of the messages, signing them with the main key instead"
+                );
+
+                Ok(self.main_assignment(main_key, msgs))
+            }
+        }
+    }
+
+    fn main_assignment<K>(&self, key_pair: K, msgs: Vec<Any>) -> Assignment<K> {
+        Assignment {
+            key_name: self.main_key_name().to_string(),
+            key_pair,
+            msgs,
+        }
+    }
+
+    fn get_key<K: SigningKeyPairSized>(
+        &self,
+        keybase: &KeyRing<K>,
+        index: usize,
+    ) -> Result<K, Error> {
+        let key_name = &self.key_names[index];
+
+        keybase
+            .get_key(key_name)
+            .map_err(|e| Error::key_not_found(key_name.clone(), e))
+    }
+
+    /// Select the index of the key to assign the given batch of messages to.
+    fn select(&mut self, msgs: &[Any]) -> usize {
+        let pool_size = self.key_names.len();
+
+        if pool_size == 1
+            || !msgs
+                .iter()
+                .all(|msg| msg.type_url.starts_with(IBC_CORE_TYPE_URL_PREFIX))
+        {
+            return 0;
+        }
+
+        match self.selection {
+            KeySelection::RoundRobin => {
+                let index = self.next;
+                self.next = (self.next + 1) % pool_size;
+                index
+            }
+            KeySelection::Hash => msgs
+                .iter()
+                .find_map(packet_channel)
+                .map(|channel| {
+                    let mut hasher = DefaultHasher::new();
+                    channel.hash(&mut hasher);
+                    (hasher.finish() % pool_size as u64) as usize
+                })
+                .unwrap_or(0),
+        }
+    }
+}
+
+/// The port and channel identifiers, on the chain the message is submitted to,
+/// of the packet carried by the given message, if any.
+fn packet_channel(msg: &Any) -> Option<(String, String)> {
+    fn source(packet: Option<Packet>) -> Option<(String, String)> {
+        packet.map(|p| (p.source_port, p.source_channel))
+    }
+
+    let value = msg.value.as_slice();
+
+    match msg.type_url.as_str() {
+        recv_packet::TYPE_URL => MsgRecvPacket::decode(value)
+            .ok()?
+            .packet
+            .map(|p| (p.destination_port, p.destination_channel)),
+        acknowledgement::TYPE_URL => source(MsgAcknowledgement::decode(value).ok()?.packet),
+        timeout::TYPE_URL => source(MsgTimeout::decode(value).ok()?.packet),
+        timeout_on_close::TYPE_URL => source(MsgTimeoutOnClose::decode(value).ok()?.packet),
+        _ => None,
+    }
+}
+
+/// Replace the signer of the given message with a new one, by rewriting
+/// the top-level string fields of the message which are equal to the signer.
+///
+/// Returns `None` if the message is not a valid protobuf message,
+/// or if it does not contain the signer.
+fn replace_signer(msg: &Any, signer: &str, new_signer: &str) -> Option<Any> {
+    let mut input = msg.value.as_slice();
+    let mut output = Vec::with_capacity(msg.value.len());
+    let mut replaced = false;
+
+    while !input.is_empty() {
+        let field = input;
+        let key = read_varint(&mut input)?;
+
+        match key & 0b111 {
+            // Varint
+            0 => {
+                read_varint(&mut input)?;
+            }
+            // 64-bit
+            1 => input = input.get(8..)?,
+            // Length-delimited
+            2 => {
+                let len = usize::try_from(read_varint(&mut input)?).ok()?;
+                let value = input.get(..len)?;
+                input = &input[len..];
+
+                if value == signer.as_bytes() {
+                    write_varint(key, &mut output);
+                    write_varint(new_signer.len() as u64, &mut output);
+                    output.extend_from_slice(new_signer.as_bytes());
+                    replaced = true;
+
+                    continue;
+                }
+            }
+            // 32-bit
+            5 => input = input.get(4..)?,
+            // Groups are deprecated and not used by IBC messages
+            _ => return None,
+        }
+
+        output.extend_from_slice(&field[..field.len() - input.len()]);
+    }
+
+    replaced.then(|| Any {
+        type_url: msg.type_url.clone(),
+        value: output,
+    })
+}
+
+fn read_varint(input: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = input.split_first()?;
+        *input = rest;
+
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+
+    None
+}
+
+fn write_varint(mut value: u64, output: &mut Vec<u8>) {
+    while value >= 0x80 {
+        output.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+
+    output.push(value as u8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_proto::ibc::applications::transfer::v1::MsgTransfer;
+    use ibc_proto::ibc::core::client::v1::{Height, MsgUpdateClient};
+
+    const SIGNER: &str = "cosmos1signer";
+    const NEW_SIGNER: &str = "cosmos1anothersignerwithalongeraddress";
+
+    fn pool(size: usize, selection: KeySelection) -> KeyPool {
+        KeyPool {
+            key_names: (0..size).map(|i| format!("key-{i}")).collect(),
+            selection,
+            next: 0,
+            accounts: HashMap::new(),
+        }
+    }
+
+    fn recv_packet(channel: &str, sequence: u64) -> Any {
+        let msg = MsgRecvPacket {
+            packet: Some(Packet {
+                sequence,
+                source_port: "transfer".to_string(),
+                source_channel: "channel-0".to_string(),
+                destination_port: "transfer".to_string(),
+                destination_channel: channel.to_string(),
+                data: SIGNER.as_bytes().to_vec(),
+                timeout_height: None,
+                timeout_timestamp: 1_000_000_000,
+            }),
+            proof_commitment: vec![1, 2, 3],
+            proof_height: Some(Height {
+                revision_number: 1,
+                revision_height: 300,
+            }),
+            signer: SIGNER.to_string(),
+        };
+
+        Any {
+            type_url: recv_packet::TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+
+    fn transfer() -> Any {
+        let msg = MsgTransfer {
+            source_port: "transfer".to_string(),
+            source_channel: "channel-0".to_string(),
+            sender: SIGNER.to_string(),
+            receiver: "cosmos1receiver".to_string(),
+            ..Default::default()
+        };
+
+        Any {
+            type_url: "/ibc.applications.transfer.v1.MsgTransfer".to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+
+    #[test]
+    fn replace_signer_of_top_level_field_only() {
+        let msg = recv_packet("channel-1", 1);
+        let replaced = replace_signer(&msg, SIGNER, NEW_SIGNER).unwrap();
+
+        let decoded = MsgRecvPacket::decode(replaced.value.as_slice()).unwrap();
+        let original = MsgRecvPacket::decode(msg.value.as_slice()).unwrap();
+
+        assert_eq!(replaced.type_url, msg.type_url);
+        assert_eq!(decoded.signer, NEW_SIGNER);
+        assert_eq!(decoded.packet, original.packet);
+        assert_eq!(decoded.proof_commitment, original.proof_commitment);
+        assert_eq!(decoded.proof_height, original.proof_height);
+    }
+
+    #[test]
+    fn replace_signer_requires_signer() {
+        let msg = Any {
+            type_url: "/ibc.core.client.v1.MsgUpdateClient".to_string(),
+            value: MsgUpdateClient {
+                client_id: "07-tendermint-0".to_string(),
+                client_message: None,
+                signer: "cosmos1other".to_string(),
+            }
+            .encode_to_vec(),
+        };
+
+        assert!(replace_signer(&msg, SIGNER, NEW_SIGNER).is_none());
+
+        let invalid = Any {
+            type_url: msg.type_url,
+            value: vec![0x0a, 0xff],
+        };
+
+        assert!(replace_signer(&invalid, SIGNER, NEW_SIGNER).is_none());
+    }
+
+    #[test]
+    fn round_robin_selection() {
+        let mut pool = pool(3, KeySelection::RoundRobin);
+        let msgs = vec![recv_packet("channel-1", 1)];
+
+        let selected: Vec<_> = (0..4).map(|_| pool.select(&msgs)).collect();
+        assert_eq!(selected, vec![0, 1, 2, 0]);
+    }
+
+    #[test]
+    fn hash_selection_is_stable_per_channel() {
+        let mut pool = pool(4, KeySelection::Hash);
+
+        let first = pool.select(&[recv_packet("channel-1", 1)]);
+        for sequence in 2..10 {
+            assert_eq!(pool.select(&[recv_packet("channel-1", sequence)]), first);
+        }
+
+        let selected: Vec<_> = (0..32)
+            .map(|i| pool.select(&[recv_packet(&format!("channel-{i}"), 1)]))
+            .collect();
+        assert!(selected.iter().any(|&index| index != first));
+    }
+
+    #[test]
+    fn non_core_messages_use_main_key() {
+        let mut pool = pool(3, KeySelection::RoundRobin);
+        let msgs = vec![recv_packet("channel-1", 1), transfer()];
+
+        assert_eq!(pool.select(&msgs), 0);
+        assert_eq!(pool.select(&msgs), 0);
+    }
+
+    #[test]
+    fn varint_roundtrip() {
+        for value in [0, 1, 127, 128, 300, u32::MAX as u64, u64::MAX] {
+            let mut encoded = Vec::new();
+            write_varint(value, &mut encoded);
+            assert_eq!(read_varint(&mut encoded.as_slice()), Some(value));
+        }
+    }
+}
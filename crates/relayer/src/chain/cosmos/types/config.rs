@@ -24,6 +24,16 @@ pub struct TxConfig {
     pub extension_options: Vec<Any>,
 }
 
+impl TxConfig {
+    /// The configuration for building transactions whose gas price
+    /// is bumped the given number of times.
+    pub fn with_fee_bumps(&self, fee_bumps: u32) -> Self {
+        let mut config = self.clone();
+        config.gas_config.fee_bumps = fee_bumps;
+        config
+    }
+}
+
 impl<'a> TryFrom<&'a CosmosSdkConfig> for TxConfig {
     type Error = Error;
 
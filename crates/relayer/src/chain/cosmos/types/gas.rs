@@ -3,6 +3,7 @@ use ibc_proto::cosmos::tx::v1beta1::Fee;
 use crate::chain::cosmos::calculate_fee;
 use crate::chain::cosmos::config::CosmosSdkConfig;
 use crate::config::dynamic_gas::DynamicGasPrice;
+use crate::config::fee_bump::FeeBump;
 use crate::config::GasPrice;
 
 /// Default gas limit when submitting a transaction.
@@ -19,6 +20,9 @@ pub struct GasConfig {
     pub max_fee: Fee,
     pub fee_granter: String,
     pub dynamic_gas_price: DynamicGasPrice,
+    pub fee_bump: FeeBump,
+    /// Number of times the gas price is bumped, for resubmitting stuck transactions
+    pub fee_bumps: u32,
 }
 
 impl<'a> From<&'a CosmosSdkConfig> for GasConfig {
@@ -31,6 +35,8 @@ impl<'a> From<&'a CosmosSdkConfig> for GasConfig {
             max_fee: max_fee_from_config(config),
             fee_granter: fee_granter_from_config(config),
             dynamic_gas_price: config.dynamic_gas_price,
+            fee_bump: config.fee_bump,
+            fee_bumps: 0,
         }
     }
 }
@@ -1,5 +1,6 @@
 use alloc::sync::Arc;
 
+use ibc_proto::cosmos::upgrade::v1beta1::Plan;
 use ibc_proto::ibc::core::channel::v1::{QueryUpgradeErrorRequest, QueryUpgradeRequest};
 use ibc_relayer_types::core::ics02_client::height::Height;
 use tokio::runtime::Runtime as TokioRuntime;
@@ -81,7 +82,9 @@ pub trait ChainEndpoint: Sized {
     type Time;
 
     /// Type of the key pair used for signatures of messages on chain
-    type SigningKeyPair: SigningKeyPairSized + Into<AnySigningKeyPair>;
+    type SigningKeyPair: SigningKeyPairSized
+        + Into<AnySigningKeyPair>
+        + TryFrom<AnySigningKeyPair, Error = AnySigningKeyPair>;
 
     /// Returns the chain's identifier
     fn id(&self) -> &ChainId;
@@ -100,6 +103,12 @@ pub trait ChainEndpoint: Sized {
     /// Perform a health check
     fn health_check(&mut self) -> Result<HealthCheck, Error>;
 
+    /// Switch to another full node if the one currently in use has been
+    /// found to be unhealthy. Does nothing for chains without backup nodes.
+    fn maybe_failover(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
     // Events
     fn subscribe(&mut self) -> Result<Subscription, Error>;
 
@@ -116,6 +125,10 @@ pub trait ChainEndpoint: Sized {
     /// Get the signing key pair
     fn get_key(&self) -> Result<Self::SigningKeyPair, Error>;
 
+    /// Get the names and signing key pairs of all the keys used to sign transactions,
+    /// starting with the one returned by [`ChainEndpoint::get_key`]
+    fn get_keys(&self) -> Result<Vec<(String, Self::SigningKeyPair)>, Error>;
+
     fn add_key(&mut self, key_name: &str, key_pair: Self::SigningKeyPair) -> Result<(), Error> {
         self.keybase_mut()
             .add_key(key_name, key_pair)
@@ -703,4 +716,7 @@ pub trait ChainEndpoint: Sized {
         height: Height,
         include_proof: IncludeProof,
     ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error>;
+
+    /// Query the upgrade plan currently scheduled on the chain, if any.
+    fn query_upgrade_plan(&self) -> Result<Option<Plan>, Error>;
 }
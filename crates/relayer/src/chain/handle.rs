@@ -4,6 +4,7 @@ use core::fmt::{self, Debug, Display};
 use crossbeam_channel as channel;
 use tracing::Span;
 
+use ibc_proto::cosmos::upgrade::v1beta1::Plan;
 use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
@@ -137,6 +138,10 @@ pub enum ChainRequest {
         reply_to: ReplyTo<AnySigningKeyPair>,
     },
 
+    GetKeys {
+        reply_to: ReplyTo<Vec<(String, AnySigningKeyPair)>>,
+    },
+
     AddKey {
         key_name: String,
         key: AnySigningKeyPair,
@@ -387,6 +392,10 @@ pub enum ChainRequest {
         include_proof: IncludeProof,
         reply_to: ReplyTo<(ErrorReceipt, Option<MerkleProof>)>,
     },
+
+    QueryUpgradePlan {
+        reply_to: ReplyTo<Option<Plan>>,
+    },
 }
 
 pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
@@ -426,6 +435,10 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
 
     fn get_key(&self) -> Result<AnySigningKeyPair, Error>;
 
+    /// Return the names and key pairs of all the keys used to sign transactions,
+    /// starting with the key specified by `key_name` in the chain configuration.
+    fn get_keys(&self) -> Result<Vec<(String, AnySigningKeyPair)>, Error>;
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error>;
 
     /// Return the version of the IBC protocol that this chain is running, if known.
@@ -714,4 +727,7 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
         height: Height,
         include_proof: IncludeProof,
     ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error>;
+
+    /// Query the upgrade plan currently scheduled on the chain, if any.
+    fn query_upgrade_plan(&self) -> Result<Option<Plan>, Error>;
 }
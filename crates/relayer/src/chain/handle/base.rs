@@ -3,6 +3,7 @@ use core::fmt::{Debug, Display, Error as FmtError, Formatter};
 use crossbeam_channel as channel;
 use tracing::Span;
 
+use ibc_proto::cosmos::upgrade::v1beta1::Plan;
 use ibc_proto::ibc::{
     apps::fee::v1::{QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse},
     core::channel::v1::{QueryUpgradeErrorRequest, QueryUpgradeRequest},
@@ -143,6 +144,10 @@ impl ChainHandle for BaseChainHandle {
         self.send(|reply_to| ChainRequest::GetKey { reply_to })
     }
 
+    fn get_keys(&self) -> Result<Vec<(String, AnySigningKeyPair)>, Error> {
+        self.send(|reply_to| ChainRequest::GetKeys { reply_to })
+    }
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error> {
         self.send(|reply_to| ChainRequest::AddKey {
             key_name,
@@ -553,4 +558,8 @@ impl ChainHandle for BaseChainHandle {
             reply_to,
         })
     }
+
+    fn query_upgrade_plan(&self) -> Result<Option<Plan>, Error> {
+        self.send(|reply_to| ChainRequest::QueryUpgradePlan { reply_to })
+    }
 }
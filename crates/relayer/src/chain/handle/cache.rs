@@ -4,6 +4,7 @@ use ibc_relayer_types::core::ics02_client::header::AnyHeader;
 use ibc_relayer_types::core::ics04_channel::upgrade::ErrorReceipt;
 use tracing::Span;
 
+use ibc_proto::cosmos::upgrade::v1beta1::Plan;
 use ibc_proto::ibc::apps::fee::v1::QueryIncentivizedPacketRequest;
 use ibc_proto::ibc::apps::fee::v1::QueryIncentivizedPacketResponse;
 use ibc_proto::ibc::core::channel::v1::{QueryUpgradeErrorRequest, QueryUpgradeRequest};
@@ -122,6 +123,10 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
         self.inner().get_key()
     }
 
+    fn get_keys(&self) -> Result<Vec<(String, AnySigningKeyPair)>, Error> {
+        self.inner().get_keys()
+    }
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error> {
         self.inner().add_key(key_name, key)
     }
@@ -537,4 +542,8 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
         self.inner
             .query_upgrade_error(request, height, include_proof)
     }
+
+    fn query_upgrade_plan(&self) -> Result<Option<Plan>, Error> {
+        self.inner.query_upgrade_plan()
+    }
 }
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use std::sync::{Arc, RwLock, RwLockReadGuard};
 
 use crossbeam_channel as channel;
+use ibc_proto::cosmos::upgrade::v1beta1::Plan;
 use ibc_proto::ibc::core::channel::v1::{QueryUpgradeErrorRequest, QueryUpgradeRequest};
 use ibc_relayer_types::core::ics04_channel::upgrade::{ErrorReceipt, Upgrade};
 use tracing::{debug, Span};
@@ -148,6 +149,11 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner().get_key()
     }
 
+    fn get_keys(&self) -> Result<Vec<(String, AnySigningKeyPair)>, Error> {
+        self.inc_metric("get_keys");
+        self.inner().get_keys()
+    }
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error> {
         self.inc_metric("add_key");
         self.inner().add_key(key_name, key)
@@ -532,4 +538,9 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner
             .query_upgrade_error(request, height, include_proof)
     }
+
+    fn query_upgrade_plan(&self) -> Result<Option<Plan>, Error> {
+        self.inc_metric("query_upgrade_plan");
+        self.inner.query_upgrade_plan()
+    }
 }
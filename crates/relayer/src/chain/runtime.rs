@@ -5,6 +5,7 @@ use crossbeam_channel as channel;
 use tokio::runtime::Runtime as TokioRuntime;
 use tracing::{error, Span};
 
+use ibc_proto::cosmos::upgrade::v1beta1::Plan;
 use ibc_proto::ibc::{
     apps::fee::v1::{QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse},
     core::channel::v1::{QueryUpgradeErrorRequest, QueryUpgradeRequest},
@@ -146,6 +147,13 @@ where
 
                     let _span = span.entered();
 
+                    // No need to look for a healthy full node to shut down
+                    if !matches!(event, ChainRequest::Shutdown { .. }) {
+                        if let Err(e) = self.chain.maybe_failover() {
+                            error!("failed to switch to a healthy full node: {}", e);
+                        }
+                    }
+
                     match event {
                         ChainRequest::Shutdown { reply_to } => {
                             let res = self.chain.shutdown();
@@ -184,6 +192,10 @@ where
                             self.get_key(reply_to)?
                         },
 
+                        ChainRequest::GetKeys { reply_to } => {
+                            self.get_keys(reply_to)?
+                        },
+
                         ChainRequest::AddKey { key_name, key, reply_to } => {
                             self.add_key(key_name, key, reply_to)?
                         },
@@ -363,6 +375,10 @@ where
                         ChainRequest::QueryUpgradeError { request, height, include_proof, reply_to } => {
                             self.query_upgrade_error(request, height, include_proof, reply_to)?
                         },
+
+                        ChainRequest::QueryUpgradePlan { reply_to } => {
+                            self.query_upgrade_plan(reply_to)?
+                        },
                     }
                 },
             }
@@ -446,15 +462,27 @@ where
         reply_to.send(result).map_err(Error::send)
     }
 
+    fn get_keys(
+        &mut self,
+        reply_to: ReplyTo<Vec<(String, AnySigningKeyPair)>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.get_keys().map(|keys| {
+            keys.into_iter()
+                .map(|(key_name, key)| (key_name, key.into()))
+                .collect()
+        });
+
+        reply_to.send(result).map_err(Error::send)
+    }
+
     fn add_key(
         &mut self,
         key_name: String,
         key: AnySigningKeyPair,
         reply_to: ReplyTo<()>,
     ) -> Result<(), Error> {
-        let key = key
-            .downcast()
-            .ok_or_else(|| Error::invalid_key_type(key.key_type()))?;
+        let key = Endpoint::SigningKeyPair::try_from(key)
+            .map_err(|key| Error::invalid_key_type(key.key_type()))?;
         let result = self.chain.add_key(&key_name, key);
         reply_to.send(result).map_err(Error::send)
     }
@@ -902,4 +930,11 @@ where
 
         Ok(())
     }
+
+    fn query_upgrade_plan(&self, reply_to: ReplyTo<Option<Plan>>) -> Result<(), Error> {
+        let result = self.chain.query_upgrade_plan();
+        reply_to.send(result).map_err(Error::send)?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,1058 @@
+//! A solo machine, ie. an off-chain process which signs the values of its IBC store
+//! with a single key, as verified by ICS-06 clients on its counterparty chains.
+//!
+//! The solo machine signs with a key of the Hermes keyring and keeps its IBC store in memory.
+//! Its height is the sequence of its latest signature: every proof it produces is a signature
+//! at the next sequence, which the client of the solo machine on the counterparty chain expects
+//! to verify in the same order.
+
+use alloc::sync::Arc;
+use core::cell::Cell;
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use sha2::{Digest, Sha256};
+use tokio::runtime::Runtime as TokioRuntime;
+use tracing::warn;
+
+use ibc_proto::cosmos::upgrade::v1beta1::Plan;
+use ibc_proto::ibc::apps::fee::v1::{
+    QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
+};
+use ibc_proto::ibc::core::channel::v1::{QueryUpgradeErrorRequest, QueryUpgradeRequest};
+use ibc_proto::Protobuf;
+use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::clients::ics06_solomachine::client_state::ClientState as SoloMachineClientState;
+use ibc_relayer_types::clients::ics06_solomachine::consensus_state::{
+    ConsensusState as SoloMachineConsensusState, PublicKey,
+};
+use ibc_relayer_types::clients::ics06_solomachine::header::Header as SoloMachineHeader;
+use ibc_relayer_types::clients::ics06_solomachine::sign_bytes::{
+    encode_signature_data, merkle_path_bytes, HeaderData, SignBytes, TimestampedSignatureData,
+    SENTINEL_HEADER_PATH,
+};
+use ibc_relayer_types::core::ics02_client::client_type::ClientType;
+use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
+use ibc_relayer_types::core::ics02_client::events::{NewBlock, UpdateClient};
+use ibc_relayer_types::core::ics03_connection::connection::{
+    ConnectionEnd, IdentifiedConnectionEnd, State as ConnectionState,
+};
+use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd, Ordering};
+use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
+use ibc_relayer_types::core::ics04_channel::upgrade::{ErrorReceipt, Upgrade};
+use ibc_relayer_types::core::ics23_commitment::commitment::{
+    CommitmentPrefix, CommitmentProofBytes,
+};
+use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
+use ibc_relayer_types::core::ics24_host::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId,
+};
+use ibc_relayer_types::core::ics24_host::path::{
+    AcksPath, ChannelEndsPath, ClientConsensusStatePath, ClientStatePath, ConnectionsPath,
+    ReceiptsPath, SeqRecvsPath,
+};
+use ibc_relayer_types::events::{IbcEvent, WithBlockDataType};
+use ibc_relayer_types::proofs::{ConsensusProof, Proofs};
+use ibc_relayer_types::signer::Signer;
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height as ICSHeight;
+
+use tendermint::abci::Code;
+use tendermint::Hash as TxHash;
+use tendermint_rpc::endpoint::broadcast::tx_sync::Response as TxResponse;
+
+use crate::account::Balance;
+use crate::chain::client::ClientSettings;
+use crate::chain::cosmos::encode::key_pair_to_signer;
+use crate::chain::cosmos::version::Specs;
+use crate::chain::endpoint::{ChainEndpoint, ChainStatus, HealthCheck};
+use crate::chain::handle::Subscription;
+use crate::chain::requests::*;
+use crate::chain::tracking::TrackedMsgs;
+use crate::client_state::{AnyClientState, IdentifiedAnyClientState};
+use crate::config::ChainConfig;
+use crate::connection::ConnectionMsgType;
+use crate::consensus_state::AnyConsensusState;
+use crate::denom::DenomTrace;
+use crate::error::Error;
+use crate::event::bus::EventBus;
+use crate::event::source::{EventBatch, Result as MonitorResult};
+use crate::event::IbcEventWithHeight;
+use crate::keyring::{AnySecp256k1KeyPair, KeyRing, SigningKeyPair};
+use crate::misbehaviour::MisbehaviourEvidence;
+
+use self::config::SoloMachineConfig;
+use self::error::Error as SoloMachineError;
+use self::store::SoloMachineStore;
+
+pub mod config;
+pub mod error;
+pub mod store;
+
+pub struct SoloMachineChain {
+    config: SoloMachineConfig,
+    keybase: KeyRing<AnySecp256k1KeyPair>,
+    store: SoloMachineStore,
+
+    /// The sequence of the latest signature of the solo machine
+    sequence: Cell<u64>,
+
+    /// The timestamp of the latest signature of the solo machine,
+    /// which the timestamps of its next signatures must not precede
+    timestamp: Cell<Timestamp>,
+
+    /// The events emitted by the transactions applied to the store
+    events: Vec<IbcEventWithHeight>,
+    txs: HashMap<TxHash, Vec<IbcEventWithHeight>>,
+    event_bus: EventBus<Arc<MonitorResult<EventBatch>>>,
+}
+
+impl SoloMachineChain {
+    fn height(&self) -> ICSHeight {
+        ICSHeight::new(0, self.sequence.get()).expect("the sequence is never zero")
+    }
+
+    fn key(&self) -> Result<AnySecp256k1KeyPair, Error> {
+        self.keybase
+            .get_key(&self.config.key_name)
+            .map_err(|e| Error::key_not_found(self.config.key_name.clone(), e))
+    }
+
+    fn public_key(&self) -> Result<PublicKey, Error> {
+        Ok(PublicKey::from_bytes(
+            self.key()?.public_key().serialize().to_vec(),
+        ))
+    }
+
+    /// The timestamp of the next signature, which is never older than the previous one.
+    fn next_timestamp(&self) -> Timestamp {
+        let timestamp = Timestamp::now().max(self.timestamp.get());
+        self.timestamp.set(timestamp);
+        timestamp
+    }
+
+    fn sign_bytes(&self, sign_bytes: SignBytes) -> Result<Vec<u8>, Error> {
+        self.key()?
+            .sign(&sign_bytes.encode_to_vec())
+            .map_err(Error::key_base)
+    }
+
+    /// Sign the value stored at the given path with the next sequence of the solo machine.
+    /// Returns the signature as a proof, along with its sequence.
+    fn sign(&self, path: String, data: Vec<u8>) -> Result<(CommitmentProofBytes, u64), Error> {
+        let sequence = self.sequence.get() + 1;
+        let timestamp = self.next_timestamp();
+
+        let signature = self.sign_bytes(SignBytes {
+            sequence,
+            timestamp,
+            diversifier: self.config.diversifier(),
+            path: merkle_path_bytes(&self.query_commitment_prefix()?, &path),
+            data,
+        })?;
+
+        self.sequence.set(sequence);
+
+        let proof =
+            CommitmentProofBytes::try_from(TimestampedSignatureData::new(signature, timestamp))
+                .map_err(Error::malformed_proof)?;
+
+        Ok((proof, sequence))
+    }
+
+    fn proofs_height(sequence: u64) -> ICSHeight {
+        ICSHeight::new(0, sequence).expect("the sequence is never zero")
+    }
+
+    /// Apply the given messages to the store, as a single transaction
+    /// which either emits the events of all the messages or a single error event.
+    fn apply(&mut self, tracked_msgs: TrackedMsgs) -> Vec<IbcEventWithHeight> {
+        let height = self.height();
+
+        match self
+            .store
+            .apply(tracked_msgs.messages(), height, Timestamp::now())
+        {
+            Ok(events) => {
+                let events: Vec<_> = events
+                    .into_iter()
+                    .map(|event| IbcEventWithHeight::new(event, height))
+                    .collect();
+
+                self.events.extend(events.iter().cloned());
+
+                let mut batch_events = vec![IbcEventWithHeight::new(
+                    IbcEvent::NewBlock(NewBlock::new(height)),
+                    height,
+                )];
+                batch_events.extend(events.iter().cloned());
+
+                self.event_bus.broadcast(Arc::new(Ok(EventBatch {
+                    chain_id: self.config.id.clone(),
+                    tracking_id: tracked_msgs.tracking_id(),
+                    height,
+                    events: batch_events,
+                })));
+
+                events
+            }
+            Err(e) => {
+                warn!("failed to apply transaction to solo machine store: {e}");
+
+                vec![IbcEventWithHeight::new(
+                    IbcEvent::ChainError(e.to_string()),
+                    height,
+                )]
+            }
+        }
+    }
+
+    fn unsupported<T>(operation: &str) -> Result<T, Error> {
+        Err(Error::solo_machine_unsupported(operation.to_string()))
+    }
+}
+
+impl ChainEndpoint for SoloMachineChain {
+    type LightBlock = SoloMachineConsensusState;
+    type Header = SoloMachineHeader;
+    type ConsensusState = SoloMachineConsensusState;
+    type ClientState = SoloMachineClientState;
+    type Time = Timestamp;
+    type SigningKeyPair = AnySecp256k1KeyPair;
+
+    fn id(&self) -> &ChainId {
+        &self.config.id
+    }
+
+    fn config(&self) -> ChainConfig {
+        ChainConfig::SoloMachine(self.config.clone())
+    }
+
+    fn bootstrap(config: ChainConfig, _rt: Arc<TokioRuntime>) -> Result<Self, Error> {
+        let ChainConfig::SoloMachine(config) = config else {
+            return Err(Error::config(crate::config::Error::wrong_type()));
+        };
+
+        let keybase = KeyRing::new(
+            config.key_store_type,
+            &config.account_prefix,
+            &config.id,
+            &config.key_store_folder,
+            &config.key_store_passphrase,
+            &None,
+        )
+        .map_err(Error::key_base)?;
+
+        Ok(Self {
+            config,
+            keybase,
+            store: SoloMachineStore::default(),
+            // Heights cannot be zero, the first signature of the solo machine is thus at sequence 2
+            sequence: Cell::new(1),
+            timestamp: Cell::new(Timestamp::now()),
+            events: Vec::new(),
+            txs: HashMap::new(),
+            event_bus: EventBus::new(),
+        })
+    }
+
+    fn shutdown(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn health_check(&mut self) -> Result<HealthCheck, Error> {
+        Ok(HealthCheck::Healthy)
+    }
+
+    fn subscribe(&mut self) -> Result<Subscription, Error> {
+        Ok(self.event_bus.subscribe())
+    }
+
+    fn keybase(&self) -> &KeyRing<Self::SigningKeyPair> {
+        &self.keybase
+    }
+
+    fn keybase_mut(&mut self) -> &mut KeyRing<Self::SigningKeyPair> {
+        &mut self.keybase
+    }
+
+    fn get_signer(&self) -> Result<Signer, Error> {
+        key_pair_to_signer(&self.key()?)
+    }
+
+    fn get_key(&self) -> Result<Self::SigningKeyPair, Error> {
+        self.key()
+    }
+
+    fn get_keys(&self) -> Result<Vec<(String, Self::SigningKeyPair)>, Error> {
+        Ok(vec![(self.config.key_name.clone(), self.key()?)])
+    }
+
+    fn version_specs(&self) -> Result<Specs, Error> {
+        Ok(Specs {
+            cosmos_sdk: None,
+            ibc_go: None,
+            consensus: None,
+        })
+    }
+
+    fn send_messages_and_wait_commit(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        Ok(self.apply(tracked_msgs))
+    }
+
+    fn send_messages_and_wait_check_tx(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<TxResponse>, Error> {
+        let mut hasher = Sha256::new();
+        hasher.update((self.txs.len() as u64).to_be_bytes());
+        for msg in tracked_msgs.messages() {
+            hasher.update(&msg.value);
+        }
+        let hash = TxHash::Sha256(hasher.finalize().into());
+
+        let events = self.apply(tracked_msgs);
+        self.txs.insert(hash, events);
+
+        Ok(vec![TxResponse {
+            codespace: String::new(),
+            code: Code::Ok,
+            data: Bytes::new(),
+            log: String::new(),
+            hash,
+        }])
+    }
+
+    /// The solo machine has no light client, its current consensus state is always trusted,
+    /// unless the given client state is the one of the solo machine itself.
+    fn verify_header(
+        &mut self,
+        _trusted: ICSHeight,
+        _target: ICSHeight,
+        client_state: &AnyClientState,
+    ) -> Result<Self::LightBlock, Error> {
+        match client_state {
+            AnyClientState::SoloMachine(client_state) => Ok(client_state.consensus_state.clone()),
+            _ => self.query_host_consensus_state(QueryHostConsensusStateRequest {
+                height: QueryHeight::Latest,
+            }),
+        }
+    }
+
+    fn check_misbehaviour(
+        &mut self,
+        _update: &UpdateClient,
+        _client_state: &AnyClientState,
+    ) -> Result<Option<MisbehaviourEvidence>, Error> {
+        Ok(None)
+    }
+
+    fn query_balance(
+        &self,
+        _key_name: Option<&str>,
+        _denom: Option<&str>,
+    ) -> Result<Balance, Error> {
+        Self::unsupported("balance queries")
+    }
+
+    fn query_all_balances(&self, _key_name: Option<&str>) -> Result<Vec<Balance>, Error> {
+        Self::unsupported("balance queries")
+    }
+
+    fn query_denom_trace(&self, _hash: String) -> Result<DenomTrace, Error> {
+        Self::unsupported("denom trace queries")
+    }
+
+    fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
+        CommitmentPrefix::try_from(self.config.store_prefix.as_bytes().to_vec())
+            .map_err(|_| Error::ics02(ClientError::empty_prefix()))
+    }
+
+    fn query_application_status(&self) -> Result<ChainStatus, Error> {
+        Ok(ChainStatus {
+            height: self.height(),
+            timestamp: Timestamp::now(),
+        })
+    }
+
+    fn query_clients(
+        &self,
+        _request: QueryClientStatesRequest,
+    ) -> Result<Vec<IdentifiedAnyClientState>, Error> {
+        Ok(self.store.clients())
+    }
+
+    fn query_client_state(
+        &self,
+        request: QueryClientStateRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(AnyClientState, Option<MerkleProof>), Error> {
+        let client_state = self
+            .store
+            .client_state(&request.client_id)
+            .map_err(Error::solo_machine)?;
+
+        Ok((client_state, None))
+    }
+
+    fn query_consensus_state(
+        &self,
+        request: QueryConsensusStateRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(AnyConsensusState, Option<MerkleProof>), Error> {
+        let consensus_state = self
+            .store
+            .consensus_state(&request.client_id, request.consensus_height)
+            .map_err(Error::solo_machine)?;
+
+        Ok((consensus_state, None))
+    }
+
+    fn query_consensus_state_heights(
+        &self,
+        request: QueryConsensusStateHeightsRequest,
+    ) -> Result<Vec<ICSHeight>, Error> {
+        self.store
+            .consensus_state_heights(&request.client_id)
+            .map_err(Error::solo_machine)
+    }
+
+    fn query_upgraded_client_state(
+        &self,
+        _request: QueryUpgradedClientStateRequest,
+    ) -> Result<(AnyClientState, MerkleProof), Error> {
+        Self::unsupported("upgrades")
+    }
+
+    fn query_upgraded_consensus_state(
+        &self,
+        _request: QueryUpgradedConsensusStateRequest,
+    ) -> Result<(AnyConsensusState, MerkleProof), Error> {
+        Self::unsupported("upgrades")
+    }
+
+    fn query_connections(
+        &self,
+        _request: QueryConnectionsRequest,
+    ) -> Result<Vec<IdentifiedConnectionEnd>, Error> {
+        Ok(self.store.connections())
+    }
+
+    fn query_client_connections(
+        &self,
+        request: QueryClientConnectionsRequest,
+    ) -> Result<Vec<ConnectionId>, Error> {
+        Ok(self
+            .store
+            .connections()
+            .into_iter()
+            .filter(|connection| connection.end().client_id() == &request.client_id)
+            .map(|connection| connection.id().clone())
+            .collect())
+    }
+
+    fn query_connection(
+        &self,
+        request: QueryConnectionRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(ConnectionEnd, Option<MerkleProof>), Error> {
+        let connection = self
+            .store
+            .connection(&request.connection_id)
+            .map_err(Error::solo_machine)?;
+
+        Ok((connection, None))
+    }
+
+    fn query_connection_channels(
+        &self,
+        request: QueryConnectionChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        Ok(self
+            .store
+            .channels()
+            .into_iter()
+            .filter(|channel| {
+                channel.channel_end.connection_hops().first() == Some(&request.connection_id)
+            })
+            .collect())
+    }
+
+    fn query_channels(
+        &self,
+        _request: QueryChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        Ok(self.store.channels())
+    }
+
+    fn query_channel(
+        &self,
+        request: QueryChannelRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(ChannelEnd, Option<MerkleProof>), Error> {
+        let channel = self
+            .store
+            .channel(&request.port_id, &request.channel_id)
+            .map_err(Error::solo_machine)?;
+
+        Ok((channel, None))
+    }
+
+    fn query_channel_client_state(
+        &self,
+        request: QueryChannelClientStateRequest,
+    ) -> Result<Option<IdentifiedAnyClientState>, Error> {
+        let channel = self
+            .store
+            .channel(&request.port_id, &request.channel_id)
+            .map_err(Error::solo_machine)?;
+
+        let Some(connection_id) = channel.connection_hops().first() else {
+            return Ok(None);
+        };
+
+        let connection = self
+            .store
+            .connection(connection_id)
+            .map_err(Error::solo_machine)?;
+
+        let client_state = self
+            .store
+            .client_state(connection.client_id())
+            .map_err(Error::solo_machine)?;
+
+        Ok(Some(IdentifiedAnyClientState::new(
+            connection.client_id().clone(),
+            client_state,
+        )))
+    }
+
+    /// The solo machine does not send packets, it thus stores no packet commitments.
+    fn query_packet_commitment(
+        &self,
+        _request: QueryPacketCommitmentRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        Ok((Vec::new(), None))
+    }
+
+    fn query_packet_commitments(
+        &self,
+        _request: QueryPacketCommitmentsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        Ok((Vec::new(), self.height()))
+    }
+
+    fn query_packet_receipt(
+        &self,
+        request: QueryPacketReceiptRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        let receipt = if self.store.has_packet_receipt(
+            &request.port_id,
+            &request.channel_id,
+            request.sequence,
+        ) {
+            vec![1]
+        } else {
+            Vec::new()
+        };
+
+        Ok((receipt, None))
+    }
+
+    fn query_unreceived_packets(
+        &self,
+        request: QueryUnreceivedPacketsRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        let channel = self
+            .store
+            .channel(&request.port_id, &request.channel_id)
+            .map_err(Error::solo_machine)?;
+
+        let next_sequence_recv = self
+            .store
+            .next_sequence_recv(&request.port_id, &request.channel_id);
+
+        Ok(request
+            .packet_commitment_sequences
+            .into_iter()
+            .filter(|sequence| {
+                if channel.order_matches(&Ordering::Ordered) {
+                    *sequence >= next_sequence_recv
+                } else {
+                    !self
+                        .store
+                        .has_packet_receipt(&request.port_id, &request.channel_id, *sequence)
+                }
+            })
+            .collect())
+    }
+
+    fn query_packet_acknowledgement(
+        &self,
+        request: QueryPacketAcknowledgementRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        let ack = self
+            .store
+            .packet_acknowledgement(&request.port_id, &request.channel_id, request.sequence)
+            .unwrap_or_default();
+
+        Ok((ack, None))
+    }
+
+    fn query_packet_acknowledgements(
+        &self,
+        request: QueryPacketAcknowledgementsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        let sequences = self
+            .store
+            .packet_acknowledgements(&request.port_id, &request.channel_id)
+            .into_iter()
+            .filter(|sequence| {
+                request.packet_commitment_sequences.is_empty()
+                    || request.packet_commitment_sequences.contains(sequence)
+            })
+            .collect();
+
+        Ok((sequences, self.height()))
+    }
+
+    /// The solo machine does not send packets, it thus awaits no acknowledgements.
+    fn query_unreceived_acknowledgements(
+        &self,
+        _request: QueryUnreceivedAcksRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        Ok(Vec::new())
+    }
+
+    fn query_next_sequence_receive(
+        &self,
+        request: QueryNextSequenceReceiveRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(Sequence, Option<MerkleProof>), Error> {
+        let sequence = self
+            .store
+            .next_sequence_recv(&request.port_id, &request.channel_id);
+
+        Ok((sequence, None))
+    }
+
+    fn query_txs(&self, request: QueryTxRequest) -> Result<Vec<IbcEventWithHeight>, Error> {
+        match request {
+            QueryTxRequest::Client(request) => Ok(self
+                .events
+                .iter()
+                .rev()
+                .find(|event| match (&request.event_id, &event.event) {
+                    (WithBlockDataType::CreateClient, IbcEvent::CreateClient(create)) => {
+                        create.client_id() == &request.client_id
+                    }
+                    (WithBlockDataType::UpdateClient, IbcEvent::UpdateClient(update)) => {
+                        update.client_id() == &request.client_id
+                            && update.consensus_height() == request.consensus_height
+                    }
+                    _ => false,
+                })
+                .cloned()
+                .into_iter()
+                .collect()),
+
+            QueryTxRequest::Transaction(tx) => Ok(self.txs.get(&tx.0).cloned().unwrap_or_default()),
+        }
+    }
+
+    fn query_packet_events(
+        &self,
+        request: QueryPacketEventDataRequest,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let height_matches = |height: ICSHeight| match request.height {
+            Qualified::SmallerEqual(QueryHeight::Specific(h)) => height <= h,
+            Qualified::Equal(QueryHeight::Specific(h)) => height == h,
+            Qualified::SmallerEqual(QueryHeight::Latest)
+            | Qualified::Equal(QueryHeight::Latest) => true,
+        };
+
+        Ok(self
+            .events
+            .iter()
+            .filter(|event| {
+                let type_matches = matches!(
+                    (&request.event_id, &event.event),
+                    (WithBlockDataType::SendPacket, IbcEvent::SendPacket(_))
+                        | (
+                            WithBlockDataType::WriteAck,
+                            IbcEvent::WriteAcknowledgement(_)
+                        )
+                );
+
+                let packet_matches = event.event.packet().is_some_and(|packet| {
+                    packet.source_port == request.source_port_id
+                        && packet.source_channel == request.source_channel_id
+                        && packet.destination_port == request.destination_port_id
+                        && packet.destination_channel == request.destination_channel_id
+                        && request.sequences.contains(&packet.sequence)
+                });
+
+                type_matches && packet_matches && height_matches(event.height)
+            })
+            .cloned()
+            .collect())
+    }
+
+    fn query_host_consensus_state(
+        &self,
+        _request: QueryHostConsensusStateRequest,
+    ) -> Result<Self::ConsensusState, Error> {
+        Ok(SoloMachineConsensusState::new(
+            self.public_key()?,
+            self.config.diversifier(),
+            self.timestamp.get(),
+        ))
+    }
+
+    /// Build the client state of the solo machine, which expects the next signature of
+    /// the solo machine and verifies it against the current key of the solo machine.
+    fn build_client_state(
+        &self,
+        _height: ICSHeight,
+        settings: ClientSettings,
+    ) -> Result<Self::ClientState, Error> {
+        let ClientSettings::SoloMachine = settings else {
+            return Err(Error::client_type_mismatch(
+                ClientType::SoloMachine,
+                ClientType::Tendermint,
+            ));
+        };
+
+        let consensus_state = SoloMachineConsensusState::new(
+            self.public_key()?,
+            self.config.diversifier(),
+            self.next_timestamp(),
+        );
+
+        SoloMachineClientState::new(self.sequence.get() + 1, consensus_state)
+            .map_err(|e| Error::ics02(e.into()))
+    }
+
+    fn build_consensus_state(
+        &self,
+        light_block: Self::LightBlock,
+    ) -> Result<Self::ConsensusState, Error> {
+        Ok(light_block)
+    }
+
+    /// Sign a header at every sequence from the trusted height of the client up to the
+    /// target height, so that the client expects the signatures of the solo machine
+    /// from the target height onwards.
+    fn build_header(
+        &mut self,
+        trusted_height: ICSHeight,
+        target_height: ICSHeight,
+        client_state: &AnyClientState,
+    ) -> Result<(Self::Header, Vec<Self::Header>), Error> {
+        if !matches!(client_state, AnyClientState::SoloMachine(_)) {
+            return Err(Error::client_type_mismatch(
+                ClientType::SoloMachine,
+                client_state.client_type(),
+            ));
+        }
+
+        let public_key = self.public_key()?;
+        let diversifier = self.config.diversifier();
+
+        let mut headers = (trusted_height.revision_height()..target_height.revision_height())
+            .map(|sequence| {
+                let timestamp = self.next_timestamp();
+
+                let signature = self.sign_bytes(SignBytes {
+                    sequence,
+                    timestamp,
+                    diversifier: diversifier.clone(),
+                    path: SENTINEL_HEADER_PATH.as_bytes().to_vec(),
+                    data: HeaderData {
+                        new_public_key: public_key.clone(),
+                        new_diversifier: diversifier.clone(),
+                    }
+                    .encode_to_vec(),
+                })?;
+
+                Ok(SoloMachineHeader {
+                    sequence,
+                    timestamp,
+                    signature: encode_signature_data(signature),
+                    new_public_key: public_key.clone(),
+                    new_diversifier: diversifier.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let Some(target) = headers.pop() else {
+            return Err(Error::solo_machine(
+                SoloMachineError::invalid_header_heights(trusted_height, target_height),
+            ));
+        };
+
+        self.sequence.set(self.sequence.get().max(target.sequence));
+
+        Ok((target, headers))
+    }
+
+    /// Sign the connection end, and the client and consensus states of the counterparty
+    /// for `ConnOpenTry` and `ConnOpenAck`, in the order in which the counterparty
+    /// verifies them.
+    fn build_connection_proofs_and_client_state(
+        &self,
+        message_type: ConnectionMsgType,
+        connection_id: &ConnectionId,
+        client_id: &ClientId,
+        _height: ICSHeight,
+    ) -> Result<(Option<AnyClientState>, Proofs), Error> {
+        let connection_end = self
+            .store
+            .connection(connection_id)
+            .map_err(Error::solo_machine)?;
+
+        let expected_states: &[ConnectionState] = match message_type {
+            ConnectionMsgType::OpenTry => &[ConnectionState::Init, ConnectionState::TryOpen],
+            ConnectionMsgType::OpenAck => &[ConnectionState::TryOpen, ConnectionState::Open],
+            ConnectionMsgType::OpenConfirm => &[ConnectionState::Open],
+        };
+
+        if !expected_states
+            .iter()
+            .any(|state| connection_end.state_matches(state))
+        {
+            return Err(Error::bad_connection_state());
+        }
+
+        let (connection_proof, sequence) = self.sign(
+            ConnectionsPath(connection_id.clone()).to_string(),
+            connection_end.encode_vec(),
+        )?;
+
+        let mut client_state = None;
+        let mut client_proof = None;
+        let mut consensus_proof = None;
+
+        if let ConnectionMsgType::OpenTry | ConnectionMsgType::OpenAck = message_type {
+            let client_state_value = self
+                .store
+                .client_state(client_id)
+                .map_err(Error::solo_machine)?;
+
+            let consensus_height = client_state_value.latest_height();
+            let consensus_state = self
+                .store
+                .consensus_state(client_id, consensus_height)
+                .map_err(Error::solo_machine)?;
+
+            let (proof, _) = self.sign(
+                ClientStatePath(client_id.clone()).to_string(),
+                Protobuf::<ibc_proto::google::protobuf::Any>::encode_vec(
+                    client_state_value.clone(),
+                ),
+            )?;
+            client_proof = Some(proof);
+
+            let (proof, _) = self.sign(
+                ClientConsensusStatePath {
+                    client_id: client_id.clone(),
+                    epoch: consensus_height.revision_number(),
+                    height: consensus_height.revision_height(),
+                }
+                .to_string(),
+                Protobuf::<ibc_proto::google::protobuf::Any>::encode_vec(consensus_state),
+            )?;
+            consensus_proof =
+                Some(ConsensusProof::new(proof, consensus_height).map_err(Error::consensus_proof)?);
+
+            client_state = Some(client_state_value);
+        }
+
+        Ok((
+            client_state,
+            Proofs::new(
+                connection_proof,
+                client_proof,
+                consensus_proof,
+                None,
+                None,
+                Self::proofs_height(sequence),
+            )
+            .map_err(Error::malformed_proof)?,
+        ))
+    }
+
+    fn build_channel_proofs(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        _height: ICSHeight,
+    ) -> Result<Proofs, Error> {
+        let channel = self
+            .store
+            .channel(port_id, channel_id)
+            .map_err(Error::solo_machine)?;
+
+        let (channel_proof, sequence) = self.sign(
+            ChannelEndsPath(port_id.clone(), channel_id.clone()).to_string(),
+            channel.encode_vec(),
+        )?;
+
+        Proofs::new(
+            channel_proof,
+            None,
+            None,
+            None,
+            None,
+            Self::proofs_height(sequence),
+        )
+        .map_err(Error::malformed_proof)
+    }
+
+    /// Sign the packet data expected by the counterparty, after the channel end for
+    /// timeouts on close since the counterparty verifies the channel end first.
+    fn build_packet_proofs(
+        &self,
+        packet_type: PacketMsgType,
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+        _height: ICSHeight,
+    ) -> Result<Proofs, Error> {
+        let mut first_sequence = None;
+
+        let channel_proof = match packet_type {
+            PacketMsgType::TimeoutOnCloseUnordered | PacketMsgType::TimeoutOnCloseOrdered => {
+                let channel = self
+                    .store
+                    .channel(&port_id, &channel_id)
+                    .map_err(Error::solo_machine)?;
+
+                let (proof, signed_sequence) = self.sign(
+                    ChannelEndsPath(port_id.clone(), channel_id.clone()).to_string(),
+                    channel.encode_vec(),
+                )?;
+                first_sequence = Some(signed_sequence);
+
+                Some(proof)
+            }
+            _ => None,
+        };
+
+        let (path, data) = match packet_type {
+            // The solo machine does not send packets
+            PacketMsgType::Recv => return Err(Error::queried_proof_not_found()),
+
+            PacketMsgType::Ack => {
+                let Some(ack) = self
+                    .store
+                    .packet_acknowledgement(&port_id, &channel_id, sequence)
+                else {
+                    return Err(Error::queried_proof_not_found());
+                };
+
+                let path = AcksPath {
+                    port_id,
+                    channel_id,
+                    sequence,
+                };
+
+                (path.to_string(), ack)
+            }
+
+            PacketMsgType::TimeoutUnordered | PacketMsgType::TimeoutOnCloseUnordered => {
+                if self
+                    .store
+                    .has_packet_receipt(&port_id, &channel_id, sequence)
+                {
+                    return Err(Error::queried_proof_not_found());
+                }
+
+                let path = ReceiptsPath {
+                    port_id,
+                    channel_id,
+                    sequence,
+                };
+
+                // The absence of a value is signed as empty data
+                (path.to_string(), Vec::new())
+            }
+
+            PacketMsgType::TimeoutOrdered | PacketMsgType::TimeoutOnCloseOrdered => {
+                let next_sequence_recv = self.store.next_sequence_recv(&port_id, &channel_id);
+
+                (
+                    SeqRecvsPath(port_id, channel_id).to_string(),
+                    u64::from(next_sequence_recv).to_be_bytes().to_vec(),
+                )
+            }
+        };
+
+        let (packet_proof, signed_sequence) = self.sign(path, data)?;
+
+        Proofs::new(
+            packet_proof,
+            None,
+            None,
+            None,
+            channel_proof,
+            Self::proofs_height(first_sequence.unwrap_or(signed_sequence)),
+        )
+        .map_err(Error::malformed_proof)
+    }
+
+    fn maybe_register_counterparty_payee(
+        &mut self,
+        _channel_id: &ChannelId,
+        _port_id: &PortId,
+        _counterparty_payee: &Signer,
+    ) -> Result<(), Error> {
+        Self::unsupported("fee middleware")
+    }
+
+    fn cross_chain_query(
+        &self,
+        _requests: Vec<CrossChainQueryRequest>,
+    ) -> Result<Vec<CrossChainQueryResponse>, Error> {
+        Self::unsupported("cross-chain queries")
+    }
+
+    fn query_incentivized_packet(
+        &self,
+        _request: QueryIncentivizedPacketRequest,
+    ) -> Result<QueryIncentivizedPacketResponse, Error> {
+        Self::unsupported("fee middleware")
+    }
+
+    fn query_consumer_chains(&self) -> Result<Vec<(ChainId, ClientId)>, Error> {
+        Ok(Vec::new())
+    }
+
+    fn query_upgrade(
+        &self,
+        _request: QueryUpgradeRequest,
+        _height: ICSHeight,
+        _include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        Self::unsupported("channel upgrades")
+    }
+
+    fn query_upgrade_error(
+        &self,
+        _request: QueryUpgradeErrorRequest,
+        _height: ICSHeight,
+        _include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error> {
+        Self::unsupported("channel upgrades")
+    }
+
+    fn query_upgrade_plan(&self) -> Result<Option<Plan>, Error> {
+        Ok(None)
+    }
+}
@@ -0,0 +1,73 @@
+use core::time::Duration;
+use std::path::PathBuf;
+
+use serde_derive::{Deserialize, Serialize};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::config::default;
+use crate::config::fee_priority::FeePriority;
+use crate::config::rate_limit::RateLimitConfig;
+use crate::config::PacketFilter;
+use crate::keyring::{PassphraseSource, Store};
+use crate::util::excluded_sequences::ExcludedSequences;
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SoloMachineConfig {
+    /// The identifier under which Hermes refers to the solo machine
+    pub id: ChainId,
+
+    /// The diversifier committed to by the signatures of the solo machine,
+    /// which defaults to its identifier
+    pub diversifier: Option<String>,
+
+    pub account_prefix: String,
+
+    /// The name of the key which signs on behalf of the solo machine
+    pub key_name: String,
+    #[serde(default)]
+    pub key_store_type: Store,
+    pub key_store_folder: Option<PathBuf>,
+    /// Where to get the passphrase of the encrypted `File` key store from
+    #[serde(default)]
+    pub key_store_passphrase: PassphraseSource,
+
+    /// The prefix of the paths of the values signed by the solo machine
+    #[serde(default = "SoloMachineConfig::default_store_prefix")]
+    pub store_prefix: String,
+
+    #[serde(default = "default::max_block_time", with = "humantime_serde")]
+    pub max_block_time: Duration,
+
+    /// How many packets to fetch at once from the solo machine when clearing packets
+    #[serde(default = "default::query_packets_chunk_size")]
+    pub query_packets_chunk_size: usize,
+
+    pub clear_interval: Option<u64>,
+
+    #[serde(default)]
+    pub packet_filter: PacketFilter,
+
+    #[serde(default)]
+    pub fee_priority: FeePriority,
+
+    #[serde(default)]
+    pub rate_limit: RateLimitConfig,
+
+    #[serde(default)]
+    pub excluded_sequences: ExcludedSequences,
+}
+
+impl SoloMachineConfig {
+    fn default_store_prefix() -> String {
+        "ibc".to_string()
+    }
+
+    /// The diversifier of the signatures of the solo machine.
+    pub fn diversifier(&self) -> String {
+        self.diversifier
+            .clone()
+            .unwrap_or_else(|| self.id.to_string())
+    }
+}
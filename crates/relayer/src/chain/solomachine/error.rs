@@ -0,0 +1,97 @@
+use flex_error::define_error;
+
+use ibc_relayer_types::core::ics02_client::client_type::ClientType;
+use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
+use ibc_relayer_types::core::ics03_connection::connection::State as ConnectionState;
+use ibc_relayer_types::core::ics03_connection::error::Error as ConnectionError;
+use ibc_relayer_types::core::ics04_channel::channel::State as ChannelState;
+use ibc_relayer_types::core::ics04_channel::error::Error as ChannelError;
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc_relayer_types::Height;
+
+define_error! {
+    Error {
+        UnsupportedMessage
+            { type_url: String }
+            |e| { format!("the solo machine does not support messages of type `{}`", e.type_url) },
+
+        DecodeMessage
+            { type_url: String }
+            [ tendermint_proto::Error ]
+            |e| { format!("failed to decode message of type `{}`", e.type_url) },
+
+        UnsupportedClientType
+            { client_type: ClientType }
+            |e| { format!("the solo machine does not host clients of type `{}`", e.client_type) },
+
+        Client
+            [ ClientError ]
+            |_| { "invalid client message" },
+
+        Connection
+            [ ConnectionError ]
+            |_| { "invalid connection message" },
+
+        Channel
+            [ ChannelError ]
+            |_| { "invalid channel message" },
+
+        ClientNotFound
+            { client_id: ClientId }
+            |e| { format!("client `{}` not found", e.client_id) },
+
+        ConsensusStateNotFound
+            { client_id: ClientId, height: Height }
+            |e| { format!("consensus state of client `{}` at height {} not found", e.client_id, e.height) },
+
+        InvalidHeaderHeights
+            { trusted: Height, target: Height }
+            |e| {
+                format!("cannot build headers from trusted height {} to target height {}",
+                    e.trusted, e.target)
+            },
+
+        ConnectionNotFound
+            { connection_id: ConnectionId }
+            |e| { format!("connection `{}` not found", e.connection_id) },
+
+        ChannelNotFound
+            { port_id: PortId, channel_id: ChannelId }
+            |e| { format!("channel `{}/{}` not found", e.port_id, e.channel_id) },
+
+        ConnectionStateMismatch
+            {
+                connection_id: ConnectionId,
+                expected: ConnectionState,
+                got: ConnectionState,
+            }
+            |e| {
+                format!("connection `{}` is in state `{}` but `{}` was expected",
+                    e.connection_id, e.got, e.expected)
+            },
+
+        ChannelStateMismatch
+            {
+                port_id: PortId,
+                channel_id: ChannelId,
+                expected: ChannelState,
+                got: ChannelState,
+            }
+            |e| {
+                format!("channel `{}/{}` is in state `{}` but `{}` was expected",
+                    e.port_id, e.channel_id, e.got, e.expected)
+            },
+
+        PacketTimedOut
+            { sequence: Sequence }
+            |e| { format!("packet with sequence {} has timed out", e.sequence) },
+
+        PacketSequenceMismatch
+            { expected: Sequence, got: Sequence }
+            |e| {
+                format!("packet with sequence {} received on an ordered channel expecting sequence {}",
+                    e.got, e.expected)
+            },
+    }
+}
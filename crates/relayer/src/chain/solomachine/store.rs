@@ -0,0 +1,813 @@
+//! The IBC state of a solo machine, kept in memory.
+//!
+//! The store applies the messages relayed to the solo machine without verifying
+//! the proofs they carry: the solo machine trusts the relayer which it runs.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+use sha2::{Digest, Sha256};
+
+use ibc_relayer_types::applications::transfer::acknowledgement::Acknowledgement;
+use ibc_relayer_types::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
+use ibc_relayer_types::core::ics02_client::events::{
+    Attributes as ClientAttributes, CreateClient, UpdateClient,
+};
+use ibc_relayer_types::core::ics02_client::header::{AnyHeader, Header};
+use ibc_relayer_types::core::ics02_client::msgs::create_client::{
+    MsgCreateClient, TYPE_URL as CREATE_CLIENT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics02_client::msgs::update_client::{
+    MsgUpdateClient, TYPE_URL as UPDATE_CLIENT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics03_connection::connection::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, IdentifiedConnectionEnd,
+    State as ConnectionState,
+};
+use ibc_relayer_types::core::ics03_connection::events::{
+    self as connection_events, Attributes as ConnectionAttributes,
+};
+use ibc_relayer_types::core::ics03_connection::msgs::conn_open_ack::{
+    MsgConnectionOpenAck, TYPE_URL as CONN_OPEN_ACK_TYPE_URL,
+};
+use ibc_relayer_types::core::ics03_connection::msgs::conn_open_confirm::{
+    MsgConnectionOpenConfirm, TYPE_URL as CONN_OPEN_CONFIRM_TYPE_URL,
+};
+use ibc_relayer_types::core::ics03_connection::msgs::conn_open_init::{
+    MsgConnectionOpenInit, TYPE_URL as CONN_OPEN_INIT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics03_connection::msgs::conn_open_try::{
+    MsgConnectionOpenTry, TYPE_URL as CONN_OPEN_TRY_TYPE_URL,
+};
+use ibc_relayer_types::core::ics03_connection::version::{get_compatible_versions, pick_version};
+use ibc_relayer_types::core::ics04_channel::channel::{
+    ChannelEnd, IdentifiedChannelEnd, Ordering, State as ChannelState, UpgradeState,
+};
+use ibc_relayer_types::core::ics04_channel::events as channel_events;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_close_confirm::{
+    MsgChannelCloseConfirm, TYPE_URL as CHAN_CLOSE_CONFIRM_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_close_init::{
+    MsgChannelCloseInit, TYPE_URL as CHAN_CLOSE_INIT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_open_ack::{
+    MsgChannelOpenAck, TYPE_URL as CHAN_OPEN_ACK_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_open_confirm::{
+    MsgChannelOpenConfirm, TYPE_URL as CHAN_OPEN_CONFIRM_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_open_init::{
+    MsgChannelOpenInit, TYPE_URL as CHAN_OPEN_INIT_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_open_try::{
+    MsgChannelOpenTry, TYPE_URL as CHAN_OPEN_TRY_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::msgs::recv_packet::{
+    MsgRecvPacket, TYPE_URL as RECV_PACKET_TYPE_URL,
+};
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height;
+
+use crate::chain::solomachine::error::Error;
+use crate::client_state::{AnyClientState, IdentifiedAnyClientState};
+use crate::consensus_state::AnyConsensusState;
+
+/// A client hosted by the solo machine, along with its consensus states.
+#[derive(Clone, Debug)]
+struct StoredClient {
+    client_state: AnyClientState,
+    consensus_states: BTreeMap<Height, AnyConsensusState>,
+}
+
+type ChannelKey = (PortId, ChannelId);
+type PacketKey = (PortId, ChannelId, Sequence);
+
+#[derive(Clone, Debug, Default)]
+pub struct SoloMachineStore {
+    client_counter: u64,
+    connection_counter: u64,
+    channel_counter: u64,
+    clients: BTreeMap<ClientId, StoredClient>,
+    connections: BTreeMap<ConnectionId, ConnectionEnd>,
+    channels: BTreeMap<ChannelKey, ChannelEnd>,
+    next_sequence_recv: BTreeMap<ChannelKey, Sequence>,
+    packet_receipts: BTreeSet<PacketKey>,
+    packet_acknowledgements: BTreeMap<PacketKey, Vec<u8>>,
+}
+
+impl SoloMachineStore {
+    /// Apply the given messages, all at once or not at all, at the given height and
+    /// time of the solo machine. Returns the events emitted by every message.
+    pub fn apply(
+        &mut self,
+        msgs: &[Any],
+        height: Height,
+        timestamp: Timestamp,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        let mut store = self.clone();
+        let mut events = Vec::new();
+
+        for msg in msgs {
+            events.extend(store.apply_msg(msg, height, timestamp)?);
+        }
+
+        *self = store;
+
+        Ok(events)
+    }
+
+    fn apply_msg(
+        &mut self,
+        msg: &Any,
+        height: Height,
+        timestamp: Timestamp,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        match msg.type_url.as_str() {
+            CREATE_CLIENT_TYPE_URL => self.create_client(decode(msg)?),
+            UPDATE_CLIENT_TYPE_URL => self.update_client(decode(msg)?),
+            CONN_OPEN_INIT_TYPE_URL => self.conn_open_init(decode(msg)?),
+            CONN_OPEN_TRY_TYPE_URL => self.conn_open_try(decode(msg)?),
+            CONN_OPEN_ACK_TYPE_URL => self.conn_open_ack(decode(msg)?),
+            CONN_OPEN_CONFIRM_TYPE_URL => self.conn_open_confirm(decode(msg)?),
+            CHAN_OPEN_INIT_TYPE_URL => self.chan_open_init(decode(msg)?),
+            CHAN_OPEN_TRY_TYPE_URL => self.chan_open_try(decode(msg)?),
+            CHAN_OPEN_ACK_TYPE_URL => self.chan_open_ack(decode(msg)?),
+            CHAN_OPEN_CONFIRM_TYPE_URL => self.chan_open_confirm(decode(msg)?),
+            CHAN_CLOSE_INIT_TYPE_URL => self.chan_close_init(decode(msg)?),
+            CHAN_CLOSE_CONFIRM_TYPE_URL => self.chan_close_confirm(decode(msg)?),
+            RECV_PACKET_TYPE_URL => self.recv_packet(decode(msg)?, height, timestamp),
+            type_url => Err(Error::unsupported_message(type_url.to_string())),
+        }
+    }
+
+    fn create_client(&mut self, msg: MsgCreateClient) -> Result<Vec<IbcEvent>, Error> {
+        let client_state = AnyClientState::try_from(msg.client_state).map_err(Error::client)?;
+        let consensus_state =
+            AnyConsensusState::try_from(msg.consensus_state).map_err(Error::client)?;
+
+        let client_type = client_state.client_type();
+        if !matches!(client_state, AnyClientState::Tendermint(_)) {
+            return Err(Error::unsupported_client_type(client_type));
+        }
+
+        let client_id = ClientId::new(client_type, self.client_counter)
+            .map_err(|e| Error::client(ClientError::invalid_client_identifier(e)))?;
+        self.client_counter += 1;
+
+        let consensus_height = client_state.latest_height();
+        self.clients.insert(
+            client_id.clone(),
+            StoredClient {
+                client_state,
+                consensus_states: BTreeMap::from([(consensus_height, consensus_state)]),
+            },
+        );
+
+        Ok(vec![IbcEvent::CreateClient(CreateClient(
+            ClientAttributes {
+                client_id,
+                client_type,
+                consensus_height,
+            },
+        ))])
+    }
+
+    fn update_client(&mut self, msg: MsgUpdateClient) -> Result<Vec<IbcEvent>, Error> {
+        let header = AnyHeader::try_from(msg.header).map_err(Error::client)?;
+        let client = self.client_mut(&msg.client_id)?;
+
+        let (AnyClientState::Tendermint(client_state), AnyHeader::Tendermint(tm_header)) =
+            (&client.client_state, &header)
+        else {
+            return Err(Error::unsupported_client_type(header.client_type()));
+        };
+
+        let consensus_height = header.height();
+        if consensus_height > client_state.latest_height() {
+            client.client_state = AnyClientState::Tendermint(
+                client_state
+                    .clone()
+                    .with_header(tm_header.clone())
+                    .map_err(|e| Error::client(e.into()))?,
+            );
+        }
+
+        client.consensus_states.insert(
+            consensus_height,
+            AnyConsensusState::Tendermint(TmConsensusState::from(tm_header.clone())),
+        );
+
+        Ok(vec![IbcEvent::UpdateClient(UpdateClient {
+            common: ClientAttributes {
+                client_id: msg.client_id,
+                client_type: header.client_type(),
+                consensus_height,
+            },
+            header: Some(header),
+        })])
+    }
+
+    fn conn_open_init(&mut self, msg: MsgConnectionOpenInit) -> Result<Vec<IbcEvent>, Error> {
+        self.client(&msg.client_id)?;
+
+        let versions = match msg.version {
+            Some(version) => vec![version],
+            None => get_compatible_versions(),
+        };
+
+        let connection_id = self.next_connection_id();
+        let attributes = ConnectionAttributes {
+            connection_id: Some(connection_id.clone()),
+            client_id: msg.client_id.clone(),
+            counterparty_connection_id: None,
+            counterparty_client_id: msg.counterparty.client_id().clone(),
+        };
+
+        self.connections.insert(
+            connection_id,
+            ConnectionEnd::new(
+                ConnectionState::Init,
+                msg.client_id,
+                msg.counterparty,
+                versions,
+                msg.delay_period,
+            ),
+        );
+
+        Ok(vec![IbcEvent::OpenInitConnection(
+            connection_events::OpenInit(attributes),
+        )])
+    }
+
+    fn conn_open_try(&mut self, msg: MsgConnectionOpenTry) -> Result<Vec<IbcEvent>, Error> {
+        self.client(&msg.client_id)?;
+
+        let version = pick_version(get_compatible_versions(), msg.counterparty_versions)
+            .map_err(Error::connection)?;
+
+        let connection_id = self.next_connection_id();
+        let attributes = ConnectionAttributes {
+            connection_id: Some(connection_id.clone()),
+            client_id: msg.client_id.clone(),
+            counterparty_connection_id: msg.counterparty.connection_id().cloned(),
+            counterparty_client_id: msg.counterparty.client_id().clone(),
+        };
+
+        self.connections.insert(
+            connection_id,
+            ConnectionEnd::new(
+                ConnectionState::TryOpen,
+                msg.client_id,
+                msg.counterparty,
+                vec![version],
+                msg.delay_period,
+            ),
+        );
+
+        Ok(vec![IbcEvent::OpenTryConnection(
+            connection_events::OpenTry(attributes),
+        )])
+    }
+
+    fn conn_open_ack(&mut self, msg: MsgConnectionOpenAck) -> Result<Vec<IbcEvent>, Error> {
+        let connection = self.connection_in_state(&msg.connection_id, ConnectionState::Init)?;
+
+        let counterparty = ConnectionCounterparty::new(
+            connection.counterparty().client_id().clone(),
+            Some(msg.counterparty_connection_id.clone()),
+            connection.counterparty().prefix().clone(),
+        );
+
+        connection.set_state(ConnectionState::Open);
+        connection.set_version(msg.version);
+        connection.set_counterparty(counterparty);
+
+        let attributes = ConnectionAttributes {
+            connection_id: Some(msg.connection_id),
+            client_id: connection.client_id().clone(),
+            counterparty_connection_id: Some(msg.counterparty_connection_id),
+            counterparty_client_id: connection.counterparty().client_id().clone(),
+        };
+
+        Ok(vec![IbcEvent::OpenAckConnection(
+            connection_events::OpenAck(attributes),
+        )])
+    }
+
+    fn conn_open_confirm(&mut self, msg: MsgConnectionOpenConfirm) -> Result<Vec<IbcEvent>, Error> {
+        let connection = self.connection_in_state(&msg.connection_id, ConnectionState::TryOpen)?;
+
+        connection.set_state(ConnectionState::Open);
+
+        let attributes = ConnectionAttributes {
+            connection_id: Some(msg.connection_id),
+            client_id: connection.client_id().clone(),
+            counterparty_connection_id: connection.counterparty().connection_id().cloned(),
+            counterparty_client_id: connection.counterparty().client_id().clone(),
+        };
+
+        Ok(vec![IbcEvent::OpenConfirmConnection(
+            connection_events::OpenConfirm(attributes),
+        )])
+    }
+
+    fn chan_open_init(&mut self, msg: MsgChannelOpenInit) -> Result<Vec<IbcEvent>, Error> {
+        let connection_id = self.channel_connection(&msg.channel)?;
+
+        let channel_id = self.next_channel_id();
+        let mut channel = msg.channel;
+        channel.set_state(ChannelState::Init);
+
+        let event = channel_events::OpenInit {
+            port_id: msg.port_id.clone(),
+            channel_id: Some(channel_id.clone()),
+            connection_id,
+            counterparty_port_id: channel.counterparty().port_id().clone(),
+            counterparty_channel_id: None,
+        };
+
+        self.insert_channel(msg.port_id, channel_id, channel);
+
+        Ok(vec![event.into()])
+    }
+
+    fn chan_open_try(&mut self, msg: MsgChannelOpenTry) -> Result<Vec<IbcEvent>, Error> {
+        let connection_id = self.channel_connection(&msg.channel)?;
+
+        let channel_id = self.next_channel_id();
+        let mut channel = msg.channel;
+        channel.set_state(ChannelState::TryOpen);
+        channel.set_version(msg.counterparty_version);
+
+        let event = channel_events::OpenTry {
+            port_id: msg.port_id.clone(),
+            channel_id: Some(channel_id.clone()),
+            connection_id,
+            counterparty_port_id: channel.counterparty().port_id().clone(),
+            counterparty_channel_id: channel.counterparty().channel_id().cloned(),
+        };
+
+        self.insert_channel(msg.port_id, channel_id, channel);
+
+        Ok(vec![event.into()])
+    }
+
+    fn chan_open_ack(&mut self, msg: MsgChannelOpenAck) -> Result<Vec<IbcEvent>, Error> {
+        let channel = self.channel_in_state(&msg.port_id, &msg.channel_id, ChannelState::Init)?;
+
+        channel.set_state(ChannelState::Open(UpgradeState::NotUpgrading));
+        channel.set_version(msg.counterparty_version);
+        channel.set_counterparty_channel_id(msg.counterparty_channel_id.clone());
+
+        let event = channel_events::OpenAck {
+            port_id: msg.port_id,
+            channel_id: Some(msg.channel_id),
+            counterparty_channel_id: Some(msg.counterparty_channel_id),
+            connection_id: channel.connection_hops()[0].clone(),
+            counterparty_port_id: channel.counterparty().port_id().clone(),
+        };
+
+        Ok(vec![event.into()])
+    }
+
+    fn chan_open_confirm(&mut self, msg: MsgChannelOpenConfirm) -> Result<Vec<IbcEvent>, Error> {
+        let channel =
+            self.channel_in_state(&msg.port_id, &msg.channel_id, ChannelState::TryOpen)?;
+
+        channel.set_state(ChannelState::Open(UpgradeState::NotUpgrading));
+
+        let event = channel_events::OpenConfirm {
+            port_id: msg.port_id,
+            channel_id: Some(msg.channel_id),
+            connection_id: channel.connection_hops()[0].clone(),
+            counterparty_port_id: channel.counterparty().port_id().clone(),
+            counterparty_channel_id: channel.counterparty().channel_id().cloned(),
+        };
+
+        Ok(vec![event.into()])
+    }
+
+    fn chan_close_init(&mut self, msg: MsgChannelCloseInit) -> Result<Vec<IbcEvent>, Error> {
+        let channel = self.open_channel(&msg.port_id, &msg.channel_id)?;
+
+        channel.set_state(ChannelState::Closed);
+
+        let event = channel_events::CloseInit {
+            port_id: msg.port_id,
+            channel_id: msg.channel_id,
+            connection_id: channel.connection_hops()[0].clone(),
+            counterparty_port_id: channel.counterparty().port_id().clone(),
+            counterparty_channel_id: channel.counterparty().channel_id().cloned(),
+        };
+
+        Ok(vec![event.into()])
+    }
+
+    fn chan_close_confirm(&mut self, msg: MsgChannelCloseConfirm) -> Result<Vec<IbcEvent>, Error> {
+        let channel = self.open_channel(&msg.port_id, &msg.channel_id)?;
+
+        channel.set_state(ChannelState::Closed);
+
+        let event = channel_events::CloseConfirm {
+            channel_id: Some(msg.channel_id),
+            port_id: msg.port_id,
+            connection_id: channel.connection_hops()[0].clone(),
+            counterparty_port_id: channel.counterparty().port_id().clone(),
+            counterparty_channel_id: channel.counterparty().channel_id().cloned(),
+        };
+
+        Ok(vec![event.into()])
+    }
+
+    /// Receive a packet, which the solo machine acknowledges with the successful
+    /// acknowledgement of ICS-20 transfers.
+    fn recv_packet(
+        &mut self,
+        msg: MsgRecvPacket,
+        height: Height,
+        timestamp: Timestamp,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        let packet = msg.packet;
+        let port_id = packet.destination_port.clone();
+        let channel_id = packet.destination_channel.clone();
+
+        let ordered = self
+            .open_channel(&port_id, &channel_id)?
+            .order_matches(&Ordering::Ordered);
+
+        if packet.timed_out(&timestamp, height) {
+            return Err(Error::packet_timed_out(packet.sequence));
+        }
+
+        let key = (port_id.clone(), channel_id.clone(), packet.sequence);
+
+        if ordered {
+            let next_sequence = self
+                .next_sequence_recv
+                .entry((port_id, channel_id))
+                .or_insert_with(|| Sequence::from(1));
+
+            if packet.sequence != *next_sequence {
+                return Err(Error::packet_sequence_mismatch(
+                    *next_sequence,
+                    packet.sequence,
+                ));
+            }
+
+            *next_sequence = next_sequence.increment();
+        } else if !self.packet_receipts.insert(key.clone()) {
+            // The packet was already received, there is nothing to do
+            return Ok(vec![]);
+        }
+
+        let ack = serde_json::to_vec(&Acknowledgement::success())
+            .expect("the acknowledgement is serializable");
+
+        self.packet_acknowledgements
+            .insert(key, Sha256::digest(&ack).to_vec());
+
+        Ok(vec![
+            IbcEvent::ReceivePacket(channel_events::ReceivePacket {
+                packet: packet.clone(),
+            }),
+            IbcEvent::WriteAcknowledgement(channel_events::WriteAcknowledgement { packet, ack }),
+        ])
+    }
+
+    pub fn client_state(&self, client_id: &ClientId) -> Result<AnyClientState, Error> {
+        Ok(self.client(client_id)?.client_state.clone())
+    }
+
+    pub fn consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Result<AnyConsensusState, Error> {
+        self.client(client_id)?
+            .consensus_states
+            .get(&height)
+            .cloned()
+            .ok_or_else(|| Error::consensus_state_not_found(client_id.clone(), height))
+    }
+
+    /// The heights of the consensus states of the given client, from the highest to the lowest.
+    pub fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, Error> {
+        Ok(self
+            .client(client_id)?
+            .consensus_states
+            .keys()
+            .rev()
+            .copied()
+            .collect())
+    }
+
+    pub fn clients(&self) -> Vec<IdentifiedAnyClientState> {
+        self.clients
+            .iter()
+            .map(|(client_id, client)| {
+                IdentifiedAnyClientState::new(client_id.clone(), client.client_state.clone())
+            })
+            .collect()
+    }
+
+    pub fn connection(&self, connection_id: &ConnectionId) -> Result<ConnectionEnd, Error> {
+        self.connections
+            .get(connection_id)
+            .cloned()
+            .ok_or_else(|| Error::connection_not_found(connection_id.clone()))
+    }
+
+    pub fn connections(&self) -> Vec<IdentifiedConnectionEnd> {
+        self.connections
+            .iter()
+            .map(|(connection_id, connection)| {
+                IdentifiedConnectionEnd::new(connection_id.clone(), connection.clone())
+            })
+            .collect()
+    }
+
+    pub fn channel(&self, port_id: &PortId, channel_id: &ChannelId) -> Result<ChannelEnd, Error> {
+        self.channels
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned()
+            .ok_or_else(|| Error::channel_not_found(port_id.clone(), channel_id.clone()))
+    }
+
+    pub fn channels(&self) -> Vec<IdentifiedChannelEnd> {
+        self.channels
+            .iter()
+            .map(|((port_id, channel_id), channel)| {
+                IdentifiedChannelEnd::new(port_id.clone(), channel_id.clone(), channel.clone())
+            })
+            .collect()
+    }
+
+    pub fn has_packet_receipt(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> bool {
+        self.packet_receipts
+            .contains(&(port_id.clone(), channel_id.clone(), sequence))
+    }
+
+    /// The commitment of the acknowledgement of the given packet, if it was received.
+    pub fn packet_acknowledgement(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Option<Vec<u8>> {
+        self.packet_acknowledgements
+            .get(&(port_id.clone(), channel_id.clone(), sequence))
+            .cloned()
+    }
+
+    /// The sequences of the packets acknowledged on the given channel.
+    pub fn packet_acknowledgements(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Vec<Sequence> {
+        self.packet_acknowledgements
+            .keys()
+            .filter(|(port, channel, _)| port == port_id && channel == channel_id)
+            .map(|(_, _, sequence)| *sequence)
+            .collect()
+    }
+
+    pub fn next_sequence_recv(&self, port_id: &PortId, channel_id: &ChannelId) -> Sequence {
+        self.next_sequence_recv
+            .get(&(port_id.clone(), channel_id.clone()))
+            .copied()
+            .unwrap_or_else(|| Sequence::from(1))
+    }
+
+    fn client(&self, client_id: &ClientId) -> Result<&StoredClient, Error> {
+        self.clients
+            .get(client_id)
+            .ok_or_else(|| Error::client_not_found(client_id.clone()))
+    }
+
+    fn client_mut(&mut self, client_id: &ClientId) -> Result<&mut StoredClient, Error> {
+        self.clients
+            .get_mut(client_id)
+            .ok_or_else(|| Error::client_not_found(client_id.clone()))
+    }
+
+    fn connection_in_state(
+        &mut self,
+        connection_id: &ConnectionId,
+        state: ConnectionState,
+    ) -> Result<&mut ConnectionEnd, Error> {
+        let connection = self
+            .connections
+            .get_mut(connection_id)
+            .ok_or_else(|| Error::connection_not_found(connection_id.clone()))?;
+
+        if !connection.state_matches(&state) {
+            return Err(Error::connection_state_mismatch(
+                connection_id.clone(),
+                state,
+                *connection.state(),
+            ));
+        }
+
+        Ok(connection)
+    }
+
+    /// The identifier of the connection of the given channel, which must be open.
+    fn channel_connection(&self, channel: &ChannelEnd) -> Result<ConnectionId, Error> {
+        let connection_id = channel.connection_hops()[0].clone();
+        let connection = self.connection(&connection_id)?;
+
+        if !connection.state_matches(&ConnectionState::Open) {
+            return Err(Error::connection_state_mismatch(
+                connection_id,
+                ConnectionState::Open,
+                *connection.state(),
+            ));
+        }
+
+        Ok(connection_id)
+    }
+
+    fn channel_in_state(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        state: ChannelState,
+    ) -> Result<&mut ChannelEnd, Error> {
+        let channel = self
+            .channels
+            .get_mut(&(port_id.clone(), channel_id.clone()))
+            .ok_or_else(|| Error::channel_not_found(port_id.clone(), channel_id.clone()))?;
+
+        if !channel.state_matches(&state) {
+            return Err(Error::channel_state_mismatch(
+                port_id.clone(),
+                channel_id.clone(),
+                state,
+                *channel.state(),
+            ));
+        }
+
+        Ok(channel)
+    }
+
+    fn open_channel(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<&mut ChannelEnd, Error> {
+        self.channel_in_state(
+            port_id,
+            channel_id,
+            ChannelState::Open(UpgradeState::NotUpgrading),
+        )
+    }
+
+    fn insert_channel(&mut self, port_id: PortId, channel_id: ChannelId, channel: ChannelEnd) {
+        let key = (port_id, channel_id);
+
+        self.next_sequence_recv
+            .insert(key.clone(), Sequence::from(1));
+        self.channels.insert(key, channel);
+    }
+
+    fn next_connection_id(&mut self) -> ConnectionId {
+        let connection_id = ConnectionId::new(self.connection_counter);
+        self.connection_counter += 1;
+        connection_id
+    }
+
+    fn next_channel_id(&mut self) -> ChannelId {
+        let channel_id = ChannelId::new(self.channel_counter);
+        self.channel_counter += 1;
+        channel_id
+    }
+}
+
+fn decode<M, R>(msg: &Any) -> Result<M, Error>
+where
+    M: Protobuf<R> + Clone + TryFrom<R>,
+    R: prost::Message + From<M> + Default,
+    <M as TryFrom<R>>::Error: core::fmt::Display,
+{
+    M::decode_vec(&msg.value).map_err(|e| Error::decode_message(msg.type_url.clone(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::str::FromStr;
+    use core::time::Duration;
+
+    use ibc_relayer_types::clients::ics07_tendermint::client_state::{
+        AllowUpdate, ClientState as TmClientState,
+    };
+    use ibc_relayer_types::core::ics02_client::trust_threshold::TrustThreshold;
+    use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentRoot;
+    use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+    use ibc_relayer_types::signer::Signer;
+    use ibc_relayer_types::tx_msg::Msg;
+
+    fn signer() -> Signer {
+        Signer::from_str("cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng").unwrap()
+    }
+
+    fn create_client_msg() -> Any {
+        let client_state = TmClientState::new(
+            ChainId::from_string("ibc-0"),
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(64000),
+            Duration::from_secs(128000),
+            Duration::from_secs(3),
+            Height::new(0, 10).unwrap(),
+            ProofSpecs::default(),
+            vec![],
+            AllowUpdate {
+                after_expiry: true,
+                after_misbehaviour: true,
+            },
+        )
+        .unwrap();
+
+        let consensus_state = TmConsensusState::new(
+            CommitmentRoot::from_bytes(b"root"),
+            tendermint::Time::now(),
+            tendermint::Hash::Sha256([0; 32]),
+        );
+
+        MsgCreateClient::new(client_state.into(), consensus_state.into(), signer())
+            .unwrap()
+            .to_any()
+    }
+
+    fn conn_open_init_msg(client_id: ClientId) -> Any {
+        MsgConnectionOpenInit {
+            client_id,
+            counterparty: ConnectionCounterparty::new(
+                ClientId::from_str("06-solomachine-0").unwrap(),
+                None,
+                b"ibc".to_vec().try_into().unwrap(),
+            ),
+            version: None,
+            delay_period: Duration::ZERO,
+            signer: signer(),
+        }
+        .to_any()
+    }
+
+    #[test]
+    fn create_client_and_open_connection() {
+        let mut store = SoloMachineStore::default();
+        let height = Height::new(0, 2).unwrap();
+
+        let events = store
+            .apply(&[create_client_msg()], height, Timestamp::now())
+            .unwrap();
+
+        let IbcEvent::CreateClient(create) = &events[0] else {
+            panic!("expected a CreateClient event, got {events:?}");
+        };
+        let client_id = create.client_id().clone();
+        assert_eq!(
+            store.consensus_state_heights(&client_id).unwrap(),
+            vec![Height::new(0, 10).unwrap()]
+        );
+
+        store
+            .apply(&[conn_open_init_msg(client_id)], height, Timestamp::now())
+            .unwrap();
+
+        let connection = store
+            .connection(&ConnectionId::from_str("connection-0").unwrap())
+            .unwrap();
+        assert!(connection.state_matches(&ConnectionState::Init));
+    }
+
+    #[test]
+    fn failed_transaction_leaves_store_unchanged() {
+        let mut store = SoloMachineStore::default();
+        let height = Height::new(0, 2).unwrap();
+
+        // The connection refers to a client which does not exist yet
+        let msgs = [
+            conn_open_init_msg(ClientId::from_str("07-tendermint-0").unwrap()),
+            create_client_msg(),
+        ];
+
+        assert!(store.apply(&msgs, height, Timestamp::now()).is_err());
+        assert!(store.clients().is_empty());
+        assert!(store.connections().is_empty());
+    }
+}
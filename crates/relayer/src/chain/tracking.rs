@@ -70,17 +70,25 @@ impl Display for TrackingId {
 pub struct TrackedMsgs {
     pub msgs: Vec<Any>,
     pub tracking_id: TrackingId,
+    /// Number of times the gas price is bumped when submitting
+    /// the messages, non-zero when resubmitting a stuck transaction.
+    pub fee_bumps: u32,
 }
 
 impl TrackedMsgs {
     pub fn new(msgs: Vec<Any>, tracking_id: TrackingId) -> Self {
-        Self { msgs, tracking_id }
+        Self {
+            msgs,
+            tracking_id,
+            fee_bumps: 0,
+        }
     }
 
     pub fn new_static(msgs: Vec<Any>, tracking_id: &'static str) -> Self {
         Self {
             msgs,
             tracking_id: TrackingId::Static(tracking_id),
+            fee_bumps: 0,
         }
     }
 
@@ -88,6 +96,7 @@ impl TrackedMsgs {
         Self {
             msgs,
             tracking_id: TrackingId::Uuid(tracking_id),
+            fee_bumps: 0,
         }
     }
 
@@ -95,6 +104,7 @@ impl TrackedMsgs {
         Self {
             msgs: vec![msg],
             tracking_id: TrackingId::Static(tracking_id),
+            fee_bumps: 0,
         }
     }
 
@@ -102,6 +112,7 @@ impl TrackedMsgs {
         Self {
             msgs: vec![msg],
             tracking_id: TrackingId::Uuid(tracking_id),
+            fee_bumps: 0,
         }
     }
 
@@ -112,4 +123,9 @@ impl TrackedMsgs {
     pub fn tracking_id(&self) -> TrackingId {
         self.tracking_id
     }
+
+    pub fn with_fee_bumps(mut self, fee_bumps: u32) -> Self {
+        self.fee_bumps = fee_bumps;
+        self
+    }
 }
@@ -28,6 +28,7 @@ use ibc_relayer_types::core::ics24_host::identifier::{
     ChainId, ChannelId, ClientId, ConnectionId, PortId,
 };
 use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::proofs::Proofs;
 use ibc_relayer_types::tx_msg::Msg;
 use ibc_relayer_types::Height;
 
@@ -40,8 +41,10 @@ use crate::chain::requests::{
 use crate::chain::tracking::TrackedMsgs;
 use crate::connection::Connection;
 use crate::foreign_client::{ForeignClient, HasExpiredOrFrozenError};
+use crate::multihop::{self, resolve_channel_hops, ChannelHop, HopProofs};
 use crate::object::Channel as WorkerChannelObject;
 use crate::supervisor::error::Error as SupervisorError;
+use crate::util::handshake::{pair_half_open_ends, HalfOpenHandshake};
 use crate::util::pretty::{PrettyDuration, PrettyOption};
 use crate::util::retry::retry_with_index;
 use crate::util::retry::RetryResult;
@@ -101,6 +104,8 @@ pub struct ChannelSide<Chain: ChainHandle> {
     port_id: PortId,
     channel_id: Option<ChannelId>,
     version: Option<Version>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    hops: Vec<ChannelHop<Chain>>,
 }
 
 impl<Chain: ChainHandle> Display for ChannelSide<Chain> {
@@ -130,9 +135,18 @@ impl<Chain: ChainHandle> ChannelSide<Chain> {
             port_id,
             channel_id,
             version,
+            hops: Vec::new(),
         }
     }
 
+    /// Sets the intermediate chains of a multi-hop channel, from this end
+    /// of the channel to the counterparty chain. In that case, `client_id` and
+    /// `connection_id` are the client and connection of the first hop.
+    pub fn with_hops(mut self, hops: Vec<ChannelHop<Chain>>) -> Self {
+        self.hops = hops;
+        self
+    }
+
     pub fn chain_id(&self) -> ChainId {
         self.chain.id()
     }
@@ -157,6 +171,14 @@ impl<Chain: ChainHandle> ChannelSide<Chain> {
         self.version.as_ref()
     }
 
+    pub fn hops(&self) -> &[ChannelHop<Chain>] {
+        &self.hops
+    }
+
+    pub fn connection_hops(&self) -> Vec<ConnectionId> {
+        multihop::connection_hops(&self.connection_id, &self.hops)
+    }
+
     pub fn map_chain<ChainB: ChainHandle>(
         self,
         mapper: impl Fn(Chain) -> ChainB,
@@ -168,6 +190,11 @@ impl<Chain: ChainHandle> ChannelSide<Chain> {
             port_id: self.port_id,
             channel_id: self.channel_id,
             version: self.version,
+            hops: self
+                .hops
+                .into_iter()
+                .map(|hop| hop.map_chain(&mapper))
+                .collect(),
         }
     }
 }
@@ -203,6 +230,52 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         a_port: PortId,
         b_port: PortId,
         version: Option<Version>,
+    ) -> Result<Self, ChannelError> {
+        let mut channel = Self::unopened(connection, ordering, a_port, b_port, version)?;
+
+        channel.handshake()?;
+
+        Ok(channel)
+    }
+
+    /// Resume the handshake of a channel between the given ports over the given connection
+    /// which was left half-open, eg. by an interrupted `create channel`, and complete it.
+    /// If there is no such handshake, a new channel is created like with [`Channel::new`].
+    ///
+    /// Fails if several half-open handshakes with the same ordering are found
+    /// between the ports, since there is no telling which one to resume.
+    pub fn resume(
+        connection: Connection<ChainA, ChainB>,
+        ordering: Ordering,
+        a_port: PortId,
+        b_port: PortId,
+        version: Option<Version>,
+    ) -> Result<Self, ChannelError> {
+        let mut channel = Self::unopened(connection, ordering, a_port, b_port, version)?;
+
+        match channel.find_half_open_handshake()? {
+            Some(handshake) => {
+                info!("resuming half-open channel handshake {handshake}");
+
+                channel.a_side.channel_id = handshake.a_id;
+                channel.b_side.channel_id = handshake.b_id;
+            }
+            None => info!("no half-open channel handshake to resume, starting a new one"),
+        }
+
+        channel.handshake()?;
+
+        Ok(channel)
+    }
+
+    /// A channel between the given ports over the given connection
+    /// whose handshake has not started yet.
+    fn unopened(
+        connection: Connection<ChainA, ChainB>,
+        ordering: Ordering,
+        a_port: PortId,
+        b_port: PortId,
+        version: Option<Version>,
     ) -> Result<Self, ChannelError> {
         let src_connection_id = connection
             .src_connection_id()
@@ -211,7 +284,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             .dst_connection_id()
             .ok_or_else(|| ChannelError::missing_local_connection(connection.dst_chain().id()))?;
 
-        let mut channel = Self {
+        Ok(Self {
             ordering,
             a_side: ChannelSide::new(
                 connection.src_chain(),
@@ -230,6 +303,74 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
                 version,
             ),
             connection_delay: connection.delay_period,
+        })
+    }
+
+    /// Look for a handshake between the ports of this channel, over its connection
+    /// and with the same ordering, which was started but not completed.
+    fn find_half_open_handshake(
+        &self,
+    ) -> Result<Option<HalfOpenHandshake<ChannelId>>, ChannelError> {
+        let a_ends = half_open_channel_ends(&self.a_side, &self.b_side.port_id, self.ordering)?;
+        let b_ends = half_open_channel_ends(&self.b_side, &self.a_side.port_id, self.ordering)?;
+
+        let mut handshakes = pair_half_open_ends(&a_ends, &b_ends);
+
+        if handshakes.len() > 1 {
+            return Err(ChannelError::ambiguous_half_open_handshakes(
+                handshakes.iter().map(ToString::to_string).collect(),
+            ));
+        }
+
+        Ok(handshakes.pop())
+    }
+
+    /// Creates a new multi-hop channel (ICS 033) over the given connection hops on chain A,
+    /// which lead to chain B through intermediate chains, and completes the channel handshake.
+    ///
+    /// The handles of the intermediate chains are looked up by chain identifier
+    /// with `a_hop_chain` and `b_hop_chain`.
+    pub fn new_multihop(
+        a_chain: ChainA,
+        b_chain: ChainB,
+        a_connection_hops: &[ConnectionId],
+        ordering: Ordering,
+        a_port: PortId,
+        b_port: PortId,
+        version: Option<Version>,
+        a_hop_chain: impl Fn(&ChainId) -> Option<ChainA>,
+        b_hop_chain: impl Fn(&ChainId) -> Option<ChainB>,
+    ) -> Result<Self, ChannelError> {
+        let (a_hops, b_hops) = resolve_channel_hops(
+            &a_chain,
+            &b_chain,
+            a_connection_hops,
+            a_hop_chain,
+            b_hop_chain,
+        )
+        .map_err(ChannelError::multihop)?;
+
+        let mut channel = Self {
+            ordering,
+            a_side: ChannelSide::new(
+                a_chain,
+                a_hops.connection.client_id().clone(),
+                a_connection_hops[0].clone(),
+                a_port,
+                Default::default(),
+                version.clone(),
+            )
+            .with_hops(a_hops.hops),
+            b_side: ChannelSide::new(
+                b_chain,
+                a_hops.counterparty_client_id,
+                a_hops.counterparty_connection_id,
+                b_port,
+                Default::default(),
+                version,
+            )
+            .with_hops(b_hops.hops),
+            connection_delay: a_hops.connection.delay_period(),
         };
 
         channel.handshake()?;
@@ -317,6 +458,13 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             )
             .map_err(ChannelError::relayer)?;
 
+        if a_channel.is_multihop() {
+            return Err(ChannelError::multihop_channel(
+                channel.src_channel_id.clone(),
+                chain.id(),
+            ));
+        }
+
         let a_connection_id = a_channel.connection_hops().first().ok_or_else(|| {
             ChannelError::supervisor(SupervisorError::missing_connection_hops(
                 channel.src_channel_id.clone(),
@@ -368,26 +516,123 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         };
 
         if a_channel.state_matches(&State::Init) && a_channel.remote.channel_id.is_none() {
-            let channels: Vec<IdentifiedChannelEnd> = counterparty_chain
-                .query_connection_channels(QueryConnectionChannelsRequest {
-                    connection_id: b_connection_id,
-                    pagination: Some(PageRequest::all()),
-                })
-                .map_err(ChannelError::relayer)?;
+            handshake_channel.b_side.channel_id = find_counterparty_channel(
+                &counterparty_chain,
+                b_connection_id,
+                &channel.src_channel_id,
+            )?;
+        }
 
-            for chan in channels {
-                if let Some(remote_channel_id) = chan.channel_end.remote.channel_id() {
-                    if remote_channel_id == &channel.src_channel_id {
-                        handshake_channel.b_side.channel_id = Some(chan.channel_id);
-                        break;
-                    }
-                }
-            }
+        Ok((handshake_channel, a_channel.state))
+    }
+
+    /// Same as [`Channel::restore_from_state`], for a multi-hop channel (ICS 033) whose
+    /// connection hops traverse intermediate chains. The handles of the intermediate
+    /// chains are looked up by chain identifier with `a_hop_chain` and `b_hop_chain`.
+    pub fn restore_multihop_from_state(
+        chain: ChainA,
+        counterparty_chain: ChainB,
+        channel: WorkerChannelObject,
+        height: QueryHeight,
+        a_hop_chain: impl Fn(&ChainId) -> Option<ChainA>,
+        b_hop_chain: impl Fn(&ChainId) -> Option<ChainB>,
+    ) -> Result<(Channel<ChainA, ChainB>, State), ChannelError> {
+        let (a_channel, _) = chain
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: channel.src_port_id.clone(),
+                    channel_id: channel.src_channel_id.clone(),
+                    height,
+                },
+                // IncludeProof::Yes forces a new query when the CachingChainHandle
+                // is used.
+                IncludeProof::Yes,
+            )
+            .map_err(ChannelError::relayer)?;
+
+        let (a_hops, b_hops) = resolve_channel_hops(
+            &chain,
+            &counterparty_chain,
+            a_channel.connection_hops(),
+            a_hop_chain,
+            b_hop_chain,
+        )
+        .map_err(ChannelError::multihop)?;
+
+        let b_connection_id = a_hops.counterparty_connection_id.clone();
+
+        let mut handshake_channel = Channel {
+            ordering: *a_channel.ordering(),
+            a_side: ChannelSide::new(
+                chain,
+                a_hops.connection.client_id().clone(),
+                a_channel.connection_hops()[0].clone(),
+                channel.src_port_id.clone(),
+                Some(channel.src_channel_id.clone()),
+                None,
+            )
+            .with_hops(a_hops.hops),
+            b_side: ChannelSide::new(
+                counterparty_chain.clone(),
+                a_hops.counterparty_client_id,
+                a_hops.counterparty_connection_id,
+                a_channel.remote.port_id.clone(),
+                a_channel.remote.channel_id.clone(),
+                None,
+            )
+            .with_hops(b_hops.hops),
+            connection_delay: a_hops.connection.delay_period(),
+        };
+
+        if a_channel.state_matches(&State::Init) && a_channel.remote.channel_id.is_none() {
+            handshake_channel.b_side.channel_id = find_counterparty_channel(
+                &counterparty_chain,
+                b_connection_id,
+                &channel.src_channel_id,
+            )?;
         }
 
         Ok((handshake_channel, a_channel.state))
     }
 
+    /// Same as [`Channel::restore_from_event`], for a multi-hop channel (ICS 033).
+    /// The channel is restored from its state on chain, since the event only
+    /// carries the first connection hop of the channel.
+    pub fn restore_multihop_from_event(
+        chain: ChainA,
+        counterparty_chain: ChainB,
+        channel_open_event: IbcEvent,
+        a_hop_chain: impl Fn(&ChainId) -> Option<ChainA>,
+        b_hop_chain: impl Fn(&ChainId) -> Option<ChainB>,
+    ) -> Result<Channel<ChainA, ChainB>, ChannelError> {
+        let channel_event_attributes = channel_open_event
+            .clone()
+            .channel_attributes()
+            .ok_or_else(|| ChannelError::invalid_event(channel_open_event.clone()))?;
+
+        let channel_id = channel_event_attributes
+            .channel_id
+            .ok_or_else(|| ChannelError::invalid_event(channel_open_event))?;
+
+        let channel = WorkerChannelObject {
+            dst_chain_id: counterparty_chain.id(),
+            src_chain_id: chain.id(),
+            src_channel_id: channel_id,
+            src_port_id: channel_event_attributes.port_id,
+        };
+
+        let (channel, _) = Self::restore_multihop_from_state(
+            chain,
+            counterparty_chain,
+            channel,
+            QueryHeight::Latest,
+            a_hop_chain,
+            b_hop_chain,
+        )?;
+
+        Ok(channel)
+    }
+
     pub fn src_chain(&self) -> &ChainA {
         &self.a_side.chain
     }
@@ -880,10 +1125,17 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
     }
 
     pub fn build_update_client_on_dst(&self, height: Height) -> Result<Vec<Any>, ChannelError> {
+        // The client on the destination chain of a multi-hop channel
+        // tracks the last intermediate chain.
+        let src_chain = match self.a_side.hops.last() {
+            Some(hop) => hop.chain.clone(),
+            None => self.src_chain().clone(),
+        };
+
         let client = ForeignClient::restore(
             self.dst_client_id().clone(),
             self.dst_chain().clone(),
-            self.src_chain().clone(),
+            src_chain,
         );
 
         client.wait_and_build_update_client(height).map_err(|e| {
@@ -891,6 +1143,32 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         })
     }
 
+    /// Builds the proofs of the channel end on the source chain at the given height,
+    /// turned into multi-hop proofs if the channel traverses intermediate chains,
+    /// or into sentinel proofs if the channel is built on the localhost connection.
+    fn build_channel_proofs(
+        &self,
+        src_channel_id: &ChannelId,
+        height: Height,
+    ) -> Result<Proofs, ChannelError> {
+        let proofs = self
+            .src_chain()
+            .build_channel_proofs(self.src_port_id(), src_channel_id, height)
+            .map_err(ChannelError::channel_proof)?;
+
+        if self.dst_connection_id().is_localhost() {
+            return Ok(proofs.into_localhost());
+        }
+
+        if self.a_side.hops.is_empty() {
+            return Ok(proofs);
+        }
+
+        HopProofs::build(self.src_chain(), &self.a_side.hops, proofs.height())
+            .and_then(|hop_proofs| hop_proofs.wrap(proofs))
+            .map_err(ChannelError::multihop)
+    }
+
     pub fn build_chan_open_init(&self) -> Result<Vec<Any>, ChannelError> {
         let signer = self
             .dst_chain()
@@ -921,7 +1199,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             State::Init,
             self.ordering,
             counterparty,
-            vec![self.dst_connection_id().clone()],
+            self.b_side.connection_hops(),
             version,
             Sequence::from(0),
         );
@@ -1001,7 +1279,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             highest_state,
             self.ordering,
             counterparty,
-            vec![self.dst_connection_id().clone()],
+            self.b_side.connection_hops(),
             Version::empty(),
             Sequence::from(0),
         );
@@ -1075,10 +1353,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             .query_latest_height()
             .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
 
-        let proofs = self
-            .src_chain()
-            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
-            .map_err(ChannelError::channel_proof)?;
+        let proofs = self.build_channel_proofs(src_channel_id, query_height)?;
 
         // Build message(s) to update client on destination
         let mut msgs = self.build_update_client_on_dst(proofs.height())?;
@@ -1093,7 +1368,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             State::TryOpen,
             *src_channel.ordering(),
             counterparty,
-            vec![self.dst_connection_id().clone()],
+            self.b_side.connection_hops(),
             version,
             Sequence::from(0),
         );
@@ -1196,10 +1471,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             .query_latest_height()
             .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
 
-        let proofs = self
-            .src_chain()
-            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
-            .map_err(ChannelError::channel_proof)?;
+        let proofs = self.build_channel_proofs(src_channel_id, query_height)?;
 
         // Build message(s) to update client on destination
         let mut msgs = self.build_update_client_on_dst(proofs.height())?;
@@ -1304,10 +1576,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             .query_latest_height()
             .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
 
-        let proofs = self
-            .src_chain()
-            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
-            .map_err(ChannelError::channel_proof)?;
+        let proofs = self.build_channel_proofs(src_channel_id, query_height)?;
 
         // Build message(s) to update client on destination
         let mut msgs = self.build_update_client_on_dst(proofs.height())?;
@@ -1476,10 +1745,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             .query_latest_height()
             .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
 
-        let proofs = self
-            .src_chain()
-            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
-            .map_err(ChannelError::channel_proof)?;
+        let proofs = self.build_channel_proofs(src_channel_id, query_height)?;
 
         let counterparty_upgrade_sequence = src_channel_end.upgrade_sequence;
 
@@ -2255,6 +2521,64 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
     }
 }
 
+/// The channel end on the given connection of the counterparty chain whose
+/// counterparty is the given channel, if any.
+fn find_counterparty_channel<Chain: ChainHandle>(
+    counterparty_chain: &Chain,
+    connection_id: ConnectionId,
+    channel_id: &ChannelId,
+) -> Result<Option<ChannelId>, ChannelError> {
+    let channels: Vec<IdentifiedChannelEnd> = counterparty_chain
+        .query_connection_channels(QueryConnectionChannelsRequest {
+            connection_id,
+            pagination: Some(PageRequest::all()),
+        })
+        .map_err(ChannelError::relayer)?;
+
+    let counterparty_channel = channels
+        .into_iter()
+        .find(|chan| chan.channel_end.remote.channel_id() == Some(channel_id))
+        .map(|chan| chan.channel_id);
+
+    Ok(counterparty_channel)
+}
+
+/// The channel ends on the port and connection of the given side, in `INIT` or `TRYOPEN` state,
+/// whose counterparty is on the given port and which have the given ordering, along with
+/// the identifier of their counterparty channel end, if known.
+fn half_open_channel_ends<Chain: ChainHandle>(
+    side: &ChannelSide<Chain>,
+    counterparty_port_id: &PortId,
+    ordering: Ordering,
+) -> Result<Vec<(ChannelId, Option<ChannelId>)>, ChannelError> {
+    let channels = side
+        .chain
+        .query_connection_channels(QueryConnectionChannelsRequest {
+            connection_id: side.connection_id.clone(),
+            pagination: Some(PageRequest::all()),
+        })
+        .map_err(|e| ChannelError::query(side.chain_id(), e))?;
+
+    let ends = channels
+        .into_iter()
+        .filter(|channel| {
+            let end = &channel.channel_end;
+
+            channel.port_id == side.port_id
+                && matches!(end.state(), State::Init | State::TryOpen)
+                && !end.is_multihop()
+                && end.counterparty().port_id() == counterparty_port_id
+                && *end.ordering() == ordering
+        })
+        .map(|channel| {
+            let counterparty_id = channel.channel_end.counterparty().channel_id().cloned();
+            (channel.channel_id, counterparty_id)
+        })
+        .collect();
+
+    Ok(ends)
+}
+
 pub fn extract_channel_id(event: &IbcEvent) -> Result<&ChannelId, ChannelError> {
     match event {
         IbcEvent::OpenInitChannel(ev) => ev.channel_id(),
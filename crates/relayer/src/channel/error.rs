@@ -12,6 +12,7 @@ use ibc_relayer_types::proofs::ProofError;
 
 use crate::error::Error as RelayerError;
 use crate::foreign_client::{ForeignClientError, HasExpiredOrFrozenError};
+use crate::multihop::MultihopError;
 use crate::supervisor::Error as SupervisorError;
 
 define_error! {
@@ -81,6 +82,20 @@ define_error! {
             [ RelayerError ]
             |_| { "failed to build channel proofs" },
 
+        Multihop
+            [ MultihopError ]
+            |_| { "multi-hop channel error" },
+
+        MultihopChannel
+            {
+                channel_id: ChannelId,
+                chain_id: ChainId,
+            }
+            | e | {
+                format_args!("channel {0} on chain {1} is a multi-hop channel, which needs the handles of its intermediate chains",
+                    e.channel_id, e.chain_id)
+            },
+
         InvalidOrdering
             {
                 channel_ordering: Ordering,
@@ -132,6 +147,13 @@ define_error! {
         HandshakeFinalize
             |_| { "continue handshake" },
 
+        AmbiguousHalfOpenHandshakes
+            { handshakes: Vec<String> }
+            |e| {
+                format_args!("found several half-open channel handshakes between the ports, cannot tell which one to resume: {}",
+                    e.handshakes.join(", "))
+            },
+
         PartialOpenHandshake
             {
                 state: State,
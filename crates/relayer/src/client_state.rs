@@ -4,71 +4,176 @@ use serde::{Deserialize, Serialize};
 
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::IdentifiedClientState;
+use ibc_proto::ibc::lightclients::localhost::v2::ClientState as RawLocalhostClientState;
+use ibc_proto::ibc::lightclients::solomachine::v3::ClientState as RawSoloMachineClientState;
 use ibc_proto::ibc::lightclients::tendermint::v1::ClientState as RawTmClientState;
+use ibc_proto::ibc::lightclients::wasm::v1::ClientState as RawWasmClientState;
 use ibc_proto::Protobuf;
+use ibc_relayer_types::clients::ics06_solomachine::client_state::{
+    ClientState as SoloMachineClientState, SOLOMACHINE_CLIENT_STATE_TYPE_URL,
+};
 use ibc_relayer_types::clients::ics07_tendermint::client_state::{
     ClientState as TmClientState, TENDERMINT_CLIENT_STATE_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::client_message::{
+    Header as WasmHeader, Misbehaviour as WasmMisbehaviour,
+};
+use ibc_relayer_types::clients::ics08_wasm::client_state::{
+    ClientState as WasmClientState, WASM_CLIENT_STATE_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics08_wasm::consensus_state::ConsensusState as WasmConsensusState;
+use ibc_relayer_types::clients::ics09_localhost::client_state::{
+    ClientState as LocalhostClientState, LOCALHOST_CLIENT_STATE_TYPE_URL,
+};
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::error::Error;
+use ibc_relayer_types::core::ics02_client::header::AnyHeader;
 use ibc_relayer_types::core::ics02_client::trust_threshold::TrustThreshold;
 use ibc_relayer_types::core::ics24_host::error::ValidationError;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
 use ibc_relayer_types::Height;
 
+use crate::consensus_state::AnyConsensusState;
+use crate::misbehaviour::{AnyMisbehaviour, MisbehaviourEvidence};
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum AnyClientState {
     Tendermint(TmClientState),
+    SoloMachine(SoloMachineClientState),
+    Wasm(WasmClientState),
+    Localhost(LocalhostClientState),
 }
 
 impl AnyClientState {
     pub fn chain_id(&self) -> ChainId {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.chain_id(),
+            AnyClientState::SoloMachine(state) => state.chain_id(),
+            AnyClientState::Wasm(state) => state.chain_id(),
+            AnyClientState::Localhost(state) => state.chain_id(),
         }
     }
 
     pub fn latest_height(&self) -> Height {
         match self {
             Self::Tendermint(tm_state) => tm_state.latest_height(),
+            Self::SoloMachine(state) => state.latest_height(),
+            Self::Wasm(state) => state.latest_height(),
+            Self::Localhost(state) => state.latest_height(),
         }
     }
 
     pub fn frozen_height(&self) -> Option<Height> {
         match self {
             Self::Tendermint(tm_state) => tm_state.frozen_height(),
+            Self::SoloMachine(state) => state.frozen_height(),
+            Self::Wasm(state) => state.frozen_height(),
+            Self::Localhost(state) => state.frozen_height(),
         }
     }
 
     pub fn trust_threshold(&self) -> Option<TrustThreshold> {
         match self {
             AnyClientState::Tendermint(state) => Some(state.trust_threshold),
+            AnyClientState::SoloMachine(_) => None,
+            AnyClientState::Wasm(state) => Some(state.inner.trust_threshold),
+            AnyClientState::Localhost(_) => None,
         }
     }
 
     pub fn trusting_period(&self) -> Duration {
         match self {
             AnyClientState::Tendermint(state) => state.trusting_period,
+            // Solo machine clients do not expire
+            AnyClientState::SoloMachine(_) => Duration::MAX,
+            AnyClientState::Wasm(state) => state.inner.trusting_period,
+            // Localhost clients are updated at every block and do not expire
+            AnyClientState::Localhost(_) => Duration::MAX,
         }
     }
 
     pub fn max_clock_drift(&self) -> Duration {
         match self {
             AnyClientState::Tendermint(state) => state.max_clock_drift,
+            AnyClientState::SoloMachine(_) => Duration::ZERO,
+            AnyClientState::Wasm(state) => state.inner.max_clock_drift,
+            AnyClientState::Localhost(_) => Duration::ZERO,
         }
     }
 
     pub fn client_type(&self) -> ClientType {
         match self {
             Self::Tendermint(state) => state.client_type(),
+            Self::SoloMachine(state) => state.client_type(),
+            Self::Wasm(state) => state.client_type(),
+            Self::Localhost(state) => state.client_type(),
         }
     }
 
     pub fn expired(&self, elapsed: Duration) -> bool {
         match self {
             Self::Tendermint(state) => state.expired(elapsed),
+            Self::SoloMachine(state) => state.expired(elapsed),
+            Self::Wasm(state) => state.expired(elapsed),
+            Self::Localhost(state) => state.expired(elapsed),
+        }
+    }
+
+    /// The chain identifier of a localhost client is not part of its encoded state,
+    /// set it to the identifier of the given chain which hosts the client.
+    pub fn with_host_chain_id(self, host_chain_id: &ChainId) -> Self {
+        match self {
+            Self::Localhost(state) => Self::Localhost(state.with_chain_id(host_chain_id.clone())),
+            client_state => client_state,
+        }
+    }
+
+    /// Wrap this client state and the given consensus state into the states of
+    /// a Wasm client running the contract with the given checksum.
+    pub fn into_wasm(
+        self,
+        consensus_state: AnyConsensusState,
+        checksum: Vec<u8>,
+    ) -> (Self, AnyConsensusState) {
+        match (self, consensus_state) {
+            (Self::Tendermint(state), AnyConsensusState::Tendermint(consensus_state)) => (
+                Self::Wasm(WasmClientState::new(checksum, state)),
+                AnyConsensusState::Wasm(WasmConsensusState::new(consensus_state)),
+            ),
+            (client_state, consensus_state) => (client_state, consensus_state),
+        }
+    }
+
+    /// Wrap the given header of the light client into a client message,
+    /// if this is the state of a Wasm client.
+    pub fn wrap_header(&self, header: AnyHeader) -> AnyHeader {
+        match (self, header) {
+            (Self::Wasm(_), AnyHeader::Tendermint(header)) => {
+                AnyHeader::Wasm(WasmHeader::new(header))
+            }
+            (_, header) => header,
+        }
+    }
+
+    /// Wrap the misbehaviour and the supporting headers of the given evidence
+    /// into client messages, if this is the state of a Wasm client.
+    pub fn wrap_evidence(&self, evidence: MisbehaviourEvidence) -> MisbehaviourEvidence {
+        let misbehaviour = match (self, evidence.misbehaviour) {
+            (Self::Wasm(_), AnyMisbehaviour::Tendermint(misbehaviour)) => {
+                AnyMisbehaviour::Wasm(WasmMisbehaviour::new(misbehaviour))
+            }
+            (_, misbehaviour) => misbehaviour,
+        };
+
+        MisbehaviourEvidence {
+            misbehaviour,
+            supporting_headers: evidence
+                .supporting_headers
+                .into_iter()
+                .map(|header| self.wrap_header(header))
+                .collect(),
         }
     }
 }
@@ -87,6 +192,21 @@ impl TryFrom<Any> for AnyClientState {
                     .map_err(Error::decode_raw_client_state)?,
             )),
 
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::SoloMachine(
+                Protobuf::<RawSoloMachineClientState>::decode_vec(&raw.value)
+                    .map_err(Error::decode_raw_client_state)?,
+            )),
+
+            WASM_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Wasm(
+                Protobuf::<RawWasmClientState>::decode_vec(&raw.value)
+                    .map_err(Error::decode_raw_client_state)?,
+            )),
+
+            LOCALHOST_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Localhost(
+                Protobuf::<RawLocalhostClientState>::decode_vec(&raw.value)
+                    .map_err(Error::decode_raw_client_state)?,
+            )),
+
             _ => Err(Error::unknown_client_state_type(raw.type_url)),
         }
     }
@@ -99,6 +219,9 @@ impl From<AnyClientState> for Any {
                 type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawTmClientState>::encode_vec(value),
             },
+            AnyClientState::SoloMachine(value) => value.into(),
+            AnyClientState::Wasm(value) => value.into(),
+            AnyClientState::Localhost(value) => value.into(),
         }
     }
 }
@@ -131,6 +254,24 @@ impl From<TmClientState> for AnyClientState {
     }
 }
 
+impl From<SoloMachineClientState> for AnyClientState {
+    fn from(cs: SoloMachineClientState) -> Self {
+        Self::SoloMachine(cs)
+    }
+}
+
+impl From<WasmClientState> for AnyClientState {
+    fn from(cs: WasmClientState) -> Self {
+        Self::Wasm(cs)
+    }
+}
+
+impl From<LocalhostClientState> for AnyClientState {
+    fn from(cs: LocalhostClientState) -> Self {
+        Self::Localhost(cs)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub struct IdentifiedAnyClientState {
@@ -3,9 +3,13 @@
 pub mod compat_mode;
 pub mod dynamic_gas;
 pub mod error;
+pub mod fee_bump;
+pub mod fee_priority;
 pub mod filter;
 pub mod gas_multiplier;
+pub mod ics20_filter;
 pub mod proof_specs;
+pub mod rate_limit;
 pub mod refresh_rate;
 pub mod types;
 
@@ -16,7 +20,13 @@ use core::str::FromStr;
 use core::time::Duration;
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
 use std::borrow::Cow;
-use std::{fs, fs::File, io::Write, ops::Range, path::Path};
+use std::{
+    fs,
+    fs::File,
+    io::Write,
+    ops::Range,
+    path::{Path, PathBuf},
+};
 
 use byte_unit::Byte;
 use serde::{Deserialize, Serialize};
@@ -29,11 +39,16 @@ use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId
 use ibc_relayer_types::timestamp::ZERO_DURATION;
 
 use crate::chain::cosmos::config::CosmosSdkConfig;
+use crate::chain::cosmos::types::gas::default_gas_from_config;
+use crate::chain::solomachine::config::SoloMachineConfig;
+use crate::config::fee_bump::FeeBump;
+use crate::config::fee_priority::FeePriority;
+use crate::config::rate_limit::RateLimitConfig;
 use crate::config::types::ics20_field_size_limit::Ics20FieldSizeLimit;
 use crate::config::types::TrustThreshold;
 use crate::error::Error as RelayerError;
 use crate::extension_options::ExtensionOptionDynamicFeeTx;
-use crate::keyring::{AnySigningKeyPair, KeyRing, Store};
+use crate::keyring::{AnySecp256k1KeyPair, AnySigningKeyPair, KeyRing};
 
 use crate::keyring;
 
@@ -43,6 +58,10 @@ pub use error::Error;
 pub use filter::PacketFilter;
 pub use refresh_rate::RefreshRate;
 
+/// The default folder, relative to the home folder, where Hermes keeps
+/// the state it needs across restarts.
+pub const DATA_DEFAULT_FOLDER: &str = ".hermes/data";
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GasPrice {
     pub price: f64,
@@ -186,6 +205,10 @@ pub mod default {
         Duration::from_millis(500)
     }
 
+    pub fn stall_timeout() -> Duration {
+        Duration::from_secs(20)
+    }
+
     pub fn clock_drift() -> Duration {
         Duration::from_secs(5)
     }
@@ -214,6 +237,26 @@ pub mod default {
         TrustThreshold::TWO_THIRDS
     }
 
+    pub fn client_expiry_warning() -> Duration {
+        Duration::from_secs(60 * 60 * 24)
+    }
+
+    pub fn shard_count() -> u64 {
+        1
+    }
+
+    pub fn catch_up_max_lookback() -> u64 {
+        10_000
+    }
+
+    pub fn lease_duration() -> Duration {
+        Duration::from_secs(30)
+    }
+
+    pub fn lease_renew_interval() -> Duration {
+        Duration::from_secs(10)
+    }
+
     pub fn client_refresh_rate() -> RefreshRate {
         // Refresh the client three times per trusting period
         RefreshRate::new(1, 3)
@@ -254,6 +297,10 @@ pub mod default {
     pub fn clear_limit() -> usize {
         50
     }
+
+    pub fn remote_signer_timeout() -> Duration {
+        Duration::from_secs(10)
+    }
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -271,6 +318,12 @@ pub struct Config {
     pub chains: Vec<ChainConfig>,
     #[serde(default)]
     pub tracing_server: TracingServerConfig,
+    #[serde(default)]
+    pub high_availability: HighAvailabilityConfig,
+    #[serde(default)]
+    pub sharding: ShardingConfig,
+    #[serde(default)]
+    pub catch_up: CatchUpConfig,
 }
 
 impl Config {
@@ -327,17 +380,24 @@ impl Config {
                         .validate()
                         .map_err(Into::<Diagnostic<Error>>::into)?;
                 }
+                ChainConfig::SoloMachine(_) => {}
             }
         }
 
         // Check for invalid mode config
         self.mode.validate()?;
 
+        self.high_availability.validate()?;
+
+        self.sharding.validate()?;
+
+        self.catch_up.validate()?;
+
         Ok(())
     }
 }
 
-#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct ModeConfig {
     pub clients: Clients,
@@ -361,9 +421,13 @@ impl ModeConfig {
             )));
         }
 
-        if self.clients.enabled && !self.clients.refresh && !self.clients.misbehaviour {
+        if self.clients.enabled
+            && !self.clients.refresh
+            && !self.clients.misbehaviour
+            && !self.clients.upgrade
+        {
             return Err(Diagnostic::Error(Error::invalid_mode(
-                "either `refresh` or `misbehaviour` must be set to true if `clients.enabled` is set to true".to_string(),
+                "either `refresh`, `misbehaviour` or `upgrade` must be set to true if `clients.enabled` is set to true".to_string(),
             )));
         }
 
@@ -379,6 +443,8 @@ impl Default for ModeConfig {
                 enabled: true,
                 refresh: true,
                 misbehaviour: true,
+                upgrade: false,
+                expiry_warning: default::client_expiry_warning(),
             },
             connections: Connections { enabled: false },
             channels: Channels { enabled: false },
@@ -390,7 +456,7 @@ impl Default for ModeConfig {
     }
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Clients {
     pub enabled: bool,
@@ -398,21 +464,27 @@ pub struct Clients {
     pub refresh: bool,
     #[serde(default)]
     pub misbehaviour: bool,
+    #[serde(default)]
+    pub upgrade: bool,
+    /// Warn about the clients whose trusting period lapses within this duration.
+    /// A zero duration disables the warnings.
+    #[serde(default = "default::client_expiry_warning", with = "humantime_serde")]
+    pub expiry_warning: Duration,
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Connections {
     pub enabled: bool,
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Channels {
     pub enabled: bool,
 }
 
-#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Packets {
     pub enabled: bool,
@@ -430,6 +502,10 @@ pub struct Packets {
     pub ics20_max_receiver_size: Ics20FieldSizeLimit,
     #[serde(default = "default::clear_limit")]
     pub clear_limit: usize,
+    /// Whether to record the transactions awaiting confirmation in an on-disk journal,
+    /// so that they are confirmed or resubmitted when Hermes restarts
+    #[serde(default)]
+    pub tx_journal: bool,
 
     #[serde(skip)]
     pub force_disable_clear_on_start: bool,
@@ -446,6 +522,7 @@ impl Default for Packets {
             ics20_max_memo_size: default::ics20_max_memo_size(),
             ics20_max_receiver_size: default::ics20_max_receiver_size(),
             clear_limit: default::clear_limit(),
+            tx_journal: false,
             force_disable_clear_on_start: false,
         }
     }
@@ -482,13 +559,27 @@ impl Display for LogLevel {
     }
 }
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct GlobalConfig {
     pub log_level: LogLevel,
+    /// The folder where Hermes keeps the state it needs across restarts,
+    /// `$HOME/.hermes/data` if not specified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_dir: Option<PathBuf>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+impl GlobalConfig {
+    /// The folder where Hermes keeps the state it needs across restarts,
+    /// or `None` if it is not specified and the home folder cannot be determined.
+    pub fn data_dir(&self) -> Option<PathBuf> {
+        self.data_dir
+            .clone()
+            .or_else(|| dirs_next::home_dir().map(|home| home.join(DATA_DEFAULT_FOLDER)))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct TelemetryConfig {
     pub enabled: bool,
@@ -498,7 +589,7 @@ pub struct TelemetryConfig {
     pub buckets: HistogramBuckets,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct HistogramBuckets {
     #[serde(default = "default::latency_submitted")]
     pub latency_submitted: HistogramConfig,
@@ -515,7 +606,7 @@ impl Default for HistogramBuckets {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(try_from = "HistogramRangeUnchecked")]
 pub struct HistogramConfig {
     #[serde(flatten)]
@@ -564,7 +655,7 @@ impl Default for TelemetryConfig {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct RestConfig {
     pub enabled: bool,
@@ -631,6 +722,27 @@ pub enum EventSourceMode {
         batch_delay: Duration,
     },
 
+    /// Push-based event source, via WebSocket, which pulls the blocks
+    /// it missed via RPC /block_results
+    Hybrid {
+        /// The WebSocket URL to connect to
+        url: WebSocketClientUrl,
+
+        /// Maximum amount of time to wait for a NewBlock event before emitting the event batch
+        #[serde(default = "default::batch_delay", with = "humantime_serde")]
+        batch_delay: Duration,
+
+        /// Maximum amount of time to wait for a block over WebSocket
+        /// before pulling the blocks committed since the last one
+        #[serde(default = "default::stall_timeout", with = "humantime_serde")]
+        stall_timeout: Duration,
+
+        /// The maximum retries to collect the block results
+        /// before giving up and moving to the next block
+        #[serde(default = "default::max_retries")]
+        max_retries: u32,
+    },
+
     /// Pull-based event source, via RPC /block_results
     #[serde(alias = "poll")]
     Pull {
@@ -653,49 +765,102 @@ pub enum EventSourceMode {
 // below when adding a new chain type.
 #[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(tag = "type")]
+#[allow(clippy::large_enum_variant)]
 pub enum ChainConfig {
     CosmosSdk(CosmosSdkConfig),
+    SoloMachine(SoloMachineConfig),
 }
 
 impl ChainConfig {
     pub fn id(&self) -> &ChainId {
         match self {
             Self::CosmosSdk(config) => &config.id,
+            Self::SoloMachine(config) => &config.id,
         }
     }
 
     pub fn packet_filter(&self) -> &PacketFilter {
         match self {
             Self::CosmosSdk(config) => &config.packet_filter,
+            Self::SoloMachine(config) => &config.packet_filter,
         }
     }
 
     pub fn max_block_time(&self) -> Duration {
         match self {
             Self::CosmosSdk(config) => config.max_block_time,
+            Self::SoloMachine(config) => config.max_block_time,
+        }
+    }
+
+    pub fn fee_bump(&self) -> FeeBump {
+        match self {
+            Self::CosmosSdk(config) => config.fee_bump,
+            Self::SoloMachine(_) => FeeBump::disabled(),
+        }
+    }
+
+    pub fn fee_priority(&self) -> &FeePriority {
+        match self {
+            Self::CosmosSdk(config) => &config.fee_priority,
+            Self::SoloMachine(config) => &config.fee_priority,
+        }
+    }
+
+    pub fn rate_limit(&self) -> &RateLimitConfig {
+        match self {
+            Self::CosmosSdk(config) => &config.rate_limit,
+            Self::SoloMachine(config) => &config.rate_limit,
+        }
+    }
+
+    pub fn default_gas(&self) -> u64 {
+        match self {
+            Self::CosmosSdk(config) => default_gas_from_config(config),
+            // The solo machine does not meter its transactions
+            Self::SoloMachine(_) => 0,
         }
     }
 
     pub fn key_name(&self) -> &String {
         match self {
             Self::CosmosSdk(config) => &config.key_name,
+            Self::SoloMachine(config) => &config.key_name,
         }
     }
 
     pub fn set_key_name(&mut self, key_name: String) {
         match self {
             Self::CosmosSdk(config) => config.key_name = key_name,
+            Self::SoloMachine(config) => config.key_name = key_name,
         }
     }
 
     pub fn list_keys(&self) -> Result<Vec<(String, AnySigningKeyPair)>, keyring::errors::Error> {
         let keys = match self {
             ChainConfig::CosmosSdk(config) => {
-                let keyring = KeyRing::new_secp256k1(
-                    Store::Test,
+                let keyring = KeyRing::<AnySecp256k1KeyPair>::new(
+                    config.key_store_type.persistent(),
                     &config.account_prefix,
                     &config.id,
                     &config.key_store_folder,
+                    &config.key_store_passphrase,
+                    &config.remote_signer,
+                )?;
+                keyring
+                    .keys()?
+                    .into_iter()
+                    .map(|(key_name, keys)| (key_name, keys.into()))
+                    .collect()
+            }
+            ChainConfig::SoloMachine(config) => {
+                let keyring = KeyRing::<AnySecp256k1KeyPair>::new(
+                    config.key_store_type.persistent(),
+                    &config.account_prefix,
+                    &config.id,
+                    &config.key_store_folder,
+                    &config.key_store_passphrase,
+                    &None,
                 )?;
                 keyring
                     .keys()?
@@ -711,35 +876,41 @@ impl ChainConfig {
     pub fn clear_interval(&self) -> Option<u64> {
         match self {
             Self::CosmosSdk(config) => config.clear_interval,
+            Self::SoloMachine(config) => config.clear_interval,
         }
     }
 
     pub fn query_packets_chunk_size(&self) -> usize {
         match self {
             Self::CosmosSdk(config) => config.query_packets_chunk_size,
+            Self::SoloMachine(config) => config.query_packets_chunk_size,
         }
     }
 
     pub fn set_query_packets_chunk_size(&mut self, query_packets_chunk_size: usize) {
         match self {
             Self::CosmosSdk(config) => config.query_packets_chunk_size = query_packets_chunk_size,
+            Self::SoloMachine(config) => config.query_packets_chunk_size = query_packets_chunk_size,
         }
     }
 
     pub fn excluded_sequences(&self, channel_id: &ChannelId) -> Cow<'_, [Sequence]> {
-        match self {
-            Self::CosmosSdk(config) => config
-                .excluded_sequences
-                .map
-                .get(channel_id)
-                .map(|seqs| Cow::Borrowed(seqs.as_slice()))
-                .unwrap_or_else(|| Cow::Owned(Vec::new())),
-        }
+        let excluded_sequences = match self {
+            Self::CosmosSdk(config) => &config.excluded_sequences,
+            Self::SoloMachine(config) => &config.excluded_sequences,
+        };
+
+        excluded_sequences
+            .map
+            .get(channel_id)
+            .map(|seqs| Cow::Borrowed(seqs.as_slice()))
+            .unwrap_or_else(|| Cow::Owned(Vec::new()))
     }
 
     pub fn allow_ccq(&self) -> bool {
         match self {
             Self::CosmosSdk(config) => config.allow_ccq,
+            Self::SoloMachine(_) => false,
         }
     }
 }
@@ -768,6 +939,9 @@ impl<'de> Deserialize<'de> for ChainConfig {
             "CosmosSdk" => CosmosSdkConfig::deserialize(value)
                 .map(Self::CosmosSdk)
                 .map_err(|e| serde::de::Error::custom(format!("invalid CosmosSdk config: {e}"))),
+            "SoloMachine" => SoloMachineConfig::deserialize(value)
+                .map(Self::SoloMachine)
+                .map_err(|e| serde::de::Error::custom(format!("invalid SoloMachine config: {e}"))),
 
             //
             // <-- Add new chain types here -->
@@ -824,6 +998,153 @@ impl Default for TracingServerConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct HighAvailabilityConfig {
+    /// Whether the instance only relays while it holds the lease,
+    /// standing by for another instance to let it lapse otherwise
+    #[serde(default)]
+    pub enabled: bool,
+    /// The file holding the lease, on storage shared by all the instances
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lease_file: Option<PathBuf>,
+    /// How the instance identifies itself as the holder of the lease,
+    /// derived from the process id and start time if not specified
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance_id: Option<String>,
+    /// How long the lease is held for without being renewed
+    #[serde(default = "default::lease_duration", with = "humantime_serde")]
+    pub lease_duration: Duration,
+    /// How often the holder renews the lease, and a standby instance tries to take it over
+    #[serde(default = "default::lease_renew_interval", with = "humantime_serde")]
+    pub renew_interval: Duration,
+}
+
+impl Default for HighAvailabilityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            lease_file: None,
+            instance_id: None,
+            lease_duration: default::lease_duration(),
+            renew_interval: default::lease_renew_interval(),
+        }
+    }
+}
+
+impl HighAvailabilityConfig {
+    fn validate(&self) -> Result<(), Diagnostic<Error>> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.lease_file.is_none() {
+            return Err(Diagnostic::Error(Error::invalid_high_availability(
+                "`lease_file` must be set if `enabled` is set to true".to_string(),
+            )));
+        }
+
+        if self.renew_interval >= self.lease_duration {
+            return Err(Diagnostic::Error(Error::invalid_high_availability(
+                "`renew_interval` must be shorter than `lease_duration`".to_string(),
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ShardingConfig {
+    /// The number of instances sharing the relaying on the chains
+    #[serde(default = "default::shard_count")]
+    pub shard_count: u64,
+    /// The index of this instance among them, from `0` to `shard_count - 1`
+    #[serde(default)]
+    pub shard_index: u64,
+    /// The channels this instance relays on, instead of the share of
+    /// the channels assigned to it by hashing their identifiers
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channels: Vec<ShardChannel>,
+}
+
+/// A channel explicitly assigned to an instance.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ShardChannel {
+    pub chain_id: ChainId,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+}
+
+impl Default for ShardingConfig {
+    fn default() -> Self {
+        Self {
+            shard_count: default::shard_count(),
+            shard_index: 0,
+            channels: Vec::new(),
+        }
+    }
+}
+
+impl ShardingConfig {
+    /// Whether the relaying is shared with other instances.
+    pub fn is_enabled(&self) -> bool {
+        self.shard_count > 1 || !self.channels.is_empty()
+    }
+
+    fn validate(&self) -> Result<(), Diagnostic<Error>> {
+        if self.shard_count == 0 {
+            return Err(Diagnostic::Error(Error::invalid_sharding(
+                "`shard_count` must be at least 1".to_string(),
+            )));
+        }
+
+        if self.shard_index >= self.shard_count {
+            return Err(Diagnostic::Error(Error::invalid_sharding(format!(
+                "`shard_index` must be lower than `shard_count` ({})",
+                self.shard_count
+            ))));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CatchUpConfig {
+    /// Whether to record the last block height handled on each chain,
+    /// and to catch up on the events emitted since then when restarting
+    #[serde(default)]
+    pub enabled: bool,
+    /// The maximum number of blocks to catch up on, back from the latest height
+    #[serde(default = "default::catch_up_max_lookback")]
+    pub max_lookback: u64,
+}
+
+impl Default for CatchUpConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_lookback: default::catch_up_max_lookback(),
+        }
+    }
+}
+
+impl CatchUpConfig {
+    fn validate(&self) -> Result<(), Diagnostic<Error>> {
+        if self.enabled && self.max_lookback == 0 {
+            return Err(Diagnostic::Error(Error::invalid_catch_up(
+                "`max_lookback` must be at least 1".to_string(),
+            )));
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum Diagnostic<E> {
     Warning(E),
@@ -852,7 +1173,7 @@ impl From<CosmosConfigError> for Error {
 mod tests {
     use core::str::FromStr;
 
-    use super::{load, parse_gas_prices, store_writer, ChainConfig};
+    use super::{load, parse_gas_prices, store_writer, ChainConfig, ModeConfig};
     use crate::config::GasPrice;
     use test_log::test;
 
@@ -927,7 +1248,28 @@ mod tests {
             super::ChainConfig::CosmosSdk(_) => {
                 // all good
             }
+            _ => panic!("expected a CosmosSdk chain config"),
+        }
+    }
+
+    #[test]
+    fn parse_solo_machine_chain_type() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/config/fixtures/relayer_conf_example_solo_machine.toml"
+        );
+
+        let config = load(path).expect("could not parse config");
+
+        match &config.chains[1] {
+            super::ChainConfig::SoloMachine(solo) => {
+                assert_eq!(solo.diversifier(), "solo");
+                assert_eq!(solo.store_prefix, "ibc");
+            }
+            _ => panic!("expected a SoloMachine chain config"),
         }
+
+        assert!(config.validate_config().is_ok());
     }
 
     #[test]
@@ -954,10 +1296,12 @@ mod tests {
 
         let excluded_sequences1 = match config.chains.first().unwrap() {
             ChainConfig::CosmosSdk(chain_config) => chain_config.excluded_sequences.clone(),
+            _ => panic!("expected a CosmosSdk chain config"),
         };
 
         let excluded_sequences2 = match config.chains.last().unwrap() {
             ChainConfig::CosmosSdk(chain_config) => chain_config.excluded_sequences.clone(),
+            _ => panic!("expected a CosmosSdk chain config"),
         };
 
         assert_eq!(excluded_sequences1, excluded_sequences2);
@@ -976,6 +1320,40 @@ mod tests {
         assert!(load(path).is_err());
     }
 
+    #[test]
+    fn backup_endpoints_require_websocket_addr_in_push_mode() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/config/fixtures/relayer_conf_example.toml"
+        );
+
+        let mut config = load(path).expect("could not parse config");
+        assert!(config.validate_config().is_ok());
+
+        match config.chains.first_mut().unwrap() {
+            ChainConfig::CosmosSdk(chain_config) => {
+                assert_eq!(chain_config.backup_endpoints.len(), 1);
+                chain_config.backup_endpoints[0].websocket_addr = None;
+            }
+            _ => panic!("expected a CosmosSdk chain config"),
+        }
+
+        assert!(config.validate_config().is_err());
+    }
+
+    #[test]
+    fn clients_mode_requires_a_client_worker() {
+        let mut mode = ModeConfig::default();
+        mode.clients.enabled = true;
+        mode.clients.refresh = false;
+        mode.clients.misbehaviour = false;
+        mode.clients.upgrade = false;
+        assert!(mode.validate().is_err());
+
+        mode.clients.upgrade = true;
+        assert!(mode.validate().is_ok());
+    }
+
     #[test]
     fn gas_price_from_str() {
         let gp_original = GasPrice::new(10.0, "atom".to_owned());
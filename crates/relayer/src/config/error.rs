@@ -23,6 +23,27 @@ define_error! {
                     e.reason)
             },
 
+        InvalidHighAvailability
+            { reason: String, }
+            |e| {
+                format!("config file specifies invalid high availability config, caused by: {0}",
+                    e.reason)
+            },
+
+        InvalidSharding
+            { reason: String, }
+            |e| {
+                format!("config file specifies invalid sharding config, caused by: {0}",
+                    e.reason)
+            },
+
+        InvalidCatchUp
+            { reason: String, }
+            |e| {
+                format!("config file specifies invalid catch-up config, caused by: {0}",
+                    e.reason)
+            },
+
         DuplicateChains
             { chain_id: ChainId }
             |e| {
@@ -0,0 +1,191 @@
+use serde::de::Error as DeserializeError;
+use serde::de::Unexpected;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde_derive::Serialize;
+
+flex_error::define_error! {
+    Error {
+        MultiplierTooSmall
+            { value: f64 }
+            |e| {
+                format_args!("`multiplier` in fee_bump configuration must be greater than {}, found {}",
+                FeeBump::MIN_MULTIPLIER, e.value)
+            },
+
+        ZeroBlocks
+            |_| { "`blocks` in fee_bump configuration must be greater than 0" },
+    }
+}
+
+/// Policy for escalating the gas price of transactions which stay
+/// unconfirmed, eg. because of a fee spike or a priority mempool.
+///
+/// A transaction which is not committed after `blocks` blocks is
+/// resubmitted with its gas price multiplied by `multiplier` once per
+/// resubmission, up to a gas price of `max`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize)]
+pub struct FeeBump {
+    pub enabled: bool,
+    pub multiplier: f64,
+    pub max: f64,
+    pub blocks: u64,
+}
+
+impl FeeBump {
+    const DEFAULT_MULTIPLIER: f64 = 1.2;
+    const DEFAULT_MAX: f64 = 0.6;
+    const DEFAULT_BLOCKS: u64 = 5;
+    const MIN_MULTIPLIER: f64 = 1.0;
+
+    pub fn enabled(multiplier: f64, max: f64, blocks: u64) -> Result<Self, Error> {
+        Self::new(true, multiplier, max, blocks)
+    }
+
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            multiplier: Self::DEFAULT_MULTIPLIER,
+            max: Self::DEFAULT_MAX,
+            blocks: Self::DEFAULT_BLOCKS,
+        }
+    }
+
+    pub fn new(enabled: bool, multiplier: f64, max: f64, blocks: u64) -> Result<Self, Error> {
+        if multiplier <= Self::MIN_MULTIPLIER {
+            return Err(Error::multiplier_too_small(multiplier));
+        }
+
+        if blocks == 0 {
+            return Err(Error::zero_blocks());
+        }
+
+        Ok(Self {
+            enabled,
+            multiplier,
+            max,
+            blocks,
+        })
+    }
+
+    /// The gas price to pay after bumping the given gas price `bumps` times.
+    ///
+    /// The bumped price is capped at `max`, but never lower than the given price.
+    pub fn bump(&self, price: f64, bumps: u32) -> f64 {
+        if !self.enabled || bumps == 0 {
+            return price;
+        }
+
+        let exponent = i32::try_from(bumps).unwrap_or(i32::MAX);
+        let bumped = price * self.multiplier.powi(exponent);
+
+        bumped.min(self.max).max(price)
+    }
+}
+
+impl Default for FeeBump {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+impl<'de> Deserialize<'de> for FeeBump {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct RawFeeBump {
+            enabled: bool,
+            #[serde(default = "default_multiplier")]
+            multiplier: f64,
+            max: f64,
+            #[serde(default = "default_blocks")]
+            blocks: u64,
+        }
+
+        fn default_multiplier() -> f64 {
+            FeeBump::DEFAULT_MULTIPLIER
+        }
+
+        fn default_blocks() -> u64 {
+            FeeBump::DEFAULT_BLOCKS
+        }
+
+        let RawFeeBump {
+            enabled,
+            multiplier,
+            max,
+            blocks,
+        } = RawFeeBump::deserialize(deserializer)?;
+
+        FeeBump::new(enabled, multiplier, max, blocks).map_err(|e| match e.detail() {
+            ErrorDetail::MultiplierTooSmall(_) => D::Error::invalid_value(
+                Unexpected::Float(multiplier),
+                &format!(
+                    "a floating-point value greater than {}",
+                    Self::MIN_MULTIPLIER
+                )
+                .as_str(),
+            ),
+            ErrorDetail::ZeroBlocks(_) => D::Error::invalid_value(
+                Unexpected::Unsigned(blocks),
+                &"a positive number of blocks",
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde::Deserialize;
+    use test_log::test;
+
+    #[derive(Debug, Deserialize)]
+    struct DummyConfig {
+        fee_bump: FeeBump,
+    }
+
+    #[test]
+    fn parse_fee_bump() {
+        let config = toml::from_str::<DummyConfig>(
+            "fee_bump = { enabled = true, multiplier = 1.5, max = 0.1, blocks = 3 }",
+        )
+        .unwrap();
+
+        assert_eq!(config.fee_bump, FeeBump::enabled(1.5, 0.1, 3).unwrap());
+    }
+
+    #[test]
+    fn parse_invalid_fee_bump() {
+        let err = toml::from_str::<DummyConfig>(
+            "fee_bump = { enabled = true, multiplier = 1.0, max = 0.1 }",
+        )
+        .unwrap_err()
+        .to_string();
+
+        assert!(err.contains("expected a floating-point value greater than"));
+
+        let err =
+            toml::from_str::<DummyConfig>("fee_bump = { enabled = true, max = 0.1, blocks = 0 }")
+                .unwrap_err()
+                .to_string();
+
+        assert!(err.contains("expected a positive number of blocks"));
+    }
+
+    #[test]
+    fn bump_is_capped() {
+        let fee_bump = FeeBump::enabled(2.0, 0.1, 5).unwrap();
+
+        assert_eq!(fee_bump.bump(0.01, 0), 0.01);
+        assert_eq!(fee_bump.bump(0.01, 1), 0.02);
+        assert_eq!(fee_bump.bump(0.01, 2), 0.04);
+        assert_eq!(fee_bump.bump(0.01, 4), 0.1);
+        assert_eq!(fee_bump.bump(0.2, 1), 0.2);
+        assert_eq!(FeeBump::disabled().bump(0.01, 3), 0.01);
+    }
+}
@@ -0,0 +1,97 @@
+use core::time::Duration;
+
+use serde_derive::{Deserialize, Serialize};
+
+use ibc_relayer_types::applications::transfer::{Amount, RawCoin};
+use ibc_relayer_types::bigint::U256;
+
+/// Ordering of the packets relayed to a chain by the value of their ICS-29 fees,
+/// instead of the order in which they were sent.
+///
+/// The messages of a batch, and the batches waiting to be relayed, are ordered
+/// by decreasing fee value, except for the batches which have been waiting for
+/// at least `max_wait`, which are relayed first so that unpaid packets are not starved.
+/// Only the fees in `denoms` are counted, or the fees in any denom if it is empty.
+/// Packets of ordered channels are always relayed in order.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FeePriority {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub denoms: Vec<String>,
+    #[serde(default = "FeePriority::default_max_wait", with = "humantime_serde")]
+    pub max_wait: Duration,
+}
+
+impl FeePriority {
+    fn default_max_wait() -> Duration {
+        Duration::from_secs(60)
+    }
+
+    /// The value of the given fee, ie. the sum of its amounts in the counted denoms.
+    pub fn value(&self, fee: &[RawCoin]) -> Amount {
+        fee.iter()
+            .filter(|coin| self.denoms.is_empty() || self.denoms.contains(&coin.denom))
+            .fold(Amount::from(0u64), |total, coin| {
+                total.checked_add(coin.amount).unwrap_or(Amount(U256::MAX))
+            })
+    }
+}
+
+impl Default for FeePriority {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            denoms: Vec::new(),
+            max_wait: Self::default_max_wait(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde::Deserialize;
+    use test_log::test;
+
+    #[derive(Debug, Deserialize)]
+    struct DummyConfig {
+        fee_priority: FeePriority,
+    }
+
+    #[test]
+    fn parse_fee_priority() {
+        let config = toml::from_str::<DummyConfig>(
+            "fee_priority = { enabled = true, denoms = ['uatom'], max_wait = '30s' }",
+        )
+        .unwrap();
+
+        assert!(config.fee_priority.enabled);
+        assert_eq!(config.fee_priority.denoms, vec!["uatom".to_owned()]);
+        assert_eq!(config.fee_priority.max_wait, Duration::from_secs(30));
+
+        let config = toml::from_str::<DummyConfig>("fee_priority = { enabled = true }").unwrap();
+        assert_eq!(config.fee_priority.max_wait, Duration::from_secs(60));
+    }
+
+    #[test]
+    fn fee_value() {
+        let fee = vec![
+            RawCoin::new("uatom".to_owned(), 10u64),
+            RawCoin::new("stake".to_owned(), 5u64),
+            RawCoin::new("uatom".to_owned(), 2u64),
+        ];
+
+        let all = FeePriority::default();
+        assert_eq!(all.value(&fee), Amount::from(17u64));
+
+        let uatom = FeePriority {
+            denoms: vec!["uatom".to_owned()],
+            ..FeePriority::default()
+        };
+        assert_eq!(uatom.value(&fee), Amount::from(12u64));
+        assert_eq!(uatom.value(&[]), Amount::from(0u64));
+    }
+}
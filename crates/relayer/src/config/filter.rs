@@ -9,10 +9,12 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::HashMap;
 use std::hash::Hash;
 
-use ibc_relayer_types::applications::transfer::RawCoin;
+use ibc_relayer_types::applications::ics29_fee::packet_fee::Fee;
+use ibc_relayer_types::applications::transfer::{Amount, RawCoin};
 use ibc_relayer_types::bigint::U256;
 use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
-use ibc_relayer_types::events::IbcEventType;
+
+use crate::config::ics20_filter::Ics20Filter;
 
 /// Represents all the filtering policies for packets.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -21,6 +23,8 @@ pub struct PacketFilter {
     pub channel_policy: ChannelPolicy,
     #[serde(default)]
     pub min_fees: HashMap<ChannelFilterMatch, FeePolicy>,
+    #[serde(default, skip_serializing_if = "Ics20Filter::is_empty")]
+    pub ics20: Ics20Filter,
 }
 
 impl Default for PacketFilter {
@@ -29,6 +33,7 @@ impl Default for PacketFilter {
         Self {
             channel_policy: ChannelPolicy::default(),
             min_fees: HashMap::new(),
+            ics20: Ics20Filter::default(),
         }
     }
 }
@@ -41,6 +46,7 @@ impl PacketFilter {
         Self {
             channel_policy,
             min_fees,
+            ics20: Ics20Filter::default(),
         }
     }
 
@@ -70,27 +76,94 @@ pub enum ChannelPolicy {
 }
 
 /// Represents the policy used to filter incentivized packets.
-/// Currently only filtering on `recv_fee` is authorized.
+///
+/// Each of `recv`, `ack` and `timeout` lists the minimum fees accepted
+/// for the corresponding ICS-29 fee, and is satisfied if any of these
+/// minimums is met. An empty list does not set any requirement on that fee.
+/// The `require` setting specifies whether all the fees with requirements
+/// must satisfy them, or any one of them.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FeePolicy {
+    #[serde(default)]
     recv: Vec<MinFee>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    ack: Vec<MinFee>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    timeout: Vec<MinFee>,
+    #[serde(default)]
+    require: FeeRequirement,
+}
+
+/// How the requirements of a [`FeePolicy`] on the different fees are combined.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeeRequirement {
+    /// Every fee with a requirement must satisfy it.
+    #[default]
+    All,
+    /// At least one of the fees with a requirement must satisfy it.
+    Any,
 }
 
 impl FeePolicy {
     pub fn new(recv: Vec<MinFee>) -> Self {
-        Self { recv }
+        Self {
+            recv,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_ack_fee(self, ack: Vec<MinFee>) -> Self {
+        Self { ack, ..self }
+    }
+
+    pub fn with_timeout_fee(self, timeout: Vec<MinFee>) -> Self {
+        Self { timeout, ..self }
+    }
+
+    pub fn with_requirement(self, require: FeeRequirement) -> Self {
+        Self { require, ..self }
     }
 
-    pub fn should_relay(&self, event_type: IbcEventType, fees: &[RawCoin]) -> bool {
-        match event_type {
-            IbcEventType::SendPacket => fees
+    /// Whether a packet incentivized with the given total fees should be relayed.
+    pub fn should_relay(&self, fee: &Fee) -> bool {
+        let mut satisfied = [
+            (&self.recv, &fee.recv_fee),
+            (&self.ack, &fee.ack_fee),
+            (&self.timeout, &fee.timeout_fee),
+        ]
+        .into_iter()
+        .filter(|(min_fees, _)| !min_fees.is_empty())
+        .map(|(min_fees, fees)| {
+            group_by_denom(fees)
                 .iter()
-                .any(|fee| self.recv.iter().any(|e| e.is_enough(fee))),
-            _ => true,
+                .any(|fee| min_fees.iter().any(|min_fee| min_fee.is_enough(fee)))
+        })
+        .peekable();
+
+        // Without any requirement, all packets are relayed
+        if satisfied.peek().is_none() {
+            return true;
+        }
+
+        match self.require {
+            FeeRequirement::All => satisfied.all(|ok| ok),
+            FeeRequirement::Any => satisfied.any(|ok| ok),
         }
     }
 }
 
+/// Sums up the amounts of the coins with the same denom.
+fn group_by_denom(coins: &[RawCoin]) -> Vec<RawCoin> {
+    coins
+        .iter()
+        .map(|coin| (&coin.denom, coin.amount))
+        .into_group_map()
+        .into_iter()
+        .map(|(denom, amounts)| RawCoin::new(denom.to_owned(), amounts.into_iter().sum::<Amount>()))
+        .collect()
+}
+
 /// Represents the minimum fee authorized when filtering.
 /// If no denom is specified, any denom is allowed.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -625,4 +698,93 @@ mod tests {
         let assert_allow = matches!(pf, ChannelPolicy::Allow(filters) if filters.is_exact());
         assert!(assert_allow);
     }
+
+    #[test]
+    fn deserialize_fee_policy() {
+        let toml_content = r#"
+            recv = [{ amount = 10, denom = 'uatom' }]
+            ack = [{ amount = 5 }]
+            require = 'any'
+            "#;
+
+        let fee_policy: FeePolicy =
+            toml::from_str(toml_content).expect("could not parse fee policy");
+
+        let expected = FeePolicy::new(vec![MinFee::new(10, Some("uatom".to_owned()))])
+            .with_ack_fee(vec![MinFee::new(5, None)])
+            .with_requirement(FeeRequirement::Any);
+
+        assert_eq!(fee_policy, expected);
+    }
+
+    #[test]
+    fn fee_policy_combines_requirements() {
+        let coins = |amount: u64| vec![RawCoin::new("uatom".to_owned(), amount)];
+
+        let fee = Fee {
+            recv_fee: coins(10),
+            ack_fee: coins(1),
+            timeout_fee: vec![],
+        };
+
+        let recv = vec![MinFee::new(10, Some("uatom".to_owned()))];
+        let ack = vec![MinFee::new(5, None)];
+
+        assert!(FeePolicy::default().should_relay(&fee));
+        assert!(FeePolicy::new(recv.clone()).should_relay(&fee));
+
+        let all = FeePolicy::new(recv.clone()).with_ack_fee(ack.clone());
+        assert!(!all.should_relay(&fee));
+
+        let any = all.with_requirement(FeeRequirement::Any);
+        assert!(any.should_relay(&fee));
+
+        let timeout = FeePolicy::default()
+            .with_timeout_fee(vec![MinFee::new(0, None)])
+            .with_requirement(FeeRequirement::Any);
+        assert!(!timeout.should_relay(&fee));
+    }
+
+    #[test]
+    fn fee_policy_sums_fees_per_denom() {
+        let fee = Fee {
+            recv_fee: vec![
+                RawCoin::new("uatom".to_owned(), 6u64),
+                RawCoin::new("stake".to_owned(), 6u64),
+                RawCoin::new("uatom".to_owned(), 6u64),
+            ],
+            ack_fee: vec![],
+            timeout_fee: vec![],
+        };
+
+        let policy = FeePolicy::new(vec![MinFee::new(10, Some("uatom".to_owned()))]);
+        assert!(policy.should_relay(&fee));
+
+        let policy = FeePolicy::new(vec![MinFee::new(10, Some("stake".to_owned()))]);
+        assert!(!policy.should_relay(&fee));
+    }
+
+    #[test]
+    fn deserialize_packet_filter_with_ics20_filter() {
+        let toml_content = r#"
+            policy = 'allow'
+            list = [['transfer', 'channel-0']]
+
+            [ics20]
+            denoms = { policy = 'deny', list = ['/^ibc//'] }
+            max_amount = '1000000'
+            "#;
+
+        let packet_filter: PacketFilter =
+            toml::from_str(toml_content).expect("could not parse packet filter");
+
+        assert!(!packet_filter.ics20.is_empty());
+        assert_eq!(
+            packet_filter.ics20.max_amount,
+            Some(Amount::from(1000000u64))
+        );
+
+        let packet_filter: PacketFilter = toml::from_str("policy = 'allowall'").unwrap();
+        assert!(packet_filter.ics20.is_empty());
+    }
 }
@@ -0,0 +1,437 @@
+//! Filtering of ICS-20 packets based on the content of their packet data.
+
+use core::fmt;
+use core::str::FromStr;
+
+use serde::de::Error as DeserializeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
+use ibc_relayer_types::applications::transfer::Amount;
+
+flex_error::define_error! {
+    Error {
+        InvalidAmount
+            { amount: String }
+            |e| { format_args!("invalid ICS-20 amount `{}` in packet filter, expected an unsigned integer", e.amount) },
+
+        InvalidAmountRange
+            { min: Amount, max: Amount }
+            |e| { format_args!("`min_amount` ({}) must not be greater than `max_amount` ({}) in ICS-20 packet filter", e.min, e.max) },
+    }
+}
+
+/// Rules on the content of ICS-20 packets, ie. the denomination, amount,
+/// sender and receiver found in their packet data.
+///
+/// A packet is only relayed if it satisfies all the configured rules.
+/// Packets whose data cannot be decoded as ICS-20 packet data are not
+/// subject to these rules.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Ics20Filter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub denoms: Option<ListPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none", with = "amount")]
+    pub min_amount: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none", with = "amount")]
+    pub max_amount: Option<Amount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub senders: Option<ListPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receivers: Option<ListPolicy>,
+}
+
+impl Ics20Filter {
+    /// Whether this filter does not set any rule.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Returns the first rule which the given packet data does not satisfy,
+    /// or `None` if the packet should be relayed.
+    pub fn rejection(&self, data: &RawPacketData) -> Option<Ics20Rejection> {
+        if let Some(denoms) = &self.denoms {
+            if !denoms.allows(|pattern| pattern.matches_denom(&data.denom)) {
+                return Some(Ics20Rejection::Denom(data.denom.clone()));
+            }
+        }
+
+        if self.min_amount.is_some() || self.max_amount.is_some() {
+            let within_range = Amount::from_str(&data.amount).is_ok_and(|amount| {
+                self.min_amount.map_or(true, |min| amount >= min)
+                    && self.max_amount.map_or(true, |max| amount <= max)
+            });
+
+            if !within_range {
+                return Some(Ics20Rejection::Amount(data.amount.clone()));
+            }
+        }
+
+        if let Some(senders) = &self.senders {
+            if !senders.allows(|pattern| pattern.matches(&data.sender)) {
+                return Some(Ics20Rejection::Sender(data.sender.clone()));
+            }
+        }
+
+        if let Some(receivers) = &self.receivers {
+            if !receivers.allows(|pattern| pattern.matches(&data.receiver)) {
+                return Some(Ics20Rejection::Receiver(data.receiver.clone()));
+            }
+        }
+
+        None
+    }
+}
+
+impl<'de> Deserialize<'de> for Ics20Filter {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct RawIcs20Filter {
+            #[serde(default)]
+            denoms: Option<ListPolicy>,
+            #[serde(default, with = "amount")]
+            min_amount: Option<Amount>,
+            #[serde(default, with = "amount")]
+            max_amount: Option<Amount>,
+            #[serde(default)]
+            senders: Option<ListPolicy>,
+            #[serde(default)]
+            receivers: Option<ListPolicy>,
+        }
+
+        let RawIcs20Filter {
+            denoms,
+            min_amount,
+            max_amount,
+            senders,
+            receivers,
+        } = RawIcs20Filter::deserialize(deserializer)?;
+
+        if let (Some(min), Some(max)) = (min_amount, max_amount) {
+            if min > max {
+                return Err(D::Error::custom(Error::invalid_amount_range(min, max)));
+            }
+        }
+
+        Ok(Self {
+            denoms,
+            min_amount,
+            max_amount,
+            senders,
+            receivers,
+        })
+    }
+}
+
+/// The rule of an [`Ics20Filter`] which rejected a packet, with the offending value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Ics20Rejection {
+    Denom(String),
+    Amount(String),
+    Sender(String),
+    Receiver(String),
+}
+
+impl fmt::Display for Ics20Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Denom(denom) => write!(f, "denomination `{denom}` is not allowed"),
+            Self::Amount(amount) => write!(f, "amount `{amount}` is out of the allowed range"),
+            Self::Sender(sender) => write!(f, "sender `{sender}` is not allowed"),
+            Self::Receiver(receiver) => write!(f, "receiver `{receiver}` is not allowed"),
+        }
+    }
+}
+
+/// Allows or denies the values matching any of the patterns in the list.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    rename_all = "lowercase",
+    tag = "policy",
+    content = "list",
+    deny_unknown_fields
+)]
+pub enum ListPolicy {
+    /// Only allow the values matching one of the patterns.
+    Allow(Vec<ContentPattern>),
+    /// Deny the values matching one of the patterns.
+    Deny(Vec<ContentPattern>),
+}
+
+impl ListPolicy {
+    fn allows(&self, matches: impl Fn(&ContentPattern) -> bool) -> bool {
+        match self {
+            Self::Allow(patterns) => patterns.iter().any(matches),
+            Self::Deny(patterns) => !patterns.iter().any(matches),
+        }
+    }
+}
+
+/// A value in a [`ListPolicy`], either given exactly or as
+/// a regular expression enclosed in slashes, eg. `/^cosmos1.*$/`.
+#[derive(Clone, Debug)]
+pub enum ContentPattern {
+    Exact(String),
+    Regex(regex::Regex),
+}
+
+impl ContentPattern {
+    pub fn matches(&self, value: &str) -> bool {
+        match self {
+            Self::Exact(exact) => exact == value,
+            Self::Regex(regex) => regex.is_match(value),
+        }
+    }
+
+    /// Matches an exact pattern against both the full denomination,
+    /// eg. `transfer/channel-0/uatom`, and its base denomination, eg. `uatom`.
+    /// A regular expression is only matched against the full denomination.
+    pub fn matches_denom(&self, denom: &str) -> bool {
+        match self {
+            Self::Exact(exact) => exact == denom || exact == base_denom(denom),
+            Self::Regex(regex) => regex.is_match(denom),
+        }
+    }
+}
+
+/// Strips the `{port-id}/{channel-id}/` prefixes from a denomination.
+///
+/// Unlike parsing a `PrefixedDenom`, this accepts base denominations
+/// which contain slashes themselves, eg. `factory/{creator}/{subdenom}`.
+fn base_denom(denom: &str) -> &str {
+    let mut base = denom;
+
+    while let Some((_port, rest)) = base.split_once('/') {
+        match rest.split_once('/') {
+            Some((channel, rest)) if channel.starts_with("channel-") => base = rest,
+            _ => break,
+        }
+    }
+
+    base
+}
+
+impl FromStr for ContentPattern {
+    type Err = regex::Error;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        match pattern
+            .strip_prefix('/')
+            .and_then(|pattern| pattern.strip_suffix('/'))
+        {
+            Some(regex) => Ok(Self::Regex(regex.parse()?)),
+            None => Ok(Self::Exact(pattern.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for ContentPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exact(exact) => write!(f, "{exact}"),
+            Self::Regex(regex) => write!(f, "/{regex}/"),
+        }
+    }
+}
+
+impl PartialEq for ContentPattern {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Exact(a), Self::Exact(b)) => a == b,
+            (Self::Regex(a), Self::Regex(b)) => a.as_str() == b.as_str(),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ContentPattern {}
+
+impl Serialize for ContentPattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ContentPattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let pattern = String::deserialize(deserializer)?;
+        pattern.parse().map_err(D::Error::custom)
+    }
+}
+
+/// Amounts are written as strings, since they may not fit in a TOML integer,
+/// but small amounts may also be written as integers.
+mod amount {
+    use super::*;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawAmount {
+        Int(u64),
+        Str(String),
+    }
+
+    pub fn serialize<S>(amount: &Option<Amount>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match amount {
+            Some(amount) => serializer.serialize_str(&amount.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Amount>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<RawAmount>::deserialize(deserializer)? {
+            Some(RawAmount::Int(amount)) => Ok(Some(Amount::from(amount))),
+            Some(RawAmount::Str(amount)) => Amount::from_str(&amount)
+                .map(Some)
+                .map_err(|_| D::Error::custom(Error::invalid_amount(amount))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    fn packet_data(denom: &str, amount: &str, sender: &str, receiver: &str) -> RawPacketData {
+        RawPacketData {
+            denom: denom.to_string(),
+            amount: amount.to_string(),
+            sender: sender.to_string(),
+            receiver: receiver.to_string(),
+            memo: String::new(),
+        }
+    }
+
+    #[test]
+    fn parse_ics20_filter() {
+        let filter: Ics20Filter = toml::from_str(
+            r#"
+            denoms = { policy = 'allow', list = ['uatom', 'transfer/channel-1/uosmo'] }
+            min_amount = 1000
+            max_amount = '100000000000000000000000'
+            senders = { policy = 'deny', list = ['cosmos1spam', '/^osmo1.*$/'] }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(filter.min_amount, Some(Amount::from(1000u64)));
+        assert_eq!(
+            filter.senders,
+            Some(ListPolicy::Deny(vec![
+                "cosmos1spam".parse().unwrap(),
+                "/^osmo1.*$/".parse().unwrap(),
+            ]))
+        );
+        assert!(filter.receivers.is_none());
+
+        let serialized = toml::to_string(&filter).unwrap();
+        assert_eq!(toml::from_str::<Ics20Filter>(&serialized).unwrap(), filter);
+
+        let err = toml::from_str::<Ics20Filter>("min_amount = 10\nmax_amount = 1")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("must not be greater than"));
+    }
+
+    #[test]
+    fn ics20_filter_rejection() {
+        let filter: Ics20Filter = toml::from_str(
+            r#"
+            denoms = { policy = 'allow', list = ['uatom', 'factory/osmo1creator/token', 'transfer/channel-1/uosmo'] }
+            min_amount = 10
+            max_amount = 1000
+            senders = { policy = 'deny', list = ['cosmos1spam', '/^cosmos1bot/'] }
+            receivers = { policy = 'allow', list = ['/^osmo1/'] }
+            "#,
+        )
+        .unwrap();
+
+        let allowed = [
+            packet_data("uatom", "10", "cosmos1alice", "osmo1bob"),
+            packet_data(
+                "transfer/channel-0/uatom",
+                "1000",
+                "cosmos1alice",
+                "osmo1bob",
+            ),
+            packet_data(
+                "transfer/channel-0/factory/osmo1creator/token",
+                "500",
+                "cosmos1alice",
+                "osmo1bob",
+            ),
+            packet_data(
+                "transfer/channel-1/uosmo",
+                "500",
+                "cosmos1alice",
+                "osmo1bob",
+            ),
+        ];
+
+        for data in &allowed {
+            assert_eq!(filter.rejection(data), None, "{data:?}");
+        }
+
+        let rejected = [
+            (
+                packet_data(
+                    "transfer/channel-0/uosmo",
+                    "500",
+                    "cosmos1alice",
+                    "osmo1bob",
+                ),
+                Ics20Rejection::Denom("transfer/channel-0/uosmo".to_string()),
+            ),
+            (
+                packet_data("uatom", "9", "cosmos1alice", "osmo1bob"),
+                Ics20Rejection::Amount("9".to_string()),
+            ),
+            (
+                packet_data("uatom", "1001", "cosmos1alice", "osmo1bob"),
+                Ics20Rejection::Amount("1001".to_string()),
+            ),
+            (
+                packet_data("uatom", "not-an-amount", "cosmos1alice", "osmo1bob"),
+                Ics20Rejection::Amount("not-an-amount".to_string()),
+            ),
+            (
+                packet_data("uatom", "500", "cosmos1spam", "osmo1bob"),
+                Ics20Rejection::Sender("cosmos1spam".to_string()),
+            ),
+            (
+                packet_data("uatom", "500", "cosmos1bot42", "osmo1bob"),
+                Ics20Rejection::Sender("cosmos1bot42".to_string()),
+            ),
+            (
+                packet_data("uatom", "500", "cosmos1alice", "cosmos1bob"),
+                Ics20Rejection::Receiver("cosmos1bob".to_string()),
+            ),
+        ];
+
+        for (data, rejection) in &rejected {
+            assert_eq!(filter.rejection(data).as_ref(), Some(rejection));
+        }
+
+        let data = packet_data("anything", "0", "anyone", "anyone");
+        assert_eq!(Ics20Filter::default().rejection(&data), None);
+    }
+}
@@ -0,0 +1,239 @@
+#![allow(clippy::mutable_key_type)]
+
+use core::time::Duration;
+use std::collections::HashMap;
+
+use serde::de::Error as DeserializeError;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde_derive::Serialize;
+
+use ibc_relayer_types::core::ics24_host::identifier::ChannelId;
+
+use crate::config::filter::ChannelFilterMatch;
+
+flex_error::define_error! {
+    Error {
+        ZeroInterval
+            |_| { "`interval` in rate_limit configuration must be greater than 0" },
+
+        ZeroLimit
+            { field: String }
+            |e| { format_args!("`{}` in rate_limit configuration must be greater than 0", e.field) },
+    }
+}
+
+/// Limits on the rate at which packets are relayed to a chain, which keep
+/// a busy channel from using up the transaction budget of the chain.
+///
+/// The limits are token buckets refilled over `interval`, counted in packets
+/// and in gas, for the chain as a whole and for each of its channels.
+/// Packets over the limits are deferred until the buckets are refilled.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct RateLimitConfig {
+    #[serde(with = "humantime_serde")]
+    pub interval: Duration,
+    #[serde(flatten)]
+    pub chain: RateLimit,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub channels: HashMap<ChannelFilterMatch, RateLimit>,
+}
+
+/// The number of packets and the amount of gas allowed per interval.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct RateLimit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub packets: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas: Option<u64>,
+}
+
+impl RateLimit {
+    pub fn is_unlimited(&self) -> bool {
+        self.packets.is_none() && self.gas.is_none()
+    }
+
+    fn validate(&self, prefix: &str) -> Result<(), Error> {
+        if self.packets == Some(0) {
+            return Err(Error::zero_limit(format!("{prefix}packets")));
+        }
+
+        if self.gas == Some(0) {
+            return Err(Error::zero_limit(format!("{prefix}gas")));
+        }
+
+        Ok(())
+    }
+}
+
+impl RateLimitConfig {
+    const DEFAULT_INTERVAL: Duration = Duration::from_secs(60);
+
+    pub fn new(
+        interval: Duration,
+        chain: RateLimit,
+        channels: HashMap<ChannelFilterMatch, RateLimit>,
+    ) -> Result<Self, Error> {
+        if interval.is_zero() {
+            return Err(Error::zero_interval());
+        }
+
+        chain.validate("")?;
+
+        for (channel, limit) in &channels {
+            limit.validate(&format!("channels.'{channel}'."))?;
+        }
+
+        Ok(Self {
+            interval,
+            chain,
+            channels,
+        })
+    }
+
+    /// Whether any limit is set.
+    pub fn is_enabled(&self) -> bool {
+        !self.chain.is_unlimited() || !self.channels.is_empty()
+    }
+
+    /// The limit of the given channel, preferring an exact match of its
+    /// identifier over a wildcard.
+    pub fn channel_limit(&self, channel_id: &ChannelId) -> RateLimit {
+        let matching = || {
+            self.channels
+                .iter()
+                .filter(|(channel, _)| channel.matches(channel_id))
+        };
+
+        matching()
+            .find(|(channel, _)| channel.is_exact())
+            .or_else(|| matching().next())
+            .map(|(_, limit)| *limit)
+            .unwrap_or_default()
+    }
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            interval: Self::DEFAULT_INTERVAL,
+            chain: RateLimit::default(),
+            channels: HashMap::new(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RateLimitConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct RawRateLimit {
+            packets: Option<u64>,
+            gas: Option<u64>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct RawRateLimitConfig {
+            #[serde(default = "default_interval", with = "humantime_serde")]
+            interval: Duration,
+            packets: Option<u64>,
+            gas: Option<u64>,
+            #[serde(default)]
+            channels: HashMap<ChannelFilterMatch, RawRateLimit>,
+        }
+
+        fn default_interval() -> Duration {
+            RateLimitConfig::DEFAULT_INTERVAL
+        }
+
+        let RawRateLimitConfig {
+            interval,
+            packets,
+            gas,
+            channels,
+        } = RawRateLimitConfig::deserialize(deserializer)?;
+
+        let channels = channels
+            .into_iter()
+            .map(|(channel, RawRateLimit { packets, gas })| (channel, RateLimit { packets, gas }))
+            .collect();
+
+        RateLimitConfig::new(interval, RateLimit { packets, gas }, channels)
+            .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    #[derive(Debug, Deserialize)]
+    struct DummyConfig {
+        rate_limit: RateLimitConfig,
+    }
+
+    #[test]
+    fn parse_rate_limit() {
+        let config = toml::from_str::<DummyConfig>(
+            r#"
+            [rate_limit]
+            interval = '10s'
+            packets = 100
+
+            [rate_limit.channels.'channel-*']
+            packets = 10
+
+            [rate_limit.channels.'channel-0']
+            packets = 50
+            gas = 5000000
+            "#,
+        )
+        .unwrap();
+
+        let rate_limit = config.rate_limit;
+
+        assert!(rate_limit.is_enabled());
+        assert_eq!(rate_limit.interval, Duration::from_secs(10));
+        assert_eq!(rate_limit.chain.packets, Some(100));
+        assert_eq!(rate_limit.chain.gas, None);
+
+        assert_eq!(
+            rate_limit.channel_limit(&ChannelId::new(0)),
+            RateLimit {
+                packets: Some(50),
+                gas: Some(5000000)
+            }
+        );
+
+        assert_eq!(
+            rate_limit.channel_limit(&ChannelId::new(1)),
+            RateLimit {
+                packets: Some(10),
+                gas: None
+            }
+        );
+
+        assert!(!RateLimitConfig::default().is_enabled());
+    }
+
+    #[test]
+    fn parse_invalid_rate_limit() {
+        let err = toml::from_str::<DummyConfig>("rate_limit = { interval = '0s', packets = 1 }")
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("`interval` in rate_limit configuration must be greater than 0"));
+
+        let err = toml::from_str::<DummyConfig>("[rate_limit.channels.'channel-0']\ngas = 0")
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("`channels.'channel-0'.gas` in rate_limit configuration"));
+    }
+}
@@ -22,11 +22,13 @@ use ibc_relayer_types::tx_msg::Msg;
 use crate::chain::counterparty::connection_state_on_destination;
 use crate::chain::handle::ChainHandle;
 use crate::chain::requests::{
-    IncludeProof, PageRequest, QueryConnectionRequest, QueryConnectionsRequest, QueryHeight,
+    IncludeProof, PageRequest, QueryClientConnectionsRequest, QueryConnectionRequest,
+    QueryConnectionsRequest, QueryHeight,
 };
 use crate::chain::tracking::TrackedMsgs;
 use crate::foreign_client::{ForeignClient, HasExpiredOrFrozenError};
 use crate::object::Connection as WorkerConnectionObject;
+use crate::util::handshake::{pair_half_open_ends, HalfOpenHandshake};
 use crate::util::pretty::{PrettyDuration, PrettyOption};
 use crate::util::retry::{retry_with_index, RetryResult};
 use crate::util::task::Next;
@@ -156,6 +158,47 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Connection<ChainA, ChainB> {
         b_to_a_client: ForeignClient<ChainA, ChainB>,
         a_to_b_client: ForeignClient<ChainB, ChainA>,
         delay_period: Duration,
+    ) -> Result<Self, ConnectionError> {
+        let mut c = Self::unopened(b_to_a_client, a_to_b_client, delay_period)?;
+
+        c.handshake()?;
+
+        Ok(c)
+    }
+
+    /// Resume the handshake of a connection between the given clients which was
+    /// left half-open, eg. by an interrupted `create connection`, and complete it.
+    /// If there is no such handshake, a new connection is created like with [`Connection::new`].
+    ///
+    /// Fails if several half-open handshakes with the same delay period are found
+    /// between the clients, since there is no telling which one to resume.
+    pub fn resume(
+        b_to_a_client: ForeignClient<ChainA, ChainB>,
+        a_to_b_client: ForeignClient<ChainB, ChainA>,
+        delay_period: Duration,
+    ) -> Result<Self, ConnectionError> {
+        let mut c = Self::unopened(b_to_a_client, a_to_b_client, delay_period)?;
+
+        match c.find_half_open_handshake()? {
+            Some(handshake) => {
+                info!("resuming half-open connection handshake {handshake}");
+
+                c.a_side.connection_id = handshake.a_id;
+                c.b_side.connection_id = handshake.b_id;
+            }
+            None => info!("no half-open connection handshake to resume, starting a new one"),
+        }
+
+        c.handshake()?;
+
+        Ok(c)
+    }
+
+    /// A connection between the given clients whose handshake has not started yet.
+    fn unopened(
+        b_to_a_client: ForeignClient<ChainA, ChainB>,
+        a_to_b_client: ForeignClient<ChainB, ChainA>,
+        delay_period: Duration,
     ) -> Result<Self, ConnectionError> {
         Self::validate_clients(&b_to_a_client, &a_to_b_client)?;
 
@@ -167,7 +210,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Connection<ChainA, ChainB> {
             ));
         }
 
-        let mut c = Self {
+        Ok(Self {
             delay_period,
             a_side: ConnectionSide::new(
                 b_to_a_client.dst_chain(),
@@ -179,11 +222,37 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Connection<ChainA, ChainB> {
                 a_to_b_client.id().clone(),
                 Default::default(),
             ),
-        };
+        })
+    }
 
-        c.handshake()?;
+    /// Look for a handshake between the clients of this connection, with the same
+    /// delay period, which was started but not completed.
+    fn find_half_open_handshake(
+        &self,
+    ) -> Result<Option<HalfOpenHandshake<ConnectionId>>, ConnectionError> {
+        let a_ends = half_open_connection_ends(
+            &self.a_side.chain,
+            &self.a_side.client_id,
+            &self.b_side.client_id,
+            self.delay_period,
+        )?;
 
-        Ok(c)
+        let b_ends = half_open_connection_ends(
+            &self.b_side.chain,
+            &self.b_side.client_id,
+            &self.a_side.client_id,
+            self.delay_period,
+        )?;
+
+        let mut handshakes = pair_half_open_ends(&a_ends, &b_ends);
+
+        if handshakes.len() > 1 {
+            return Err(ConnectionError::ambiguous_half_open_handshakes(
+                handshakes.iter().map(ToString::to_string).collect(),
+            ));
+        }
+
+        Ok(handshakes.pop())
     }
 
     pub fn restore_from_event(
@@ -1336,6 +1405,47 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Connection<ChainA, ChainB> {
     }
 }
 
+/// The connection ends of the given client, in `INIT` or `TRYOPEN` state, whose counterparty
+/// is the given client and which have the given delay period, along with the identifier
+/// of their counterparty connection end, if known.
+fn half_open_connection_ends<Chain: ChainHandle>(
+    chain: &Chain,
+    client_id: &ClientId,
+    counterparty_client_id: &ClientId,
+    delay_period: Duration,
+) -> Result<Vec<(ConnectionId, Option<ConnectionId>)>, ConnectionError> {
+    let connection_ids = chain
+        .query_client_connections(QueryClientConnectionsRequest {
+            client_id: client_id.clone(),
+        })
+        .map_err(|e| ConnectionError::chain_query(chain.id(), e))?;
+
+    let mut ends = Vec::new();
+
+    for connection_id in connection_ids {
+        let (connection_end, _) = chain
+            .query_connection(
+                QueryConnectionRequest {
+                    connection_id: connection_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ConnectionError::connection_query(connection_id.clone(), e))?;
+
+        let half_open = matches!(connection_end.state(), State::Init | State::TryOpen)
+            && connection_end.counterparty().client_id() == counterparty_client_id
+            && connection_end.delay_period() == delay_period;
+
+        if half_open {
+            let counterparty_id = connection_end.counterparty().connection_id().cloned();
+            ends.push((connection_id, counterparty_id));
+        }
+    }
+
+    Ok(ends)
+}
+
 pub fn extract_connection_id(event: &IbcEvent) -> Result<&ConnectionId, ConnectionError> {
     match event {
         IbcEvent::OpenInitConnection(ev) => ev.connection_id(),
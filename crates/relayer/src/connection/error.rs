@@ -65,6 +65,13 @@ define_error! {
         HandshakeFinalize
             |_| { "continue handshake" },
 
+        AmbiguousHalfOpenHandshakes
+            { handshakes: Vec<String> }
+            |e| {
+                format!("found several half-open connection handshakes between the clients, cannot tell which one to resume: {}",
+                    e.handshakes.join(", "))
+            },
+
         MaxDelayPeriod
             {
                 delay_period: Duration,
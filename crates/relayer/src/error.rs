@@ -35,6 +35,7 @@ use ibc_relayer_types::proofs::ProofError;
 
 use crate::chain::cosmos::version;
 use crate::chain::cosmos::BLOCK_MAX_BYTES_MAX_FRACTION;
+use crate::chain::solomachine::error::Error as SoloMachineError;
 use crate::config::Error as ConfigError;
 use crate::event::source;
 use crate::keyring::{errors::Error as KeyringError, KeyType};
@@ -130,6 +131,12 @@ define_error! {
             }
             |e| { format!("node at {} running chain {} not caught up", e.address, e.chain_id) },
 
+        NoHealthyEndpoint
+            {
+                chain_id: ChainId,
+            }
+            |e| { format!("no healthy full node to fail over to for chain {}", e.chain_id) },
+
         PrivateStore
             |_| { "requested proof for a path in the private store" },
 
@@ -633,6 +640,14 @@ define_error! {
         InvalidChannelString
             { channel: String }
             |e| { format!("invalid channel string {}", e.channel) },
+
+        SoloMachine
+            [ SoloMachineError ]
+            |_| { "solo machine error" },
+
+        SoloMachineUnsupported
+            { operation: String }
+            |e| { format!("the solo machine does not support {}", e.operation) },
     }
 }
 
@@ -1,3 +1,4 @@
+pub mod hybrid;
 pub mod rpc;
 pub mod websocket;
 
@@ -22,6 +23,7 @@ use crate::chain::{handle::Subscription, tracking::TrackingId};
 
 pub type Result<T> = core::result::Result<T, Error>;
 
+#[allow(clippy::large_enum_variant)]
 pub enum EventSource {
     WebSocket(websocket::EventSource),
     Rpc(rpc::EventSource),
@@ -31,12 +33,54 @@ impl EventSource {
     pub fn websocket(
         chain_id: ChainId,
         ws_url: WebSocketClientUrl,
+        backup_ws_urls: Vec<WebSocketClientUrl>,
         rpc_compat: CompatMode,
         batch_delay: Duration,
         rt: Arc<TokioRuntime>,
     ) -> Result<(Self, TxEventSourceCmd)> {
-        let (mut source, tx) =
-            websocket::EventSource::new(chain_id, ws_url, rpc_compat, batch_delay, rt)?;
+        let (mut source, tx) = websocket::EventSource::new(
+            chain_id,
+            ws_url,
+            backup_ws_urls,
+            rpc_compat,
+            batch_delay,
+            rt,
+        )?;
+
+        source.init_subscriptions()?;
+
+        Ok((Self::WebSocket(source), tx))
+    }
+
+    /// Receive the events over WebSocket, pulling the blocks missed over it
+    /// from the RPC endpoint.
+    #[allow(clippy::too_many_arguments)]
+    pub fn hybrid(
+        chain_id: ChainId,
+        ws_url: WebSocketClientUrl,
+        backup_ws_urls: Vec<WebSocketClientUrl>,
+        rpc_client: HttpClient,
+        rpc_compat: CompatMode,
+        batch_delay: Duration,
+        stall_timeout: Duration,
+        max_retries: u32,
+        rt: Arc<TokioRuntime>,
+    ) -> Result<(Self, TxEventSourceCmd)> {
+        let (mut source, tx) = websocket::EventSource::new(
+            chain_id.clone(),
+            ws_url,
+            backup_ws_urls,
+            rpc_compat,
+            batch_delay,
+            rt,
+        )?;
+
+        source.set_backfill(hybrid::Backfill::new(
+            chain_id,
+            rpc_client,
+            max_retries,
+            stall_timeout,
+        ));
 
         source.init_subscriptions()?;
 
@@ -46,12 +90,19 @@ impl EventSource {
     pub fn rpc(
         chain_id: ChainId,
         rpc_client: HttpClient,
+        backup_rpc_clients: Vec<HttpClient>,
         poll_interval: Duration,
         max_retries: u32,
         rt: Arc<TokioRuntime>,
     ) -> Result<(Self, TxEventSourceCmd)> {
-        let (source, tx) =
-            rpc::EventSource::new(chain_id, rpc_client, poll_interval, max_retries, rt)?;
+        let (source, tx) = rpc::EventSource::new(
+            chain_id,
+            rpc_client,
+            backup_rpc_clients,
+            poll_interval,
+            max_retries,
+            rt,
+        )?;
         Ok((Self::Rpc(source), tx))
     }
 
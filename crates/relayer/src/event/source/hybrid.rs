@@ -0,0 +1,238 @@
+//! Backfilling of the blocks missed by the WebSocket event source in hybrid mode,
+//! by pulling their results from the RPC endpoint of the chain.
+//!
+//! The heights of the batches received over WebSocket are tracked, so that
+//! the blocks skipped over, eg. while reconnecting, are pulled before the next
+//! batch is broadcast. When no batch is received for a while, the blocks
+//! committed since the last one are pulled as well. The batches are deduplicated
+//! by height, so that the subscribers never receive the events of a block twice.
+
+use core::time::Duration;
+
+use tendermint::block::Height as BlockHeight;
+use tendermint_rpc::HttpClient;
+use tracing::{debug, warn};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use super::rpc::{collect_events_with_retries, latest_height};
+use super::{EventBatch, Result};
+
+/// The most blocks pulled at once. The events of the older missed blocks
+/// are left to packet clearing.
+const MAX_BACKFILL_BLOCKS: u64 = 1000;
+
+/// How to handle a batch received over WebSocket, given the last height broadcast.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Received {
+    /// The batch follows the last one broadcast
+    InOrder,
+    /// The events at that height were already broadcast
+    Duplicate,
+    /// The blocks between the given heights were missed
+    Gap { start: u64, end: u64 },
+}
+
+/// The last height broadcast, and whether its batch was pulled, in which case
+/// it holds all the events of the block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct LastHeight {
+    height: u64,
+    pulled: bool,
+}
+
+fn classify(last: Option<LastHeight>, height: u64) -> Received {
+    let Some(last) = last else {
+        return Received::InOrder;
+    };
+
+    if height < last.height {
+        return Received::Duplicate;
+    }
+
+    // The events of a block may be split over several batches
+    // when they are received over WebSocket
+    if height == last.height {
+        return if last.pulled {
+            Received::Duplicate
+        } else {
+            Received::InOrder
+        };
+    }
+
+    if height == last.height + 1 {
+        Received::InOrder
+    } else {
+        Received::Gap {
+            start: last.height + 1,
+            end: height - 1,
+        }
+    }
+}
+
+/// Pulls the blocks missed by a WebSocket event source.
+pub struct Backfill {
+    chain_id: ChainId,
+    rpc_client: HttpClient,
+    max_retries: u32,
+    stall_timeout: Duration,
+    last: Option<LastHeight>,
+}
+
+impl Backfill {
+    pub fn new(
+        chain_id: ChainId,
+        rpc_client: HttpClient,
+        max_retries: u32,
+        stall_timeout: Duration,
+    ) -> Self {
+        Self {
+            chain_id,
+            rpc_client,
+            max_retries,
+            stall_timeout,
+            last: None,
+        }
+    }
+
+    /// How long to wait for a batch over WebSocket before pulling the blocks
+    /// committed since the last one.
+    pub fn stall_timeout(&self) -> Duration {
+        self.stall_timeout
+    }
+
+    /// Start tracking the heights from the latest height of the chain,
+    /// so that the blocks committed before the first batch is received are pulled.
+    pub async fn init(&mut self) -> Result<()> {
+        let latest = latest_height(&self.rpc_client).await?;
+
+        self.last = Some(LastHeight {
+            height: latest.value(),
+            pulled: false,
+        });
+
+        Ok(())
+    }
+
+    /// The batches to broadcast upon receiving the given batch over WebSocket:
+    /// none if the events at its height were already broadcast, or the batches
+    /// of the missed blocks followed by the given one.
+    pub async fn received(&mut self, batch: EventBatch) -> Vec<EventBatch> {
+        let height = batch.height.revision_height();
+
+        let mut batches = match classify(self.last, height) {
+            Received::Duplicate => {
+                debug!(%height, "skipping batch already broadcast");
+                return Vec::new();
+            }
+            Received::InOrder => Vec::new(),
+            Received::Gap { start, end } => {
+                warn!("missed the blocks from height {start} to {end}, pulling them");
+                self.pull(start, end).await
+            }
+        };
+
+        self.last = Some(LastHeight {
+            height,
+            pulled: false,
+        });
+
+        batches.push(batch);
+        batches
+    }
+
+    /// The batches of the blocks committed since the last one broadcast,
+    /// when no batch was received over WebSocket for the stall timeout.
+    pub async fn stalled(&mut self) -> Result<Vec<EventBatch>> {
+        let latest = latest_height(&self.rpc_client).await?.value();
+
+        let start = match self.last {
+            Some(last) if last.height >= latest => return Ok(Vec::new()),
+            Some(last) => last.height + 1,
+            None => latest,
+        };
+
+        warn!(
+            "no block received over WebSocket for {:?}, pulling the blocks from height {start} to {latest}",
+            self.stall_timeout
+        );
+
+        Ok(self.pull(start, latest).await)
+    }
+
+    async fn pull(&mut self, start: u64, end: u64) -> Vec<EventBatch> {
+        let oldest = end.saturating_sub(MAX_BACKFILL_BLOCKS - 1);
+
+        let start = if start < oldest {
+            warn!(
+                "only pulling the last {MAX_BACKFILL_BLOCKS} blocks, the events from height {start} to {} \
+                 are left to packet clearing",
+                oldest - 1
+            );
+
+            oldest
+        } else {
+            start
+        };
+
+        let mut batches = Vec::new();
+
+        for height in start..=end {
+            let Ok(block_height) = BlockHeight::try_from(height) else {
+                break;
+            };
+
+            let batch = collect_events_with_retries(
+                &self.rpc_client,
+                &self.chain_id,
+                block_height,
+                self.max_retries,
+            )
+            .await;
+
+            batches.extend(batch);
+        }
+
+        // Even if the events of some blocks could not be collected,
+        // move on, as the pull-based event source does
+        self.last = Some(LastHeight {
+            height: end,
+            pulled: true,
+        });
+
+        batches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn last(height: u64, pulled: bool) -> Option<LastHeight> {
+        Some(LastHeight { height, pulled })
+    }
+
+    #[test]
+    fn batches_in_order() {
+        assert_eq!(classify(None, 10), Received::InOrder);
+        assert_eq!(classify(last(10, false), 11), Received::InOrder);
+        assert_eq!(classify(last(10, true), 11), Received::InOrder);
+    }
+
+    #[test]
+    fn duplicate_batches() {
+        assert_eq!(classify(last(10, false), 9), Received::Duplicate);
+        assert_eq!(classify(last(10, true), 10), Received::Duplicate);
+
+        // The rest of the events of the last block received over WebSocket
+        assert_eq!(classify(last(10, false), 10), Received::InOrder);
+    }
+
+    #[test]
+    fn missed_blocks() {
+        assert_eq!(
+            classify(last(10, false), 14),
+            Received::Gap { start: 11, end: 13 }
+        );
+    }
+}
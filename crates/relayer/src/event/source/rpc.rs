@@ -7,7 +7,7 @@ use tokio::{
     runtime::Runtime as TokioRuntime,
     time::{sleep, Duration, Instant},
 };
-use tracing::{debug, error, error_span, trace};
+use tracing::{debug, error, error_span, trace, warn};
 
 use tendermint::abci;
 use tendermint::block::Height as BlockHeight;
@@ -34,13 +34,23 @@ use self::extract::extract_events;
 
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// Number of consecutive failed polling steps after which
+/// the event source fails over to the next RPC endpoint.
+const MAX_CONSECUTIVE_ERRORS: u32 = 3;
+
 /// An RPC endpoint that serves as a source of events for a given chain.
 pub struct EventSource {
     /// Chain identifier
     chain_id: ChainId,
 
-    /// RPC client
-    rpc_client: HttpClient,
+    /// RPC clients, in order of preference
+    rpc_clients: Vec<HttpClient>,
+
+    /// Index of the RPC client currently in use
+    current_client: usize,
+
+    /// Number of consecutive failed polling steps against the current RPC client
+    consecutive_errors: u32,
 
     /// Poll interval
     poll_interval: Duration,
@@ -65,6 +75,7 @@ impl EventSource {
     pub fn new(
         chain_id: ChainId,
         rpc_client: HttpClient,
+        backup_rpc_clients: Vec<HttpClient>,
         poll_interval: Duration,
         max_retries: u32,
         rt: Arc<TokioRuntime>,
@@ -75,7 +86,11 @@ impl EventSource {
         let source = Self {
             rt,
             chain_id,
-            rpc_client,
+            rpc_clients: core::iter::once(rpc_client)
+                .chain(backup_rpc_clients)
+                .collect(),
+            current_client: 0,
+            consecutive_errors: 0,
             poll_interval,
             max_retries,
             event_bus,
@@ -86,6 +101,29 @@ impl EventSource {
         Ok((source, TxEventSourceCmd(tx_cmd)))
     }
 
+    /// The RPC client currently in use.
+    fn rpc_client(&self) -> &HttpClient {
+        &self.rpc_clients[self.current_client]
+    }
+
+    /// Move on to the next RPC client, if any.
+    fn failover(&mut self) {
+        self.consecutive_errors = 0;
+
+        if self.rpc_clients.len() < 2 {
+            return;
+        }
+
+        self.current_client = (self.current_client + 1) % self.rpc_clients.len();
+
+        warn!(
+            "failing over to RPC endpoint #{} after {MAX_CONSECUTIVE_ERRORS} consecutive errors",
+            self.current_client
+        );
+
+        telemetry!(endpoint_failover, &self.chain_id, "pull");
+    }
+
     pub fn run(mut self) {
         let _span = error_span!("event_source.rpc", chain.id = %self.chain_id).entered();
 
@@ -97,7 +135,7 @@ impl EventSource {
             let mut backoff = poll_backoff(self.poll_interval);
 
             // Initialize the latest fetched height
-            if let Ok(latest_height) = latest_height(&self.rpc_client).await {
+            if let Ok(latest_height) = latest_height(self.rpc_client()).await {
                 self.last_fetched_height = latest_height;
             }
 
@@ -110,8 +148,9 @@ impl EventSource {
                     Ok(Next::Abort) => break,
 
                     Ok(Next::Continue) => {
-                        // Reset the backoff
+                        // Reset the backoff and the error count
                         backoff = poll_backoff(self.poll_interval);
+                        self.consecutive_errors = 0;
 
                         // Check if we need to wait some more before the next iteration.
                         let delay = self.poll_interval.checked_sub(before_step.elapsed());
@@ -126,6 +165,12 @@ impl EventSource {
                     Err(e) => {
                         error!("event source encountered an error: {e}");
 
+                        self.consecutive_errors += 1;
+
+                        if self.consecutive_errors >= MAX_CONSECUTIVE_ERRORS {
+                            self.failover();
+                        }
+
                         // Let's backoff the little bit to give the chain some time to recover.
                         let delay = backoff.next().expect("backoff is an infinite iterator");
 
@@ -145,7 +190,7 @@ impl EventSource {
             return Ok(Next::Abort);
         }
 
-        let latest_height = latest_height(&self.rpc_client).await?;
+        let latest_height = latest_height(self.rpc_client()).await?;
 
         let batches = if latest_height > self.last_fetched_height {
             trace!(
@@ -206,42 +251,19 @@ impl EventSource {
         let mut batches = Vec::with_capacity(heights.len());
 
         for height in heights {
-            trace!("collecting events at height {height}");
-
-            let mut attempts = 0;
-            let mut backoff = retries_backoff(self.max_retries);
-
             // NOTE: Even if we failed to collect events after max retries,
             // we still need to update to move on next block
             self.last_fetched_height = height;
 
-            loop {
-                attempts += 1;
-
-                match collect_events(&self.rpc_client, &self.chain_id, height).await {
-                    Ok(batch) => {
-                        if let Some(batch) = batch {
-                            batches.push(batch);
-                        }
-                        break;
-                    }
-                    Err(e) => match e.detail() {
-                        ErrorDetail::Rpc(_) if attempts < self.max_retries => {
-                            let delay = backoff.next().expect(
-                                "backoff has attempted to make more iterates than is expected",
-                            );
-
-                            error!(%height, "failed to collect events: {e}, retrying in {delay:?}...");
-                            sleep(delay).await;
-                        }
+            let batch = collect_events_with_retries(
+                self.rpc_client(),
+                &self.chain_id,
+                height,
+                self.max_retries,
+            )
+            .await;
 
-                        _ => {
-                            error!(%height, "failed to collect events after {attempts} attempts: {e}");
-                            break;
-                        }
-                    },
-                }
-            }
+            batches.extend(batch);
         }
 
         Ok(batches)
@@ -263,6 +285,78 @@ impl EventSource {
     }
 }
 
+/// The events of past blocks, pulled from an RPC endpoint one block at a time,
+/// to be replayed through the supervisor before it handles the live events.
+pub struct Replay {
+    chain_id: ChainId,
+    rpc_client: HttpClient,
+    max_retries: u32,
+    rt: Arc<TokioRuntime>,
+    start: BlockHeight,
+    end: BlockHeight,
+    heights: HeightRangeInclusive,
+}
+
+impl Replay {
+    /// Replay the events from the given height up to the latest height of the chain.
+    pub fn new(
+        chain_id: ChainId,
+        rpc_client: HttpClient,
+        start: BlockHeight,
+        max_retries: u32,
+        rt: Arc<TokioRuntime>,
+    ) -> Result<Self> {
+        let end = rt.block_on(latest_height(&rpc_client))?;
+
+        Ok(Self {
+            chain_id,
+            rpc_client,
+            max_retries,
+            rt,
+            start,
+            end,
+            heights: HeightRangeInclusive::new(start, end),
+        })
+    }
+
+    pub fn chain_id(&self) -> &ChainId {
+        &self.chain_id
+    }
+
+    /// The first height to replay.
+    pub fn start(&self) -> BlockHeight {
+        self.start
+    }
+
+    /// The last height to replay, ie. the latest height of the chain
+    /// when the replay was set up.
+    pub fn end(&self) -> BlockHeight {
+        self.end
+    }
+}
+
+impl Iterator for Replay {
+    type Item = EventBatch;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Skip the blocks whose events could not be collected, as the live event source does
+        for height in self.heights.by_ref() {
+            let batch = self.rt.block_on(collect_events_with_retries(
+                &self.rpc_client,
+                &self.chain_id,
+                height,
+                self.max_retries,
+            ));
+
+            if batch.is_some() {
+                return batch;
+            }
+        }
+
+        None
+    }
+}
+
 fn poll_backoff(poll_interval: Duration) -> impl Iterator<Item = Duration> {
     ConstantGrowth::new(poll_interval, Duration::from_millis(500))
         .clamp(poll_interval * 5, usize::MAX)
@@ -355,6 +449,43 @@ async fn collect_events(
     }))
 }
 
+/// Collect the IBC events at the given height, retrying on RPC errors
+/// up to `max_retries` times.
+pub(super) async fn collect_events_with_retries(
+    rpc_client: &HttpClient,
+    chain_id: &ChainId,
+    height: BlockHeight,
+    max_retries: u32,
+) -> Option<EventBatch> {
+    trace!("collecting events at height {height}");
+
+    let mut attempts = 0;
+    let mut backoff = retries_backoff(max_retries);
+
+    loop {
+        attempts += 1;
+
+        match collect_events(rpc_client, chain_id, height).await {
+            Ok(batch) => return batch,
+            Err(e) => match e.detail() {
+                ErrorDetail::Rpc(_) if attempts < max_retries => {
+                    let delay = backoff
+                        .next()
+                        .expect("backoff has attempted to make more iterates than is expected");
+
+                    error!(%height, "failed to collect events: {e}, retrying in {delay:?}...");
+                    sleep(delay).await;
+                }
+
+                _ => {
+                    error!(%height, "failed to collect events after {attempts} attempts: {e}");
+                    return None;
+                }
+            },
+        }
+    }
+}
+
 async fn fetch_all_events(
     rpc_client: &HttpClient,
     height: BlockHeight,
@@ -384,7 +515,7 @@ async fn fetch_all_events(
     Ok(events)
 }
 
-async fn latest_height(rpc_client: &HttpClient) -> Result<BlockHeight> {
+pub(super) async fn latest_height(rpc_client: &HttpClient) -> Result<BlockHeight> {
     rpc_client
         .abci_info()
         .await
@@ -425,7 +556,7 @@ impl Iterator for HeightRangeInclusive {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let size = self.end.value() - self.current.value() + 1;
+        let size = (self.end.value() + 1).saturating_sub(self.current.value());
         (size as usize, Some(size as usize))
     }
 }
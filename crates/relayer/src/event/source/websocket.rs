@@ -11,8 +11,9 @@ use futures::{
     Stream, TryStreamExt,
 };
 use tokio::task::JoinHandle;
+use tokio::time::sleep;
 use tokio::{runtime::Runtime as TokioRuntime, sync::mpsc};
-use tracing::{debug, error, info, instrument, trace};
+use tracing::{debug, error, info, instrument, trace, warn};
 
 use tendermint_rpc::{
     client::CompatMode, event::Event as RpcEvent, query::Query, SubscriptionClient,
@@ -31,6 +32,7 @@ use crate::{
     },
 };
 
+use super::hybrid::Backfill;
 use super::{EventBatch, EventSourceCmd, Result, SubscriptionStream, TxEventSourceCmd};
 
 use self::extract::extract_events;
@@ -76,14 +78,18 @@ pub struct EventSource {
     tx_err: mpsc::UnboundedSender<tendermint_rpc::Error>,
     /// Channel where to receive commands
     rx_cmd: channel::Receiver<EventSourceCmd>,
-    /// Node Address
-    ws_url: WebSocketClientUrl,
+    /// Node addresses, in order of preference
+    ws_urls: Vec<WebSocketClientUrl>,
+    /// Index of the node address currently in use
+    current_url: usize,
     /// RPC compatibility mode
     rpc_compat: CompatMode,
     /// Queries
     event_queries: Vec<Query>,
     /// All subscriptions combined in a single stream
     subscriptions: Box<SubscriptionStream>,
+    /// Pulls the blocks missed over WebSocket, in hybrid mode
+    backfill: Option<Backfill>,
     /// Tokio runtime
     rt: Arc<TokioRuntime>,
 }
@@ -99,6 +105,7 @@ impl EventSource {
     pub fn new(
         chain_id: ChainId,
         ws_url: WebSocketClientUrl,
+        backup_ws_urls: Vec<WebSocketClientUrl>,
         rpc_compat: CompatMode,
         batch_delay: Duration,
         rt: Arc<TokioRuntime>,
@@ -106,11 +113,27 @@ impl EventSource {
         let event_bus = EventBus::new();
         let (tx_cmd, rx_cmd) = channel::unbounded();
 
-        let builder = WebSocketClient::builder(ws_url.clone()).compat_mode(rpc_compat);
+        let ws_urls: Vec<_> = core::iter::once(ws_url).chain(backup_ws_urls).collect();
 
-        let (client, driver) = rt
-            .block_on(builder.build())
-            .map_err(|_| Error::client_creation_failed(chain_id.clone(), ws_url.clone()))?;
+        // Connect to the first node which accepts the connection, in order of preference
+        let mut connected = None;
+
+        for (index, url) in ws_urls.iter().enumerate() {
+            let builder = WebSocketClient::builder(url.clone()).compat_mode(rpc_compat);
+
+            match rt.block_on(builder.build()) {
+                Ok(client_and_driver) => {
+                    connected = Some((index, client_and_driver));
+                    break;
+                }
+                Err(e) => {
+                    error!("failed to connect to WebSocket endpoint {url}: {e}");
+                }
+            }
+        }
+
+        let (current_url, (client, driver)) = connected
+            .ok_or_else(|| Error::client_creation_failed(chain_id.clone(), ws_urls[0].clone()))?;
 
         let (tx_err, rx_err) = mpsc::unbounded_channel();
         let driver_handle = rt.spawn(run_driver(driver, tx_err.clone()));
@@ -129,14 +152,45 @@ impl EventSource {
             rx_err,
             tx_err,
             rx_cmd,
-            ws_url,
+            ws_urls,
+            current_url,
             rpc_compat,
             subscriptions: Box::new(stream::empty()),
+            backfill: None,
         };
 
         Ok((source, TxEventSourceCmd(tx_cmd)))
     }
 
+    /// The address of the node currently in use.
+    fn ws_url(&self) -> &WebSocketClientUrl {
+        &self.ws_urls[self.current_url]
+    }
+
+    /// Move on to the next node address, if any, so that
+    /// the next reconnection attempt is made against it.
+    fn failover(&mut self) {
+        if self.ws_urls.len() < 2 {
+            return;
+        }
+
+        let previous = self.current_url;
+        self.current_url = (self.current_url + 1) % self.ws_urls.len();
+
+        info!(
+            "failing over from WebSocket endpoint {} to {}",
+            self.ws_urls[previous],
+            self.ws_url()
+        );
+
+        telemetry!(endpoint_failover, &self.chain_id, "websocket");
+    }
+
+    /// Pull the blocks missed over WebSocket, and deduplicate the batches by height.
+    pub fn set_backfill(&mut self, backfill: Backfill) {
+        self.backfill = Some(backfill);
+    }
+
     /// The list of [`Query`] that this event source is subscribing for.
     pub fn queries(&self) -> &[Query] {
         &self.event_queries
@@ -172,13 +226,16 @@ impl EventSource {
         fields(chain = %self.chain_id)
     )]
     fn try_reconnect(&mut self) -> Result<()> {
-        trace!("trying to reconnect to WebSocket endpoint {}", self.ws_url);
+        trace!(
+            "trying to reconnect to WebSocket endpoint {}",
+            self.ws_url()
+        );
 
         // Try to reconnect
-        let builder = WebSocketClient::builder(self.ws_url.clone()).compat_mode(self.rpc_compat);
+        let builder = WebSocketClient::builder(self.ws_url().clone()).compat_mode(self.rpc_compat);
 
         let (mut client, driver) = self.rt.block_on(builder.build()).map_err(|_| {
-            Error::client_creation_failed(self.chain_id.clone(), self.ws_url.clone())
+            Error::client_creation_failed(self.chain_id.clone(), self.ws_url().clone())
         })?;
 
         let mut driver_handle = self.rt.spawn(run_driver(driver, self.tx_err.clone()));
@@ -188,7 +245,7 @@ impl EventSource {
         core::mem::swap(&mut self.client, &mut client);
         core::mem::swap(&mut self.driver_handle, &mut driver_handle);
 
-        trace!("reconnected to WebSocket endpoint {}", self.ws_url);
+        trace!("reconnected to WebSocket endpoint {}", self.ws_url());
 
         // Shut down previous client
         trace!("gracefully shutting down previous client",);
@@ -228,9 +285,10 @@ impl EventSource {
     )]
     fn reconnect(&mut self) {
         let result = retry_with_index(retry_strategy::default(), |_| {
-            // Try to reconnect
+            // Try to reconnect, moving on to the next node if that fails
             if let Err(e) = self.try_reconnect() {
                 trace!("error when reconnecting: {}", e);
+                self.failover();
                 return RetryResult::Retry(());
             }
 
@@ -246,11 +304,12 @@ impl EventSource {
         match result {
             Ok(()) => info!(
                 "successfully reconnected to WebSocket endpoint {}",
-                self.ws_url
+                self.ws_url()
             ),
             Err(e) => error!(
                 "failed to reconnect to {} after {} retries",
-                self.ws_url, e.tries
+                self.ws_url(),
+                e.tries
             ),
         }
     }
@@ -269,6 +328,12 @@ impl EventSource {
         // work around double borrow
         let rt = self.rt.clone();
 
+        if let Some(backfill) = &mut self.backfill {
+            if let Err(e) = rt.block_on(backfill.init()) {
+                warn!("failed to query the latest height, the blocks committed until the first batch is received will not be pulled: {e}");
+            }
+        }
+
         // Continuously run the event loop, so that when it aborts
         // because of WebSocket client restart, we pick up the work again.
         loop {
@@ -311,9 +376,13 @@ impl EventSource {
                 return Next::Abort;
             }
 
+            let stall_timeout = self.backfill.as_ref().map(Backfill::stall_timeout);
+
+            // `None` if no batch was received for the stall timeout, in hybrid mode
             let result = tokio::select! {
-                Some(batch) = batches.next() => batch,
-                Some(e) = self.rx_err.recv() => Err(Error::web_socket_driver(e)),
+                Some(batch) = batches.next() => Some(batch),
+                Some(e) = self.rx_err.recv() => Some(Err(Error::web_socket_driver(e))),
+                _ = sleep(stall_timeout.unwrap_or_default()), if stall_timeout.is_some() => None,
             };
 
             // Before handling the batch, check if there are any pending shutdown or subscribe commands.
@@ -329,8 +398,9 @@ impl EventSource {
             }
 
             match result {
-                Ok(batch) => self.broadcast_batch(batch),
-                Err(e) => {
+                None => self.backfill_stalled().await,
+                Some(Ok(batch)) => self.handle_batch(batch).await,
+                Some(Err(e)) => {
                     if let ErrorDetail::SubscriptionCancelled(reason) = e.detail() {
                         error!("subscription cancelled, reason: {}", reason);
 
@@ -360,6 +430,35 @@ impl EventSource {
         self.event_bus.broadcast(Arc::new(Err(error)));
     }
 
+    /// Broadcast a batch received over WebSocket, preceded by the batches
+    /// of the blocks missed before it in hybrid mode.
+    async fn handle_batch(&mut self, batch: EventBatch) {
+        let Some(backfill) = &mut self.backfill else {
+            return self.broadcast_batch(batch);
+        };
+
+        for batch in backfill.received(batch).await {
+            self.broadcast_batch(batch);
+        }
+    }
+
+    /// Broadcast the batches of the blocks committed since the last batch,
+    /// when none was received over WebSocket for a while in hybrid mode.
+    async fn backfill_stalled(&mut self) {
+        let Some(backfill) = &mut self.backfill else {
+            return;
+        };
+
+        match backfill.stalled().await {
+            Ok(batches) => {
+                for batch in batches {
+                    self.broadcast_batch(batch);
+                }
+            }
+            Err(e) => error!("failed to pull the blocks missed over WebSocket: {e}"),
+        }
+    }
+
     /// Broadcast a batch of events to all subscribers.
     fn broadcast_batch(&mut self, batch: EventBatch) {
         telemetry!(ws_events, &batch.chain_id, batch.events.len() as u64);
@@ -356,6 +356,9 @@ pub struct CreateOptions {
     pub max_clock_drift: Option<Duration>,
     pub trusting_period: Option<Duration>,
     pub trust_threshold: Option<TrustThreshold>,
+    /// The checksum of the code of a Wasm light client contract stored on the destination
+    /// chain. If set, an ICS-08 Wasm client wrapping the light client is created.
+    pub wasm_checksum: Option<Vec<u8>>,
 }
 
 /// Captures the diagnostic of verifying whether a certain
@@ -642,6 +645,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             )
         })?;
 
+        let wasm_checksum = options.wasm_checksum.clone();
         let settings = ClientSettings::for_create_command(options, &src_config, &dst_config);
 
         let client_state: AnyClientState = self
@@ -670,6 +674,11 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 )
             })?;
 
+        let (client_state, consensus_state) = match wasm_checksum {
+            Some(checksum) => client_state.into_wasm(consensus_state, checksum),
+            None => (client_state, consensus_state),
+        };
+
         //TODO Get acct_prefix
         let msg = MsgCreateClient::new(client_state.into(), consensus_state.into(), signer)
             .map_err(ForeignClientError::client)?;
@@ -806,6 +815,13 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             client_state.chain_id(), self.src_chain.id());
         }
 
+        // Solo machine and localhost clients neither expire nor keep consensus states per height
+        if let AnyClientState::SoloMachine(_) | AnyClientState::Localhost(_) = client_state {
+            return Ok(ConsensusStateTrusted::Trusted {
+                elapsed: Duration::ZERO,
+            });
+        }
+
         let consensus_state_timestamp = self.fetch_consensus_state(*height)?.timestamp();
 
         let current_src_network_time = self
@@ -857,6 +873,47 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         }
     }
 
+    /// How long until the trusting period of the client lapses,
+    /// or `None` if the client never expires.
+    ///
+    /// Returns [`Duration::ZERO`] if the client has already expired.
+    pub fn time_until_expiry(&self) -> Result<Option<Duration>, ForeignClientError> {
+        let (client_state, _) = self
+            .dst_chain
+            .query_client_state(
+                QueryClientStateRequest {
+                    client_id: self.id().clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| {
+                ForeignClientError::client_query(self.id().clone(), self.dst_chain.id(), e)
+            })?;
+
+        if client_state.is_frozen() {
+            return Err(ForeignClientError::expired_or_frozen(
+                ExpiredOrFrozen::Frozen,
+                self.id().clone(),
+                self.dst_chain.id(),
+                "client state reports that client is frozen".into(),
+            ));
+        }
+
+        if let AnyClientState::SoloMachine(_) | AnyClientState::Localhost(_) = client_state {
+            return Ok(None);
+        }
+
+        let elapsed = match self
+            .check_consensus_state_trusting_period(&client_state, &client_state.latest_height())?
+        {
+            ConsensusStateTrusted::Trusted { elapsed } => elapsed,
+            ConsensusStateTrusted::NotTrusted { elapsed, .. } => elapsed,
+        };
+
+        Ok(Some(client_state.trusting_period().saturating_sub(elapsed)))
+    }
+
     #[instrument(
         name = "foreign_client.refresh",
         level = "error",
@@ -894,6 +951,11 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
     }
 
     fn try_refresh(&mut self) -> Result<Option<Vec<IbcEvent>>, ForeignClientError> {
+        // The localhost client is updated by the chain itself at every block
+        if self.id.is_localhost() {
+            return Ok(None);
+        }
+
         let (client_state, elapsed) = self.validated_client_state()?;
 
         let src_config = self.src_chain.config().map_err(|e| {
@@ -906,6 +968,8 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         let refresh_rate = match src_config {
             ChainConfig::CosmosSdk(config) => config.client_refresh_rate,
+            // Solo machine clients do not expire, so there is nothing to refresh
+            ChainConfig::SoloMachine(_) => return Ok(None),
         };
 
         let refresh_period = client_state
@@ -1047,6 +1111,11 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             }
         );
 
+        // Solo machine clients only require the header to be newer than their consensus state
+        if let AnyHeader::SoloMachine(_) = header {
+            return Ok(());
+        }
+
         // Get latest height and time on destination chain
         let mut status = self.dst_chain().query_application_status().map_err(|e| {
             ForeignClientError::client_update(
@@ -1145,6 +1214,12 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             }
         );
 
+        // The localhost client is updated by the chain itself at every block
+        // and must never be sent client updates
+        if self.id.is_localhost() {
+            return Ok(vec![]);
+        }
+
         let consensus_state = self.dst_chain().query_consensus_state(
             QueryConsensusStateRequest {
                 client_id: self.id().clone(),
@@ -1220,9 +1295,22 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 "dst_chain": self.dst_chain().id(),
             }
         );
+
+        if self.id.is_localhost() {
+            return Ok(vec![]);
+        }
+
         // Get the latest client state on destination.
         let (client_state, _) = self.validated_client_state()?;
 
+        // Every signature verified by a solo machine client moves it forward,
+        // so there is nothing to update if the client already reached the target.
+        if let AnyClientState::SoloMachine(_) = client_state {
+            if client_state.latest_height() >= target_height {
+                return Ok(vec![]);
+            }
+        }
+
         let trusted_height = match maybe_trusted_height {
             Some(trusted_height) => {
                 self.validate_trusted_height(trusted_height, &client_state)?;
@@ -1294,7 +1382,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             );
 
             msgs.push(MsgUpdateClient {
-                header: header.into(),
+                header: client_state.wrap_header(header).into(),
                 client_id: self.id.clone(),
                 signer: signer.clone(),
             });
@@ -1307,7 +1395,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         );
 
         msgs.push(MsgUpdateClient {
-            header: header.into(),
+            header: client_state.wrap_header(header).into(),
             signer,
             client_id: self.id.clone(),
         });
@@ -1582,6 +1670,11 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         &self,
         mut update: Option<&UpdateClient>,
     ) -> Result<Option<MisbehaviourEvidence>, ForeignClientError> {
+        // The localhost client has no consensus states and cannot misbehave
+        if self.id.is_localhost() {
+            return Ok(None);
+        }
+
         // FIXME(romac): Why do we need this, and shouldn't we wait somewhere else up the call stack?
         thread::sleep(Duration::from_millis(200));
 
@@ -1700,8 +1793,8 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 }
             };
 
-            if misbehavior.is_some() {
-                return Ok(misbehavior);
+            if let Some(evidence) = misbehavior {
+                return Ok(Some(client_state.wrap_evidence(evidence)));
             }
 
             // Exit the loop if more than MAX_MISBEHAVIOUR_CHECK_DURATION was spent here.
@@ -1758,6 +1851,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         let is_ccv_consumer_chain = match chain_config {
             ChainConfig::CosmosSdk(config) => config.ccv_consumer_chain,
+            ChainConfig::SoloMachine(_) => false,
         };
 
         let mut msgs = vec![];
@@ -1775,6 +1869,8 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         let tm_misbehaviour = match &evidence.misbehaviour {
             AnyMisbehaviour::Tendermint(tm_misbehaviour) => Some(tm_misbehaviour.clone()),
+            AnyMisbehaviour::Wasm(wasm_misbehaviour) => Some(wasm_misbehaviour.inner.clone()),
+            AnyMisbehaviour::SoloMachine(_) => None,
         }
         .ok_or_else(|| {
             ForeignClientError::misbehaviour_desc(format!(
@@ -1,21 +1,30 @@
 pub mod errors;
+pub use any_secp256k1_key_pair::AnySecp256k1KeyPair;
 pub use any_signing_key_pair::AnySigningKeyPair;
 pub use ed25519_key_pair::Ed25519KeyPair;
+pub use file::{PassphraseSource, KEYSTORE_FILE_BACKEND};
 pub use key_type::KeyType;
+pub use remote_key_pair::RemoteSigningKeyPair;
+pub use remote_signer::{RemoteSigner, RemoteSignerConfig};
 pub use secp256k1_key_pair::Secp256k1KeyPair;
 pub use signing_key_pair::{SigningKeyPair, SigningKeyPairSized};
 
+mod any_secp256k1_key_pair;
 mod any_signing_key_pair;
 mod ed25519_key_pair;
+mod file;
+mod jwe;
 mod key_type;
 mod key_utils;
 mod pub_key;
+mod remote_key_pair;
+mod remote_signer;
 mod secp256k1_key_pair;
 mod signing_key_pair;
 
 use alloc::collections::btree_map::BTreeMap as HashMap;
 use std::ffi::OsStr;
-use std::fs::{self, File};
+use std::fs;
 use std::path::PathBuf;
 
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
@@ -119,7 +128,7 @@ impl<S: SigningKeyPairSized> KeyStore<S> for Test {
             return Err(Error::key_file_not_found(format!("{}", key_file.display())));
         }
 
-        let file = File::open(&key_file).map_err(|e| {
+        let file = fs::File::open(&key_file).map_err(|e| {
             Error::key_file_io(
                 key_file.display().to_string(),
                 "failed to open file".to_string(),
@@ -138,7 +147,7 @@ impl<S: SigningKeyPairSized> KeyStore<S> for Test {
         filename.set_extension(KEYSTORE_FILE_EXTENSION);
         let file_path = filename.display().to_string();
 
-        let file = File::create(filename).map_err(|e| {
+        let file = fs::File::create(filename).map_err(|e| {
             Error::key_file_io(file_path.clone(), "failed to create file".to_string(), e)
         })?;
 
@@ -184,6 +193,10 @@ impl<S: SigningKeyPairSized> KeyStore<S> for Test {
 pub enum Store {
     Memory,
     Test,
+    /// Encrypted on-disk store, compatible with the `file` backend of the Cosmos SDK keyring
+    File,
+    /// Keys held by a remote signer, which signs on behalf of Hermes
+    Remote,
 }
 
 impl Default for Store {
@@ -192,10 +205,24 @@ impl Default for Store {
     }
 }
 
+impl Store {
+    /// The store to use when managing keys from the CLI,
+    /// for which the in-memory store would be meaningless.
+    pub fn persistent(self) -> Self {
+        match self {
+            Self::File => Self::File,
+            Self::Remote => Self::Remote,
+            Self::Memory | Self::Test => Self::Test,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum KeyRing<S> {
     Memory(Memory<S>),
     Test(Test),
+    File(file::File),
+    Remote(remote_signer::Remote),
 }
 
 impl<S: SigningKeyPairSized> KeyRing<S> {
@@ -204,27 +231,43 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         account_prefix: &str,
         chain_id: &ChainId,
         ks_folder: &Option<PathBuf>,
+        passphrase: &PassphraseSource,
+        remote_signer: &Option<RemoteSignerConfig>,
     ) -> Result<Self, Error> {
         match store {
             Store::Memory => Ok(Self::Memory(Memory::new(account_prefix.to_string()))),
 
             Store::Test => {
-                let keys_folder = disk_store_path(chain_id.as_str(), ks_folder)?;
-
-                // Create keys folder if it does not exist
-                fs::create_dir_all(&keys_folder).map_err(|e| {
-                    Error::key_file_io(
-                        keys_folder.display().to_string(),
-                        "failed to create keys folder".to_string(),
-                        e,
-                    )
-                })?;
+                let keys_folder =
+                    disk_store_path(chain_id.as_str(), ks_folder, KEYSTORE_DISK_BACKEND)?;
 
                 Ok(Self::Test(Test::new(
                     account_prefix.to_string(),
                     keys_folder,
                 )))
             }
+
+            Store::File => {
+                let keys_folder =
+                    disk_store_path(chain_id.as_str(), ks_folder, KEYSTORE_FILE_BACKEND)?;
+
+                Ok(Self::File(file::File::new(
+                    account_prefix.to_string(),
+                    keys_folder,
+                    passphrase,
+                )?))
+            }
+
+            Store::Remote => {
+                let config = remote_signer
+                    .as_ref()
+                    .ok_or_else(Error::remote_signer_not_configured)?;
+
+                Ok(Self::Remote(remote_signer::Remote::new(
+                    account_prefix.to_string(),
+                    config,
+                )))
+            }
         }
     }
 
@@ -232,6 +275,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.get_key(key_name),
             Self::Test(d) => d.get_key(key_name),
+            Self::File(f) => f.get_key(key_name),
+            Self::Remote(r) => r.get_key(key_name),
         }
     }
 
@@ -239,6 +284,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.add_key(key_name, key_entry),
             Self::Test(d) => d.add_key(key_name, key_entry),
+            Self::File(f) => f.add_key(key_name, key_entry),
+            Self::Remote(r) => r.add_key(key_name, key_entry),
         }
     }
 
@@ -246,6 +293,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.remove_key(key_name),
             Self::Test(d) => <Test as KeyStore<S>>::remove_key(d, key_name),
+            Self::File(f) => <file::File as KeyStore<S>>::remove_key(f, key_name),
+            Self::Remote(r) => <remote_signer::Remote as KeyStore<S>>::remove_key(r, key_name),
         }
     }
 
@@ -253,6 +302,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.keys(),
             Self::Test(d) => d.keys(),
+            Self::File(f) => f.keys(),
+            Self::Remote(r) => r.keys(),
         }
     }
 
@@ -260,6 +311,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => &m.account_prefix,
             Self::Test(d) => &d.account_prefix,
+            Self::File(f) => &f.account_prefix,
+            Self::Remote(r) => &r.account_prefix,
         }
     }
 }
@@ -270,8 +323,17 @@ impl KeyRing<Secp256k1KeyPair> {
         account_prefix: &str,
         chain_id: &ChainId,
         ks_folder: &Option<PathBuf>,
+        passphrase: &PassphraseSource,
+        remote_signer: &Option<RemoteSignerConfig>,
     ) -> Result<Self, Error> {
-        Self::new(store, account_prefix, chain_id, ks_folder)
+        Self::new(
+            store,
+            account_prefix,
+            chain_id,
+            ks_folder,
+            passphrase,
+            remote_signer,
+        )
     }
 }
 
@@ -281,14 +343,29 @@ impl KeyRing<Ed25519KeyPair> {
         account_prefix: &str,
         chain_id: &ChainId,
         ks_folder: &Option<PathBuf>,
+        passphrase: &PassphraseSource,
+        remote_signer: &Option<RemoteSignerConfig>,
     ) -> Result<Self, Error> {
-        Self::new(store, account_prefix, chain_id, ks_folder)
+        Self::new(
+            store,
+            account_prefix,
+            chain_id,
+            ks_folder,
+            passphrase,
+            remote_signer,
+        )
     }
 }
 
 // Why is this not a method on `ChainConfig`?
 
-fn disk_store_path(folder_name: &str, keystore_folder: &Option<PathBuf>) -> Result<PathBuf, Error> {
+/// The folder of the on-disk key store using the given backend,
+/// which is created if it does not exist yet.
+fn disk_store_path(
+    folder_name: &str,
+    keystore_folder: &Option<PathBuf>,
+    backend: &str,
+) -> Result<PathBuf, Error> {
     let ks_folder = match keystore_folder {
         Some(folder) => folder.to_owned(),
         None => {
@@ -297,7 +374,16 @@ fn disk_store_path(folder_name: &str, keystore_folder: &Option<PathBuf>) -> Resu
         }
     };
 
-    let folder = ks_folder.join(folder_name).join(KEYSTORE_DISK_BACKEND);
+    let folder = ks_folder.join(folder_name).join(backend);
+
+    // Create keys folder if it does not exist
+    fs::create_dir_all(&folder).map_err(|e| {
+        Error::key_file_io(
+            folder.display().to_string(),
+            "failed to create keys folder".to_string(),
+            e,
+        )
+    })?;
 
     Ok(folder)
 }
@@ -0,0 +1,119 @@
+use core::any::Any;
+
+use hdpath::StandardHDPath;
+use ibc_proto::cosmos::crypto::keyring::v1::Record;
+use secp256k1::PublicKey;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    errors::Error, AnySigningKeyPair, KeyFile, KeyType, RemoteSigningKeyPair, Secp256k1KeyPair,
+    SigningKeyPair,
+};
+use crate::config::AddressType;
+
+/// A secp256k1 key pair, whose private key is either held
+/// by Hermes or by a remote signer.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum AnySecp256k1KeyPair {
+    Local(Secp256k1KeyPair),
+    Remote(RemoteSigningKeyPair),
+}
+
+impl AnySecp256k1KeyPair {
+    pub fn public_key(&self) -> &PublicKey {
+        match self {
+            Self::Local(key_pair) => &key_pair.public_key,
+            Self::Remote(key_pair) => &key_pair.public_key,
+        }
+    }
+}
+
+impl SigningKeyPair for AnySecp256k1KeyPair {
+    const KEY_TYPE: KeyType = KeyType::Secp256k1;
+    type KeyFile = KeyFile;
+
+    fn from_key_file(key_file: KeyFile, hd_path: &StandardHDPath) -> Result<Self, Error> {
+        Secp256k1KeyPair::from_key_file(key_file, hd_path).map(Self::Local)
+    }
+
+    fn from_mnemonic(
+        mnemonic: &str,
+        hd_path: &StandardHDPath,
+        address_type: &AddressType,
+        account_prefix: &str,
+    ) -> Result<Self, Error> {
+        Secp256k1KeyPair::from_mnemonic(mnemonic, hd_path, address_type, account_prefix)
+            .map(Self::Local)
+    }
+
+    fn from_keyring_record(record: &Record, account_prefix: &str) -> Result<Self, Error> {
+        Secp256k1KeyPair::from_keyring_record(record, account_prefix).map(Self::Local)
+    }
+
+    fn from_remote_key(key: RemoteSigningKeyPair) -> Result<Self, Error> {
+        Ok(Self::Remote(key))
+    }
+
+    fn to_keyring_record(&self, name: &str) -> Record {
+        match self {
+            Self::Local(key_pair) => key_pair.to_keyring_record(name),
+            Self::Remote(key_pair) => key_pair.to_keyring_record(name),
+        }
+    }
+
+    fn keyring_address(&self) -> Vec<u8> {
+        match self {
+            Self::Local(key_pair) => key_pair.keyring_address(),
+            Self::Remote(key_pair) => key_pair.keyring_address(),
+        }
+    }
+
+    fn account(&self) -> String {
+        match self {
+            Self::Local(key_pair) => key_pair.account(),
+            Self::Remote(key_pair) => key_pair.account(),
+        }
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        match self {
+            Self::Local(key_pair) => key_pair.sign(message),
+            Self::Remote(key_pair) => key_pair.sign(message),
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        match self {
+            Self::Local(key_pair) => key_pair.as_any(),
+            Self::Remote(key_pair) => key_pair.as_any(),
+        }
+    }
+}
+
+impl From<Secp256k1KeyPair> for AnySecp256k1KeyPair {
+    fn from(key_pair: Secp256k1KeyPair) -> Self {
+        Self::Local(key_pair)
+    }
+}
+
+impl From<AnySecp256k1KeyPair> for AnySigningKeyPair {
+    fn from(key_pair: AnySecp256k1KeyPair) -> Self {
+        match key_pair {
+            AnySecp256k1KeyPair::Local(key_pair) => Self::Secp256k1(key_pair),
+            AnySecp256k1KeyPair::Remote(key_pair) => Self::Remote(key_pair),
+        }
+    }
+}
+
+impl TryFrom<AnySigningKeyPair> for AnySecp256k1KeyPair {
+    type Error = AnySigningKeyPair;
+
+    fn try_from(key_pair: AnySigningKeyPair) -> Result<Self, Self::Error> {
+        match key_pair {
+            AnySigningKeyPair::Secp256k1(key_pair) => Ok(Self::Local(key_pair)),
+            AnySigningKeyPair::Remote(key_pair) => Ok(Self::Remote(key_pair)),
+            key_pair => Err(key_pair),
+        }
+    }
+}
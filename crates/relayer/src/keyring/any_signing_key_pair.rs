@@ -1,12 +1,13 @@
 use serde::Serialize;
 
-use super::{Ed25519KeyPair, KeyType, Secp256k1KeyPair, SigningKeyPair};
+use super::{Ed25519KeyPair, KeyType, RemoteSigningKeyPair, Secp256k1KeyPair, SigningKeyPair};
 
 #[derive(Clone, Debug, Serialize)]
 #[serde(untagged)]
 pub enum AnySigningKeyPair {
     Secp256k1(Secp256k1KeyPair),
     Ed25519(Ed25519KeyPair),
+    Remote(RemoteSigningKeyPair),
 }
 
 impl AnySigningKeyPair {
@@ -14,6 +15,7 @@ impl AnySigningKeyPair {
         match self {
             Self::Secp256k1(key_pair) => key_pair.account(),
             Self::Ed25519(key_pair) => key_pair.account(),
+            Self::Remote(key_pair) => key_pair.account(),
         }
     }
 
@@ -21,6 +23,7 @@ impl AnySigningKeyPair {
         match self {
             Self::Secp256k1(_) => Secp256k1KeyPair::KEY_TYPE,
             Self::Ed25519(_) => Ed25519KeyPair::KEY_TYPE,
+            Self::Remote(_) => RemoteSigningKeyPair::KEY_TYPE,
         }
     }
 
@@ -28,6 +31,7 @@ impl AnySigningKeyPair {
         match self {
             Self::Secp256k1(key_pair) => key_pair.as_any(),
             Self::Ed25519(key_pair) => key_pair.as_any(),
+            Self::Remote(key_pair) => key_pair.as_any(),
         }
         .downcast_ref::<T>()
         .cloned()
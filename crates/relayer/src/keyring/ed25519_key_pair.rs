@@ -4,12 +4,24 @@ use bip39::{Language, Mnemonic, Seed};
 use ed25519_dalek::{SigningKey, VerifyingKey};
 use ed25519_dalek_bip32::{ChildIndex, DerivationPath, ExtendedSigningKey};
 use hdpath::StandardHDPath;
+use ibc_proto::cosmos::crypto::ed25519::{PrivKey, PubKey};
+use ibc_proto::cosmos::crypto::keyring::v1::Record;
+use ibc_proto::google::protobuf::Any as ProtoAny;
+use prost::Message;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use signature::Signer;
 
-use super::{errors::Error, KeyFile, KeyType, SigningKeyPair};
+use super::{
+    errors::Error,
+    key_utils::{keyring_record_private_key, local_keyring_record},
+    KeyFile, KeyType, SigningKeyPair,
+};
 use crate::config::AddressType;
 
+const PUB_KEY_TYPE_URL: &str = "/cosmos.crypto.ed25519.PubKey";
+const PRIV_KEY_TYPE_URL: &str = "/cosmos.crypto.ed25519.PrivKey";
+
 pub fn private_key_from_mnemonic(
     mnemonic_words: &str,
     hd_path: &StandardHDPath,
@@ -126,6 +138,65 @@ impl SigningKeyPair for Ed25519KeyPair {
         Self::from_mnemonic_internal(mnemonic, hd_path, address_type.try_into()?)
     }
 
+    fn from_keyring_record(record: &Record, _account_prefix: &str) -> Result<Self, Error> {
+        use ed25519_dalek::KEYPAIR_LENGTH;
+
+        let private_key = keyring_record_private_key(record)?;
+
+        if private_key.type_url != PRIV_KEY_TYPE_URL {
+            return Err(Error::unsupported_keyring_record(format!(
+                "unsupported private key type '{}'",
+                private_key.type_url
+            )));
+        }
+
+        let key = PrivKey::decode(private_key.value.as_slice())
+            .map_err(Error::keyring_record_decode)?
+            .key;
+
+        // The Cosmos SDK stores the private key followed by the public key
+        let key_bytes: &[u8; KEYPAIR_LENGTH] = key.as_slice().try_into().map_err(|_| {
+            Error::invalid_private_key(format!(
+                "expected {KEYPAIR_LENGTH} bytes, got {}",
+                key.len()
+            ))
+        })?;
+
+        let signing_key = SigningKey::from_keypair_bytes(key_bytes)
+            .map_err(|e| Error::invalid_private_key(e.to_string()))?;
+
+        Ok(Self {
+            signing_key,
+            // TODO: Derive this from something in the record
+            address_type: Ed25519AddressType::Solana,
+        })
+    }
+
+    fn to_keyring_record(&self, name: &str) -> Record {
+        let public_key = ProtoAny {
+            type_url: PUB_KEY_TYPE_URL.to_string(),
+            value: PubKey {
+                key: self.signing_key.verifying_key().to_bytes().to_vec(),
+            }
+            .encode_to_vec(),
+        };
+
+        let private_key = ProtoAny {
+            type_url: PRIV_KEY_TYPE_URL.to_string(),
+            value: PrivKey {
+                key: self.signing_key.to_keypair_bytes().to_vec(),
+            }
+            .encode_to_vec(),
+        };
+
+        local_keyring_record(name, public_key, private_key)
+    }
+
+    // Cosmos SDK address: first 20 bytes of sha256(pubkey)
+    fn keyring_address(&self) -> Vec<u8> {
+        Sha256::digest(self.signing_key.verifying_key().as_bytes())[..20].to_vec()
+    }
+
     // Solana address: base58(pubkey)
     fn account(&self) -> String {
         match self.address_type {
@@ -156,6 +156,79 @@ define_error! {
             }
             |e| {
                 format!("Invalid public key length: expected {}, got {}", e.expected, e.got)
+            },
+
+        InvalidKeyName
+            { key_name: String }
+            |e| {
+                format!("invalid key name '{}': key names cannot contain path separators", e.key_name)
+            },
+
+        PassphraseUnavailable
+            { reason: String }
+            |e| {
+                format!("cannot obtain the keyring passphrase: {}", e.reason)
+            },
+
+        KeyringEncrypt
+            { reason: String }
+            |e| {
+                format!("failed to encrypt keyring entry: {}", e.reason)
+            },
+
+        KeyringDecrypt
+            |_| { "failed to decrypt keyring entry, the passphrase is probably wrong" },
+
+        KeyringItemDecode
+            { reason: String }
+            |e| {
+                format!("failed to decode keyring entry: {}", e.reason)
+            },
+
+        KeyringRecordDecode
+            [ TraceError<prost::DecodeError> ]
+            |_| { "failed to decode Cosmos SDK keyring record" },
+
+        UnsupportedKeyringRecord
+            { reason: String }
+            |e| {
+                format!("unsupported Cosmos SDK keyring record: {}", e.reason)
+            },
+
+        InvalidPrivateKey
+            { reason: String }
+            |e| {
+                format!("invalid private key: {}", e.reason)
+            },
+
+        RemoteSignerNotConfigured
+            |_| { "the `Remote` key store requires the `remote_signer` setting" },
+
+        RemoteSigner
+            {
+                url: String,
+                reason: String,
+            }
+            |e| {
+                format!("request to remote signer at '{}' failed: {}", e.url, e.reason)
+            },
+
+        InvalidRemoteSignature
+            { reason: String }
+            |e| {
+                format!("remote signer returned an invalid signature: {}", e.reason)
+            },
+
+        UnsupportedByRemoteSigner
+            { operation: String }
+            |e| {
+                format!("{} is not supported by the `Remote` key store, keys are managed by the remote signer", e.operation)
+            },
+
+        UnsupportedRemoteKey
+            { key_type: KeyType }
+            |e| {
+                format!("{} keys cannot be held by a remote signer", e.key_type)
             }
     }
 }
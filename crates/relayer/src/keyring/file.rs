@@ -0,0 +1,475 @@
+//! Encrypted key store, compatible with the `file` backend of the Cosmos SDK keyring.
+//!
+//! Each entry of the keyring is stored in its own file, encrypted with a key
+//! derived from a passphrase (see the [`jwe`](super::jwe) module).
+//! For every key, two entries are written, as done by the Cosmos SDK:
+//!
+//! - `<name>.info`, which holds the key record, including the private key;
+//! - `<address>.address`, which maps the hex-encoded address of the key to its record.
+
+use core::fmt;
+use std::ffi::OsStr;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use ibc_proto::cosmos::crypto::keyring::v1::Record;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use super::{errors::Error, jwe, KeyStore, SigningKeyPairSized};
+
+pub const KEYSTORE_FILE_BACKEND: &str = "keyring-file";
+
+pub const DEFAULT_PASSPHRASE_ENV_VAR: &str = "HERMES_KEYRING_PASSPHRASE";
+
+const INFO_SUFFIX: &str = "info";
+const ADDRESS_SUFFIX: &str = "address";
+
+/// Where to get the passphrase of the encrypted key store from.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PassphraseSource {
+    /// Read the passphrase from the given environment variable
+    Env(String),
+    /// Read the passphrase from the first line of the given file
+    File(PathBuf),
+    /// Prompt for the passphrase on the terminal
+    Prompt,
+}
+
+impl Default for PassphraseSource {
+    fn default() -> Self {
+        Self::Env(DEFAULT_PASSPHRASE_ENV_VAR.to_string())
+    }
+}
+
+impl PassphraseSource {
+    /// Obtain the passphrase from this source.
+    ///
+    /// When prompting for a passphrase and `confirm` is true,
+    /// the passphrase has to be entered twice.
+    pub fn passphrase(&self, confirm: bool) -> Result<Passphrase, Error> {
+        let passphrase = match self {
+            Self::Env(var) => std::env::var(var).map_err(|e| {
+                Error::passphrase_unavailable(format!("environment variable '{var}': {e}"))
+            })?,
+
+            Self::File(path) => {
+                let contents = Zeroizing::new(fs::read_to_string(path).map_err(|e| {
+                    Error::passphrase_unavailable(format!("file '{}': {e}", path.display()))
+                })?);
+
+                contents.lines().next().unwrap_or_default().to_string()
+            }
+
+            Self::Prompt => {
+                let prompt = dialoguer::Password::new().with_prompt("Enter keyring passphrase");
+
+                let prompt = if confirm {
+                    prompt.with_confirmation(
+                        "Re-enter keyring passphrase",
+                        "Passphrases do not match",
+                    )
+                } else {
+                    prompt
+                };
+
+                prompt
+                    .interact()
+                    .map_err(|e| Error::passphrase_unavailable(format!("prompt: {e}")))?
+            }
+        };
+
+        if passphrase.is_empty() {
+            return Err(Error::passphrase_unavailable(
+                "the passphrase cannot be empty".to_string(),
+            ));
+        }
+
+        Ok(Passphrase(Zeroizing::new(passphrase)))
+    }
+}
+
+/// The passphrase of an encrypted key store, which is never printed.
+#[derive(Clone, Default)]
+pub struct Passphrase(Zeroizing<String>);
+
+impl Passphrase {
+    fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Passphrase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Passphrase(<redacted>)")
+    }
+}
+
+/// An entry of the keyring, as serialized by the Go `keyring` library
+/// used by the Cosmos SDK.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Item {
+    key: String,
+    /// Go encodes byte slices in JSON as padded standard base64 strings
+    #[serde(with = "crate::util::serde_base64")]
+    data: Vec<u8>,
+    #[serde(default)]
+    label: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    keychain_not_trust_application: bool,
+    #[serde(default)]
+    keychain_not_synchronizable: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct File {
+    pub(super) account_prefix: String,
+    store: PathBuf,
+    #[serde(skip)]
+    passphrase: Passphrase,
+}
+
+impl File {
+    /// Open the encrypted key store at the given folder, obtaining
+    /// its passphrase from the given source.
+    ///
+    /// The passphrase is checked against the existing entries of the store, if any.
+    pub fn new(
+        account_prefix: String,
+        store: PathBuf,
+        passphrase: &PassphraseSource,
+    ) -> Result<Self, Error> {
+        // Ask for confirmation when the passphrase is set for the first time
+        let confirm = Self::info_entries(&store)?.is_empty();
+        let passphrase = passphrase.passphrase(confirm)?;
+
+        let file = Self {
+            account_prefix,
+            store,
+            passphrase,
+        };
+
+        file.check_passphrase()?;
+
+        Ok(file)
+    }
+
+    /// Decrypt one of the existing entries, if any, to make sure
+    /// that the passphrase is the one the store was created with.
+    fn check_passphrase(&self) -> Result<(), Error> {
+        match Self::info_entries(&self.store)?.first() {
+            Some(path) => self.read_item(path).map(|_| ()),
+            None => Ok(()),
+        }
+    }
+
+    /// The paths of the `.info` entries in the store.
+    fn info_entries(store: &Path) -> Result<Vec<PathBuf>, Error> {
+        let dir = fs::read_dir(store).map_err(|e| {
+            Error::key_file_io(
+                store.display().to_string(),
+                "failed to list keys".to_string(),
+                e,
+            )
+        })?;
+
+        let ext = OsStr::new(INFO_SUFFIX);
+
+        let mut entries: Vec<_> = dir
+            .into_iter()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension() == Some(ext))
+            .collect();
+
+        entries.sort();
+
+        Ok(entries)
+    }
+
+    fn info_path(&self, key_name: &str) -> Result<PathBuf, Error> {
+        if key_name.is_empty() || key_name.contains(['/', '\\']) || key_name.starts_with('.') {
+            return Err(Error::invalid_key_name(key_name.to_string()));
+        }
+
+        Ok(self.store.join(format!("{key_name}.{INFO_SUFFIX}")))
+    }
+
+    fn address_path(&self, address: &[u8]) -> PathBuf {
+        self.store
+            .join(format!("{}.{ADDRESS_SUFFIX}", hex::encode(address)))
+    }
+
+    fn read_item(&self, path: &Path) -> Result<Item, Error> {
+        if !path.exists() {
+            return Err(Error::key_file_not_found(path.display().to_string()));
+        }
+
+        let token = fs::read_to_string(path).map_err(|e| {
+            Error::key_file_io(
+                path.display().to_string(),
+                "failed to read file".to_string(),
+                e,
+            )
+        })?;
+
+        let payload = jwe::decrypt(self.passphrase.as_str(), &token)?;
+
+        serde_json::from_slice(&payload).map_err(|e| Error::keyring_item_decode(e.to_string()))
+    }
+
+    fn write_item(&self, path: &Path, item: &Item) -> Result<(), Error> {
+        let payload = Zeroizing::new(serde_json::to_vec(item).map_err(Error::encode)?);
+        let token = jwe::encrypt(self.passphrase.as_str(), &payload)?;
+
+        let file_path = path.display().to_string();
+
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let mut file = options.open(path).map_err(|e| {
+            Error::key_file_io(file_path.clone(), "failed to create file".to_string(), e)
+        })?;
+
+        file.write_all(token.as_bytes())
+            .map_err(|e| Error::key_file_io(file_path, "failed to write file".to_string(), e))
+    }
+
+    fn read_record(&self, path: &Path) -> Result<Record, Error> {
+        let item = Zeroizing::new(self.read_item(path)?.data);
+        Record::decode(item.as_slice()).map_err(Error::keyring_record_decode)
+    }
+
+    fn read_key<S: SigningKeyPairSized>(&self, path: &Path) -> Result<S, Error> {
+        S::from_keyring_record(&self.read_record(path)?, &self.account_prefix)
+    }
+}
+
+impl<S: SigningKeyPairSized> KeyStore<S> for File {
+    fn get_key(&self, key_name: &str) -> Result<S, Error> {
+        self.read_key(&self.info_path(key_name)?)
+    }
+
+    fn add_key(&mut self, key_name: &str, key_entry: S) -> Result<(), Error> {
+        let info_path = self.info_path(key_name)?;
+
+        // Remove the address entry of the key being overwritten, if any
+        if info_path.exists() {
+            <Self as KeyStore<S>>::remove_key(self, key_name)?;
+        }
+
+        let record = key_entry.to_keyring_record(key_name);
+        let info_key = format!("{key_name}.{INFO_SUFFIX}");
+
+        self.write_item(
+            &info_path,
+            &Item {
+                data: record.encode_to_vec(),
+                key: info_key.clone(),
+                ..Item::default()
+            },
+        )?;
+
+        let address = key_entry.keyring_address();
+
+        self.write_item(
+            &self.address_path(&address),
+            &Item {
+                key: format!("{}.{ADDRESS_SUFFIX}", hex::encode(&address)),
+                data: info_key.into_bytes(),
+                ..Item::default()
+            },
+        )
+    }
+
+    fn remove_key(&mut self, key_name: &str) -> Result<(), Error> {
+        let info_path = self.info_path(key_name)?;
+        let key: S = self.read_key(&info_path)?;
+
+        let address_path = self.address_path(&key.keyring_address());
+        if address_path.exists() {
+            fs::remove_file(&address_path)
+                .map_err(|e| Error::remove_io_fail(address_path.display().to_string(), e))?;
+        }
+
+        fs::remove_file(&info_path)
+            .map_err(|e| Error::remove_io_fail(info_path.display().to_string(), e))?;
+
+        Ok(())
+    }
+
+    fn keys(&self) -> Result<Vec<(String, S)>, Error> {
+        Self::info_entries(&self.store)?
+            .into_iter()
+            .filter_map(|path| {
+                let name = path.file_stem()?.to_str()?.to_string();
+                Some((name, path))
+            })
+            .map(|(name, path)| self.read_key(&path).map(|key| (name, key)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use hdpath::StandardHDPath;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    use crate::config::AddressType;
+    use crate::keyring::{KeyRing, Secp256k1KeyPair, SigningKeyPair, Store};
+
+    const MNEMONIC: &str = "such walnut usual noble image raise cabin suspect combine key absurd detail present bless yard grief amazing slam brown donate fabric opera desk minor";
+
+    fn test_key() -> Secp256k1KeyPair {
+        let hd_path = StandardHDPath::try_from("m/44'/118'/0'/0/0").unwrap();
+        Secp256k1KeyPair::from_mnemonic(MNEMONIC, &hd_path, &AddressType::Cosmos, "cosmos").unwrap()
+    }
+
+    fn assert_same_key(a: &Secp256k1KeyPair, b: &Secp256k1KeyPair) {
+        assert_eq!(a.account(), b.account());
+        assert_eq!(a.sign(b"message").unwrap(), b.sign(b"message").unwrap());
+    }
+
+    fn env_passphrase(var: &str, passphrase: &str) -> PassphraseSource {
+        std::env::set_var(var, passphrase);
+        PassphraseSource::Env(var.to_string())
+    }
+
+    #[test]
+    fn add_get_list_remove() {
+        let dir = tempfile::tempdir().unwrap();
+        let ks_folder = Some(dir.path().to_path_buf());
+        let chain_id = ChainId::from_string("chain-A");
+        let passphrase = env_passphrase("HERMES_TEST_KEYRING_PASSPHRASE_1", "passphrase");
+
+        let mut keyring = KeyRing::new_secp256k1(
+            Store::File,
+            "cosmos",
+            &chain_id,
+            &ks_folder,
+            &passphrase,
+            &None,
+        )
+        .unwrap();
+
+        let key = test_key();
+        keyring.add_key("relayer", key.clone()).unwrap();
+
+        let store = dir.path().join("chain-A").join(KEYSTORE_FILE_BACKEND);
+
+        let address_file = store.join(format!("{}.address", hex::encode(key.keyring_address())));
+        assert!(store.join("relayer.info").exists());
+        assert!(address_file.exists());
+
+        // The private key is not stored in clear
+        let contents = fs::read_to_string(store.join("relayer.info")).unwrap();
+        assert!(!contents.contains(&key.account()));
+
+        assert_same_key(&keyring.get_key("relayer").unwrap(), &key);
+
+        let keys = keyring.keys().unwrap();
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].0, "relayer");
+        assert_same_key(&keys[0].1, &key);
+
+        keyring.remove_key("relayer").unwrap();
+        assert!(!store.join("relayer.info").exists());
+        assert!(!address_file.exists());
+        assert!(keyring.keys().unwrap().is_empty());
+    }
+
+    #[test]
+    fn wrong_passphrase() {
+        let dir = tempfile::tempdir().unwrap();
+        let ks_folder = Some(dir.path().to_path_buf());
+        let chain_id = ChainId::from_string("chain-A");
+
+        let passphrase = env_passphrase("HERMES_TEST_KEYRING_PASSPHRASE_2", "passphrase");
+        let mut keyring = KeyRing::new_secp256k1(
+            Store::File,
+            "cosmos",
+            &chain_id,
+            &ks_folder,
+            &passphrase,
+            &None,
+        )
+        .unwrap();
+        keyring.add_key("relayer", test_key()).unwrap();
+
+        let wrong = env_passphrase("HERMES_TEST_KEYRING_PASSPHRASE_3", "wrong");
+        assert!(KeyRing::<Secp256k1KeyPair>::new(
+            Store::File,
+            "cosmos",
+            &chain_id,
+            &ks_folder,
+            &wrong,
+            &None,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn invalid_key_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let ks_folder = Some(dir.path().to_path_buf());
+        let chain_id = ChainId::from_string("chain-A");
+        let passphrase = env_passphrase("HERMES_TEST_KEYRING_PASSPHRASE_4", "passphrase");
+
+        let mut keyring = KeyRing::new_secp256k1(
+            Store::File,
+            "cosmos",
+            &chain_id,
+            &ks_folder,
+            &passphrase,
+            &None,
+        )
+        .unwrap();
+
+        assert!(keyring.add_key("../relayer", test_key()).is_err());
+    }
+
+    /// Entry written by an independent implementation of the Cosmos SDK `file` keyring,
+    /// holding the key derived from `MNEMONIC` under the name `relayer`,
+    /// encrypted with the passphrase `passphrase`.
+    const SDK_INFO_ENTRY: &str = concat!(
+        "eyJhbGciOiJQQkVTMi1IUzI1NitBMTI4S1ciLCJjcmVhdGVkIjoiMjAyNC0wMS0wMSAwMDowMDowMCAr",
+        "MDAwMCBVVEMiLCJlbmMiOiJBMjU2R0NNIiwicDJjIjo4MTkyLCJwMnMiOiJBZkdEcU5LclVoajZvYmVX",
+        "In0.",
+        "eJwZRHvfCGwVAZ1rPHTPOTQS_mvBfdcrXWDytzGBzx52PF-HQQ1C0w.",
+        "cqqmWhv_JqYvO4yh.",
+        "7rsIf75jfuqOm2CMVDO7Zwbvcb_P_vvgasHy3m5841tPREwCGQ2E2U53gI249bfovVeHqSYdrPKFK10R",
+        "V7ILwbmdDPtxw0Knztwcf2Nq8puJ5MHeESiy4kgAHrwb2fcRHSM6awhkIL3JhBZC8WoqgmY86Ka5AxBC",
+        "Y_jzERhMuTLhDuR0PBXiAez5clRsvBIrs9CmFbNRr3Dtpc9V1Pc7G-TFMriT23Rqrh0TGibQWXSrkhhM",
+        "3fJT8R1iYmCgtF4YQ_fEhYsGujgJk3AOSPThOIXUzExmHx0wSmFywCoNdD1d3tnmNK1AjwpMLOlIyyjL",
+        "qTXMkrK4yGLNDnw_PSFBz-mJ3B0KSMzkT5APXgmlTHLsdGt9rgsDGfF1pww6lQXNP3_stDNlL2uDTBGH",
+        "giEFas7PXpMknVMF2LYDqF18QE2X6yRmaxrglPRApd6_G6ViWEU.",
+        "oIuG2eWZvjH4O9G6ovqnKA",
+    );
+
+    #[test]
+    fn read_sdk_entry() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = dir.path().to_path_buf();
+        fs::write(store.join("relayer.info"), SDK_INFO_ENTRY).unwrap();
+
+        let passphrase = env_passphrase("HERMES_TEST_KEYRING_PASSPHRASE_5", "passphrase");
+        let file = File::new("cosmos".to_string(), store.clone(), &passphrase).unwrap();
+
+        let key: Secp256k1KeyPair = file.get_key("relayer").unwrap();
+        assert_same_key(&key, &test_key());
+    }
+}
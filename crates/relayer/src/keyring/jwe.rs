@@ -0,0 +1,215 @@
+//! Password-based JSON Web Encryption, as used by the `file` keyring backend
+//! of the Cosmos SDK to encrypt each entry of the keyring.
+//!
+//! Only the algorithms used by that backend are supported, namely
+//! `PBES2-HS256+A128KW` for wrapping the content encryption key and
+//! `A256GCM` for encrypting the content, using the compact serialization.
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use aes_kw::KekAes128;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use pbkdf2::pbkdf2_hmac;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+use super::errors::Error;
+
+const ALG: &str = "PBES2-HS256+A128KW";
+const ENC: &str = "A256GCM";
+
+/// Number of PBKDF2 iterations used when encrypting, same as the Cosmos SDK.
+const ITERATION_COUNT: u32 = 8192;
+
+/// Upper bound on the number of PBKDF2 iterations accepted when decrypting,
+/// to avoid spending an unbounded amount of time on a crafted entry.
+const MAX_ITERATION_COUNT: u32 = 1_000_000;
+
+const SALT_LEN: usize = 12;
+const CEK_LEN: usize = 32;
+const KEK_LEN: usize = 16;
+const IV_LEN: usize = 12;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Header {
+    alg: String,
+    enc: String,
+    p2c: u32,
+    p2s: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    created: Option<String>,
+}
+
+fn derive_kek(passphrase: &str, salt_input: &[u8], iterations: u32) -> Zeroizing<[u8; KEK_LEN]> {
+    // As per RFC 7518, the salt is the algorithm name followed by a NUL byte and the salt input
+    let salt = [ALG.as_bytes(), &[0], salt_input].concat();
+
+    let mut kek = Zeroizing::new([0; KEK_LEN]);
+    pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), &salt, iterations, kek.as_mut());
+    kek
+}
+
+fn decode_part(part: &str) -> Result<Vec<u8>, Error> {
+    URL_SAFE_NO_PAD
+        .decode(part)
+        .map_err(|e| Error::keyring_item_decode(e.to_string()))
+}
+
+/// Encrypt the given payload with the given passphrase, and return
+/// the resulting JWE in compact serialization.
+pub fn encrypt(passphrase: &str, payload: &[u8]) -> Result<String, Error> {
+    let mut salt_input = [0; SALT_LEN];
+    OsRng.fill_bytes(&mut salt_input);
+
+    let mut cek = Zeroizing::new([0; CEK_LEN]);
+    OsRng.fill_bytes(cek.as_mut());
+
+    let mut iv = [0; IV_LEN];
+    OsRng.fill_bytes(&mut iv);
+
+    let kek = derive_kek(passphrase, &salt_input, ITERATION_COUNT);
+
+    let mut encrypted_key = [0; CEK_LEN + 8];
+    KekAes128::from(*kek)
+        .wrap(cek.as_ref(), &mut encrypted_key)
+        .map_err(|e| Error::keyring_encrypt(e.to_string()))?;
+
+    let header = Header {
+        alg: ALG.to_string(),
+        enc: ENC.to_string(),
+        p2c: ITERATION_COUNT,
+        p2s: URL_SAFE_NO_PAD.encode(salt_input),
+        created: Some(humantime::format_rfc3339(std::time::SystemTime::now()).to_string()),
+    };
+
+    let header = serde_json::to_vec(&header).map_err(Error::encode)?;
+    let header = URL_SAFE_NO_PAD.encode(header);
+
+    let mut ciphertext = Aes256Gcm::new((&*cek).into())
+        .encrypt(
+            Nonce::from_slice(&iv),
+            Payload {
+                msg: payload,
+                aad: header.as_bytes(),
+            },
+        )
+        .map_err(|e| Error::keyring_encrypt(e.to_string()))?;
+
+    let tag = ciphertext.split_off(ciphertext.len() - 16);
+
+    Ok([
+        header,
+        URL_SAFE_NO_PAD.encode(encrypted_key),
+        URL_SAFE_NO_PAD.encode(iv),
+        URL_SAFE_NO_PAD.encode(ciphertext),
+        URL_SAFE_NO_PAD.encode(tag),
+    ]
+    .join("."))
+}
+
+/// Decrypt the given JWE in compact serialization with the given passphrase.
+///
+/// Fails if the passphrase is wrong or if the JWE was tampered with.
+pub fn decrypt(passphrase: &str, token: &str) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let parts: Vec<&str> = token.trim().split('.').collect();
+
+    let [header_part, encrypted_key, iv, ciphertext, tag] = parts[..] else {
+        return Err(Error::keyring_item_decode(format!(
+            "expected 5 parts in compact serialization, found {}",
+            parts.len()
+        )));
+    };
+
+    let header: Header = serde_json::from_slice(&decode_part(header_part)?)
+        .map_err(|e| Error::keyring_item_decode(e.to_string()))?;
+
+    if header.alg != ALG || header.enc != ENC {
+        return Err(Error::keyring_item_decode(format!(
+            "unsupported algorithm {} with encryption {}",
+            header.alg, header.enc
+        )));
+    }
+
+    if header.p2c == 0 || header.p2c > MAX_ITERATION_COUNT {
+        return Err(Error::keyring_item_decode(format!(
+            "invalid iteration count {}",
+            header.p2c
+        )));
+    }
+
+    let kek = derive_kek(passphrase, &decode_part(&header.p2s)?, header.p2c);
+
+    let encrypted_key = decode_part(encrypted_key)?;
+    if encrypted_key.len() != CEK_LEN + 8 {
+        return Err(Error::keyring_item_decode(format!(
+            "invalid encrypted key length {}",
+            encrypted_key.len()
+        )));
+    }
+
+    let mut cek = Zeroizing::new([0; CEK_LEN]);
+    KekAes128::from(*kek)
+        .unwrap(&encrypted_key, cek.as_mut())
+        .map_err(|_| Error::keyring_decrypt())?;
+
+    let iv = decode_part(iv)?;
+    if iv.len() != IV_LEN {
+        return Err(Error::keyring_item_decode(format!(
+            "invalid initialization vector length {}",
+            iv.len()
+        )));
+    }
+
+    let mut ciphertext = decode_part(ciphertext)?;
+    ciphertext.extend(decode_part(tag)?);
+
+    let payload = Aes256Gcm::new((&*cek).into())
+        .decrypt(
+            Nonce::from_slice(&iv),
+            Payload {
+                msg: &ciphertext,
+                aad: header_part.as_bytes(),
+            },
+        )
+        .map_err(|_| Error::keyring_decrypt())?;
+
+    Ok(Zeroizing::new(payload))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let token = encrypt("passphrase", b"payload").unwrap();
+
+        assert_eq!(token.split('.').count(), 5);
+        assert_eq!(
+            decrypt("passphrase", &token).unwrap().as_slice(),
+            b"payload"
+        );
+    }
+
+    #[test]
+    fn wrong_passphrase() {
+        let token = encrypt("passphrase", b"payload").unwrap();
+
+        assert!(decrypt("wrong passphrase", &token).is_err());
+    }
+
+    #[test]
+    fn tampered_header() {
+        let token = encrypt("passphrase", b"payload").unwrap();
+
+        let (header, rest) = token.split_once('.').unwrap();
+        let mut header: Header = serde_json::from_slice(&decode_part(header).unwrap()).unwrap();
+        header.created = Some("tampered".to_string());
+        let header = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header).unwrap());
+
+        assert!(decrypt("passphrase", &format!("{header}.{rest}")).is_err());
+    }
+}
@@ -1,4 +1,6 @@
 use bech32::{FromBase32, ToBase32};
+use ibc_proto::cosmos::crypto::keyring::v1::{record, Record};
+use ibc_proto::google::protobuf::Any;
 use tiny_keccak::{Hasher, Keccak};
 
 use super::errors::Error;
@@ -20,3 +22,30 @@ pub fn keccak256_hash(bytes: &[u8]) -> [u8; 32] {
     hasher.finalize(&mut output);
     output
 }
+
+/// Build a Cosmos SDK keyring record holding a local private key.
+pub fn local_keyring_record(name: &str, public_key: Any, private_key: Any) -> Record {
+    Record {
+        name: name.to_string(),
+        pub_key: Some(public_key),
+        item: Some(record::Item::Local(record::Local {
+            priv_key: Some(private_key),
+        })),
+    }
+}
+
+/// Get the private key of a Cosmos SDK keyring record, which must hold a local key.
+pub fn keyring_record_private_key(record: &Record) -> Result<&Any, Error> {
+    match &record.item {
+        Some(record::Item::Local(record::Local {
+            priv_key: Some(private_key),
+        })) => Ok(private_key),
+        Some(record::Item::Local(_)) => Err(Error::unsupported_keyring_record(
+            "local record is missing its private key".to_string(),
+        )),
+        _ => Err(Error::unsupported_keyring_record(format!(
+            "key '{}' is not stored locally (ledger, multisig and offline keys are not supported)",
+            record.name
+        ))),
+    }
+}
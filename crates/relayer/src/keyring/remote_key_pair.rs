@@ -0,0 +1,146 @@
+use core::any::Any;
+
+use hdpath::StandardHDPath;
+use ibc_proto::cosmos::crypto::keyring::v1::{record, Record};
+use ibc_proto::cosmos::crypto::secp256k1::PubKey;
+use ibc_proto::google::protobuf::Any as ProtoAny;
+use prost::Message;
+use secp256k1::{ecdsa::Signature, PublicKey, Secp256k1};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    errors::Error,
+    remote_signer::RemoteSigner,
+    secp256k1_key_pair::{encode_address, get_address, Secp256k1AddressType},
+    KeyFile, KeyType, SigningKeyPair,
+};
+use crate::config::AddressType;
+
+/// A secp256k1 key pair whose private key is held by a remote signer,
+/// which is asked to sign on behalf of Hermes.
+///
+/// Every signature returned by the remote signer is checked against
+/// the public key before being used.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RemoteSigningKeyPair {
+    signer: RemoteSigner,
+    key_name: String,
+    pub public_key: PublicKey,
+    address: [u8; 20],
+    address_type: Secp256k1AddressType,
+    account: String,
+}
+
+impl RemoteSigningKeyPair {
+    pub fn new(
+        signer: RemoteSigner,
+        key_name: &str,
+        public_key_type_url: &str,
+        public_key: &[u8],
+        account_prefix: &str,
+    ) -> Result<Self, Error> {
+        let address_type = Secp256k1AddressType::from_public_key_type_url(public_key_type_url)
+            .ok_or_else(|| {
+                Error::only_secp256k1_public_key_supported(public_key_type_url.to_string())
+            })?;
+
+        let public_key = PublicKey::from_slice(public_key)
+            .map_err(|e| Error::invalid_public_key(signature::Error::from_source(e)))?;
+
+        let address = get_address(&public_key, address_type);
+        let account = encode_address(account_prefix, &address)?;
+
+        Ok(Self {
+            signer,
+            key_name: key_name.to_string(),
+            public_key,
+            address,
+            address_type,
+            account,
+        })
+    }
+}
+
+impl SigningKeyPair for RemoteSigningKeyPair {
+    const KEY_TYPE: KeyType = KeyType::Secp256k1;
+    type KeyFile = KeyFile;
+
+    fn from_key_file(_key_file: KeyFile, _hd_path: &StandardHDPath) -> Result<Self, Error> {
+        Err(Error::unsupported_by_remote_signer(
+            "importing keys".to_string(),
+        ))
+    }
+
+    fn from_mnemonic(
+        _mnemonic: &str,
+        _hd_path: &StandardHDPath,
+        _address_type: &AddressType,
+        _account_prefix: &str,
+    ) -> Result<Self, Error> {
+        Err(Error::unsupported_by_remote_signer(
+            "importing keys".to_string(),
+        ))
+    }
+
+    fn from_keyring_record(_record: &Record, _account_prefix: &str) -> Result<Self, Error> {
+        Err(Error::unsupported_by_remote_signer(
+            "importing keys".to_string(),
+        ))
+    }
+
+    fn from_remote_key(key: RemoteSigningKeyPair) -> Result<Self, Error> {
+        Ok(key)
+    }
+
+    // The private key is not available, so the key is recorded
+    // as an offline key, as done by the Cosmos SDK.
+    fn to_keyring_record(&self, name: &str) -> Record {
+        let (pub_key_type_url, _) = self.address_type.keyring_type_urls();
+
+        Record {
+            name: name.to_string(),
+            pub_key: Some(ProtoAny {
+                type_url: pub_key_type_url.to_string(),
+                value: PubKey {
+                    key: self.public_key.serialize().to_vec(),
+                }
+                .encode_to_vec(),
+            }),
+            item: Some(record::Item::Offline(record::Offline {})),
+        }
+    }
+
+    fn keyring_address(&self) -> Vec<u8> {
+        self.address.to_vec()
+    }
+
+    fn account(&self) -> String {
+        self.account.to_owned()
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+        let signature = self.signer.sign(&self.key_name, message)?;
+
+        let parsed = Signature::from_compact(&signature)
+            .map_err(|e| Error::invalid_remote_signature(e.to_string()))?;
+
+        Secp256k1::verification_only()
+            .verify_ecdsa(
+                &self.address_type.hash_message(message),
+                &parsed,
+                &self.public_key,
+            )
+            .map_err(|_| {
+                Error::invalid_remote_signature(format!(
+                    "signature does not match the public key of key '{}'",
+                    self.key_name
+                ))
+            })?;
+
+        Ok(signature)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,422 @@
+//! Key store delegating signing to a remote signer, so that the private keys
+//! never enter the Hermes process.
+//!
+//! Hermes talks to the remote signer over HTTP, exchanging JSON documents,
+//! where all binary data is encoded with padded standard base64.
+//! Keys are identified by their name, as set by `key_name` in the chain configuration.
+//!
+//! - `GET /v1/keys` lists the names of the keys held by the signer:
+//!   `{ "keys": ["relayer"] }`
+//!
+//! - `GET /v1/keys/{name}` returns the public key of the given key, along with its
+//!   Protobuf type URL, which determines how addresses are derived and how messages
+//!   are hashed before being signed, as in the Cosmos SDK:
+//!   `{ "pub_key": { "type_url": "/cosmos.crypto.secp256k1.PubKey", "key": "A0Ad..." } }`
+//!
+//! - `POST /v1/keys/{name}/sign` with body `{ "sign_bytes": "CpMB..." }` signs the given bytes,
+//!   i.e. the serialized `SignDoc` of a transaction, and returns the 64 bytes compact
+//!   serialization of the secp256k1 ECDSA signature, with a low S value:
+//!   `{ "signature": "2Jb7..." }`
+//!
+//! Any response with a non-2xx status code is treated as a failure,
+//! and its body is reported as the reason for the failure.
+
+use core::time::Duration;
+use std::collections::HashMap;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tendermint_rpc::Url;
+
+use super::{errors::Error, KeyStore, RemoteSigningKeyPair, SigningKeyPairSized};
+use crate::config::default;
+use crate::util::lock::{LockExt, RwArc};
+
+/// Configuration of the remote signer used by the `Remote` key store.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RemoteSignerConfig {
+    /// Base URL of the remote signer
+    pub url: Url,
+
+    /// Timeout of the requests to the remote signer
+    #[serde(default = "default::remote_signer_timeout", with = "humantime_serde")]
+    pub timeout: Duration,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ListKeysResponse {
+    pub keys: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RemotePubKey {
+    pub type_url: String,
+    pub key: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GetKeyResponse {
+    pub pub_key: RemotePubKey,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SignRequest {
+    pub sign_bytes: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SignResponse {
+    pub signature: String,
+}
+
+/// Client for the remote signer protocol described in the [module documentation](self).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteSigner {
+    url: String,
+    #[serde(with = "humantime_serde")]
+    timeout: Duration,
+}
+
+impl RemoteSigner {
+    pub fn new(config: &RemoteSignerConfig) -> Self {
+        Self {
+            url: config.url.to_string().trim_end_matches('/').to_string(),
+            timeout: config.timeout,
+        }
+    }
+
+    fn key_url(&self, key_name: &str) -> String {
+        format!("{}/v1/keys/{}", self.url, percent_encode(key_name))
+    }
+
+    fn error(&self, reason: impl ToString) -> Error {
+        Error::remote_signer(self.url.clone(), reason.to_string())
+    }
+
+    fn agent(&self) -> ureq::Agent {
+        ureq::AgentBuilder::new().timeout(self.timeout).build()
+    }
+
+    fn response<T: DeserializeOwned>(
+        &self,
+        response: Result<ureq::Response, ureq::Error>,
+    ) -> Result<T, Error> {
+        match response {
+            Ok(response) => response.into_json().map_err(|e| self.error(e)),
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(self.error(format!("status {status}: {}", body.trim())))
+            }
+            Err(e) => Err(self.error(e)),
+        }
+    }
+
+    /// List the names of the keys held by the remote signer.
+    pub fn list_keys(&self) -> Result<Vec<String>, Error> {
+        let url = format!("{}/v1/keys", self.url);
+        let response: ListKeysResponse = self.response(self.agent().get(&url).call())?;
+
+        Ok(response.keys)
+    }
+
+    /// Get the type URL and bytes of the public key with the given name.
+    pub fn public_key(&self, key_name: &str) -> Result<(String, Vec<u8>), Error> {
+        let response: GetKeyResponse =
+            self.response(self.agent().get(&self.key_url(key_name)).call())?;
+
+        let key = STANDARD
+            .decode(response.pub_key.key)
+            .map_err(|e| self.error(format!("invalid public key encoding: {e}")))?;
+
+        Ok((response.pub_key.type_url, key))
+    }
+
+    /// Sign the given bytes with the key with the given name.
+    pub fn sign(&self, key_name: &str, sign_bytes: &[u8]) -> Result<Vec<u8>, Error> {
+        let url = format!("{}/sign", self.key_url(key_name));
+
+        let request = SignRequest {
+            sign_bytes: STANDARD.encode(sign_bytes),
+        };
+
+        let response: SignResponse = self.response(self.agent().post(&url).send_json(request))?;
+
+        STANDARD
+            .decode(response.signature)
+            .map_err(|e| Error::invalid_remote_signature(format!("invalid encoding: {e}")))
+    }
+}
+
+/// Percent-encode all characters of a path segment but the unreserved ones.
+fn percent_encode(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// Key store whose keys are held by a remote signer.
+///
+/// Keys can only be added to or removed from the remote signer itself.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Remote {
+    pub(super) account_prefix: String,
+    signer: RemoteSigner,
+    #[serde(skip)]
+    keys: RwArc<HashMap<String, RemoteSigningKeyPair>>,
+}
+
+impl Remote {
+    pub fn new(account_prefix: String, config: &RemoteSignerConfig) -> Self {
+        Self {
+            account_prefix,
+            signer: RemoteSigner::new(config),
+            keys: Default::default(),
+        }
+    }
+
+    /// Get the key with the given name, only querying the remote
+    /// signer the first time the key is requested.
+    fn remote_key(&self, key_name: &str) -> Result<RemoteSigningKeyPair, Error> {
+        if let Some(key) = self.keys.acquire_read().get(key_name) {
+            return Ok(key.clone());
+        }
+
+        let (type_url, public_key) = self.signer.public_key(key_name)?;
+
+        let key = RemoteSigningKeyPair::new(
+            self.signer.clone(),
+            key_name,
+            &type_url,
+            &public_key,
+            &self.account_prefix,
+        )?;
+
+        self.keys
+            .acquire_write()
+            .insert(key_name.to_string(), key.clone());
+
+        Ok(key)
+    }
+}
+
+impl<S: SigningKeyPairSized> KeyStore<S> for Remote {
+    fn get_key(&self, key_name: &str) -> Result<S, Error> {
+        S::from_remote_key(self.remote_key(key_name)?)
+    }
+
+    fn add_key(&mut self, _key_name: &str, _key_entry: S) -> Result<(), Error> {
+        Err(Error::unsupported_by_remote_signer(
+            "adding keys".to_string(),
+        ))
+    }
+
+    fn remove_key(&mut self, _key_name: &str) -> Result<(), Error> {
+        Err(Error::unsupported_by_remote_signer(
+            "deleting keys".to_string(),
+        ))
+    }
+
+    fn keys(&self) -> Result<Vec<(String, S)>, Error> {
+        self.signer
+            .list_keys()?
+            .into_iter()
+            .map(|name| self.get_key(&name).map(|key| (name, key)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+pub mod test_utils {
+    //! A stand-in for a remote signer, holding local keys.
+
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::thread;
+
+    use ibc_proto::cosmos::crypto::secp256k1::PubKey;
+    use prost::Message;
+
+    use super::*;
+    use crate::keyring::{Secp256k1KeyPair, SigningKeyPair};
+
+    /// Serve the remote signer protocol for the given keys, on a random local port,
+    /// and return the configuration to use to connect to it.
+    pub fn spawn_signer(keys: Vec<(String, Secp256k1KeyPair)>) -> RemoteSignerConfig {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                handle(stream, &keys);
+            }
+        });
+
+        RemoteSignerConfig {
+            url: format!("http://{addr}").parse().unwrap(),
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    fn handle(mut stream: TcpStream, keys: &[(String, Secp256k1KeyPair)]) {
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header).unwrap();
+
+            if header.trim().is_empty() {
+                break;
+            }
+
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next().unwrap_or_default();
+        let path = parts.next().unwrap_or_default();
+        let segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+
+        let find_key = |name: &str| keys.iter().find(|(n, _)| n == name).map(|(_, k)| k);
+
+        let (status, body) = match (method, segments.as_slice()) {
+            ("GET", ["v1", "keys"]) => {
+                let keys = keys.iter().map(|(name, _)| name.clone()).collect();
+                (
+                    200,
+                    serde_json::to_string(&ListKeysResponse { keys }).unwrap(),
+                )
+            }
+            ("GET", ["v1", "keys", name]) => match find_key(name) {
+                Some(key) => {
+                    let pub_key = key.to_keyring_record(name).pub_key.unwrap();
+                    let key_bytes = PubKey::decode(pub_key.value.as_slice()).unwrap().key;
+                    let response = GetKeyResponse {
+                        pub_key: RemotePubKey {
+                            type_url: pub_key.type_url,
+                            key: STANDARD.encode(key_bytes),
+                        },
+                    };
+                    (200, serde_json::to_string(&response).unwrap())
+                }
+                None => (404, "key not found".to_string()),
+            },
+            ("POST", ["v1", "keys", name, "sign"]) => match find_key(name) {
+                Some(key) => {
+                    let request: SignRequest = serde_json::from_slice(&body).unwrap();
+                    let sign_bytes = STANDARD.decode(request.sign_bytes).unwrap();
+                    let response = SignResponse {
+                        signature: STANDARD.encode(key.sign(&sign_bytes).unwrap()),
+                    };
+                    (200, serde_json::to_string(&response).unwrap())
+                }
+                None => (404, "key not found".to_string()),
+            },
+            _ => (400, "bad request".to_string()),
+        };
+
+        let _ = write!(
+            stream,
+            "HTTP/1.1 {status} Status\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_utils::spawn_signer;
+    use super::*;
+
+    use hdpath::StandardHDPath;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    use crate::config::AddressType;
+    use crate::keyring::{AnySecp256k1KeyPair, KeyRing, Secp256k1KeyPair, SigningKeyPair, Store};
+
+    fn test_key() -> Secp256k1KeyPair {
+        let hd_path = StandardHDPath::try_from("m/44'/118'/0'/0/0").unwrap();
+        Secp256k1KeyPair::from_mnemonic(
+            "such walnut usual noble image raise cabin suspect combine key absurd detail present bless yard grief amazing slam brown donate fabric opera desk minor",
+            &hd_path,
+            &AddressType::Cosmos,
+            "cosmos",
+        )
+        .unwrap()
+    }
+
+    fn remote_keyring(config: RemoteSignerConfig) -> KeyRing<AnySecp256k1KeyPair> {
+        KeyRing::new(
+            Store::Remote,
+            "cosmos",
+            &ChainId::from_string("chain-A"),
+            &None,
+            &Default::default(),
+            &Some(config),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn sign_with_remote_key() {
+        let local = test_key();
+        let config = spawn_signer(vec![("relayer".to_string(), local.clone())]);
+        let keyring = remote_keyring(config);
+
+        let remote = keyring.get_key("relayer").unwrap();
+        assert!(matches!(remote, AnySecp256k1KeyPair::Remote(_)));
+        assert_eq!(remote.account(), local.account());
+
+        assert_eq!(
+            remote.sign(b"message").unwrap(),
+            local.sign(b"message").unwrap()
+        );
+
+        let keys = keyring.keys().unwrap();
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].0, "relayer");
+        assert_eq!(keys[0].1.account(), local.account());
+    }
+
+    #[test]
+    fn unknown_remote_key() {
+        let config = spawn_signer(vec![("relayer".to_string(), test_key())]);
+        let keyring = remote_keyring(config);
+
+        assert!(keyring.get_key("other").is_err());
+    }
+
+    #[test]
+    fn keys_cannot_be_added() {
+        let config = spawn_signer(vec![]);
+        let mut keyring = remote_keyring(config);
+
+        assert!(keyring.add_key("relayer", test_key().into()).is_err());
+        assert!(keyring.remove_key("relayer").is_err());
+    }
+
+    #[test]
+    fn key_names_are_percent_encoded() {
+        assert_eq!(percent_encode("relayer-1_a.b~"), "relayer-1_a.b~");
+        assert_eq!(percent_encode("a/b c"), "a%2Fb%20c");
+    }
+}
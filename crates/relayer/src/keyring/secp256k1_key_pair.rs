@@ -8,6 +8,10 @@ use bitcoin::{
 use digest::Digest;
 use generic_array::{typenum::U32, GenericArray};
 use hdpath::StandardHDPath;
+use ibc_proto::cosmos::crypto::keyring::v1::Record;
+use ibc_proto::cosmos::crypto::secp256k1::{PrivKey, PubKey};
+use ibc_proto::google::protobuf::Any as ProtoAny;
+use prost::Message as _;
 use ripemd::Ripemd160;
 use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
 use serde::{Deserialize, Serialize};
@@ -16,7 +20,10 @@ use strum::{EnumIter, IntoEnumIterator};
 
 use super::{
     errors::Error,
-    key_utils::{decode_bech32, encode_bech32, keccak256_hash},
+    key_utils::{
+        decode_bech32, encode_bech32, keccak256_hash, keyring_record_private_key,
+        local_keyring_record,
+    },
     pub_key::EncodedPubKey,
     KeyFile, KeyType, SigningKeyPair,
 };
@@ -67,6 +74,55 @@ pub enum Secp256k1AddressType {
 }
 
 impl Secp256k1AddressType {
+    const COSMOS_PUB_KEY_TYPE_URL: &'static str = "/cosmos.crypto.secp256k1.PubKey";
+    const COSMOS_PRIV_KEY_TYPE_URL: &'static str = "/cosmos.crypto.secp256k1.PrivKey";
+    const ETHERMINT_PUB_KEY_TYPE_URL: &'static str = "/ethermint.crypto.v1.ethsecp256k1.PubKey";
+    const ETHERMINT_PRIV_KEY_TYPE_URL: &'static str = "/ethermint.crypto.v1.ethsecp256k1.PrivKey";
+
+    /// The type URLs of the public and private keys for this address type,
+    /// as used in the Cosmos SDK keyring.
+    pub(super) fn keyring_type_urls(&self) -> (&'static str, &'static str) {
+        match self {
+            Self::Cosmos => (
+                Self::COSMOS_PUB_KEY_TYPE_URL,
+                Self::COSMOS_PRIV_KEY_TYPE_URL,
+            ),
+            Self::Ethermint => (
+                Self::ETHERMINT_PUB_KEY_TYPE_URL,
+                Self::ETHERMINT_PRIV_KEY_TYPE_URL,
+            ),
+        }
+    }
+
+    fn from_private_key_type_url(type_url: &str) -> Option<Self> {
+        match type_url {
+            Self::COSMOS_PRIV_KEY_TYPE_URL => Some(Self::Cosmos),
+            Self::ETHERMINT_PRIV_KEY_TYPE_URL => Some(Self::Ethermint),
+            _ => None,
+        }
+    }
+
+    pub(super) fn from_public_key_type_url(type_url: &str) -> Option<Self> {
+        match type_url {
+            Self::COSMOS_PUB_KEY_TYPE_URL => Some(Self::Cosmos),
+            Self::ETHERMINT_PUB_KEY_TYPE_URL => Some(Self::Ethermint),
+            _ => None,
+        }
+    }
+
+    /// Hash a message before signing it, as done by the Cosmos SDK for this type of key.
+    pub(super) fn hash_message(&self, message: &[u8]) -> Message {
+        let hashed_message: GenericArray<u8, U32> = match self {
+            Self::Ethermint => keccak256_hash(message).into(),
+            Self::Cosmos => Sha256::digest(message),
+        };
+
+        assert!(hashed_message.len() == 32);
+
+        // SAFETY: hashed_message is 32 bytes, as expected in `Message::from_slice`.
+        Message::from_digest_slice(&hashed_message).unwrap()
+    }
+
     /// Derive the address type based on how the address was generated from the
     /// public key.
     fn derive(public_key: &PublicKey, address: &[u8]) -> Result<Self, Error> {
@@ -120,7 +176,7 @@ pub fn get_address(public_key: &PublicKey, address_type: Secp256k1AddressType) -
 // Ethermint address: bech32("evmos", keccak256(public_key)[12:])
 // - They also have ETH-compatible addresses using base64 instead of bech32.
 //   Hex addresses contain a 0x prefix.
-fn encode_address(account_prefix: &str, address: &[u8]) -> Result<String, Error> {
+pub(super) fn encode_address(account_prefix: &str, address: &[u8]) -> Result<String, Error> {
     encode_bech32(account_prefix, address)
 }
 
@@ -209,6 +265,24 @@ impl TryFrom<VersionedKeyPair> for Secp256k1KeyPair {
 }
 
 impl Secp256k1KeyPair {
+    fn from_private_key(
+        private_key: SecretKey,
+        address_type: Secp256k1AddressType,
+        account_prefix: &str,
+    ) -> Result<Self, Error> {
+        let public_key = private_key.public_key(&Secp256k1::signing_only());
+        let address = get_address(&public_key, address_type);
+        let account = encode_address(account_prefix, &address)?;
+
+        Ok(Self {
+            private_key,
+            public_key,
+            address,
+            address_type,
+            account,
+        })
+    }
+
     fn from_mnemonic_internal(
         mnemonic: &str,
         hd_path: &StandardHDPath,
@@ -285,6 +359,53 @@ impl SigningKeyPair for Secp256k1KeyPair {
         Self::from_mnemonic_internal(mnemonic, hd_path, address_type.try_into()?, account_prefix)
     }
 
+    fn from_keyring_record(record: &Record, account_prefix: &str) -> Result<Self, Error> {
+        let private_key = keyring_record_private_key(record)?;
+
+        let address_type = Secp256k1AddressType::from_private_key_type_url(&private_key.type_url)
+            .ok_or_else(|| {
+            Error::unsupported_keyring_record(format!(
+                "unsupported private key type '{}'",
+                private_key.type_url
+            ))
+        })?;
+
+        let key = PrivKey::decode(private_key.value.as_slice())
+            .map_err(Error::keyring_record_decode)?
+            .key;
+
+        let private_key =
+            SecretKey::from_slice(&key).map_err(|e| Error::invalid_private_key(e.to_string()))?;
+
+        Self::from_private_key(private_key, address_type, account_prefix)
+    }
+
+    fn to_keyring_record(&self, name: &str) -> Record {
+        let (pub_key_type_url, priv_key_type_url) = self.address_type.keyring_type_urls();
+
+        let public_key = ProtoAny {
+            type_url: pub_key_type_url.to_string(),
+            value: PubKey {
+                key: self.public_key.serialize().to_vec(),
+            }
+            .encode_to_vec(),
+        };
+
+        let private_key = ProtoAny {
+            type_url: priv_key_type_url.to_string(),
+            value: PrivKey {
+                key: self.private_key.secret_bytes().to_vec(),
+            }
+            .encode_to_vec(),
+        };
+
+        local_keyring_record(name, public_key, private_key)
+    }
+
+    fn keyring_address(&self) -> Vec<u8> {
+        self.address.to_vec()
+    }
+
     fn account(&self) -> String {
         self.account.to_owned()
     }
@@ -297,15 +418,7 @@ impl SigningKeyPair for Secp256k1KeyPair {
     // - https://github.com/evmos/ethermint/blob/main/crypto/ethsecp256k1/ethsecp256k1.go
     // - informalsystems/hermes#2863.
     fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
-        let hashed_message: GenericArray<u8, U32> = match self.address_type {
-            Secp256k1AddressType::Ethermint => keccak256_hash(message).into(),
-            Secp256k1AddressType::Cosmos => Sha256::digest(message),
-        };
-
-        assert!(hashed_message.len() == 32);
-
-        // SAFETY: hashed_message is 32 bytes, as expected in `Message::from_slice`.
-        let message = Message::from_digest_slice(&hashed_message).unwrap();
+        let message = self.address_type.hash_message(message);
 
         Ok(Secp256k1::signing_only()
             .sign_ecdsa(&message, &self.private_key)
@@ -1,9 +1,10 @@
 use core::any::Any;
 
 use hdpath::StandardHDPath;
+use ibc_proto::cosmos::crypto::keyring::v1::Record;
 use serde::{de::DeserializeOwned, Serialize};
 
-use super::{errors::Error, KeyType};
+use super::{errors::Error, KeyType, RemoteSigningKeyPair};
 use crate::config::AddressType;
 
 pub trait SigningKeyPair {
@@ -31,6 +32,25 @@ pub trait SigningKeyPair {
     where
         Self: Sized;
 
+    /// Decode a key pair from a record of the Cosmos SDK keyring.
+    fn from_keyring_record(record: &Record, account_prefix: &str) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Build a key pair whose private key is held by a remote signer.
+    fn from_remote_key(_key: RemoteSigningKeyPair) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        Err(Error::unsupported_remote_key(Self::KEY_TYPE))
+    }
+
+    /// Encode the key pair as a record of the Cosmos SDK keyring, with the given name.
+    fn to_keyring_record(&self, name: &str) -> Record;
+
+    /// The address of the key, as derived by the Cosmos SDK from its public key.
+    fn keyring_address(&self) -> Vec<u8>;
+
     fn account(&self) -> String;
     fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error>;
 
@@ -35,8 +35,10 @@ pub mod keyring;
 pub mod light_client;
 pub mod link;
 pub mod misbehaviour;
+pub mod multihop;
 pub mod object;
 pub mod path;
+pub mod recover_client;
 pub mod registry;
 pub mod rest;
 pub mod sdk_error;
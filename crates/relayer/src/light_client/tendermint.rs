@@ -1,3 +1,4 @@
+mod cache;
 mod detector;
 
 use std::time::Duration;
@@ -9,7 +10,7 @@ use tracing::{debug, error, trace, warn};
 use tendermint_light_client::{
     components::{
         self,
-        io::{AtHeight, Io, ProdIo},
+        io::{AtHeight, Io, IoError, ProdIo},
     },
     light_client::LightClient as TmLightClient,
     state::State as LightClientState,
@@ -22,6 +23,7 @@ use tendermint_rpc as rpc;
 
 use ibc_relayer_types::clients::ics07_tendermint::header::Header as TmHeader;
 use ibc_relayer_types::clients::ics07_tendermint::misbehaviour::Misbehaviour as TmMisbehaviour;
+use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics02_client::header::AnyHeader;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
@@ -30,21 +32,28 @@ use ibc_relayer_types::Height as ICSHeight;
 use crate::{
     chain::cosmos::{config::CosmosSdkConfig, CosmosSdkChain},
     client_state::AnyClientState,
+    config::GlobalConfig,
     error::Error,
     misbehaviour::{AnyMisbehaviour, MisbehaviourEvidence},
     HERMES_VERSION,
 };
 
+use self::cache::LightBlockCache;
 use super::{
     io::{AnyIo, RestartAwareIo},
     Verified,
 };
 
+/// The folder of the `data_dir` holding the light block caches of the chains,
+/// unless a chain specifies its own folder.
+pub const LIGHT_BLOCK_CACHE_FOLDER: &str = "light_blocks";
+
 pub struct LightClient {
     chain_id: ChainId,
     peer_id: PeerId,
     io: AnyIo,
     enable_verification: bool,
+    cache: Option<LightBlockCache>,
 }
 
 impl super::LightClient<CosmosSdkChain> for LightClient {
@@ -123,6 +132,8 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
             .filter(|lb| lb.height() != target.height())
             .collect_vec();
 
+        self.cache_verified_blocks(&state, client_state, now);
+
         Ok(Verified { target, supporting })
     }
 
@@ -155,10 +166,24 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
 
         let update_header: &TmHeader = match any_header {
             AnyHeader::Tendermint(header) => Ok(header),
+            AnyHeader::Wasm(header) => Ok(&header.inner),
+            AnyHeader::SoloMachine(_) => Err(Error::client_type_mismatch(
+                ClientType::Tendermint,
+                ClientType::SoloMachine,
+            )),
         }?;
 
         let client_state = match client_state {
             AnyClientState::Tendermint(client_state) => Ok(client_state),
+            AnyClientState::Wasm(client_state) => Ok(&client_state.inner),
+            AnyClientState::SoloMachine(_) => Err(Error::client_type_mismatch(
+                ClientType::Tendermint,
+                ClientType::SoloMachine,
+            )),
+            AnyClientState::Localhost(_) => Err(Error::client_type_mismatch(
+                ClientType::Tendermint,
+                ClientType::Localhost,
+            )),
         }?;
 
         let next_validators = self
@@ -303,12 +328,19 @@ impl LightClient {
         // updates after significant change in validator sets.
         let enable_verification = !config.trusted_node;
 
+        let cache = if config.light_block_cache && enable_verification {
+            open_light_block_cache(config)
+        } else {
+            None
+        };
+
         Ok(Self {
             chain_id: config.id.clone(),
             peer_id,
             io,
 
             enable_verification,
+            cache,
         })
     }
 
@@ -319,10 +351,28 @@ impl LightClient {
     ) -> Result<TmLightClient, Error> {
         let clock = components::clock::FixedClock::new(now);
         let verifier = ProdVerifier::default();
-        let scheduler = components::scheduler::basic_bisecting_schedule;
+
+        let cached_heights = self
+            .cache
+            .as_ref()
+            .map(|cache| cache.heights().collect_vec())
+            .unwrap_or_default();
+
+        let scheduler = move |light_store: &dyn LightStore, current_height, target_height| {
+            cache_aware_schedule(&cached_heights, light_store, current_height, target_height)
+        };
 
         let client_state = match client_state {
             AnyClientState::Tendermint(client_state) => Ok(client_state),
+            AnyClientState::Wasm(client_state) => Ok(&client_state.inner),
+            AnyClientState::SoloMachine(_) => Err(Error::client_type_mismatch(
+                ClientType::Tendermint,
+                ClientType::SoloMachine,
+            )),
+            AnyClientState::Localhost(_) => Err(Error::client_type_mismatch(
+                ClientType::Tendermint,
+                ClientType::Localhost,
+            )),
         }?;
 
         Ok(TmLightClient::new(
@@ -331,7 +381,10 @@ impl LightClient {
             clock,
             scheduler,
             verifier,
-            self.io.clone(),
+            CachedIo {
+                io: self.io.clone(),
+                cache: self.cache.clone(),
+            },
         ))
     }
 
@@ -345,11 +398,48 @@ impl LightClient {
     }
 
     fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, Error> {
+        if let (Some(cache), AtHeight::At(height)) = (&self.cache, &height) {
+            if let Some(block) = cache.get(*height) {
+                return Ok(block);
+            }
+        }
+
         self.io
             .fetch_light_block(height)
             .map_err(|e| Error::light_client_io(self.chain_id.to_string(), e))
     }
 
+    /// Adds the blocks verified in the given state to the light block cache,
+    /// and prunes the blocks which are now outside of the trusting period of the client.
+    fn cache_verified_blocks(
+        &mut self,
+        state: &LightClientState,
+        client_state: &AnyClientState,
+        now: Time,
+    ) {
+        let Some(cache) = &mut self.cache else {
+            return;
+        };
+
+        for block in state.light_store.all(Status::Verified) {
+            if let Err(e) = cache.insert(&block) {
+                warn!(
+                    chain = %self.chain_id,
+                    "failed to add the light block at height {} to the cache: {e}",
+                    block.height()
+                );
+            }
+        }
+
+        match cache.prune(client_state.trusting_period(), now) {
+            Ok(0) => {}
+            Ok(pruned) => {
+                debug!(chain = %self.chain_id, "pruned {pruned} light blocks from the cache")
+            }
+            Err(e) => warn!(chain = %self.chain_id, "failed to prune the light block cache: {e}"),
+        }
+    }
+
     fn adjust_headers(
         &mut self,
         trusted_height: ICSHeight,
@@ -415,3 +505,83 @@ impl LightClient {
         Ok((target_header, supporting_headers))
     }
 }
+
+fn open_light_block_cache(config: &CosmosSdkConfig) -> Option<LightBlockCache> {
+    let folder = config.light_block_cache_folder.clone().or_else(|| {
+        GlobalConfig::default()
+            .data_dir()
+            .map(|data_dir| data_dir.join(LIGHT_BLOCK_CACHE_FOLDER))
+    });
+
+    let Some(folder) = folder else {
+        warn!(
+            chain = %config.id,
+            "no folder available for the light block cache, light blocks will not be cached"
+        );
+        return None;
+    };
+
+    let dir = folder.join(config.id.as_str());
+
+    LightBlockCache::open(&dir)
+        .map_err(|e| {
+            warn!(
+                chain = %config.id,
+                "failed to open the light block cache at {}, light blocks will not be cached: {e}",
+                dir.display()
+            )
+        })
+        .ok()
+}
+
+/// Serves the light blocks held in the cache, and fetches the others from the full node.
+#[derive(Clone, Debug)]
+struct CachedIo {
+    io: AnyIo,
+    cache: Option<LightBlockCache>,
+}
+
+impl Io for CachedIo {
+    fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, IoError> {
+        if let (Some(cache), AtHeight::At(height)) = (&self.cache, &height) {
+            if let Some(block) = cache.get(*height) {
+                return Ok(block);
+            }
+        }
+
+        self.io.fetch_light_block(height)
+    }
+}
+
+/// Like the basic bisecting schedule, but when bisecting, prefers the highest cached block
+/// between the latest trusted block and the block which could not be verified from it.
+/// Cached blocks were verified before, so they are likely to be verifiable again, and
+/// they do not need to be fetched from the full node.
+fn cache_aware_schedule(
+    cached_heights: &[TMHeight],
+    light_store: &dyn LightStore,
+    current_height: TMHeight,
+    target_height: TMHeight,
+) -> TMHeight {
+    let trusted_height = light_store
+        .highest_trusted_or_verified_before(target_height)
+        .map(|lb| lb.height());
+
+    let cached_pivot = trusted_height
+        .filter(|trusted_height| *trusted_height < current_height)
+        .and_then(|trusted_height| {
+            cached_heights
+                .iter()
+                .rev()
+                .find(|height| trusted_height < **height && **height < current_height)
+        });
+
+    match cached_pivot {
+        Some(height) => *height,
+        None => components::scheduler::basic_bisecting_schedule(
+            light_store,
+            current_height,
+            target_height,
+        ),
+    }
+}
@@ -0,0 +1,338 @@
+//! An on-disk cache of the light blocks verified for a chain, kept when
+//! `light_block_cache` is enabled.
+//!
+//! Without the cache, every verification starts from an empty light store and
+//! fetches again all the blocks it needs from the full node, including the ones
+//! fetched by the previous verifications. After a restart, updating a client
+//! which has not been updated for a long time therefore requires a lot of
+//! bisection traffic.
+//!
+//! The cache holds the light blocks verified so far, one JSON file per height,
+//! in a folder dedicated to the chain. The name of each file holds the height and
+//! the header time of the block, so that the cache can be opened without reading
+//! the blocks themselves. The blocks it holds are served instead of
+//! fetching them from the full node, and the verifications prefer them as
+//! bisection pivots, so that repeated and restarted client updates reuse the
+//! work done before. Cached blocks are verified again like any other block,
+//! the cache only saves fetching them.
+//!
+//! Blocks whose header is older than the trusting period of the client
+//! being updated are pruned, since they cannot be trusted anymore. The cache
+//! also holds at most [`MAX_CACHED_BLOCKS`] blocks, evicting the lowest ones first.
+
+use alloc::collections::BTreeMap;
+use core::time::Duration;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tendermint::Time;
+use tendermint_light_client::verifier::types::{Height, LightBlock};
+use tracing::warn;
+
+const EXTENSION: &str = "json";
+
+/// The maximum number of blocks held in the cache of a chain.
+pub const MAX_CACHED_BLOCKS: usize = 500;
+
+/// The light blocks verified for a chain, kept in a folder on disk.
+#[derive(Clone, Debug)]
+pub struct LightBlockCache {
+    dir: PathBuf,
+    /// The height and the header time of the cached blocks
+    index: BTreeMap<Height, Time>,
+    max_blocks: usize,
+}
+
+impl LightBlockCache {
+    /// Opens the cache held in the given folder, creating the folder if needed.
+    ///
+    /// The cache is indexed from the names of its files, those whose name is not
+    /// the one of a cached block are removed. Blocks beyond [`MAX_CACHED_BLOCKS`]
+    /// are evicted.
+    pub fn open(dir: &Path) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+
+        let mut index = BTreeMap::new();
+
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+
+            if path.extension().map_or(true, |ext| ext != EXTENSION) {
+                continue;
+            }
+
+            match parse_file_name(&path) {
+                Some((height, time)) => {
+                    index.insert(height, time);
+                }
+                None => {
+                    warn!("removing unexpected file {} from the cache", path.display());
+
+                    fs::remove_file(&path)?;
+                }
+            }
+        }
+
+        let mut cache = Self {
+            dir: dir.to_path_buf(),
+            index,
+            max_blocks: MAX_CACHED_BLOCKS,
+        };
+
+        cache.evict()?;
+
+        Ok(cache)
+    }
+
+    /// The heights of the cached blocks, in ascending order.
+    pub fn heights(&self) -> impl Iterator<Item = Height> + '_ {
+        self.index.keys().copied()
+    }
+
+    /// The block cached at the given height, if any.
+    pub fn get(&self, height: Height) -> Option<LightBlock> {
+        let time = self.index.get(&height)?;
+
+        read_light_block(&self.path(height, *time))
+            .map_err(|e| {
+                warn!("failed to read the light block at height {height} from the cache: {e}")
+            })
+            .ok()
+    }
+
+    /// Adds a verified block to the cache, evicting the lowest
+    /// cached block if the cache is full.
+    pub fn insert(&mut self, block: &LightBlock) -> io::Result<()> {
+        let height = block.height();
+        let time = block.signed_header.header.time;
+
+        if self.index.contains_key(&height) {
+            return Ok(());
+        }
+
+        let json = serde_json::to_vec(block).map_err(io::Error::other)?;
+
+        // Write to a temporary file first, so that an interrupted write
+        // never leaves a truncated block behind
+        let path = self.path(height, time);
+        let tmp_path = path.with_extension("tmp");
+
+        fs::write(&tmp_path, json)?;
+        fs::rename(&tmp_path, &path)?;
+
+        self.index.insert(height, time);
+
+        self.evict()
+    }
+
+    /// Removes the blocks whose header is older than `trusting_period` at time `now`,
+    /// and returns how many were removed.
+    pub fn prune(&mut self, trusting_period: Duration, now: Time) -> io::Result<usize> {
+        let expired = self
+            .index
+            .iter()
+            .filter(|(_, time)| {
+                now.duration_since(**time)
+                    .is_ok_and(|elapsed| elapsed > trusting_period)
+            })
+            .map(|(height, _)| *height)
+            .collect::<Vec<_>>();
+
+        for height in &expired {
+            self.remove(*height)?;
+        }
+
+        Ok(expired.len())
+    }
+
+    /// Removes the lowest blocks until the cache holds at most `max_blocks` blocks.
+    fn evict(&mut self) -> io::Result<()> {
+        while self.index.len() > self.max_blocks {
+            let Some(height) = self.index.keys().next().copied() else {
+                break;
+            };
+
+            self.remove(height)?;
+        }
+
+        Ok(())
+    }
+
+    fn remove(&mut self, height: Height) -> io::Result<()> {
+        let Some(time) = self.index.remove(&height) else {
+            return Ok(());
+        };
+
+        match fs::remove_file(self.path(height, time)) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn path(&self, height: Height, time: Time) -> PathBuf {
+        self.dir.join(file_name(height, time))
+    }
+}
+
+/// The name of the file holding the block at the given height,
+/// made of the height and the header time of the block in nanoseconds.
+fn file_name(height: Height, time: Time) -> String {
+    format!("{height}_{}.{EXTENSION}", time.unix_timestamp_nanos())
+}
+
+/// The height and the header time of the block held in the file at the given path.
+fn parse_file_name(path: &Path) -> Option<(Height, Time)> {
+    let (height, nanos) = path.file_stem()?.to_str()?.split_once('_')?;
+
+    let height = Height::try_from(height.parse::<u64>().ok()?).ok()?;
+    let nanos = nanos.parse::<i128>().ok()?;
+
+    let secs = i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?;
+    let time = Time::from_unix_timestamp(secs, nanos.rem_euclid(1_000_000_000) as u32).ok()?;
+
+    Some((height, time))
+}
+
+fn read_light_block(path: &Path) -> io::Result<LightBlock> {
+    let json = fs::read(path)?;
+    serde_json::from_slice(&json).map_err(io::Error::other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tendermint_testgen::{light_block::LightBlock as TestgenLightBlock, Generator};
+
+    fn light_block(height: u64, time: Time) -> LightBlock {
+        let block = TestgenLightBlock::new_default_with_time_and_chain_id(
+            "ibc-0".to_string(),
+            time,
+            height,
+        )
+        .generate()
+        .unwrap();
+
+        LightBlock::new(
+            block.signed_header,
+            block.validators,
+            block.next_validators,
+            block.provider,
+        )
+    }
+
+    fn height(value: u64) -> Height {
+        Height::try_from(value).unwrap()
+    }
+
+    fn time(secs: i64) -> Time {
+        Time::from_unix_timestamp(secs, 0).unwrap()
+    }
+
+    #[test]
+    fn cached_blocks_are_read_back_after_reopening() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("ibc-0");
+
+        let mut cache = LightBlockCache::open(&dir).unwrap();
+        let block = light_block(10, time(1_000));
+        cache.insert(&block).unwrap();
+        cache.insert(&light_block(20, time(2_000))).unwrap();
+
+        let cache = LightBlockCache::open(&dir).unwrap();
+        assert_eq!(
+            cache.heights().collect::<Vec<_>>(),
+            [height(10), height(20)]
+        );
+        assert_eq!(cache.get(height(10)), Some(block));
+        assert_eq!(cache.get(height(15)), None);
+    }
+
+    #[test]
+    fn unexpected_files_are_removed_when_opening() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("ibc-0");
+
+        let mut cache = LightBlockCache::open(&dir).unwrap();
+        cache.insert(&light_block(10, time(1_000))).unwrap();
+        fs::write(dir.join("11.json"), b"{}").unwrap();
+
+        let cache = LightBlockCache::open(&dir).unwrap();
+        assert_eq!(cache.heights().collect::<Vec<_>>(), [height(10)]);
+        assert!(!dir.join("11.json").exists());
+    }
+
+    #[test]
+    fn unreadable_blocks_are_not_served() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("ibc-0");
+
+        let mut cache = LightBlockCache::open(&dir).unwrap();
+        cache.insert(&light_block(10, time(1_000))).unwrap();
+        fs::write(
+            dir.join(file_name(height(10), time(1_000))),
+            b"{\"signed_header\":",
+        )
+        .unwrap();
+
+        assert_eq!(cache.get(height(10)), None);
+    }
+
+    #[test]
+    fn file_names_hold_the_height_and_time() {
+        let time = Time::from_unix_timestamp(1_700_000_000, 123_456_789).unwrap();
+        let name = file_name(height(42), time);
+
+        assert_eq!(parse_file_name(Path::new(&name)), Some((height(42), time)));
+        assert_eq!(parse_file_name(Path::new("42.json")), None);
+    }
+
+    #[test]
+    fn lowest_blocks_are_evicted_when_full() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("ibc-0");
+
+        let mut cache = LightBlockCache::open(&dir).unwrap();
+        cache.max_blocks = 2;
+
+        cache.insert(&light_block(20, time(2_000))).unwrap();
+        cache.insert(&light_block(10, time(1_000))).unwrap();
+        cache.insert(&light_block(30, time(3_000))).unwrap();
+
+        assert_eq!(
+            cache.heights().collect::<Vec<_>>(),
+            [height(20), height(30)]
+        );
+        assert!(!dir.join(file_name(height(10), time(1_000))).exists());
+    }
+
+    #[test]
+    fn blocks_older_than_the_trusting_period_are_pruned() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("ibc-0");
+
+        let mut cache = LightBlockCache::open(&dir).unwrap();
+        cache.insert(&light_block(10, time(1_000))).unwrap();
+        cache.insert(&light_block(20, time(2_000))).unwrap();
+        cache.insert(&light_block(30, time(3_000))).unwrap();
+
+        let pruned = cache
+            .prune(Duration::from_secs(1_500), time(3_200))
+            .unwrap();
+
+        assert_eq!(pruned, 1);
+        assert_eq!(
+            cache.heights().collect::<Vec<_>>(),
+            [height(20), height(30)]
+        );
+        assert!(!dir.join(file_name(height(10), time(1_000))).exists());
+
+        let cache = LightBlockCache::open(&dir).unwrap();
+        assert_eq!(
+            cache.heights().collect::<Vec<_>>(),
+            [height(20), height(30)]
+        );
+    }
+}
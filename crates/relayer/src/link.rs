@@ -1,9 +1,10 @@
 use ibc_relayer_types::core::{
     ics03_connection::connection::State as ConnectionState,
+    ics04_channel::channel::ChannelEnd,
     ics04_channel::channel::State as ChannelState,
     ics04_channel::channel::UpgradeState,
     ics04_channel::packet::Sequence,
-    ics24_host::identifier::{ChannelId, PortChannelId, PortId},
+    ics24_host::identifier::{ChainId, ChannelId, PortChannelId, PortId},
 };
 use tracing::info;
 
@@ -11,6 +12,7 @@ use crate::chain::{counterparty::check_channel_counterparty, requests::QueryConn
 use crate::chain::{handle::ChainHandle, requests::IncludeProof};
 use crate::channel::{Channel, ChannelSide};
 use crate::link::error::LinkError;
+use crate::multihop::resolve_channel_hops;
 use crate::{
     chain::requests::{QueryChannelRequest, QueryHeight},
     config::types::ics20_field_size_limit::Ics20FieldSizeLimit,
@@ -20,7 +22,9 @@ pub mod cli;
 pub mod error;
 pub mod operational_data;
 pub mod packet_events;
+pub mod rate_limit;
 
+mod journal;
 mod pending;
 mod relay_path;
 mod relay_sender;
@@ -65,6 +69,130 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
         with_tx_confirmation: bool,
         auto_register_counterparty_payee: bool,
     ) -> Result<Link<ChainA, ChainB>, LinkError> {
+        let a_channel = Self::query_relayable_channel(&a_chain, &b_chain, &opts)?;
+
+        if a_channel.is_multihop() {
+            return Err(LinkError::multihop_channel(
+                opts.src_channel_id.clone(),
+                a_chain.id(),
+            ));
+        }
+
+        // Check the underlying connection
+        let a_connection_id = a_channel.connection_hops()[0].clone();
+        let (a_connection, _) = a_chain
+            .query_connection(
+                QueryConnectionRequest {
+                    connection_id: a_connection_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(LinkError::relayer)?;
+
+        if !a_connection.state_matches(&ConnectionState::Open) {
+            return Err(LinkError::channel_not_opened(
+                opts.src_channel_id.clone(),
+                a_chain.id(),
+            ));
+        }
+
+        let channel = Channel {
+            ordering: a_channel.ordering,
+            a_side: ChannelSide::new(
+                a_chain.clone(),
+                a_connection.client_id().clone(),
+                a_connection_id,
+                opts.src_port_id.clone(),
+                Some(opts.src_channel_id.clone()),
+                None,
+            ),
+            b_side: ChannelSide::new(
+                b_chain.clone(),
+                a_connection.counterparty().client_id().clone(),
+                a_connection.counterparty().connection_id().unwrap().clone(),
+                a_channel.counterparty().port_id.clone(),
+                a_channel.counterparty().channel_id.clone(),
+                None,
+            ),
+            connection_delay: a_connection.delay_period(),
+        };
+
+        if auto_register_counterparty_payee {
+            Self::register_counterparty_payee(&a_chain, &b_chain, &a_channel)?;
+        }
+
+        Link::new(channel, with_tx_confirmation, opts)
+    }
+
+    /// Same as [`Link::new_from_opts`], for a multi-hop channel (ICS 033) whose
+    /// connection hops traverse intermediate chains. The handles of the intermediate
+    /// chains are looked up by chain identifier with `a_hop_chain` and `b_hop_chain`.
+    pub fn new_multihop_from_opts(
+        a_chain: ChainA,
+        b_chain: ChainB,
+        opts: LinkParameters,
+        with_tx_confirmation: bool,
+        auto_register_counterparty_payee: bool,
+        a_hop_chain: impl Fn(&ChainId) -> Option<ChainA>,
+        b_hop_chain: impl Fn(&ChainId) -> Option<ChainB>,
+    ) -> Result<Link<ChainA, ChainB>, LinkError> {
+        let a_channel = Self::query_relayable_channel(&a_chain, &b_chain, &opts)?;
+
+        let (a_hops, b_hops) = resolve_channel_hops(
+            &a_chain,
+            &b_chain,
+            a_channel.connection_hops(),
+            a_hop_chain,
+            b_hop_chain,
+        )
+        .map_err(LinkError::multihop)?;
+
+        if !a_hops.connection.state_matches(&ConnectionState::Open) {
+            return Err(LinkError::channel_not_opened(
+                opts.src_channel_id.clone(),
+                a_chain.id(),
+            ));
+        }
+
+        let channel = Channel {
+            ordering: a_channel.ordering,
+            a_side: ChannelSide::new(
+                a_chain.clone(),
+                a_hops.connection.client_id().clone(),
+                a_channel.connection_hops()[0].clone(),
+                opts.src_port_id.clone(),
+                Some(opts.src_channel_id.clone()),
+                None,
+            )
+            .with_hops(a_hops.hops),
+            b_side: ChannelSide::new(
+                b_chain.clone(),
+                a_hops.counterparty_client_id,
+                a_hops.counterparty_connection_id,
+                a_channel.counterparty().port_id.clone(),
+                a_channel.counterparty().channel_id.clone(),
+                None,
+            )
+            .with_hops(b_hops.hops),
+            connection_delay: a_hops.connection.delay_period(),
+        };
+
+        if auto_register_counterparty_payee {
+            Self::register_counterparty_payee(&a_chain, &b_chain, &a_channel)?;
+        }
+
+        Link::new(channel, with_tx_confirmation, opts)
+    }
+
+    /// Queries the source channel of the link and checks that its
+    /// packets can be relayed, ie. that it is open or being closed
+    /// or upgraded, and that its counterparty matches.
+    fn query_relayable_channel(
+        a_chain: &ChainA,
+        b_chain: &ChainB,
+        opts: &LinkParameters,
+    ) -> Result<ChannelEnd, LinkError> {
         // Check that the packet's channel on source chain is Open
         let a_channel_id = &opts.src_channel_id;
         let a_port_id = &opts.src_port_id;
@@ -103,8 +231,6 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
             .channel_id()
             .ok_or_else(|| LinkError::counterparty_channel_not_found(a_channel_id.clone()))?;
 
-        let b_port_id = a_channel.counterparty().port_id.clone();
-
         if a_channel.connection_hops().is_empty() {
             return Err(LinkError::no_connection_hop(
                 a_channel_id.clone(),
@@ -126,61 +252,37 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
         )
         .map_err(LinkError::initialization)?;
 
-        // Check the underlying connection
-        let a_connection_id = a_channel.connection_hops()[0].clone();
-        let (a_connection, _) = a_chain
-            .query_connection(
-                QueryConnectionRequest {
-                    connection_id: a_connection_id.clone(),
-                    height: QueryHeight::Latest,
-                },
-                IncludeProof::No,
-            )
-            .map_err(LinkError::relayer)?;
-
-        if !a_connection.state_matches(&ConnectionState::Open) {
-            return Err(LinkError::channel_not_opened(
-                a_channel_id.clone(),
-                a_chain.id(),
-            ));
-        }
+        Ok(a_channel)
+    }
 
-        let channel = Channel {
-            ordering: a_channel.ordering,
-            a_side: ChannelSide::new(
-                a_chain.clone(),
-                a_connection.client_id().clone(),
-                a_connection_id,
-                opts.src_port_id.clone(),
-                Some(opts.src_channel_id.clone()),
-                None,
-            ),
-            b_side: ChannelSide::new(
-                b_chain.clone(),
-                a_connection.counterparty().client_id().clone(),
-                a_connection.counterparty().connection_id().unwrap().clone(),
-                a_channel.counterparty().port_id.clone(),
-                Some(b_channel_id.clone()),
-                None,
-            ),
-            connection_delay: a_connection.delay_period(),
+    fn register_counterparty_payee(
+        a_chain: &ChainA,
+        b_chain: &ChainB,
+        a_channel: &ChannelEnd,
+    ) -> Result<(), LinkError> {
+        let Some(b_channel_id) = a_channel.counterparty().channel_id() else {
+            return Ok(());
         };
 
-        if auto_register_counterparty_payee && a_channel.version.supports_fee() {
-            let address_a = a_chain.get_signer().map_err(LinkError::relayer)?;
+        if !a_channel.version.supports_fee() {
+            return Ok(());
+        }
 
-            info!(
-                "auto registering counterparty payee on chain {} as {} on chain {}",
-                b_chain.id(),
-                address_a,
-                a_chain.id()
-            );
+        let address_a = a_chain.get_signer().map_err(LinkError::relayer)?;
 
-            b_chain
-                .maybe_register_counterparty_payee(b_channel_id.clone(), b_port_id, address_a)
-                .map_err(LinkError::relayer)?;
-        }
+        info!(
+            "auto registering counterparty payee on chain {} as {} on chain {}",
+            b_chain.id(),
+            address_a,
+            a_chain.id()
+        );
 
-        Link::new(channel, with_tx_confirmation, opts)
+        b_chain
+            .maybe_register_counterparty_payee(
+                b_channel_id.clone(),
+                a_channel.counterparty().port_id.clone(),
+                address_a,
+            )
+            .map_err(LinkError::relayer)
     }
 }
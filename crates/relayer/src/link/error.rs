@@ -1,4 +1,4 @@
-use flex_error::define_error;
+use flex_error::{define_error, TraceError};
 use ibc_relayer_types::core::ics02_client::error::Error as Ics02Error;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::events::IbcEvent;
@@ -8,6 +8,7 @@ use crate::channel::ChannelError;
 use crate::connection::ConnectionError;
 use crate::error::Error;
 use crate::foreign_client::{ForeignClientError, HasExpiredOrFrozenError};
+use crate::multihop::MultihopError;
 use crate::supervisor::Error as SupervisorError;
 use crate::transfer::TransferError;
 
@@ -136,6 +137,20 @@ define_error! {
                     e.channel_id)
             },
 
+        Multihop
+            [ MultihopError ]
+            |_| { "multi-hop channel error" },
+
+        MultihopChannel
+            {
+                channel_id: ChannelId,
+                chain_id: ChainId,
+            }
+            |e| {
+                format!("channel {} on chain {} is a multi-hop channel, which needs the handles of its intermediate chains",
+                    e.channel_id, e.chain_id)
+            },
+
         NoConnectionHop
             {
                 channel_id: ChannelId,
@@ -148,6 +163,25 @@ define_error! {
 
         UpdateClientFailed
              |_| { "failed to update client" },
+
+        Journal
+            { path: String }
+            [ TraceError<std::io::Error> ]
+            |e| {
+                format!("failed to access the transaction journal at {}", e.path)
+            },
+
+        JournalRecord
+            { path: String, line: usize }
+            [ TraceError<serde_json::Error> ]
+            |e| {
+                format!("invalid record at line {} of the transaction journal at {}",
+                    e.line, e.path)
+            },
+
+        DataDirUnavailable
+            |_| { "the folder to keep the transaction journal in is not configured, \
+                and the home folder could not be determined" },
    }
 }
 
@@ -0,0 +1,523 @@
+//! An on-disk write-ahead log of the transactions submitted by a [`RelayPath`]
+//! which are awaiting confirmation, kept when `tx_journal` is enabled.
+//!
+//! [`PendingTxs`] only tracks pending transactions in memory, so if Hermes stops
+//! between the broadcast of a transaction and its confirmation, it forgets which
+//! packets were submitted. The journal records every submission along with the
+//! messages and the events they were built from, and its resolution once the
+//! transactions are confirmed or given up on. When Hermes restarts, the submissions
+//! which were not resolved are read back so that they can be confirmed or
+//! resubmitted before packet clearing starts.
+//!
+//! The journal is an append-only file holding one JSON record per line.
+//! It is compacted when it is opened, and whenever enough submissions were
+//! resolved, by rewriting it with the unresolved submissions only.
+//!
+//! [`RelayPath`]: crate::link::RelayPath
+//! [`PendingTxs`]: crate::link::pending::PendingTxs
+
+use alloc::collections::BTreeMap;
+use core::time::Duration;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Instant, SystemTime};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
+use ibc_relayer_types::core::ics04_channel::events::{SendPacket, WriteAcknowledgement};
+use ibc_relayer_types::core::ics04_channel::packet::Packet;
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::Height;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use tendermint::Hash as TxHash;
+use tracing::warn;
+
+use crate::chain::tracking::TrackingId;
+use crate::event::IbcEventWithHeight;
+use crate::link::error::LinkError;
+use crate::link::operational_data::{OperationalData, OperationalDataTarget, TransitMessage};
+use crate::link::TxHashes;
+
+/// Number of resolved submissions after which the journal is compacted.
+const COMPACTION_THRESHOLD: usize = 1000;
+
+/// A record of the journal.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "record", rename_all = "snake_case")]
+enum Record {
+    /// Transactions were submitted and are awaiting confirmation
+    Submitted(Submission),
+    /// The transactions of a submission were confirmed or given up on
+    Resolved { id: u64 },
+}
+
+/// Transactions submitted for a piece of [`OperationalData`].
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Submission {
+    pub id: u64,
+    pub target: OperationalDataTarget,
+    pub proofs_height: Height,
+    pub tx_hashes: Vec<TxHash>,
+    pub submitted_at: SystemTime,
+    messages: Vec<SubmittedMessage>,
+}
+
+/// A message of a submission, along with the packet event it was built from.
+#[derive(Clone, Serialize, Deserialize)]
+struct SubmittedMessage {
+    event: PacketEvent,
+    height: Height,
+    type_url: String,
+    #[serde(with = "crate::util::serde_base64")]
+    value: Vec<u8>,
+}
+
+/// A packet event, with the packet in its Protobuf encoding.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum PacketEvent {
+    SendPacket {
+        #[serde(with = "crate::util::serde_base64")]
+        packet: Vec<u8>,
+    },
+    WriteAcknowledgement {
+        #[serde(with = "crate::util::serde_base64")]
+        packet: Vec<u8>,
+        #[serde(with = "crate::util::serde_base64")]
+        ack: Vec<u8>,
+    },
+}
+
+impl PacketEvent {
+    fn from_event(event: &IbcEvent) -> Option<Self> {
+        let encode = |packet: &Packet| RawPacket::from(packet.clone()).encode_to_vec();
+
+        match event {
+            IbcEvent::SendPacket(event) => Some(Self::SendPacket {
+                packet: encode(&event.packet),
+            }),
+            IbcEvent::WriteAcknowledgement(event) => Some(Self::WriteAcknowledgement {
+                packet: encode(&event.packet),
+                ack: event.ack.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    fn to_event(&self) -> Option<IbcEvent> {
+        let decode = |packet: &[u8]| {
+            let raw = RawPacket::decode(packet).ok()?;
+            Packet::try_from(raw).ok()
+        };
+
+        match self {
+            Self::SendPacket { packet } => Some(IbcEvent::SendPacket(SendPacket {
+                packet: decode(packet)?,
+            })),
+            Self::WriteAcknowledgement { packet, ack } => {
+                Some(IbcEvent::WriteAcknowledgement(WriteAcknowledgement {
+                    packet: decode(packet)?,
+                    ack: ack.clone(),
+                }))
+            }
+        }
+    }
+}
+
+impl Submission {
+    /// The submission of the given operational data, or `None` if some of its
+    /// messages were not built from a packet event, and cannot be recorded.
+    fn new(id: u64, odata: &OperationalData, tx_hashes: &TxHashes) -> Option<Self> {
+        let messages = odata
+            .batch
+            .iter()
+            .map(|transit| {
+                Some(SubmittedMessage {
+                    event: PacketEvent::from_event(&transit.event_with_height.event)?,
+                    height: transit.event_with_height.height,
+                    type_url: transit.msg.type_url.clone(),
+                    value: transit.msg.value.clone(),
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self {
+            id,
+            target: odata.target,
+            proofs_height: odata.proofs_height,
+            tx_hashes: tx_hashes.0.clone(),
+            submitted_at: SystemTime::now(),
+            messages,
+        })
+    }
+
+    /// Rebuild the operational data the transactions were submitted for,
+    /// or `None` if some of its packets cannot be decoded.
+    pub fn operational_data(&self, connection_delay: Duration) -> Option<OperationalData> {
+        let mut odata = OperationalData::new(
+            self.proofs_height,
+            self.target,
+            TrackingId::new_uuid(),
+            connection_delay,
+        );
+
+        for message in &self.messages {
+            odata.push(TransitMessage {
+                event_with_height: IbcEventWithHeight::new(
+                    message.event.to_event()?,
+                    message.height,
+                ),
+                msg: Any {
+                    type_url: message.type_url.clone(),
+                    value: message.value.clone(),
+                },
+            });
+        }
+
+        Some(odata)
+    }
+
+    /// The time at which the transactions were submitted, as an [`Instant`].
+    pub fn submit_time(&self) -> Instant {
+        let elapsed = self.submitted_at.elapsed().unwrap_or_default();
+        Instant::now()
+            .checked_sub(elapsed)
+            .unwrap_or_else(Instant::now)
+    }
+}
+
+/// The transaction journal of a [`RelayPath`](crate::link::RelayPath).
+pub struct Journal {
+    path: PathBuf,
+    state: Mutex<State>,
+}
+
+struct State {
+    file: File,
+    next_id: u64,
+    unresolved: BTreeMap<u64, Submission>,
+    resolved_since_compaction: usize,
+}
+
+impl Journal {
+    /// Open the journal at the given path, creating it if it does not exist,
+    /// and return it along with the submissions which are not resolved yet.
+    pub fn open(path: PathBuf) -> Result<(Self, Vec<Submission>), LinkError> {
+        let journal_error = |e| LinkError::journal(path.display().to_string(), e);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(journal_error)?;
+        }
+
+        let unresolved = read_unresolved(&path)?;
+        let file = rewrite(&path, &unresolved).map_err(journal_error)?;
+
+        let next_id = unresolved.keys().next_back().map_or(0, |id| id + 1);
+        let submissions = unresolved.values().cloned().collect();
+
+        let journal = Self {
+            path,
+            state: Mutex::new(State {
+                file,
+                next_id,
+                unresolved,
+                resolved_since_compaction: 0,
+            }),
+        };
+
+        Ok((journal, submissions))
+    }
+
+    /// Record the submission of transactions for the given operational data,
+    /// and return its identifier, or `None` if it cannot be recorded.
+    pub fn record_submitted(
+        &self,
+        odata: &OperationalData,
+        tx_hashes: &TxHashes,
+    ) -> Result<Option<u64>, LinkError> {
+        let mut state = self.state.lock().unwrap();
+
+        let id = state.next_id;
+        let Some(submission) = Submission::new(id, odata, tx_hashes) else {
+            return Ok(None);
+        };
+
+        state
+            .append(&Record::Submitted(submission.clone()))
+            .map_err(|e| self.error(e))?;
+
+        state.next_id += 1;
+        state.unresolved.insert(id, submission);
+
+        Ok(Some(id))
+    }
+
+    /// Record that the transactions of the given submission were confirmed or given up on.
+    pub fn record_resolved(&self, id: u64) -> Result<(), LinkError> {
+        let mut state = self.state.lock().unwrap();
+
+        if state.unresolved.remove(&id).is_none() {
+            return Ok(());
+        }
+
+        state
+            .append(&Record::Resolved { id })
+            .map_err(|e| self.error(e))?;
+
+        state.resolved_since_compaction += 1;
+
+        if state.resolved_since_compaction >= COMPACTION_THRESHOLD {
+            state.file = rewrite(&self.path, &state.unresolved).map_err(|e| self.error(e))?;
+            state.resolved_since_compaction = 0;
+        }
+
+        Ok(())
+    }
+
+    fn error(&self, e: io::Error) -> LinkError {
+        LinkError::journal(self.path.display().to_string(), e)
+    }
+}
+
+impl State {
+    fn append(&mut self, record: &Record) -> io::Result<()> {
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+
+        self.file.write_all(&line)?;
+        self.file.sync_data()
+    }
+}
+
+/// Read the journal at the given path, if any, and return the submissions
+/// which are not resolved yet.
+///
+/// An invalid last line, which is the result of Hermes stopping
+/// while the record was being written, is ignored.
+fn read_unresolved(path: &Path) -> Result<BTreeMap<u64, Submission>, LinkError> {
+    let mut unresolved = BTreeMap::new();
+
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(unresolved),
+        Err(e) => return Err(LinkError::journal(path.display().to_string(), e)),
+    };
+
+    let lines = BufReader::new(file)
+        .lines()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| LinkError::journal(path.display().to_string(), e))?;
+
+    for (index, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match serde_json::from_str(line) {
+            Ok(Record::Submitted(submission)) => {
+                unresolved.insert(submission.id, submission);
+            }
+            Ok(Record::Resolved { id }) => {
+                unresolved.remove(&id);
+            }
+            Err(e) if index + 1 == lines.len() => {
+                warn!(
+                    path = %path.display(),
+                    "ignoring incomplete last record of the transaction journal: {e}"
+                );
+            }
+            Err(e) => {
+                return Err(LinkError::journal_record(
+                    path.display().to_string(),
+                    index + 1,
+                    e,
+                ))
+            }
+        }
+    }
+
+    Ok(unresolved)
+}
+
+/// Atomically replace the journal at the given path with one holding
+/// the given submissions only, and open it for appending new records.
+fn rewrite(path: &Path, unresolved: &BTreeMap<u64, Submission>) -> io::Result<File> {
+    let tmp_path = path.with_extension("tmp");
+
+    let mut tmp = File::create(&tmp_path)?;
+    for submission in unresolved.values() {
+        let mut line = serde_json::to_vec(&Record::Submitted(submission.clone()))?;
+        line.push(b'\n');
+        tmp.write_all(&line)?;
+    }
+    tmp.sync_all()?;
+
+    fs::rename(&tmp_path, path)?;
+
+    OpenOptions::new().append(true).open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_relayer_types::core::ics02_client::events::NewBlock;
+    use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+    use ibc_relayer_types::core::ics04_channel::timeout::TimeoutHeight;
+    use ibc_relayer_types::timestamp::Timestamp;
+
+    fn height(revision_height: u64) -> Height {
+        Height::new(1, revision_height).unwrap()
+    }
+
+    fn packet(sequence: u64) -> Packet {
+        Packet {
+            sequence: Sequence::from(sequence),
+            source_port: "transfer".parse().unwrap(),
+            source_channel: "channel-0".parse().unwrap(),
+            destination_port: "transfer".parse().unwrap(),
+            destination_channel: "channel-1".parse().unwrap(),
+            data: b"{\"amount\":\"1\"}".to_vec(),
+            timeout_height: TimeoutHeight::At(height(100)),
+            timeout_timestamp: Timestamp::none(),
+        }
+    }
+
+    fn odata(sequences: &[u64]) -> OperationalData {
+        let mut odata = OperationalData::new(
+            height(10),
+            OperationalDataTarget::Destination,
+            TrackingId::new_uuid(),
+            Duration::ZERO,
+        );
+
+        for &sequence in sequences {
+            odata.push(TransitMessage {
+                event_with_height: IbcEventWithHeight::new(
+                    IbcEvent::SendPacket(SendPacket {
+                        packet: packet(sequence),
+                    }),
+                    height(9),
+                ),
+                msg: Any {
+                    type_url: "/ibc.core.channel.v1.MsgRecvPacket".to_string(),
+                    value: vec![sequence as u8; 4],
+                },
+            });
+        }
+
+        odata
+    }
+
+    fn tx_hashes(byte: u8) -> TxHashes {
+        TxHashes(vec![TxHash::Sha256([byte; 32])])
+    }
+
+    fn sequences(submission: &Submission) -> Vec<Sequence> {
+        submission
+            .operational_data(Duration::ZERO)
+            .unwrap()
+            .batch
+            .iter()
+            .map(|transit| transit.event_with_height.event.packet().unwrap().sequence)
+            .collect()
+    }
+
+    #[test]
+    fn unresolved_submissions_are_read_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("channel-0.jsonl");
+
+        let (journal, submissions) = Journal::open(path.clone()).unwrap();
+        assert!(submissions.is_empty());
+
+        let first = journal
+            .record_submitted(&odata(&[1, 2]), &tx_hashes(1))
+            .unwrap()
+            .unwrap();
+        let second = journal
+            .record_submitted(&odata(&[3]), &tx_hashes(2))
+            .unwrap()
+            .unwrap();
+        journal.record_resolved(first).unwrap();
+        drop(journal);
+
+        let (journal, submissions) = Journal::open(path.clone()).unwrap();
+        assert_eq!(submissions.len(), 1);
+
+        let submission = &submissions[0];
+        assert_eq!(submission.id, second);
+        assert_eq!(submission.tx_hashes, tx_hashes(2).0);
+        assert_eq!(submission.proofs_height, height(10));
+        assert_eq!(sequences(submission), vec![Sequence::from(3)]);
+
+        let odata = submission.operational_data(Duration::ZERO).unwrap();
+        assert_eq!(odata.batch[0].msg.value, vec![3; 4]);
+        assert_eq!(odata.batch[0].event_with_height.height, height(9));
+
+        // New submissions do not reuse the identifiers of unresolved ones
+        let third = journal
+            .record_submitted(&odata, &tx_hashes(3))
+            .unwrap()
+            .unwrap();
+        assert!(third > second);
+    }
+
+    #[test]
+    fn journal_is_compacted_on_open() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("channel-0.jsonl");
+
+        let (journal, _) = Journal::open(path.clone()).unwrap();
+        for sequence in 0..10 {
+            let id = journal
+                .record_submitted(&odata(&[sequence]), &tx_hashes(1))
+                .unwrap()
+                .unwrap();
+            journal.record_resolved(id).unwrap();
+        }
+        drop(journal);
+
+        let (_journal, submissions) = Journal::open(path.clone()).unwrap();
+        assert!(submissions.is_empty());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "");
+    }
+
+    #[test]
+    fn incomplete_last_record_is_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("channel-0.jsonl");
+
+        let (journal, _) = Journal::open(path.clone()).unwrap();
+        journal
+            .record_submitted(&odata(&[1]), &tx_hashes(1))
+            .unwrap();
+        drop(journal);
+
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"record\":\"submitted\",\"id\":1,\"tar")
+            .unwrap();
+        drop(file);
+
+        let (_journal, submissions) = Journal::open(path.clone()).unwrap();
+        assert_eq!(submissions.len(), 1);
+    }
+
+    #[test]
+    fn only_packet_events_are_recorded() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("channel-0.jsonl");
+
+        let mut odata = odata(&[1]);
+        odata.batch[0].event_with_height.event = IbcEvent::NewBlock(NewBlock { height: height(9) });
+
+        let (journal, _) = Journal::open(path.clone()).unwrap();
+        assert_eq!(
+            journal.record_submitted(&odata, &tx_hashes(1)).unwrap(),
+            None
+        );
+    }
+}
@@ -2,6 +2,7 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 use std::ops::Add;
 use std::time::{Duration, Instant};
 
+use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
 use ibc_proto::google::protobuf::Any;
@@ -19,7 +20,8 @@ use crate::link::error::LinkError;
 use crate::link::RelayPath;
 
 /// The chain that the events associated with a piece of [`OperationalData`] are bound for.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum OperationalDataTarget {
     /// The chain which generated the events associated with the `OperationalData`.
     Source,
@@ -96,8 +98,13 @@ pub struct OperationalData {
     /// A unique ID for tracking this batch of events starting from when they were received
     /// until the transactions corresponding to those events is submitted.
     pub tracking_id: TrackingId,
+    /// Number of times the gas price is bumped when submitting the messages,
+    /// incremented each time the transaction gets stuck and is resubmitted.
+    pub fee_bumps: u32,
     /// Stores `Some(ConnectionDelay)` if the delay is non-zero and `None` otherwise
     connection_delay: Option<ConnectionDelay>,
+    /// When this piece of operational data was created
+    created_at: Instant,
 }
 
 impl OperationalData {
@@ -119,9 +126,16 @@ impl OperationalData {
             target,
             connection_delay,
             tracking_id,
+            fee_bumps: 0,
+            created_at: Instant::now(),
         }
     }
 
+    /// How long this piece of operational data has been waiting to be relayed.
+    pub fn age(&self) -> Duration {
+        self.created_at.elapsed()
+    }
+
     pub fn push(&mut self, msg: TransitMessage) {
         self.batch.push(msg)
     }
@@ -202,7 +216,9 @@ impl OperationalData {
             };
 
             if client_state.is_frozen() {
-                return Ok(TrackedMsgs::new(vec![], self.tracking_id));
+                return Ok(
+                    TrackedMsgs::new(vec![], self.tracking_id).with_fee_bumps(self.fee_bumps)
+                );
             }
 
             vec![]
@@ -213,7 +229,7 @@ impl OperationalData {
             .chain(self.batch.iter().map(|gm| gm.msg.clone()))
             .collect();
 
-        let tm = TrackedMsgs::new(msgs, self.tracking_id);
+        let tm = TrackedMsgs::new(msgs, self.tracking_id).with_fee_bumps(self.fee_bumps);
 
         info!("assembled batch of {} message(s)", tm.messages().len());
 
@@ -1,14 +1,17 @@
 use core::time::Duration;
+use std::sync::Arc;
 use std::time::Instant;
 
-use tracing::{debug, error, trace, trace_span};
+use tracing::{debug, error, trace, trace_span, warn};
 
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::events::IbcEvent;
 
 use crate::chain::requests::{QueryTxHash, QueryTxRequest};
 use crate::chain::tracking::TrackingId;
+use crate::config::fee_bump::FeeBump;
 use crate::error::Error as RelayerError;
+use crate::link::journal::{Journal, Submission};
 use crate::link::{error::LinkError, RelayPath};
 use crate::telemetry;
 use crate::util::queue::Queue;
@@ -32,6 +35,8 @@ pub struct PendingData {
     pub tx_hashes: TxHashes,
     pub submit_time: Instant,
     pub error_events: Vec<IbcEvent>,
+    /// The identifier of the submission in the transaction journal, if recorded
+    pub journal_id: Option<u64>,
 }
 
 impl PendingData {
@@ -48,6 +53,10 @@ pub struct PendingTxs<Chain> {
     pub port_id: PortId,
     pub counterparty_chain_id: ChainId,
     pub pending_queue: Queue<PendingData>,
+    journal: Option<Arc<Journal>>,
+    /// How long to wait for a transaction to be committed before
+    /// resubmitting it with a bumped gas price, if fee bumping is enabled
+    fee_bump_after: Option<Duration>,
 }
 
 impl<Chain> PendingTxs<Chain> {
@@ -63,8 +72,41 @@ impl<Chain> PendingTxs<Chain> {
             port_id,
             counterparty_chain_id,
             pending_queue: Queue::new(),
+            journal: None,
+            fee_bump_after: None,
         }
     }
+
+    /// Resubmit the transactions which are not committed after `fee_bump.blocks`
+    /// blocks with a bumped gas price, if fee bumping is enabled.
+    pub fn set_fee_bump(&mut self, fee_bump: FeeBump, max_block_time: Duration) {
+        self.fee_bump_after = fee_bump.enabled.then(|| {
+            max_block_time.saturating_mul(u32::try_from(fee_bump.blocks).unwrap_or(u32::MAX))
+        });
+    }
+
+    /// Record the pending transactions in the given journal from now on.
+    pub fn set_journal(&mut self, journal: Arc<Journal>) {
+        self.journal = Some(journal);
+    }
+
+    /// Whether the given packet event is handled by a pending transaction.
+    pub fn contains_packet_event(&self, event: &IbcEvent) -> bool {
+        let Some(packet) = event.packet() else {
+            return false;
+        };
+
+        self.pending_queue.any(|pending| {
+            pending.original_od.batch.iter().any(|transit| {
+                let pending_event = &transit.event_with_height.event;
+
+                pending_event.event_type() == event.event_type()
+                    && pending_event
+                        .packet()
+                        .is_some_and(|p| p.sequence == packet.sequence)
+            })
+        })
+    }
 }
 
 impl<Chain: ChainHandle> PendingTxs<Chain> {
@@ -115,16 +157,60 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
             }
         }
 
+        let tx_hashes = TxHashes(tx_hashes);
+
+        let journal_id = match &self.journal {
+            Some(journal) if !tx_hashes.0.is_empty() => journal
+                .record_submitted(&od, &tx_hashes)
+                .unwrap_or_else(|e| {
+                    error!("failed to record pending transactions {tx_hashes}: {e}");
+                    None
+                }),
+            _ => None,
+        };
+
         let u = PendingData {
             original_od: od,
-            tx_hashes: TxHashes(tx_hashes),
+            tx_hashes,
             submit_time: Instant::now(),
             error_events,
+            journal_id,
         };
 
         self.pending_queue.push_back(u);
     }
 
+    /// Insert a pending transaction read back from the transaction journal
+    /// to the back of the queue, keeping its original submission time.
+    pub fn restore_pending_tx(&self, submission: Submission, connection_delay: Duration) {
+        let Some(original_od) = submission.operational_data(connection_delay) else {
+            warn!(
+                id = submission.id,
+                "discarding journaled transactions with undecodable packets"
+            );
+            self.resolve_in_journal(Some(submission.id));
+            return;
+        };
+
+        self.pending_queue.push_back(PendingData {
+            original_od,
+            tx_hashes: TxHashes(submission.tx_hashes.clone()),
+            submit_time: submission.submit_time(),
+            error_events: vec![],
+            journal_id: Some(submission.id),
+        });
+    }
+
+    /// Record in the transaction journal, if any, that the given
+    /// pending transactions were confirmed or given up on.
+    fn resolve_in_journal(&self, journal_id: Option<u64>) {
+        if let (Some(journal), Some(id)) = (&self.journal, journal_id) {
+            if let Err(e) = journal.record_resolved(id) {
+                error!("failed to record resolution of pending transactions: {e}");
+            }
+        }
+    }
+
     fn check_tx_events(&self, tx_hashes: &TxHashes) -> Result<Option<Vec<IbcEvent>>, RelayerError> {
         let mut all_events = Vec::new();
         for hash in &tx_hashes.0 {
@@ -160,6 +246,7 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
             let submit_time = &pending.submit_time;
 
             if tx_hashes.0.is_empty() {
+                self.resolve_in_journal(pending.journal_id);
                 return Ok(Some(RelaySummary::from_events(pending.error_events)));
             }
 
@@ -184,7 +271,11 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
 
                     trace!("transaction is not yet committed: {} ", tx_hashes);
 
-                    if submit_time.elapsed() > timeout {
+                    // When resubmitting with fee bumping, the transaction is considered
+                    // stuck after the configured number of blocks instead of the timeout.
+                    let fee_bump_after = self.fee_bump_after.filter(|_| resubmit.is_some());
+
+                    if submit_time.elapsed() > fee_bump_after.unwrap_or(timeout) {
                         // The submission time for the transaction has exceeded the
                         // timeout threshold. Returning Outcome::Timeout for the
                         // relayer to resubmit the transaction to the chain again.
@@ -192,17 +283,48 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
 
                         match resubmit {
                             Some(f) => {
+                                // A bumped transaction does not replace the stuck one in the mempool,
+                                // it is signed with the next account sequence, which is not taken yet.
+                                let fee_bumps = match fee_bump_after {
+                                    Some(_) => pending.original_od.fee_bumps + 1,
+                                    None => pending.original_od.fee_bumps,
+                                };
+
                                 // The pending tx needs to be resubmitted. This involves replacing the tx's
                                 // stale operational data with a fresh copy and then applying the `resubmit`
                                 // closure to it.
                                 let new_od = relay_path
-                                    .regenerate_operational_data(pending.original_od.clone());
+                                    .regenerate_operational_data(pending.original_od.clone())
+                                    .map(|mut od| {
+                                        od.fee_bumps = fee_bumps;
+                                        od
+                                    });
 
                                 trace!("regenerated operational data for {}", tx_hashes);
 
                                 match new_od.map(f) {
                                     Some(Ok(reply)) => {
-                                        self.insert_new_pending_tx(reply, pending.original_od);
+                                        if fee_bump_after.is_some() {
+                                            debug!(
+                                                bumps = fee_bumps,
+                                                "resubmitted {} with a bumped gas price", tx_hashes
+                                            );
+
+                                            telemetry!(
+                                                fee_bump,
+                                                &self.chain.id(),
+                                                &self.counterparty_chain_id,
+                                                &self.channel_id,
+                                                &self.port_id,
+                                                fee_bumps
+                                            );
+                                        }
+
+                                        let mut original_od = pending.original_od;
+                                        original_od.fee_bumps = fee_bumps;
+
+                                        self.resolve_in_journal(pending.journal_id);
+                                        self.insert_new_pending_tx(reply, original_od);
                                         Ok(None)
                                     }
                                     Some(Err(e)) => {
@@ -211,12 +333,14 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                                     }
                                     None => {
                                         // No operational data was regenerated; nothing to resubmit
+                                        self.resolve_in_journal(pending.journal_id);
                                         Ok(None)
                                     }
                                 }
                             }
                             None => {
                                 // `clear_interval != 0` such that resubmission has been disabled
+                                self.resolve_in_journal(pending.journal_id);
                                 Ok(None)
                             }
                         }
@@ -250,6 +374,8 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                         &self.counterparty_chain_id
                     );
 
+                    self.resolve_in_journal(pending.journal_id);
+
                     // Append the events corresponding to errors from the pending tx.
                     events.extend(pending.error_events);
 
@@ -0,0 +1,361 @@
+//! Token-bucket rate limiting of the packets relayed to the chains,
+//! shared by all the packet workers.
+
+use alloc::collections::BTreeMap;
+use alloc::sync::Arc;
+use core::time::Duration;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+use crate::config::rate_limit::{RateLimit, RateLimitConfig};
+
+/// The cost of relaying a batch of messages.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Cost {
+    pub packets: u64,
+    pub gas: u64,
+}
+
+/// The number of packets to a channel of a chain which are
+/// currently deferred because of the rate limits of that chain.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeferredPackets {
+    pub chain_id: ChainId,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub packets: u64,
+}
+
+/// Rate limiter holding the token buckets of the chains and of their channels.
+///
+/// Cloning a rate limiter yields a handle to the same buckets.
+#[derive(Clone, Debug, Default)]
+pub struct RateLimiter {
+    state: Arc<Mutex<State>>,
+}
+
+type ChannelKey = (ChainId, PortId, ChannelId);
+
+#[derive(Debug, Default)]
+struct State {
+    chains: BTreeMap<ChainId, Buckets>,
+    channels: BTreeMap<ChannelKey, Buckets>,
+    deferred: BTreeMap<ChannelKey, u64>,
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take the given cost out of the buckets of the chain and of the channel, if both
+    /// have enough tokens left. Returns whether the messages can be relayed now.
+    pub fn try_acquire(
+        &self,
+        chain_id: &ChainId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        config: &RateLimitConfig,
+        cost: Cost,
+    ) -> bool {
+        self.try_acquire_at(chain_id, port_id, channel_id, config, cost, Instant::now())
+    }
+
+    fn try_acquire_at(
+        &self,
+        chain_id: &ChainId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        config: &RateLimitConfig,
+        cost: Cost,
+        now: Instant,
+    ) -> bool {
+        if !config.is_enabled() {
+            return true;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        let State {
+            chains, channels, ..
+        } = &mut *state;
+
+        let chain_buckets = chains
+            .entry(chain_id.clone())
+            .or_insert_with(|| Buckets::new(config.chain, config.interval, now));
+        chain_buckets.reconfigure(config.chain, config.interval, now);
+
+        let channel_limit = config.channel_limit(channel_id);
+        let channel_buckets = channels
+            .entry((chain_id.clone(), port_id.clone(), channel_id.clone()))
+            .or_insert_with(|| Buckets::new(channel_limit, config.interval, now));
+        channel_buckets.reconfigure(channel_limit, config.interval, now);
+
+        if chain_buckets.has_room(cost, now) && channel_buckets.has_room(cost, now) {
+            chain_buckets.take(cost);
+            channel_buckets.take(cost);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Record the number of packets to the given channel which are currently deferred.
+    pub fn set_deferred(
+        &self,
+        chain_id: &ChainId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        packets: u64,
+    ) {
+        let key = (chain_id.clone(), port_id.clone(), channel_id.clone());
+        let mut state = self.state.lock().unwrap();
+
+        if packets == 0 {
+            state.deferred.remove(&key);
+        } else {
+            state.deferred.insert(key, packets);
+        }
+    }
+
+    /// The channels with deferred packets.
+    pub fn deferred(&self) -> Vec<DeferredPackets> {
+        let state = self.state.lock().unwrap();
+
+        state
+            .deferred
+            .iter()
+            .map(
+                |((chain_id, port_id, channel_id), packets)| DeferredPackets {
+                    chain_id: chain_id.clone(),
+                    port_id: port_id.clone(),
+                    channel_id: channel_id.clone(),
+                    packets: *packets,
+                },
+            )
+            .collect()
+    }
+}
+
+/// The packet and gas buckets of a chain or of a channel.
+#[derive(Debug)]
+struct Buckets {
+    limit: RateLimit,
+    interval: Duration,
+    packets: Option<TokenBucket>,
+    gas: Option<TokenBucket>,
+}
+
+impl Buckets {
+    fn new(limit: RateLimit, interval: Duration, now: Instant) -> Self {
+        Self {
+            limit,
+            interval,
+            packets: limit
+                .packets
+                .map(|capacity| TokenBucket::new(capacity, interval, now)),
+            gas: limit
+                .gas
+                .map(|capacity| TokenBucket::new(capacity, interval, now)),
+        }
+    }
+
+    /// Reset the buckets if the limits changed, eg. after reloading the configuration.
+    fn reconfigure(&mut self, limit: RateLimit, interval: Duration, now: Instant) {
+        if self.limit != limit || self.interval != interval {
+            *self = Self::new(limit, interval, now);
+        }
+    }
+
+    fn has_room(&mut self, cost: Cost, now: Instant) -> bool {
+        let packets = self.packets.as_mut();
+        let gas = self.gas.as_mut();
+
+        packets.map_or(true, |bucket| bucket.has_room(cost.packets, now))
+            && gas.map_or(true, |bucket| bucket.has_room(cost.gas, now))
+    }
+
+    fn take(&mut self, cost: Cost) {
+        if let Some(bucket) = &mut self.packets {
+            bucket.take(cost.packets);
+        }
+
+        if let Some(bucket) = &mut self.gas {
+            bucket.take(cost.gas);
+        }
+    }
+}
+
+/// A bucket holding up to `capacity` tokens, refilled continuously
+/// at the rate of `capacity` tokens per `interval`.
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_sec: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: u64, interval: Duration, now: Instant) -> Self {
+        let capacity = capacity as f64;
+
+        Self {
+            capacity,
+            tokens: capacity,
+            refill_per_sec: capacity / interval.as_secs_f64(),
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens =
+            (self.tokens + elapsed.as_secs_f64() * self.refill_per_sec).min(self.capacity);
+        self.last_refill = now;
+    }
+
+    /// Whether the given amount of tokens can be taken out of the bucket.
+    ///
+    /// An amount larger than the capacity can be taken out of a full bucket,
+    /// which then goes into debt, so that large batches are deferred but not starved.
+    fn has_room(&mut self, amount: u64, now: Instant) -> bool {
+        self.refill(now);
+        self.tokens >= (amount as f64).min(self.capacity)
+    }
+
+    fn take(&mut self, amount: u64) {
+        self.tokens -= amount as f64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashMap;
+
+    use test_log::test;
+
+    use crate::config::filter::FilterPattern;
+
+    #[allow(clippy::mutable_key_type)]
+    fn config(chain: RateLimit, channel: RateLimit) -> RateLimitConfig {
+        let channels = HashMap::from([(FilterPattern::Wildcard("*".parse().unwrap()), channel)]);
+
+        RateLimitConfig::new(Duration::from_secs(10), chain, channels).unwrap()
+    }
+
+    fn packets(packets: u64) -> Cost {
+        Cost { packets, gas: 0 }
+    }
+
+    #[test]
+    fn channel_limit_is_refilled() {
+        let limiter = RateLimiter::new();
+        let config = config(
+            RateLimit::default(),
+            RateLimit {
+                packets: Some(10),
+                gas: None,
+            },
+        );
+
+        let chain_id = ChainId::from_string("chain-a");
+        let port_id = PortId::transfer();
+        let channel_0 = ChannelId::new(0);
+        let channel_1 = ChannelId::new(1);
+        let start = Instant::now();
+
+        let acquire = |channel_id: &ChannelId, cost: Cost, secs: u64| {
+            let now = start + Duration::from_secs(secs);
+            limiter.try_acquire_at(&chain_id, &port_id, channel_id, &config, cost, now)
+        };
+
+        assert!(acquire(&channel_0, packets(6), 0));
+        assert!(!acquire(&channel_0, packets(6), 0));
+        // Each channel has its own bucket
+        assert!(acquire(&channel_1, packets(6), 0));
+        // Half of the capacity is refilled after half of the interval
+        assert!(acquire(&channel_0, packets(9), 5));
+        assert!(!acquire(&channel_0, packets(1), 5));
+    }
+
+    #[test]
+    fn chain_limit_is_shared_by_channels() {
+        let limiter = RateLimiter::new();
+        let config = config(
+            RateLimit {
+                packets: None,
+                gas: Some(1000),
+            },
+            RateLimit::default(),
+        );
+
+        let chain_id = ChainId::from_string("chain-a");
+        let port_id = PortId::transfer();
+        let now = Instant::now();
+
+        let gas = |gas| Cost { packets: 1, gas };
+
+        assert!(limiter.try_acquire_at(
+            &chain_id,
+            &port_id,
+            &ChannelId::new(0),
+            &config,
+            gas(600),
+            now
+        ));
+        assert!(!limiter.try_acquire_at(
+            &chain_id,
+            &port_id,
+            &ChannelId::new(1),
+            &config,
+            gas(600),
+            now
+        ));
+
+        // A batch over the capacity of the bucket is let through once the bucket is full
+        let later = now + Duration::from_secs(10);
+        assert!(limiter.try_acquire_at(
+            &chain_id,
+            &port_id,
+            &ChannelId::new(1),
+            &config,
+            gas(5000),
+            later
+        ));
+        assert!(!limiter.try_acquire_at(
+            &chain_id,
+            &port_id,
+            &ChannelId::new(1),
+            &config,
+            gas(1),
+            later + Duration::from_secs(30)
+        ));
+    }
+
+    #[test]
+    fn deferred_packets() {
+        let limiter = RateLimiter::new();
+        let chain_id = ChainId::from_string("chain-a");
+        let port_id = PortId::transfer();
+
+        limiter.set_deferred(&chain_id, &port_id, &ChannelId::new(0), 3);
+        limiter.set_deferred(&chain_id, &port_id, &ChannelId::new(1), 2);
+        limiter.set_deferred(&chain_id, &port_id, &ChannelId::new(1), 0);
+
+        assert_eq!(
+            limiter.deferred(),
+            vec![DeferredPackets {
+                chain_id,
+                port_id,
+                channel_id: ChannelId::new(0),
+                packets: 3,
+            }]
+        );
+    }
+}
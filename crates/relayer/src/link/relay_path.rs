@@ -1,14 +1,21 @@
 use alloc::collections::BTreeMap as HashMap;
+use alloc::collections::BTreeSet as HashSet;
 use alloc::collections::VecDeque;
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
 use std::ops::Sub;
+use std::path::Path;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
+use ibc_proto::ibc::apps::fee::v1::QueryIncentivizedPacketRequest;
+use ibc_proto::ibc::core::channel::v1::PacketId as RawPacketId;
 use itertools::Itertools;
 use tracing::{debug, error, info, span, trace, warn, Level};
 
+use ibc_relayer_types::applications::ics29_fee::packet_fee::{Fee, IdentifiedPacketFees};
+use ibc_relayer_types::applications::transfer::Amount;
 use ibc_relayer_types::core::ics02_client::events::ClientMisbehaviour as ClientMisbehaviourEvent;
 use ibc_relayer_types::core::ics04_channel::channel::{
     ChannelEnd, Ordering, State as ChannelState,
@@ -19,8 +26,11 @@ use ibc_relayer_types::core::ics04_channel::msgs::{
     recv_packet::MsgRecvPacket, timeout::MsgTimeout, timeout_on_close::MsgTimeoutOnClose,
 };
 use ibc_relayer_types::core::ics04_channel::packet::{Packet, PacketMsgType};
-use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc_relayer_types::core::ics24_host::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId,
+};
 use ibc_relayer_types::events::{IbcEvent, IbcEventType, WithBlockDataType};
+use ibc_relayer_types::proofs::Proofs;
 use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::timestamp::Timestamp;
 use ibc_relayer_types::tx_msg::Msg;
@@ -45,12 +55,17 @@ use crate::chain::tracking::TrackedMsgs;
 use crate::chain::tracking::TrackingId;
 use crate::channel::error::ChannelError;
 use crate::channel::Channel;
+use crate::config::fee_priority::FeePriority;
+use crate::config::filter::FeePolicy;
+use crate::config::ics20_filter::Ics20Filter;
+use crate::config::rate_limit::RateLimitConfig;
 use crate::config::types::ics20_field_size_limit::Ics20FieldSizeLimit;
 use crate::config::types::ics20_field_size_limit::ValidationResult;
 use crate::event::source::EventBatch;
 use crate::event::IbcEventWithHeight;
 use crate::foreign_client::{ForeignClient, ForeignClientError};
 use crate::link::error::{self, LinkError};
+use crate::link::journal::Journal;
 use crate::link::operational_data::{
     OperationalData, OperationalDataTarget, TrackedEvents, TransitMessage,
 };
@@ -58,18 +73,25 @@ use crate::link::packet_events::query_packet_events_with;
 use crate::link::packet_events::query_send_packet_events;
 use crate::link::packet_events::query_write_ack_events;
 use crate::link::pending::PendingTxs;
+use crate::link::rate_limit::{Cost, RateLimiter};
 use crate::link::relay_sender::{AsyncReply, SubmitReply};
 use crate::link::relay_summary::RelaySummary;
 use crate::link::LinkParameters;
 use crate::link::{pending, relay_sender};
+use crate::multihop::{ChannelHop, HopProofs};
 use crate::path::PathIdentifiers;
 use crate::telemetry;
 use crate::util::collate::CollatedIterExt;
+use crate::util::lock::{LockExt, RwArc};
 use crate::util::pretty::PrettyEvents;
 use crate::util::queue::Queue;
 
 const MAX_RETRIES: usize = 5;
 
+/// The number of heights for which the proofs of the intermediate
+/// chains of a multi-hop channel are kept around.
+const MAX_CACHED_HOP_PROOFS: usize = 16;
+
 /// Whether or not to resubmit packets when pending transactions
 /// fail to process within the given timeout duration.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -115,6 +137,35 @@ pub struct RelayPath<ChainA: ChainHandle, ChainB: ChainHandle> {
     pending_txs_src: PendingTxs<ChainA>,
     pending_txs_dst: PendingTxs<ChainB>,
 
+    // Whether the pending transactions are recorded in a transaction journal.
+    journal_enabled: bool,
+
+    // The ICS-29 fee policy of the channel, if any, to filter the packets
+    // when clearing and the acknowledgements when relaying.
+    fee_policy: Option<FeePolicy>,
+
+    // The rules on the content of the ICS-20 packets sent from the source chain.
+    ics20_filter: Ics20Filter,
+
+    // The rate limiter shared by the packet workers, if rate limiting is enabled,
+    // with the rate limits and the estimated gas per message of both chains.
+    rate_limiter: Option<RateLimiter>,
+    src_rate_limit: RateLimitConfig,
+    dst_rate_limit: RateLimitConfig,
+    src_default_gas: u64,
+    dst_default_gas: u64,
+
+    // The ordering by fee value of the packets relayed to each chain, and the
+    // ICS-29 fees of the scheduled packets, by sending chain and sequence.
+    src_fee_priority: FeePriority,
+    dst_fee_priority: FeePriority,
+    packet_fees: RwArc<HashMap<(ChainId, Sequence), Fee>>,
+
+    // The proofs of the intermediate chains of a multi-hop channel, which let
+    // each chain verify the proofs of the other chain, by height of these proofs.
+    src_hop_proofs: RwArc<HashMap<Height, HopProofs>>,
+    dst_hop_proofs: RwArc<HashMap<Height, HopProofs>>,
+
     pub max_memo_size: Ics20FieldSizeLimit,
     pub max_receiver_size: Ics20FieldSizeLimit,
     pub exclude_src_sequences: Vec<Sequence>,
@@ -152,6 +203,20 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             counterparty_channel_id: src_channel_id.clone(),
         };
 
+        let mut pending_txs_src =
+            PendingTxs::new(src_chain.clone(), src_channel_id, src_port_id, dst_chain_id);
+        let mut pending_txs_dst =
+            PendingTxs::new(dst_chain.clone(), dst_channel_id, dst_port_id, src_chain_id);
+
+        let src_config = src_chain.config().map_err(LinkError::relayer)?;
+        let dst_config = dst_chain.config().map_err(LinkError::relayer)?;
+        let ics20_filter = src_config.packet_filter().ics20.clone();
+
+        if with_tx_confirmation {
+            pending_txs_src.set_fee_bump(src_config.fee_bump(), src_config.max_block_time());
+            pending_txs_dst.set_fee_bump(dst_config.fee_bump(), dst_config.max_block_time());
+        }
+
         Ok(Self {
             channel,
 
@@ -161,8 +226,24 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             dst_operational_data: Queue::new(),
 
             confirm_txes: with_tx_confirmation,
-            pending_txs_src: PendingTxs::new(src_chain, src_channel_id, src_port_id, dst_chain_id),
-            pending_txs_dst: PendingTxs::new(dst_chain, dst_channel_id, dst_port_id, src_chain_id),
+            pending_txs_src,
+            pending_txs_dst,
+            journal_enabled: false,
+            fee_policy: None,
+            ics20_filter,
+
+            rate_limiter: None,
+            src_rate_limit: src_config.rate_limit().clone(),
+            dst_rate_limit: dst_config.rate_limit().clone(),
+            src_default_gas: src_config.default_gas(),
+            dst_default_gas: dst_config.default_gas(),
+
+            src_fee_priority: src_config.fee_priority().clone(),
+            dst_fee_priority: dst_config.fee_priority().clone(),
+            packet_fees: RwArc::new_lock(HashMap::new()),
+
+            src_hop_proofs: RwArc::new_lock(HashMap::new()),
+            dst_hop_proofs: RwArc::new_lock(HashMap::new()),
 
             max_memo_size: link_parameters.max_memo_size,
             max_receiver_size: link_parameters.max_receiver_size,
@@ -171,6 +252,141 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         })
     }
 
+    /// Record the pending transactions of this path in a transaction journal
+    /// kept in the given data folder, and restore the pending transactions
+    /// which a previous run of the relayer recorded but did not resolve.
+    ///
+    /// Returns the number of restored pending transactions.
+    pub fn recover_from_journal(&mut self, data_dir: &Path) -> Result<usize, LinkError> {
+        let path = data_dir
+            .join("journal")
+            .join(self.src_chain().id().as_str())
+            .join(self.src_port_id().as_str())
+            .join(format!("{}.jsonl", self.src_channel_id()));
+
+        let (journal, submissions) = Journal::open(path)?;
+        let journal = Arc::new(journal);
+
+        self.pending_txs_src.set_journal(journal.clone());
+        self.pending_txs_dst.set_journal(journal);
+        self.journal_enabled = true;
+
+        let restored = submissions.len();
+        let connection_delay = self.channel.connection_delay;
+
+        for submission in submissions {
+            match submission.target {
+                OperationalDataTarget::Source => self
+                    .pending_txs_src
+                    .restore_pending_tx(submission, connection_delay),
+                OperationalDataTarget::Destination => self
+                    .pending_txs_dst
+                    .restore_pending_tx(submission, connection_delay),
+            }
+        }
+
+        Ok(restored)
+    }
+
+    /// Only relay the packets whose ICS-29 fees satisfy the given policy.
+    ///
+    /// The policy is applied to the packets found when clearing and to the
+    /// acknowledgements, the event source worker applies it to the `SendPacket` events.
+    pub fn set_fee_policy(&mut self, fee_policy: FeePolicy) {
+        self.fee_policy = Some(fee_policy);
+    }
+
+    /// Defer the operational data which exceed the rate limits of the chain it targets,
+    /// counted by the given rate limiter shared with the other packet workers.
+    pub fn set_rate_limiter(&mut self, rate_limiter: RateLimiter) {
+        self.rate_limiter = Some(rate_limiter);
+    }
+
+    /// Whether the given operational data can be relayed without exceeding
+    /// the rate limits of the chain it targets.
+    ///
+    /// The gas it costs is estimated as the default gas of the chain per message.
+    fn acquire_rate_limit(&self, od: &OperationalData) -> bool {
+        let Some(rate_limiter) = &self.rate_limiter else {
+            return true;
+        };
+
+        let packets = od.batch.len() as u64;
+
+        match od.target {
+            OperationalDataTarget::Source => rate_limiter.try_acquire(
+                &self.src_chain().id(),
+                self.src_port_id(),
+                self.src_channel_id(),
+                &self.src_rate_limit,
+                Cost {
+                    packets,
+                    gas: packets * self.src_default_gas,
+                },
+            ),
+            OperationalDataTarget::Destination => rate_limiter.try_acquire(
+                &self.dst_chain().id(),
+                self.dst_port_id(),
+                self.dst_channel_id(),
+                &self.dst_rate_limit,
+                Cost {
+                    packets,
+                    gas: packets * self.dst_default_gas,
+                },
+            ),
+        }
+    }
+
+    /// Record the number of packets to the given chain deferred by its rate limits.
+    fn record_deferred(&self, target: OperationalDataTarget, packets: u64) {
+        let Some(rate_limiter) = &self.rate_limiter else {
+            return;
+        };
+
+        match target {
+            OperationalDataTarget::Source => {
+                rate_limiter.set_deferred(
+                    &self.src_chain().id(),
+                    self.src_port_id(),
+                    self.src_channel_id(),
+                    packets,
+                );
+
+                telemetry!(
+                    deferred_packets,
+                    &self.src_chain().id(),
+                    &self.dst_chain().id(),
+                    self.src_channel_id(),
+                    self.src_port_id(),
+                    packets
+                );
+            }
+            OperationalDataTarget::Destination => {
+                rate_limiter.set_deferred(
+                    &self.dst_chain().id(),
+                    self.dst_port_id(),
+                    self.dst_channel_id(),
+                    packets,
+                );
+
+                telemetry!(
+                    deferred_packets,
+                    &self.dst_chain().id(),
+                    &self.src_chain().id(),
+                    self.dst_channel_id(),
+                    self.dst_port_id(),
+                    packets
+                );
+            }
+        }
+    }
+
+    /// Whether the given packet event is already handled by a pending transaction.
+    fn packet_event_pending(&self, event: &IbcEvent) -> bool {
+        self.pending_txs_src.contains_packet_event(event)
+            || self.pending_txs_dst.contains_packet_event(event)
+    }
+
     pub fn src_chain(&self) -> &ChainA {
         self.channel.src_chain()
     }
@@ -329,20 +545,115 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         self.channel.ordering == Ordering::Ordered
     }
 
+    /// Builds the messages updating the client on the destination chain, for
+    /// verifying the proofs of the source chain at the given height.
+    ///
+    /// For a multi-hop channel, the client tracks the last intermediate chain,
+    /// and is updated to the height of the proofs of the intermediate chains.
     pub fn build_update_client_on_dst(&self, height: Height) -> Result<Vec<Any>, LinkError> {
+        let height = match self.src_hop_proofs(height)? {
+            Some(hop_proofs) => hop_proofs.height(),
+            None => height,
+        };
+
         let client = self.restore_dst_client();
         client
             .wait_and_build_update_client(height)
             .map_err(LinkError::client)
     }
 
+    /// Builds the messages updating the client on the source chain, for
+    /// verifying the proofs of the destination chain at the given height.
     pub fn build_update_client_on_src(&self, height: Height) -> Result<Vec<Any>, LinkError> {
+        let height = match self.dst_hop_proofs(height)? {
+            Some(hop_proofs) => hop_proofs.height(),
+            None => height,
+        };
+
         let client = self.restore_src_client();
         client
             .wait_and_build_update_client(height)
             .map_err(LinkError::client)
     }
 
+    /// The proofs of the intermediate chains of a multi-hop channel, which let the
+    /// destination chain verify the proofs of the source chain at the given height.
+    /// Returns `None` if the channel does not traverse intermediate chains.
+    fn src_hop_proofs(&self, height: Height) -> Result<Option<HopProofs>, LinkError> {
+        Self::hop_proofs(
+            &self.src_hop_proofs,
+            self.src_chain(),
+            self.channel.a_side.hops(),
+            height,
+        )
+    }
+
+    /// The proofs of the intermediate chains of a multi-hop channel, which let the
+    /// source chain verify the proofs of the destination chain at the given height.
+    fn dst_hop_proofs(&self, height: Height) -> Result<Option<HopProofs>, LinkError> {
+        Self::hop_proofs(
+            &self.dst_hop_proofs,
+            self.dst_chain(),
+            self.channel.b_side.hops(),
+            height,
+        )
+    }
+
+    fn hop_proofs<Chain: ChainHandle>(
+        cache: &RwArc<HashMap<Height, HopProofs>>,
+        chain: &Chain,
+        hops: &[ChannelHop<Chain>],
+        height: Height,
+    ) -> Result<Option<HopProofs>, LinkError> {
+        if hops.is_empty() {
+            return Ok(None);
+        }
+
+        if let Some(hop_proofs) = cache.acquire_read().get(&height) {
+            return Ok(Some(hop_proofs.clone()));
+        }
+
+        // Building the proofs updates the clients on the intermediate chains,
+        // so the proofs are reused by all the messages and the client update
+        // which rely on the proofs at the same height.
+        let hop_proofs = HopProofs::build(chain, hops, height).map_err(LinkError::multihop)?;
+
+        let mut cache = cache.acquire_write();
+        cache.insert(height, hop_proofs.clone());
+
+        while cache.len() > MAX_CACHED_HOP_PROOFS {
+            cache.pop_first();
+        }
+
+        Ok(Some(hop_proofs))
+    }
+
+    /// Turns the given proofs of the source chain into multi-hop proofs, for a
+    /// multi-hop channel, or into sentinel proofs, for a localhost channel.
+    fn src_multihop_proofs(&self, proofs: Proofs) -> Result<Proofs, LinkError> {
+        if self.dst_connection_id().is_localhost() {
+            return Ok(proofs.into_localhost());
+        }
+
+        match self.src_hop_proofs(proofs.height())? {
+            Some(hop_proofs) => hop_proofs.wrap(proofs).map_err(LinkError::multihop),
+            None => Ok(proofs),
+        }
+    }
+
+    /// Turns the given proofs of the destination chain into multi-hop proofs,
+    /// for a multi-hop channel, or into sentinel proofs, for a localhost channel.
+    fn dst_multihop_proofs(&self, proofs: Proofs) -> Result<Proofs, LinkError> {
+        if self.src_connection_id().is_localhost() {
+            return Ok(proofs.into_localhost());
+        }
+
+        match self.dst_hop_proofs(proofs.height())? {
+            Some(hop_proofs) => hop_proofs.wrap(proofs).map_err(LinkError::multihop),
+            None => Ok(proofs),
+        }
+    }
+
     fn build_chan_close_confirm_from_event(
         &self,
         event: &IbcEventWithHeight,
@@ -366,6 +677,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             .src_chain()
             .build_channel_proofs(self.src_port_id(), src_channel_id, event.height)
             .map_err(|e| LinkError::channel(ChannelError::channel_proof(e)))?;
+        let proofs = self.src_multihop_proofs(proofs)?;
 
         let counterparty_upgrade_sequence = self.src_channel(QueryHeight::Latest)?.upgrade_sequence;
 
@@ -404,6 +716,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 IbcEvent::WriteAcknowledgement(write_ack_ev) => {
                     if src_channel_id == write_ack_ev.dst_channel_id()
                         && self.src_port_id() == write_ack_ev.dst_port_id()
+                        && self.fee_policy_allows(&event_with_height.event)
                     {
                         result.push(event_with_height);
                     }
@@ -430,6 +743,53 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         TrackedEvents::new(result, tracking_id)
     }
 
+    /// Drops the packet events whose ICS-29 fees do not satisfy the fee policy, if any.
+    fn retain_by_fee_policy(&self, mut events: Vec<IbcEventWithHeight>) -> Vec<IbcEventWithHeight> {
+        if self.fee_policy.is_some() {
+            events.retain(|event_with_height| self.fee_policy_allows(&event_with_height.event));
+        }
+
+        events
+    }
+
+    /// Whether the ICS-29 fees of the packet of the given `SendPacket` or
+    /// `WriteAcknowledgement` event satisfy the fee policy, if any.
+    ///
+    /// Packets which are not incentivized have no fees.
+    fn fee_policy_allows(&self, event: &IbcEvent) -> bool {
+        let Some(fee_policy) = &self.fee_policy else {
+            return true;
+        };
+
+        // The fees are escrowed on the chain which sent the packet
+        let fee = match event {
+            IbcEvent::SendPacket(ev) => query_packet_fee(self.src_chain(), &ev.packet),
+            IbcEvent::WriteAcknowledgement(ev) => query_packet_fee(self.dst_chain(), &ev.packet),
+            _ => return true,
+        };
+
+        if fee_policy.should_relay(&fee) {
+            return true;
+        }
+
+        debug!(%event, "packet fees do not satisfy the fee policy, skipping");
+
+        if let Some(packet) = event.packet() {
+            telemetry!(
+                filtered_packets,
+                &self.src_chain().id(),
+                &self.dst_chain().id(),
+                &packet.source_channel,
+                &packet.destination_channel,
+                &packet.source_port,
+                &packet.destination_port,
+                1
+            );
+        }
+
+        false
+    }
+
     fn relay_pending_packets(
         &self,
         height: Option<Height>,
@@ -496,6 +856,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         )
         .entered();
 
+        self.record_packet_fees(&batch.events);
+
         // Collect relevant events from the incoming batch & adjust their height.
         let events = self.filter_relaying_events(batch.events, batch.tracking_id);
 
@@ -602,6 +964,13 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 }
             }
 
+            // Packets submitted before a restart are only tracked by the pending
+            // transactions restored from the journal, skip them to avoid relaying them twice.
+            if self.journal_enabled && self.packet_event_pending(&event_with_height.event) {
+                debug!(event = %event_with_height, "packet event is handled by a pending transaction");
+                continue;
+            }
+
             let (dst_msg, src_msg) = match &event_with_height.event {
                 IbcEvent::CloseInitChannel(_) => (
                     self.build_chan_close_confirm_from_event(event_with_height)?,
@@ -1212,6 +1581,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 }
             });
 
+            let events_chunk = self.retain_by_fee_policy(events_chunk);
+
             self.events_to_operational_data(TrackedEvents::new(events_chunk, tracking_id))?;
         }
 
@@ -1282,6 +1653,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             query_write_ack_events,
         ) {
             telemetry!(self.record_cleared_acknowledgments(events_chunk.iter()));
+
+            let events_chunk = self.retain_by_fee_policy(events_chunk);
+
             self.events_to_operational_data(TrackedEvents::new(events_chunk, tracking_id))?;
         }
 
@@ -1299,6 +1673,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 height,
             )
             .map_err(|e| LinkError::packet_proofs_constructor(self.src_chain().id(), e))?;
+        let proofs = self.src_multihop_proofs(proofs)?;
 
         let msg = MsgRecvPacket::new(packet.clone(), proofs.clone(), self.dst_signer()?);
 
@@ -1324,6 +1699,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 height,
             )
             .map_err(|e| LinkError::packet_proofs_constructor(self.src_chain().id(), e))?;
+        let proofs = self.src_multihop_proofs(proofs)?;
 
         let msg = MsgAcknowledgement::new(
             packet,
@@ -1374,6 +1750,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 height,
             )
             .map_err(|e| LinkError::packet_proofs_constructor(self.dst_chain().id(), e))?;
+        let proofs = self.dst_multihop_proofs(proofs)?;
 
         let msg = MsgTimeout::new(
             packet.clone(),
@@ -1423,6 +1800,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 height,
             )
             .map_err(|e| LinkError::packet_proofs_constructor(self.dst_chain().id(), e))?;
+        let proofs = self.dst_multihop_proofs(proofs)?;
 
         let counterparty_upgrade_sequence = self.src_channel(QueryHeight::Latest)?.upgrade_sequence;
 
@@ -1476,6 +1854,21 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         if timeout.is_some() {
             Ok((None, timeout))
+        } else if !check_ics20_content(&event.packet.data, &self.ics20_filter) {
+            // Only the packets yet to be received are filtered on their content, so that
+            // the timeouts and acknowledgements of the packets in flight are still relayed.
+            telemetry!(
+                filtered_packets,
+                &self.src_chain().id(),
+                &self.dst_chain().id(),
+                &event.packet.source_channel,
+                &event.packet.destination_channel,
+                &event.packet.source_port,
+                &event.packet.destination_port,
+                1
+            );
+
+            Ok((None, None))
         } else {
             Ok((self.build_recv_packet(&event.packet, height)?, None))
         }
@@ -1511,6 +1904,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         target_chain: OperationalDataTarget,
     ) -> Result<VecDeque<OperationalData>, (VecDeque<OperationalData>, LinkError)> {
         let mut unprocessed = VecDeque::new();
+        let mut deferred = 0;
 
         while let Some(od) = operations.next() {
             let elapsed_result = match target_chain {
@@ -1528,7 +1922,19 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
             match elapsed_result {
                 Ok(elapsed) => {
-                    if elapsed {
+                    if elapsed && !self.acquire_rate_limit(&od) {
+                        // The current piece of operational data exceeds the rate limits of the
+                        // target chain; defer it, along with all of the subsequent pieces of
+                        // operational data so that they are relayed in order, until the rate
+                        // limits allow it.
+                        deferred += od.batch.len() as u64;
+                        unprocessed.push_back(od);
+
+                        for od in operations.by_ref() {
+                            deferred += od.batch.len() as u64;
+                            unprocessed.push_back(od);
+                        }
+                    } else if elapsed {
                         // The current piece of operational data has elapsed; we can go ahead and
                         // attempt to relay it.
                         match self
@@ -1564,6 +1970,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             }
         }
 
+        self.record_deferred(target_chain, deferred);
+
         Ok(unprocessed)
     }
 
@@ -1572,10 +1980,19 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     /// operational data items to both the source and destination chains.
     ///
     /// Any operational data items that do not get successfully relayed are
-    /// dropped. Subsequent pending operational data items that went unprocessed
-    /// are queued up again for re-submission.
+    /// dropped. Subsequent pending operational data items that went unprocessed,
+    /// or that were deferred by the rate limits of their target chain, are queued
+    /// up again for re-submission.
     pub fn execute_schedule(&mut self) -> Result<(), LinkError> {
-        let src_od_iter = self.src_operational_data.take().into_iter();
+        let mut scheduled_packets = HashSet::new();
+
+        let src_od_iter = self
+            .prioritize(
+                self.src_operational_data.take(),
+                OperationalDataTarget::Source,
+                &mut scheduled_packets,
+            )
+            .into_iter();
 
         match self.execute_schedule_for_target_chain(src_od_iter, OperationalDataTarget::Source) {
             Ok(unprocessed_src_data) => self.src_operational_data = unprocessed_src_data.into(),
@@ -1585,7 +2002,13 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             }
         }
 
-        let dst_od_iter = self.dst_operational_data.take().into_iter();
+        let dst_od_iter = self
+            .prioritize(
+                self.dst_operational_data.take(),
+                OperationalDataTarget::Destination,
+                &mut scheduled_packets,
+            )
+            .into_iter();
 
         match self
             .execute_schedule_for_target_chain(dst_od_iter, OperationalDataTarget::Destination)
@@ -1597,9 +2020,139 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             }
         }
 
+        // Only keep the fees of the packets which are still scheduled
+        self.packet_fees
+            .acquire_write()
+            .retain(|packet, _| scheduled_packets.contains(packet));
+
         Ok(())
     }
 
+    /// Orders the given operational data, and the messages of each of them, by decreasing
+    /// value of their ICS-29 fees if fee priority is enabled on the target chain.
+    ///
+    /// The operational data which have been waiting for at least `max_wait` come first,
+    /// in their original order, so that the packets with low or no fees are not starved.
+    /// The packets of ordered channels are left in order.
+    fn prioritize(
+        &self,
+        operations: VecDeque<OperationalData>,
+        target: OperationalDataTarget,
+        scheduled_packets: &mut HashSet<(ChainId, Sequence)>,
+    ) -> VecDeque<OperationalData> {
+        let fee_priority = match target {
+            OperationalDataTarget::Source => &self.src_fee_priority,
+            OperationalDataTarget::Destination => &self.dst_fee_priority,
+        };
+
+        if !fee_priority.enabled || self.ordered_channel() {
+            return operations;
+        }
+
+        let mut valued = operations
+            .into_iter()
+            .map(|mut od| {
+                let mut msgs = od
+                    .batch
+                    .drain(..)
+                    .map(|msg| {
+                        let value = self.fee_value(&msg, target, fee_priority, scheduled_packets);
+                        (value, msg)
+                    })
+                    .collect::<Vec<_>>();
+
+                msgs.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+                let total = msgs.iter().fold(Amount::from(0u64), |total, (value, _)| {
+                    total.checked_add(*value).unwrap_or(total)
+                });
+
+                od.batch = msgs.into_iter().map(|(_, msg)| msg).collect();
+
+                (total, od)
+            })
+            .collect::<Vec<_>>();
+
+        valued.sort_by(|(a_value, a), (b_value, b)| {
+            let a_starved = a.age() >= fee_priority.max_wait;
+            let b_starved = b.age() >= fee_priority.max_wait;
+
+            match (a_starved, b_starved) {
+                (true, true) => core::cmp::Ordering::Equal,
+                (false, false) => b_value.cmp(a_value),
+                _ => b_starved.cmp(&a_starved),
+            }
+        });
+
+        valued.into_iter().map(|(_, od)| od).collect()
+    }
+
+    /// The value of the ICS-29 fee paid for relaying the given message: the `recv_fee`
+    /// for a `RecvPacket`, the `ack_fee` for an `Acknowledgement` and the `timeout_fee`
+    /// for a `Timeout`. The fees are taken from the `IncentivizedPacket` events seen
+    /// so far, or queried from the chain which sent the packet.
+    fn fee_value(
+        &self,
+        msg: &TransitMessage,
+        target: OperationalDataTarget,
+        fee_priority: &FeePriority,
+        scheduled_packets: &mut HashSet<(ChainId, Sequence)>,
+    ) -> Amount {
+        let (key, fee) = match &msg.event_with_height.event {
+            IbcEvent::SendPacket(ev) => {
+                let key = (self.src_chain().id(), ev.packet.sequence);
+                let fee =
+                    self.cached_packet_fee(&key, || query_packet_fee(self.src_chain(), &ev.packet));
+
+                match target {
+                    OperationalDataTarget::Destination => (key, fee.recv_fee),
+                    OperationalDataTarget::Source => (key, fee.timeout_fee),
+                }
+            }
+            IbcEvent::WriteAcknowledgement(ev) => {
+                let key = (self.dst_chain().id(), ev.packet.sequence);
+                let fee =
+                    self.cached_packet_fee(&key, || query_packet_fee(self.dst_chain(), &ev.packet));
+
+                (key, fee.ack_fee)
+            }
+            _ => return Amount::from(0u64),
+        };
+
+        scheduled_packets.insert(key);
+        fee_priority.value(&fee)
+    }
+
+    fn cached_packet_fee(&self, key: &(ChainId, Sequence), query: impl FnOnce() -> Fee) -> Fee {
+        if let Some(fee) = self.packet_fees.acquire_read().get(key) {
+            return fee.clone();
+        }
+
+        let fee = query();
+        self.packet_fees
+            .acquire_write()
+            .insert(key.clone(), fee.clone());
+        fee
+    }
+
+    /// Record the fees of the packets sent on the source channel end
+    /// found in `IncentivizedPacket` events, if fee priority is enabled.
+    fn record_packet_fees(&self, events: &[IbcEventWithHeight]) {
+        if !self.src_fee_priority.enabled && !self.dst_fee_priority.enabled {
+            return;
+        }
+
+        for event_with_height in events {
+            if let IbcEvent::IncentivizedPacket(ev) = &event_with_height.event {
+                if &ev.channel_id == self.src_channel_id() && &ev.port_id == self.src_port_id() {
+                    self.packet_fees
+                        .acquire_write()
+                        .insert((self.src_chain().id(), ev.sequence), ev.total_fee());
+                }
+            }
+        }
+    }
+
     /// Kicks off the process of relaying pending txs to the source and destination chains.
     ///
     /// See [`Resubmit::from_clear_interval`] for more info about the `resubmit` parameter.
@@ -1860,19 +2413,33 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok((elapsed_src_ods, elapsed_dst_ods))
     }
 
+    /// The client on the source chain, which tracks the destination chain,
+    /// or the last intermediate chain of a multi-hop channel.
     fn restore_src_client(&self) -> ForeignClient<ChainA, ChainB> {
+        let counterparty_chain = match self.channel.b_side.hops().last() {
+            Some(hop) => hop.chain.clone(),
+            None => self.dst_chain().clone(),
+        };
+
         ForeignClient::restore(
             self.src_client_id().clone(),
             self.src_chain().clone(),
-            self.dst_chain().clone(),
+            counterparty_chain,
         )
     }
 
+    /// The client on the destination chain, which tracks the source chain,
+    /// or the last intermediate chain of a multi-hop channel.
     fn restore_dst_client(&self) -> ForeignClient<ChainB, ChainA> {
+        let counterparty_chain = match self.channel.a_side.hops().last() {
+            Some(hop) => hop.chain.clone(),
+            None => self.src_chain().clone(),
+        };
+
         ForeignClient::restore(
             self.dst_client_id().clone(),
             self.dst_chain().clone(),
-            self.src_chain().clone(),
+            counterparty_chain,
         )
     }
 
@@ -1975,6 +2542,58 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     }
 }
 
+/// Queries the ICS-29 fees escrowed for the given packet on the chain which sent it.
+///
+/// Packets which are not incentivized, or whose fees cannot be queried, have no fees.
+fn query_packet_fee(chain: &impl ChainHandle, packet: &Packet) -> Fee {
+    let request = QueryIncentivizedPacketRequest {
+        packet_id: Some(RawPacketId {
+            port_id: packet.source_port.to_string(),
+            channel_id: packet.source_channel.to_string(),
+            sequence: packet.sequence.into(),
+        }),
+        query_height: 0,
+    };
+
+    let fees = chain
+        .query_incentivized_packet(request)
+        .map_err(|e| {
+            debug!(
+                "failed to query the fees of packet {}: {e}",
+                packet.sequence
+            )
+        })
+        .ok()
+        .and_then(|response| response.incentivized_packet)
+        .and_then(|fees| IdentifiedPacketFees::try_from(fees).ok());
+
+    fees.map(|fees| fees.total_fee()).unwrap_or_default()
+}
+
+fn check_ics20_content(data: &[u8], filter: &Ics20Filter) -> bool {
+    if filter.is_empty() {
+        return true;
+    }
+
+    match serde_json::from_slice::<RawPacketData>(data) {
+        Ok(packet_data) => {
+            match filter.rejection(&packet_data) {
+                None => true,
+                Some(rejection) => {
+                    warn!("ICS-20 packet rejected by the packet filter, not relaying packet: {rejection}");
+
+                    false
+                }
+            }
+        }
+        Err(e) => {
+            trace!("failed to decode ICS20 packet data with error `{e}`");
+
+            true
+        }
+    }
+}
+
 #[tracing::instrument(skip_all)]
 fn check_ics20_fields_size(
     data: &[u8],
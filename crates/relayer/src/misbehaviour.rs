@@ -1,9 +1,15 @@
 use serde::{Deserialize, Serialize};
 
 use ibc_proto::google::protobuf::Any;
+use ibc_relayer_types::clients::ics06_solomachine::misbehaviour::{
+    Misbehaviour as SoloMachineMisbehaviour, SOLOMACHINE_MISBEHAVIOUR_TYPE_URL,
+};
 use ibc_relayer_types::clients::ics07_tendermint::misbehaviour::{
     Misbehaviour as TmMisbehaviour, TENDERMINT_MISBEHAVIOR_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::client_message::{
+    Misbehaviour as WasmMisbehaviour, WASM_CLIENT_MESSAGE_TYPE_URL,
+};
 use ibc_relayer_types::core::ics02_client::error::Error;
 use ibc_relayer_types::core::ics02_client::header::AnyHeader;
 use ibc_relayer_types::core::ics02_client::misbehaviour::Misbehaviour;
@@ -21,18 +27,24 @@ pub struct MisbehaviourEvidence {
 #[allow(clippy::large_enum_variant)]
 pub enum AnyMisbehaviour {
     Tendermint(TmMisbehaviour),
+    SoloMachine(SoloMachineMisbehaviour),
+    Wasm(WasmMisbehaviour),
 }
 
 impl Misbehaviour for AnyMisbehaviour {
     fn client_id(&self) -> &ClientId {
         match self {
             Self::Tendermint(misbehaviour) => misbehaviour.client_id(),
+            Self::SoloMachine(misbehaviour) => misbehaviour.client_id(),
+            Self::Wasm(misbehaviour) => misbehaviour.client_id(),
         }
     }
 
     fn height(&self) -> Height {
         match self {
             Self::Tendermint(misbehaviour) => misbehaviour.height(),
+            Self::SoloMachine(misbehaviour) => misbehaviour.height(),
+            Self::Wasm(misbehaviour) => misbehaviour.height(),
         }
     }
 }
@@ -48,6 +60,10 @@ impl TryFrom<Any> for AnyMisbehaviour {
                 TmMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
             )),
 
+            SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::SoloMachine(raw.try_into()?)),
+
+            WASM_CLIENT_MESSAGE_TYPE_URL => Ok(AnyMisbehaviour::Wasm(raw.try_into()?)),
+
             _ => Err(Error::unknown_misbehaviour_type(raw.type_url)),
         }
     }
@@ -60,6 +76,8 @@ impl From<AnyMisbehaviour> for Any {
                 type_url: TENDERMINT_MISBEHAVIOR_TYPE_URL.to_string(),
                 value: misbehaviour.encode_vec(),
             },
+            AnyMisbehaviour::SoloMachine(misbehaviour) => misbehaviour.into(),
+            AnyMisbehaviour::Wasm(misbehaviour) => misbehaviour.into(),
         }
     }
 }
@@ -68,6 +86,8 @@ impl core::fmt::Display for AnyMisbehaviour {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
             AnyMisbehaviour::Tendermint(tm) => write!(f, "{tm}"),
+            AnyMisbehaviour::SoloMachine(solo) => write!(f, "{solo}"),
+            AnyMisbehaviour::Wasm(wasm) => write!(f, "{wasm}"),
         }
     }
 }
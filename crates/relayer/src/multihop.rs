@@ -0,0 +1,766 @@
+//! Relaying over multi-hop channels (ICS 033), ie. channels whose connection hops
+//! traverse intermediate chains between the two channel ends.
+//!
+//! The proofs of the state of one end of a multi-hop channel are verified by the
+//! other end through the clients of the intermediate chains. To build them, the
+//! relayer updates the client of each chain on the next chain along the path, and
+//! proves the consensus states and connection ends stored on the intermediate chains.
+
+use flex_error::define_error;
+use serde::Serialize;
+use tracing::debug;
+
+use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
+use ibc_relayer_types::core::ics04_channel::multihop::{MultihopProof, MultihopProofs};
+use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentProofBytes;
+use ibc_relayer_types::core::ics23_commitment::merkle::{apply_prefix, MerkleProof};
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId, ConnectionId};
+use ibc_relayer_types::core::ics24_host::path::{ClientConsensusStatePath, ConnectionsPath};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::proofs::{ProofError, Proofs};
+use ibc_relayer_types::Height;
+use tendermint_proto::Protobuf;
+
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{
+    IncludeProof, QueryClientStateRequest, QueryConnectionRequest, QueryConsensusStateRequest,
+    QueryHeight,
+};
+use crate::chain::tracking::TrackedMsgs;
+use crate::error::Error as RelayerError;
+use crate::foreign_client::{ForeignClient, ForeignClientError};
+
+define_error! {
+    MultihopError {
+        Relayer
+            [ RelayerError ]
+            |_| { "relayer error" },
+
+        Client
+            {
+                client_id: ClientId,
+                chain_id: ChainId,
+            }
+            [ ForeignClientError ]
+            | e | {
+                format_args!("failed to update client {0} on intermediate chain {1}",
+                    e.client_id, e.chain_id)
+            },
+
+        MalformedProof
+            [ ProofError ]
+            |_| { "malformed proof" },
+
+        MissingProof
+            { chain_id: ChainId }
+            | e | {
+                format_args!("missing proof in the query response from chain {0}",
+                    e.chain_id)
+            },
+
+        MissingConnectionHops
+            |_| { "channel has no connection hops" },
+
+        MissingCounterpartyConnection
+            {
+                connection_id: ConnectionId,
+                chain_id: ChainId,
+            }
+            | e | {
+                format_args!("missing counterparty of connection {0} on chain {1}",
+                    e.connection_id, e.chain_id)
+            },
+
+        UnexpectedCounterpartyChain
+            {
+                expected: ChainId,
+                actual: ChainId,
+            }
+            | e | {
+                format_args!("the connection hops of the multi-hop channel lead to chain {1} instead of chain {0}",
+                    e.expected, e.actual)
+            },
+
+        UnknownHopChain
+            { chain_id: ChainId }
+            | e | {
+                format_args!("no handle for the intermediate chain {0} of the multi-hop channel",
+                    e.chain_id)
+            },
+    }
+}
+
+/// An intermediate chain of a multi-hop channel, as seen from one end of the channel.
+#[derive(Clone, Debug, Serialize)]
+#[serde(bound(serialize = "(): Serialize"))]
+pub struct ChannelHop<Chain: ChainHandle> {
+    #[serde(skip)]
+    pub chain: Chain,
+    /// The client of the previous chain on the path from this end of the channel.
+    client_id: ClientId,
+    /// The connection towards this end of the channel, built on `client_id`.
+    connection_id: ConnectionId,
+    /// The connection towards the other end of the channel.
+    next_connection_id: ConnectionId,
+}
+
+impl<Chain: ChainHandle> ChannelHop<Chain> {
+    pub fn chain_id(&self) -> ChainId {
+        self.chain.id()
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    pub fn connection_id(&self) -> &ConnectionId {
+        &self.connection_id
+    }
+
+    pub fn next_connection_id(&self) -> &ConnectionId {
+        &self.next_connection_id
+    }
+
+    pub fn map_chain<ChainB: ChainHandle>(
+        self,
+        mapper: impl Fn(Chain) -> ChainB,
+    ) -> ChannelHop<ChainB> {
+        ChannelHop {
+            chain: mapper(self.chain),
+            client_id: self.client_id,
+            connection_id: self.connection_id,
+            next_connection_id: self.next_connection_id,
+        }
+    }
+}
+
+/// The intermediate chains of a multi-hop channel, resolved
+/// from the connection hops of one end of the channel.
+#[derive(Clone, Debug)]
+pub struct ResolvedHops<Chain: ChainHandle> {
+    /// The first connection hop, on the chain of the channel end.
+    pub connection: ConnectionEnd,
+    /// The intermediate chains, from the chain of the channel end to the counterparty chain.
+    pub hops: Vec<ChannelHop<Chain>>,
+    pub counterparty_chain_id: ChainId,
+    /// The client of the last intermediate chain on the counterparty chain.
+    pub counterparty_client_id: ClientId,
+    /// The last connection hop, on the counterparty chain.
+    pub counterparty_connection_id: ConnectionId,
+}
+
+impl<Chain: ChainHandle> ResolvedHops<Chain> {
+    /// The connection hops of the channel end on the counterparty chain.
+    pub fn counterparty_connection_hops(&self) -> Vec<ConnectionId> {
+        core::iter::once(self.counterparty_connection_id.clone())
+            .chain(self.hops.iter().rev().map(|hop| hop.connection_id.clone()))
+            .collect()
+    }
+}
+
+/// The connection hops of a channel end, given its first connection hop
+/// and the intermediate chains towards the counterparty chain.
+pub fn connection_hops<Chain: ChainHandle>(
+    connection_id: &ConnectionId,
+    hops: &[ChannelHop<Chain>],
+) -> Vec<ConnectionId> {
+    core::iter::once(connection_id.clone())
+        .chain(hops.iter().map(|hop| hop.next_connection_id.clone()))
+        .collect()
+}
+
+/// Follows the given connection hops of a channel end on `chain` through the
+/// intermediate chains, whose handles are looked up with `hop_chain`.
+pub fn resolve_hops<Chain: ChainHandle>(
+    chain: &Chain,
+    connection_hops: &[ConnectionId],
+    mut hop_chain: impl FnMut(&ChainId) -> Option<Chain>,
+) -> Result<ResolvedHops<Chain>, MultihopError> {
+    let (first_connection_id, next_connection_ids) = connection_hops
+        .split_first()
+        .ok_or_else(MultihopError::missing_connection_hops)?;
+
+    let first_connection = query_connection(chain, first_connection_id)?;
+    let mut chain_id = client_chain_id(chain, first_connection.client_id())?;
+    let mut connection = (first_connection_id.clone(), first_connection.clone());
+    let mut previous_chain_id = chain.id();
+    let mut hops = Vec::with_capacity(next_connection_ids.len());
+
+    for next_connection_id in next_connection_ids {
+        let hop_chain = hop_chain(&chain_id)
+            .ok_or_else(|| MultihopError::unknown_hop_chain(chain_id.clone()))?;
+
+        let (connection_id, connection_end) = &connection;
+        let hop = ChannelHop {
+            chain: hop_chain,
+            client_id: connection_end.counterparty().client_id().clone(),
+            connection_id: counterparty_connection_id(
+                connection_id,
+                connection_end,
+                &previous_chain_id,
+            )?,
+            next_connection_id: next_connection_id.clone(),
+        };
+
+        let next_connection = query_connection(&hop.chain, next_connection_id)?;
+        previous_chain_id = chain_id;
+        chain_id = client_chain_id(&hop.chain, next_connection.client_id())?;
+        connection = (next_connection_id.clone(), next_connection);
+        hops.push(hop);
+    }
+
+    let (connection_id, connection_end) = &connection;
+
+    Ok(ResolvedHops {
+        connection: first_connection,
+        hops,
+        counterparty_chain_id: chain_id,
+        counterparty_client_id: connection_end.counterparty().client_id().clone(),
+        counterparty_connection_id: counterparty_connection_id(
+            connection_id,
+            connection_end,
+            &previous_chain_id,
+        )?,
+    })
+}
+
+/// Resolves the intermediate chains of a multi-hop channel between `a_chain` and
+/// `b_chain` from the given connection hops of its end on `a_chain`, as seen from
+/// both ends of the channel.
+pub fn resolve_channel_hops<ChainA: ChainHandle, ChainB: ChainHandle>(
+    a_chain: &ChainA,
+    b_chain: &ChainB,
+    a_connection_hops: &[ConnectionId],
+    a_hop_chain: impl FnMut(&ChainId) -> Option<ChainA>,
+    b_hop_chain: impl FnMut(&ChainId) -> Option<ChainB>,
+) -> Result<(ResolvedHops<ChainA>, ResolvedHops<ChainB>), MultihopError> {
+    let a_hops = resolve_hops(a_chain, a_connection_hops, a_hop_chain)?;
+
+    if a_hops.counterparty_chain_id != b_chain.id() {
+        return Err(MultihopError::unexpected_counterparty_chain(
+            b_chain.id(),
+            a_hops.counterparty_chain_id,
+        ));
+    }
+
+    let b_hops = resolve_hops(b_chain, &a_hops.counterparty_connection_hops(), b_hop_chain)?;
+
+    Ok((a_hops, b_hops))
+}
+
+/// The handles of the intermediate chains of a multi-hop channel end on `chain`,
+/// looked up with `hop_chain`, along with the identifier of the counterparty chain.
+pub fn hop_chains<Chain: ChainHandle>(
+    chain: &Chain,
+    connection_hops: &[ConnectionId],
+    hop_chain: impl FnMut(&ChainId) -> Option<Chain>,
+) -> Result<(Vec<Chain>, ChainId), MultihopError> {
+    let resolved = resolve_hops(chain, connection_hops, hop_chain)?;
+    let chains = resolved.hops.into_iter().map(|hop| hop.chain).collect();
+
+    Ok((chains, resolved.counterparty_chain_id))
+}
+
+/// Looks up the handle of an intermediate chain among the given ones.
+pub fn find_hop_chain<Chain: ChainHandle>(
+    hop_chains: &[Chain],
+    chain_id: &ChainId,
+) -> Option<Chain> {
+    hop_chains
+        .iter()
+        .find(|chain| chain.id() == *chain_id)
+        .cloned()
+}
+
+fn query_connection<Chain: ChainHandle>(
+    chain: &Chain,
+    connection_id: &ConnectionId,
+) -> Result<ConnectionEnd, MultihopError> {
+    let (connection, _) = chain
+        .query_connection(
+            QueryConnectionRequest {
+                connection_id: connection_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(MultihopError::relayer)?;
+
+    Ok(connection)
+}
+
+fn client_chain_id<Chain: ChainHandle>(
+    chain: &Chain,
+    client_id: &ClientId,
+) -> Result<ChainId, MultihopError> {
+    let (client_state, _) = chain
+        .query_client_state(
+            QueryClientStateRequest {
+                client_id: client_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(MultihopError::relayer)?;
+
+    Ok(client_state.chain_id())
+}
+
+fn counterparty_connection_id(
+    connection_id: &ConnectionId,
+    connection: &ConnectionEnd,
+    chain_id: &ChainId,
+) -> Result<ConnectionId, MultihopError> {
+    connection
+        .counterparty()
+        .connection_id()
+        .cloned()
+        .ok_or_else(|| {
+            MultihopError::missing_counterparty_connection(connection_id.clone(), chain_id.clone())
+        })
+}
+
+/// The proofs of the consensus states and connection ends stored on the
+/// intermediate chains of a multi-hop channel, which let the destination
+/// chain verify the proofs of the source chain at a given height.
+#[derive(Clone, Debug)]
+pub struct HopProofs {
+    connection_proofs: Vec<MultihopProof>,
+    consensus_proofs: Vec<MultihopProof>,
+    height: Height,
+}
+
+impl HopProofs {
+    /// Builds the proofs for verifying proofs of `src_chain` at `height`, ie. proofs
+    /// checked against the consensus state of `src_chain` at `height`.
+    ///
+    /// Along the way, the client of each chain is updated on the next intermediate
+    /// chain, so that each intermediate chain holds the consensus state needed to
+    /// verify the proofs of the previous chain.
+    pub fn build<Chain: ChainHandle>(
+        src_chain: &Chain,
+        hops: &[ChannelHop<Chain>],
+        height: Height,
+    ) -> Result<Self, MultihopError> {
+        let mut connection_proofs = Vec::with_capacity(hops.len());
+        let mut consensus_proofs = Vec::with_capacity(hops.len());
+        let mut previous_chain = src_chain.clone();
+        let mut height = height;
+
+        for hop in hops {
+            update_client(hop, &previous_chain, height)?;
+
+            let query_height = hop
+                .chain
+                .query_latest_height()
+                .map_err(MultihopError::relayer)?;
+
+            let prefix = hop
+                .chain
+                .query_commitment_prefix()
+                .map_err(MultihopError::relayer)?;
+
+            let (consensus_state, consensus_state_proof) = hop
+                .chain
+                .query_consensus_state(
+                    QueryConsensusStateRequest {
+                        client_id: hop.client_id.clone(),
+                        consensus_height: height,
+                        query_height: QueryHeight::Specific(query_height),
+                    },
+                    IncludeProof::Yes,
+                )
+                .map_err(MultihopError::relayer)?;
+
+            let consensus_state_path = ClientConsensusStatePath {
+                client_id: hop.client_id.clone(),
+                epoch: height.revision_number(),
+                height: height.revision_height(),
+            };
+
+            consensus_proofs.push(MultihopProof {
+                proof: proof_bytes(&hop.chain, consensus_state_proof)?,
+                value: consensus_state.encode_vec(),
+                prefixed_key: apply_prefix(&prefix, vec![consensus_state_path.to_string()])
+                    .key_path,
+            });
+
+            let (connection, connection_proof) = hop
+                .chain
+                .query_connection(
+                    QueryConnectionRequest {
+                        connection_id: hop.connection_id.clone(),
+                        height: QueryHeight::Specific(query_height),
+                    },
+                    IncludeProof::Yes,
+                )
+                .map_err(MultihopError::relayer)?;
+
+            let connection_path = ConnectionsPath(hop.connection_id.clone());
+
+            connection_proofs.push(MultihopProof {
+                proof: proof_bytes(&hop.chain, connection_proof)?,
+                value: connection.encode_vec(),
+                prefixed_key: apply_prefix(&prefix, vec![connection_path.to_string()]).key_path,
+            });
+
+            previous_chain = hop.chain.clone();
+            height = query_height.increment();
+        }
+
+        Ok(Self {
+            connection_proofs,
+            consensus_proofs,
+            height,
+        })
+    }
+
+    /// The height of the consensus state of the last intermediate chain
+    /// which the destination chain needs to verify the proofs.
+    pub fn height(&self) -> Height {
+        self.height
+    }
+
+    /// Turns the given proofs of the source chain into multi-hop proofs, to be
+    /// verified against the consensus state of the last intermediate chain.
+    pub fn wrap(&self, proofs: Proofs) -> Result<Proofs, MultihopError> {
+        let wrap = |key_proof: &CommitmentProofBytes| {
+            CommitmentProofBytes::try_from(MultihopProofs {
+                key_proof: key_proof.clone(),
+                connection_proofs: self.connection_proofs.clone(),
+                consensus_proofs: self.consensus_proofs.clone(),
+            })
+        };
+
+        Proofs::new(
+            wrap(proofs.object_proof()).map_err(MultihopError::malformed_proof)?,
+            proofs.client_proof().cloned(),
+            proofs.consensus_proof().cloned(),
+            proofs.host_consensus_state_proof().cloned(),
+            proofs
+                .other_proof()
+                .map(wrap)
+                .transpose()
+                .map_err(MultihopError::malformed_proof)?,
+            self.height,
+        )
+        .map_err(MultihopError::malformed_proof)
+    }
+}
+
+/// Updates the client of `previous_chain` on the chain of `hop` to `height`, if needed.
+fn update_client<Chain: ChainHandle>(
+    hop: &ChannelHop<Chain>,
+    previous_chain: &Chain,
+    height: Height,
+) -> Result<(), MultihopError> {
+    let client = ForeignClient::restore(
+        hop.client_id.clone(),
+        hop.chain.clone(),
+        previous_chain.clone(),
+    );
+
+    let client_error = |e| MultihopError::client(hop.client_id.clone(), hop.chain_id(), e);
+
+    let msgs = client
+        .wait_and_build_update_client(height)
+        .map_err(client_error)?;
+
+    if msgs.is_empty() {
+        return Ok(());
+    }
+
+    debug!(
+        client = %hop.client_id,
+        chain = %hop.chain_id(),
+        %height,
+        "updating client on intermediate chain"
+    );
+
+    let events = hop
+        .chain
+        .send_messages_and_wait_commit(TrackedMsgs::new_static(msgs, "update client"))
+        .map_err(MultihopError::relayer)?;
+
+    match events
+        .into_iter()
+        .find(|event| matches!(event.event, IbcEvent::ChainError(_)))
+    {
+        Some(error) => Err(client_error(ForeignClientError::chain_error_event(
+            hop.chain_id(),
+            error.event,
+        ))),
+        None => Ok(()),
+    }
+}
+
+fn proof_bytes<Chain: ChainHandle>(
+    chain: &Chain,
+    proof: Option<MerkleProof>,
+) -> Result<CommitmentProofBytes, MultihopError> {
+    let proof = proof.ok_or_else(|| MultihopError::missing_proof(chain.id()))?;
+
+    CommitmentProofBytes::try_from(proof).map_err(MultihopError::malformed_proof)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use ibc_relayer_types::clients::ics07_tendermint::client_state::{
+        AllowUpdate, ClientState as TmClientState,
+    };
+    use ibc_relayer_types::core::ics02_client::trust_threshold::TrustThreshold;
+    use ibc_relayer_types::core::ics03_connection::connection::{Counterparty, State};
+    use ibc_relayer_types::core::ics03_connection::version::get_compatible_versions;
+    use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
+    use tracing::Span;
+
+    use crate::chain::handle::{BaseChainHandle, ChainRequest};
+    use crate::client_state::AnyClientState;
+
+    /// A chain runtime serving the given connections and clients,
+    /// identified by their client identifier and the chain they track.
+    fn chain(
+        chain_id: &str,
+        connections: Vec<(&str, ConnectionEnd)>,
+        clients: Vec<(&str, &str)>,
+    ) -> BaseChainHandle {
+        let connections: HashMap<ConnectionId, ConnectionEnd> = connections
+            .into_iter()
+            .map(|(id, connection)| (id.parse().unwrap(), connection))
+            .collect();
+
+        let clients: HashMap<ClientId, AnyClientState> = clients
+            .into_iter()
+            .map(|(id, chain_id)| (id.parse().unwrap(), client_state(chain_id)))
+            .collect();
+
+        let (sender, receiver) = crossbeam_channel::unbounded::<(Span, ChainRequest)>();
+
+        // The requests which are not served fail once their reply channel is dropped
+        std::thread::spawn(move || {
+            for (_, request) in receiver {
+                match request {
+                    ChainRequest::QueryConnection {
+                        request, reply_to, ..
+                    } => {
+                        if let Some(connection) = connections.get(&request.connection_id) {
+                            let _ = reply_to.send(Ok((connection.clone(), None)));
+                        }
+                    }
+                    ChainRequest::QueryClientState {
+                        request, reply_to, ..
+                    } => {
+                        if let Some(client_state) = clients.get(&request.client_id) {
+                            let _ = reply_to.send(Ok((client_state.clone(), None)));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        });
+
+        BaseChainHandle::new(ChainId::from_string(chain_id), sender)
+    }
+
+    fn client_state(chain_id: &str) -> AnyClientState {
+        let client_state = TmClientState::new(
+            ChainId::from_string(chain_id),
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(64000),
+            Duration::from_secs(128000),
+            Duration::from_millis(3000),
+            Height::new(1, 10).unwrap(),
+            Default::default(),
+            Vec::new(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+        )
+        .unwrap();
+
+        AnyClientState::Tendermint(client_state)
+    }
+
+    fn connection(
+        client_id: &str,
+        counterparty_client_id: &str,
+        counterparty_connection_id: &str,
+    ) -> ConnectionEnd {
+        ConnectionEnd::new(
+            State::Open,
+            client_id.parse().unwrap(),
+            Counterparty::new(
+                counterparty_client_id.parse().unwrap(),
+                Some(counterparty_connection_id.parse().unwrap()),
+                CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap(),
+            ),
+            get_compatible_versions(),
+            Duration::ZERO,
+        )
+    }
+
+    /// A multi-hop channel end on chain A, over connection-0 to chain B
+    /// and connection-2 from chain B to chain C.
+    fn chains() -> (BaseChainHandle, BaseChainHandle) {
+        let a_chain = chain(
+            "chain-a",
+            vec![(
+                "connection-0",
+                connection("07-tendermint-0", "07-tendermint-1", "connection-1"),
+            )],
+            vec![("07-tendermint-0", "chain-b")],
+        );
+
+        let b_chain = chain(
+            "chain-b",
+            vec![(
+                "connection-2",
+                connection("07-tendermint-2", "07-tendermint-3", "connection-3"),
+            )],
+            vec![("07-tendermint-2", "chain-c")],
+        );
+
+        (a_chain, b_chain)
+    }
+
+    fn connection_hops(ids: &[&str]) -> Vec<ConnectionId> {
+        ids.iter().map(|id| id.parse().unwrap()).collect()
+    }
+
+    fn proof_bytes(byte: u8) -> CommitmentProofBytes {
+        CommitmentProofBytes::try_from(vec![byte]).unwrap()
+    }
+
+    fn hop_proof(byte: u8) -> MultihopProof {
+        MultihopProof {
+            proof: proof_bytes(byte),
+            value: vec![byte],
+            prefixed_key: vec!["ibc".to_string(), format!("key-{byte}")],
+        }
+    }
+
+    #[test]
+    fn resolves_the_intermediate_chains() {
+        let (a_chain, b_chain) = chains();
+        let hops = connection_hops(&["connection-0", "connection-2"]);
+
+        let resolved = resolve_hops(&a_chain, &hops, |chain_id| {
+            (*chain_id == b_chain.id()).then(|| b_chain.clone())
+        })
+        .unwrap();
+
+        assert_eq!(resolved.hops.len(), 1);
+
+        let hop = &resolved.hops[0];
+        assert_eq!(hop.chain_id(), b_chain.id());
+        assert_eq!(hop.client_id().as_str(), "07-tendermint-1");
+        assert_eq!(hop.connection_id().as_str(), "connection-1");
+        assert_eq!(hop.next_connection_id().as_str(), "connection-2");
+
+        assert_eq!(resolved.counterparty_chain_id.as_str(), "chain-c");
+        assert_eq!(resolved.counterparty_client_id.as_str(), "07-tendermint-3");
+        assert_eq!(resolved.counterparty_connection_id.as_str(), "connection-3");
+        assert_eq!(
+            resolved.counterparty_connection_hops(),
+            connection_hops(&["connection-3", "connection-1"])
+        );
+        assert_eq!(super::connection_hops(&hops[0], &resolved.hops), hops);
+
+        let (hop_chains, counterparty_chain_id) = hop_chains(&a_chain, &hops, |chain_id| {
+            find_hop_chain(std::slice::from_ref(&b_chain), chain_id)
+        })
+        .unwrap();
+
+        assert_eq!(
+            hop_chains
+                .iter()
+                .map(|chain| chain.id())
+                .collect::<Vec<_>>(),
+            vec![b_chain.id()]
+        );
+        assert_eq!(counterparty_chain_id.as_str(), "chain-c");
+    }
+
+    #[test]
+    fn fails_on_unknown_intermediate_chain() {
+        let (a_chain, _) = chains();
+        let hops = connection_hops(&["connection-0", "connection-2"]);
+
+        let error = resolve_hops(&a_chain, &hops, |_| None).unwrap_err();
+
+        assert!(matches!(
+            error.detail(),
+            MultihopErrorDetail::UnknownHopChain(e) if e.chain_id.as_str() == "chain-b"
+        ));
+    }
+
+    #[test]
+    fn fails_without_connection_hops() {
+        let (a_chain, _) = chains();
+
+        let error = resolve_hops(&a_chain, &[], |_| None).unwrap_err();
+
+        assert!(matches!(
+            error.detail(),
+            MultihopErrorDetail::MissingConnectionHops(_)
+        ));
+    }
+
+    #[test]
+    fn no_hop_proofs_without_intermediate_chains() {
+        let (a_chain, _) = chains();
+        let height = Height::new(1, 10).unwrap();
+
+        let hop_proofs = HopProofs::build(&a_chain, &[], height).unwrap();
+
+        assert_eq!(hop_proofs.height(), height);
+        assert!(hop_proofs.connection_proofs.is_empty());
+        assert!(hop_proofs.consensus_proofs.is_empty());
+    }
+
+    #[test]
+    fn wraps_proofs_into_multihop_proofs() {
+        let hop_proofs = HopProofs {
+            connection_proofs: vec![hop_proof(3)],
+            consensus_proofs: vec![hop_proof(4)],
+            height: Height::new(2, 20).unwrap(),
+        };
+
+        let proofs = Proofs::new(
+            proof_bytes(1),
+            Some(proof_bytes(5)),
+            None,
+            None,
+            Some(proof_bytes(2)),
+            Height::new(1, 10).unwrap(),
+        )
+        .unwrap();
+
+        let wrapped = hop_proofs.wrap(proofs).unwrap();
+
+        let multihop_proofs = |key_proof| MultihopProofs {
+            key_proof,
+            connection_proofs: vec![hop_proof(3)],
+            consensus_proofs: vec![hop_proof(4)],
+        };
+
+        assert_eq!(wrapped.height(), Height::new(2, 20).unwrap());
+        assert_eq!(
+            MultihopProofs::try_from(wrapped.object_proof()).unwrap(),
+            multihop_proofs(proof_bytes(1))
+        );
+        assert_eq!(
+            MultihopProofs::try_from(wrapped.other_proof().unwrap()).unwrap(),
+            multihop_proofs(proof_bytes(2))
+        );
+
+        // The proofs which are not verified through the intermediate chains are left as is
+        assert_eq!(wrapped.client_proof(), Some(&proof_bytes(5)));
+    }
+}
@@ -380,6 +380,14 @@ impl Object {
             .map_err(ObjectError::supervisor)?
             .client;
 
+        // The localhost client is updated by the chain itself at every block
+        if client.client_id.is_localhost() {
+            return Err(ObjectError::refresh_not_required(
+                client.client_id,
+                chain.id(),
+            ));
+        }
+
         Ok(Client {
             dst_client_id: client.client_id.clone(),
             dst_chain_id: chain.id(), // The object's destination is the chain hosting the client
@@ -0,0 +1,325 @@
+//! Governance proposals for recovering expired or frozen clients with a substitute client.
+
+use flex_error::define_error;
+use serde::Serialize;
+
+use tendermint::abci::Event as AbciEvent;
+use tendermint::Hash as TxHash;
+use tendermint_rpc::{Client, HttpClient};
+
+use ibc_proto::cosmos::gov::v1::MsgSubmitProposal;
+use ibc_proto::cosmos::gov::v1beta1::MsgSubmitProposal as LegacyMsgSubmitProposal;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::client::v1::{ClientUpdateProposal, MsgRecoverClient};
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+
+use crate::chain::cosmos::config::CosmosSdkConfig;
+use crate::chain::cosmos::wait::wait_tx_succeed;
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{IncludeProof, QueryClientStateRequest, QueryHeight};
+use crate::chain::tracking::TrackedMsgs;
+use crate::client_state::AnyClientState;
+use crate::config::ChainConfig;
+use crate::error::Error;
+use crate::foreign_client::{extract_client_id, CreateOptions, ForeignClient, ForeignClientError};
+use crate::util::block_on;
+use crate::util::compat_mode::compat_mode_from_version;
+use crate::HERMES_VERSION;
+
+const SUBMIT_PROPOSAL_EVENT: &str = "submit_proposal";
+const PROPOSAL_ID_ATTRIBUTE_KEY: &str = "proposal_id";
+
+define_error! {
+    RecoverClientError {
+        Query
+            [ Error ]
+            |_| { "error during a query" },
+
+        Key
+            [ Error ]
+            |_| { "key error" },
+
+        Submit
+            { chain_id: ChainId }
+            [ Error ]
+            |e| {
+                format!("failed while submitting the recover client proposal to chain {0}", e.chain_id)
+            },
+
+        ClientNotExpiredOrFrozen
+            {
+                client_id: ClientId,
+                chain_id: ChainId,
+            }
+            |e| {
+                format!("client {} on chain {} is neither expired nor frozen, there is nothing to recover",
+                    e.client_id, e.chain_id)
+            },
+
+        SubstituteClient
+            [ ForeignClientError ]
+            |_| { "failed to create the substitute client" },
+
+        ProposalTx
+            { tx_hash: TxHash }
+            [ Error ]
+            |e| { format!("failed to fetch the result of proposal transaction {}", e.tx_hash) },
+
+        MissingProposalId
+            { tx_hash: TxHash }
+            |e| { format!("no proposal id found in the events of transaction {}", e.tx_hash) },
+
+        NoRpcEndpoint
+            { chain_id: ChainId }
+            |e| { format!("chain {} has no RPC endpoint to query the proposal from", e.chain_id) },
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RecoverClientOptions {
+    pub subject_client_id: ClientId,
+    pub substitute_client_id: ClientId,
+    pub amount: u64,
+    pub denom: String,
+    pub gov_account: String,
+}
+
+/// The proposal submitted to recover a client, as reported to the user.
+#[derive(Clone, Debug, Serialize)]
+pub struct RecoverClientProposal {
+    pub subject_client_id: ClientId,
+    pub substitute_client_id: ClientId,
+    pub tx_hash: TxHash,
+    pub proposal_id: u64,
+}
+
+/// Checks that the given subject client is expired or frozen, as only such
+/// a client can be recovered.
+pub fn ensure_expired_or_frozen<HostChain: ChainHandle, ReferenceChain: ChainHandle>(
+    subject: &ForeignClient<HostChain, ReferenceChain>,
+) -> Result<(), RecoverClientError> {
+    if !subject.is_expired_or_frozen() {
+        return Err(RecoverClientError::client_not_expired_or_frozen(
+            subject.id().clone(),
+            subject.dst_chain().id(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that the given subject client is expired or frozen, and creates a substitute
+/// client with the same trust parameters, tracking the latest state of its chain.
+pub fn create_substitute_client<HostChain: ChainHandle, ReferenceChain: ChainHandle>(
+    subject: &ForeignClient<HostChain, ReferenceChain>,
+) -> Result<ClientId, RecoverClientError> {
+    ensure_expired_or_frozen(subject)?;
+
+    let (client_state, _) = subject
+        .dst_chain()
+        .query_client_state(
+            QueryClientStateRequest {
+                client_id: subject.id().clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(RecoverClientError::query)?;
+
+    let options = CreateOptions {
+        max_clock_drift: Some(client_state.max_clock_drift()),
+        trusting_period: Some(client_state.trusting_period()),
+        trust_threshold: client_state.trust_threshold(),
+        wasm_checksum: match client_state {
+            AnyClientState::Wasm(state) => Some(state.checksum),
+            _ => None,
+        },
+    };
+
+    let event_with_height = subject
+        .build_create_client_and_send(options)
+        .map_err(RecoverClientError::substitute_client)?;
+
+    extract_client_id(&event_with_height.event)
+        .cloned()
+        .map_err(RecoverClientError::substitute_client)
+}
+
+pub fn build_and_send_recover_client_proposal(
+    host_chain: impl ChainHandle, // the chain hosting the subject and substitute clients
+    opts: &RecoverClientOptions,
+) -> Result<TxHash, RecoverClientError> {
+    // `MsgRecoverClient` was introduced alongside `MsgIBCSoftwareUpgrade` in ibc-go v8
+    let any_msg = if crate::upgrade_chain::requires_legacy_upgrade_proposal(host_chain.clone()) {
+        build_legacy_recover_client_proposal(host_chain.clone(), opts)
+    } else {
+        build_recover_client_proposal(host_chain.clone(), opts)
+    }?;
+
+    let responses = host_chain
+        .send_messages_and_wait_check_tx(TrackedMsgs::new_single(any_msg, "recover client"))
+        .map_err(|e| RecoverClientError::submit(host_chain.id(), e))?;
+
+    Ok(responses[0].hash)
+}
+
+/// Ibc-go versions up to v7.x.x use the deprecated `ClientUpdateProposal` to recover a client
+fn build_legacy_recover_client_proposal(
+    host_chain: impl ChainHandle,
+    opts: &RecoverClientOptions,
+) -> Result<Any, RecoverClientError> {
+    let proposal = ClientUpdateProposal {
+        title: format!("recover client {}", opts.subject_client_id),
+        description: format!(
+            "replace the state of client {} with the state of client {}",
+            opts.subject_client_id, opts.substitute_client_id
+        ),
+        subject_client_id: opts.subject_client_id.to_string(),
+        substitute_client_id: opts.substitute_client_id.to_string(),
+    };
+
+    let any_proposal = Any {
+        type_url: "/ibc.core.client.v1.ClientUpdateProposal".to_string(),
+        value: prost::Message::encode_to_vec(&proposal),
+    };
+
+    let proposer = host_chain.get_signer().map_err(RecoverClientError::key)?;
+
+    let msg = LegacyMsgSubmitProposal {
+        content: Some(any_proposal),
+        initial_deposit: vec![deposit(opts)],
+        proposer: proposer.to_string(),
+    };
+
+    Ok(Any {
+        type_url: "/cosmos.gov.v1beta1.MsgSubmitProposal".to_string(),
+        value: prost::Message::encode_to_vec(&msg),
+    })
+}
+
+/// Since ibc-go version v8.x.x `MsgRecoverClient` is used to recover a client
+fn build_recover_client_proposal(
+    host_chain: impl ChainHandle,
+    opts: &RecoverClientOptions,
+) -> Result<Any, RecoverClientError> {
+    let recover_msg = MsgRecoverClient {
+        subject_client_id: opts.subject_client_id.to_string(),
+        substitute_client_id: opts.substitute_client_id.to_string(),
+        signer: opts.gov_account.clone(),
+    };
+
+    let any_recover_msg = Any {
+        type_url: "/ibc.core.client.v1.MsgRecoverClient".to_string(),
+        value: prost::Message::encode_to_vec(&recover_msg),
+    };
+
+    let proposer = host_chain.get_signer().map_err(RecoverClientError::key)?;
+
+    let msg = MsgSubmitProposal {
+        messages: vec![any_recover_msg],
+        initial_deposit: vec![deposit(opts)],
+        proposer: proposer.to_string(),
+        metadata: "".to_string(),
+        title: format!("recover client {}", opts.subject_client_id),
+        summary: format!(
+            "replace the state of client {} with the state of client {}",
+            opts.subject_client_id, opts.substitute_client_id
+        ),
+    };
+
+    Ok(Any {
+        type_url: "/cosmos.gov.v1.MsgSubmitProposal".to_string(),
+        value: prost::Message::encode_to_vec(&msg),
+    })
+}
+
+fn deposit(opts: &RecoverClientOptions) -> ibc_proto::cosmos::base::v1beta1::Coin {
+    ibc_proto::cosmos::base::v1beta1::Coin {
+        denom: opts.denom.clone(),
+        amount: opts.amount.to_string(),
+    }
+}
+
+/// Waits for the transaction submitting a proposal to be committed,
+/// and returns the identifier of the proposal it created.
+pub fn query_proposal_id(
+    host_chain: impl ChainHandle,
+    tx_hash: TxHash,
+) -> Result<u64, RecoverClientError> {
+    let config = match host_chain.config().map_err(RecoverClientError::query)? {
+        ChainConfig::CosmosSdk(config) => config,
+        ChainConfig::SoloMachine(config) => {
+            return Err(RecoverClientError::no_rpc_endpoint(config.id))
+        }
+    };
+
+    let events = block_on(fetch_tx_events(&config, &tx_hash))
+        .map_err(|e| RecoverClientError::proposal_tx(tx_hash, e))?;
+
+    extract_proposal_id(&events).ok_or_else(|| RecoverClientError::missing_proposal_id(tx_hash))
+}
+
+async fn fetch_tx_events(
+    config: &CosmosSdkConfig,
+    tx_hash: &TxHash,
+) -> Result<Vec<AbciEvent>, Error> {
+    let mut rpc_client = HttpClient::builder(config.rpc_addr.clone().try_into().unwrap())
+        .user_agent(format!("hermes/{}", HERMES_VERSION))
+        .build()
+        .map_err(|e| Error::rpc(config.rpc_addr.clone(), e))?;
+
+    let status = rpc_client
+        .status()
+        .await
+        .map_err(|e| Error::rpc(config.rpc_addr.clone(), e))?;
+
+    let compat_mode = compat_mode_from_version(&config.compat_mode, status.node_info.version)?;
+    rpc_client.set_compat_mode(compat_mode.into());
+
+    let response =
+        wait_tx_succeed(&rpc_client, &config.rpc_addr, &config.rpc_timeout, tx_hash).await?;
+
+    Ok(response.tx_result.events)
+}
+
+fn extract_proposal_id(events: &[AbciEvent]) -> Option<u64> {
+    events
+        .iter()
+        .filter(|event| event.kind == SUBMIT_PROPOSAL_EVENT)
+        .flat_map(|event| event.attributes.iter())
+        .find(|attr| attr.key_bytes() == PROPOSAL_ID_ATTRIBUTE_KEY.as_bytes())
+        .and_then(|attr| attr.value_str().ok()?.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tendermint::abci::EventAttributeIndexExt;
+
+    #[test]
+    fn extract_proposal_id_from_submit_proposal_event() {
+        let events = vec![
+            AbciEvent::new("message", [("action", "submit_proposal").no_index()]),
+            AbciEvent::new(
+                SUBMIT_PROPOSAL_EVENT,
+                [
+                    ("proposal_messages", ",/ibc.core.client.v1.MsgRecoverClient").no_index(),
+                    ("proposal_id", "42").no_index(),
+                ],
+            ),
+        ];
+
+        assert_eq!(extract_proposal_id(&events), Some(42));
+    }
+
+    #[test]
+    fn extract_proposal_id_without_submit_proposal_event() {
+        let events = vec![AbciEvent::new(
+            "message",
+            [("proposal_id", "42").no_index()],
+        )];
+
+        assert_eq!(extract_proposal_id(&events), None);
+    }
+}
@@ -6,7 +6,12 @@ use crate::{
     config::Config,
     rest::request::ReplySender,
     rest::request::{Request, VersionInfo},
-    supervisor::dump_state::SupervisorState,
+    supervisor::{
+        dump_state::SupervisorState,
+        reload::ConfigUpdate,
+        replay::{ReplayFrom, ReplayRange},
+    },
+    worker::client_expiry::ClientExpiry,
 };
 
 pub mod request;
@@ -26,13 +31,12 @@ pub const VER: &str = env!(
 pub type Receiver = crossbeam_channel::Receiver<Request>;
 
 // TODO: Unify this enum with `SupervisorCmd`
-//  We won't unify yet as it is possible we will never implement
-//  REST API `/chain` adding endpoint; instead of `/chain` we might
-//  implement `/reload` for supporting a broader range of functionality
-//  e.g., adjusting chain config, removing chains, etc.
 pub enum Command {
     DumpState(ReplySender<SupervisorState>),
     ClearPackets(Option<ChainId>, ReplySender<()>),
+    ReloadConfig(ReplySender<ConfigUpdate>),
+    GetClients(ReplySender<Vec<ClientExpiry>>),
+    Replay(ReplayFrom, ReplySender<ReplayRange>),
 }
 
 /// Process incoming REST requests.
@@ -90,6 +94,24 @@ pub fn process_incoming_requests(config: &Config, channel: &Receiver) -> Option<
 
                 return Some(Command::ClearPackets(chain_id, reply_to));
             }
+
+            Request::ReloadConfig { reply_to } => {
+                trace!("ReloadConfig");
+
+                return Some(Command::ReloadConfig(reply_to));
+            }
+
+            Request::GetClients { reply_to } => {
+                trace!("GetClients");
+
+                return Some(Command::GetClients(reply_to));
+            }
+
+            Request::Replay { from, reply_to } => {
+                trace!("Replay");
+
+                return Some(Command::Replay(from, reply_to));
+            }
         },
         Err(e) => {
             if !matches!(e, TryRecvError::Empty) {
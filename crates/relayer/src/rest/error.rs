@@ -23,6 +23,12 @@ pub enum RestApiError {
     #[error("failed while parsing the request body into a chain configuration: {0}")]
     InvalidChainConfig(String),
 
+    #[error("failed to reload the configuration: {0}")]
+    ConfigReload(String),
+
+    #[error("failed to replay the events: {0}")]
+    Replay(String),
+
     #[error("not implemented")]
     Unimplemented,
 }
@@ -36,6 +42,8 @@ impl RestApiError {
             RestApiError::ChainConfigNotFound(_) => "ChainConfigNotFound",
             RestApiError::InvalidChainId(_, _) => "InvalidChainId",
             RestApiError::InvalidChainConfig(_) => "InvalidChainConfig",
+            RestApiError::ConfigReload(_) => "ConfigReload",
+            RestApiError::Replay(_) => "Replay",
             RestApiError::Unimplemented => "Unimplemented",
         }
     }
@@ -2,7 +2,16 @@ use serde::{Deserialize, Serialize};
 
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
-use crate::{config::ChainConfig, rest::RestApiError, supervisor::dump_state::SupervisorState};
+use crate::{
+    config::ChainConfig,
+    rest::RestApiError,
+    supervisor::{
+        dump_state::SupervisorState,
+        reload::ConfigUpdate,
+        replay::{ReplayFrom, ReplayRange},
+    },
+    worker::client_expiry::ClientExpiry,
+};
 
 pub type ReplySender<T> = crossbeam_channel::Sender<Result<T, RestApiError>>;
 pub type ReplyReceiver<T> = crossbeam_channel::Receiver<Result<T, RestApiError>>;
@@ -41,4 +50,17 @@ pub enum Request {
         chain_id: Option<ChainId>,
         reply_to: ReplySender<()>,
     },
+
+    ReloadConfig {
+        reply_to: ReplySender<ConfigUpdate>,
+    },
+
+    GetClients {
+        reply_to: ReplySender<Vec<ClientExpiry>>,
+    },
+
+    Replay {
+        from: ReplayFrom,
+        reply_to: ReplySender<ReplayRange>,
+    },
 }
@@ -6,9 +6,13 @@ use tokio::runtime::Runtime as TokioRuntime;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
 use crate::{
-    chain::{cosmos::CosmosSdkChain, handle::ChainHandle, runtime::ChainRuntime},
+    chain::{
+        cosmos::CosmosSdkChain, handle::ChainHandle, runtime::ChainRuntime,
+        solomachine::SoloMachineChain,
+    },
     config::{ChainConfig, Config},
     error::Error as RelayerError,
+    light_client::tendermint::LIGHT_BLOCK_CACHE_FOLDER,
 };
 
 define_error! {
@@ -52,6 +56,8 @@ pub fn spawn_chain_runtime<Handle: ChainHandle>(
         .cloned()
         .ok_or_else(|| SpawnError::missing_chain_config(chain_id.clone()))?;
 
+    let chain_config = with_light_block_cache_folder(config, chain_config);
+
     spawn_chain_runtime_with_config(chain_config, rt)
 }
 
@@ -71,6 +77,8 @@ pub fn spawn_chain_runtime_with_modified_config<Handle: ChainHandle>(
 
     modify(&mut chain_config);
 
+    let chain_config = with_light_block_cache_folder(config, chain_config);
+
     spawn_chain_runtime_with_config(chain_config, rt)
 }
 
@@ -82,8 +90,24 @@ pub fn spawn_chain_runtime_with_config<Handle: ChainHandle>(
 ) -> Result<Handle, SpawnError> {
     let handle = match config {
         ChainConfig::CosmosSdk(_) => ChainRuntime::<CosmosSdkChain>::spawn(config, rt),
+        ChainConfig::SoloMachine(_) => ChainRuntime::<SoloMachineChain>::spawn(config, rt),
     }
     .map_err(SpawnError::relayer)?;
 
     Ok(handle)
 }
+
+/// Keeps the light block cache of a chain under the `data_dir` of the relayer,
+/// unless the chain configuration specifies its own folder.
+fn with_light_block_cache_folder(config: &Config, mut chain_config: ChainConfig) -> ChainConfig {
+    if let ChainConfig::CosmosSdk(cosmos_config) = &mut chain_config {
+        if cosmos_config.light_block_cache_folder.is_none() {
+            cosmos_config.light_block_cache_folder = config
+                .global
+                .data_dir()
+                .map(|data_dir| data_dir.join(LIGHT_BLOCK_CACHE_FOLDER));
+        }
+    }
+
+    chain_config
+}
@@ -2,8 +2,11 @@ use alloc::collections::btree_map::BTreeMap as HashMap;
 use alloc::sync::Arc;
 use core::convert::Infallible;
 use core::ops::Deref;
+use core::sync::atomic::{AtomicBool, Ordering};
 use core::time::Duration;
+use std::path::PathBuf;
 use std::sync::RwLock;
+use std::time::Instant;
 
 use crossbeam_channel::{unbounded, Receiver, Sender};
 use itertools::Itertools;
@@ -16,19 +19,27 @@ use ibc_relayer_types::{
 };
 
 use crate::{
-    chain::{endpoint::HealthCheck, handle::ChainHandle, tracking::TrackingId},
-    config::Config,
+    chain::{
+        endpoint::HealthCheck,
+        handle::ChainHandle,
+        requests::{IncludeProof, QueryChannelRequest, QueryHeight},
+        tracking::TrackingId,
+    },
+    config::{Config, Error as ConfigError, HighAvailabilityConfig},
     event::{
-        source::{self, Error as EventError, ErrorDetail as EventErrorDetail, EventBatch},
+        source::{
+            self, rpc::Replay, Error as EventError, ErrorDetail as EventErrorDetail, EventBatch,
+        },
         IbcEventWithHeight,
     },
+    multihop,
     object::Object,
     registry::{Registry, SharedRegistry},
     rest,
     supervisor::scan::ScanMode,
     telemetry,
     util::{
-        lock::LockExt,
+        lock::{LockExt, RwArc},
         task::{spawn_background_task, Next, TaskError, TaskHandle},
     },
     worker::WorkerMap,
@@ -43,9 +54,22 @@ pub use error::{Error, ErrorDetail};
 pub mod dump_state;
 use dump_state::SupervisorState;
 
+pub mod lease;
+use lease::{FileLease, Leadership, LeaseGuard};
+
+pub mod reload;
+use reload::ConfigUpdate;
+
+pub mod replay;
+use replay::{ReplayFrom, ReplayRange};
+
 pub mod scan;
+pub mod shard;
 pub mod spawn;
 
+pub mod checkpoint;
+use checkpoint::{Checkpoints, CHECKPOINTS_FILE};
+
 pub mod cmd;
 use cmd::SupervisorCmd;
 
@@ -66,7 +90,7 @@ pub struct SupervisorHandle {
 }
 
 /// Options for the supervisor
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct SupervisorOptions {
     /// Perform a health check of all chains we connect to
     pub health_check: bool,
@@ -75,6 +99,14 @@ pub struct SupervisorOptions {
     /// even when an allow list is configured for a chain and the full scan could
     /// be omitted.
     pub force_full_scan: bool,
+
+    /// Path to the configuration file the supervisor was started with,
+    /// from which the configuration is read again when asked to reload it.
+    pub config_path: Option<PathBuf>,
+
+    /// Replay the events of a chain from a past height,
+    /// before handling its live events.
+    pub replay_from: Option<ReplayFrom>,
 }
 
 /**
@@ -91,7 +123,8 @@ pub fn spawn_supervisor(
 ) -> Result<SupervisorHandle, Error> {
     let (sender, receiver) = unbounded();
 
-    let tasks = spawn_supervisor_tasks(config, registry, rest_rx, receiver, options)?;
+    let tasks =
+        spawn_supervisor_tasks(config, registry, rest_rx, sender.clone(), receiver, options)?;
 
     Ok(SupervisorHandle { sender, tasks })
 }
@@ -131,12 +164,24 @@ impl SupervisorHandle {
 
         Ok(state)
     }
+
+    /// Ask the supervisor to reload its configuration from disk,
+    /// and wait for the changes to be applied.
+    pub fn reload_config(&self) -> Result<ConfigUpdate, Error> {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+
+        self.sender
+            .send(SupervisorCmd::ReloadConfig(tx))
+            .map_err(|_| Error::handle_send())?;
+
+        rx.recv().map_err(|_| Error::handle_recv())?
+    }
 }
 
 /// Whether the supervisor should scan the chains for clients, connections, and channels.
 /// The supervisor should scan if any of the following conditions are met:
 /// - the clear_on_start option is enabled
-/// - the client refresh or misbehavior workers are enabled
+/// - the client refresh, misbehavior or upgrade workers are enabled
 /// - the channel workers are enabled
 /// - the connection workers are enabled
 /// - the full_scan option is enabled
@@ -146,13 +191,16 @@ fn should_scan(config: &Config, options: &SupervisorOptions) -> bool {
         || config.mode.connections.enabled
         || config.mode.channels.enabled
         || (config.mode.clients.enabled
-            && (config.mode.clients.misbehaviour || config.mode.clients.refresh))
+            && (config.mode.clients.misbehaviour
+                || config.mode.clients.refresh
+                || config.mode.clients.upgrade))
 }
 
 pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
     config: Config,
     registry: SharedRegistry<Chain>,
     rest_rx: Option<rest::Receiver>,
+    cmd_tx: Sender<SupervisorCmd>,
     cmd_rx: Receiver<SupervisorCmd>,
     options: SupervisorOptions,
 ) -> Result<Vec<TaskHandle>, Error> {
@@ -170,9 +218,44 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
         }
     }
 
-    let workers = Arc::new(RwLock::new(WorkerMap::new()));
+    let mut workers = WorkerMap::new();
+
+    // In high-availability mode, workers are only spawned once holding the lease
+    let lease = if config.high_availability.enabled {
+        Some(init_lease(&config.high_availability, &mut workers)?)
+    } else {
+        None
+    };
+
+    // Whether the startup is over, after which the instance may become leader
+    let started = Arc::new(AtomicBool::new(false));
+
+    if config.catch_up.enabled {
+        let data_dir = config
+            .global
+            .data_dir()
+            .ok_or_else(Error::data_dir_unavailable)?;
+
+        let checkpoints =
+            Checkpoints::open(data_dir.join(CHECKPOINTS_FILE)).map_err(Error::checkpoint)?;
+
+        workers.set_checkpoints(checkpoints);
+    }
+
+    let workers = Arc::new(RwLock::new(workers));
     let client_state_filter = Arc::new(RwLock::new(FilterPolicy::default()));
 
+    // The lease is renewed from now on, as the startup may take longer than the lease duration
+    let lease_task = lease.map(|lease| {
+        spawn_lease_worker(
+            &config.high_availability,
+            workers.acquire_read().leadership().clone(),
+            lease,
+            started.clone(),
+            cmd_tx.clone(),
+        )
+    });
+
     // Only scan when needed
     if should_scan(&config, &options) {
         let scan = chain_scanner(
@@ -196,73 +279,139 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
 
     let subscriptions = init_subscriptions(&config, &mut registry.write())?;
 
+    // Set the replays up once subscribed, so that no block is missed
+    // between the last replayed block and the first live one
+    let replays = init_replays(&config, &options, workers.acquire_read().checkpoints())?;
+
+    let config = Arc::new(RwLock::new(config));
+
     let batch_tasks = spawn_batch_workers(
         &config,
         registry.clone(),
-        client_state_filter,
+        client_state_filter.clone(),
         workers.clone(),
         subscriptions,
+        replays,
     );
 
-    let cmd_task = spawn_cmd_worker(registry.clone(), workers.clone(), cmd_rx);
+    // The batch workers are owned by the command worker,
+    // as they may have to be stopped or spawned when reloading the configuration.
+    let cmd_task = spawn_cmd_worker(
+        options,
+        config.clone(),
+        registry.clone(),
+        client_state_filter,
+        workers.clone(),
+        batch_tasks,
+        cmd_rx,
+    );
 
     let mut tasks = vec![cmd_task];
-    tasks.extend(batch_tasks);
+    tasks.extend(lease_task);
 
     if let Some(rest_rx) = rest_rx {
-        let rest_task = spawn_rest_worker(config, registry, workers.clone(), rest_rx);
+        let rest_task = spawn_rest_worker(config, registry, workers.clone(), cmd_tx, rest_rx);
         tasks.push(rest_task);
     }
 
     let cleanup_task = spawn_cleanup_worker(workers);
     tasks.push(cleanup_task);
 
+    started.store(true, Ordering::SeqCst);
+
     Ok(tasks)
 }
 
 fn spawn_batch_workers<Chain: ChainHandle>(
-    config: &Config,
+    config: &RwArc<Config>,
     registry: SharedRegistry<Chain>,
-    client_state_filter: Arc<RwLock<FilterPolicy>>,
-    workers: Arc<RwLock<WorkerMap>>,
+    client_state_filter: RwArc<FilterPolicy>,
+    workers: RwArc<WorkerMap>,
     subscriptions: Vec<(Chain, Subscription)>,
-) -> Vec<TaskHandle> {
-    let mut handles = Vec::with_capacity(subscriptions.len());
+    mut replays: HashMap<ChainId, Replay>,
+) -> HashMap<ChainId, TaskHandle> {
+    let mut handles = HashMap::new();
 
     for (chain, subscription) in subscriptions {
-        let config = config.clone();
-        let registry = registry.clone();
-        let client_state_filter = client_state_filter.clone();
-        let workers = workers.clone();
-
-        let handle = spawn_background_task(
-            error_span!("worker.batch", chain = %chain.id()),
-            Some(Duration::from_millis(5)),
-            move || -> Result<Next, TaskError<Infallible>> {
-                if let Ok(batch) = subscription.try_recv() {
-                    handle_batch(
-                        &config,
-                        &mut registry.write(),
-                        &mut client_state_filter.acquire_write(),
-                        &mut workers.acquire_write(),
-                        chain.clone(),
-                        batch,
-                    );
-                }
-
-                Ok(Next::Continue)
-            },
+        let chain_id = chain.id();
+
+        let chain_replay = replays.remove(&chain_id);
+
+        let handle = spawn_batch_worker(
+            config.clone(),
+            registry.clone(),
+            client_state_filter.clone(),
+            workers.clone(),
+            chain,
+            subscription,
+            chain_replay,
         );
 
-        handles.push(handle);
+        handles.insert(chain_id, handle);
     }
 
     handles
 }
 
+fn spawn_batch_worker<Chain: ChainHandle>(
+    config: RwArc<Config>,
+    registry: SharedRegistry<Chain>,
+    client_state_filter: RwArc<FilterPolicy>,
+    workers: RwArc<WorkerMap>,
+    chain: Chain,
+    subscription: Subscription,
+    mut replay: Option<Replay>,
+) -> TaskHandle {
+    spawn_background_task(
+        error_span!("worker.batch", chain = %chain.id()),
+        Some(Duration::from_millis(5)),
+        move || -> Result<Next, TaskError<Infallible>> {
+            // Replay the past events one block at a time, while the live events
+            // are queued up in the subscription, so as to remain responsive to shutdown
+            if let Some(chain_replay) = &mut replay {
+                match chain_replay.next() {
+                    Some(batch) => {
+                        handle_batch(
+                            &config.acquire_read(),
+                            &mut registry.write(),
+                            &mut client_state_filter.acquire_write(),
+                            &mut workers.acquire_write(),
+                            chain.clone(),
+                            Arc::new(Ok(batch)),
+                        );
+                    }
+                    None => {
+                        info!("replayed the events up to height {}", chain_replay.end());
+                        replay = None;
+                    }
+                }
+
+                return Ok(Next::Continue);
+            }
+
+            if let Ok(batch) = subscription.try_recv() {
+                handle_batch(
+                    &config.acquire_read(),
+                    &mut registry.write(),
+                    &mut client_state_filter.acquire_write(),
+                    &mut workers.acquire_write(),
+                    chain.clone(),
+                    batch,
+                );
+            }
+
+            Ok(Next::Continue)
+        },
+    )
+}
+
 pub fn spawn_cmd_worker<Chain: ChainHandle>(
+    options: SupervisorOptions,
+    config: RwArc<Config>,
     registry: SharedRegistry<Chain>,
-    workers: Arc<RwLock<WorkerMap>>,
+    client_state_filter: RwArc<FilterPolicy>,
+    workers: RwArc<WorkerMap>,
+    mut batch_tasks: HashMap<ChainId, TaskHandle>,
     cmd_rx: Receiver<SupervisorCmd>,
 ) -> TaskHandle {
     spawn_background_task(
@@ -274,6 +423,48 @@ pub fn spawn_cmd_worker<Chain: ChainHandle>(
                     SupervisorCmd::DumpState(reply_to) => {
                         dump_state(&registry.read(), &workers.acquire_read(), reply_to);
                     }
+                    SupervisorCmd::ReloadConfig(reply_to) => {
+                        let result = match &options.config_path {
+                            Some(config_path) => reload::reload_config(
+                                config_path,
+                                &options,
+                                &config,
+                                &registry,
+                                &client_state_filter,
+                                &workers,
+                                &mut batch_tasks,
+                            ),
+                            None => Err(Error::missing_config_path()),
+                        };
+
+                        if let Err(e) = &result {
+                            error!("failed to reload configuration: {e}");
+                        }
+
+                        let _ = reply_to.try_send(result);
+                    }
+                    SupervisorCmd::Replay(from, reply_to) => {
+                        let result = replay_events(
+                            &from,
+                            &config,
+                            &registry,
+                            &client_state_filter,
+                            &workers,
+                            &mut batch_tasks,
+                        );
+
+                        if let Err(e) = &result {
+                            error!("failed to replay events: {e}");
+                        }
+
+                        let _ = reply_to.try_send(result);
+                    }
+                    SupervisorCmd::TakeOver => {
+                        take_over(&config, &registry, &workers);
+                    }
+                    SupervisorCmd::StandBy => {
+                        stand_by(&options, &config, &registry, &client_state_filter, &workers);
+                    }
                 }
             }
 
@@ -283,22 +474,197 @@ pub fn spawn_cmd_worker<Chain: ChainHandle>(
 }
 
 pub fn spawn_rest_worker<Chain: ChainHandle>(
-    config: Config,
+    config: RwArc<Config>,
     registry: SharedRegistry<Chain>,
-    workers: Arc<RwLock<WorkerMap>>,
+    workers: RwArc<WorkerMap>,
+    cmd_tx: Sender<SupervisorCmd>,
     rest_rx: rest::Receiver,
 ) -> TaskHandle {
     spawn_background_task(
         error_span!("rest"),
         Some(Duration::from_millis(500)),
         move || -> Result<Next, TaskError<Infallible>> {
-            handle_rest_requests(&config, &registry.read(), &workers.acquire_read(), &rest_rx);
+            handle_rest_requests(
+                &config.acquire_read(),
+                &registry.read(),
+                &workers.acquire_read(),
+                &cmd_tx,
+                &rest_rx,
+            );
+
+            Ok(Next::Continue)
+        },
+    )
+}
+
+/// Set up the lease of an instance running in high-availability mode.
+///
+/// The instance starts on standby, and only acquires the lease from the task
+/// spawned by [`spawn_lease_worker`], which keeps renewing it during the startup.
+fn init_lease(
+    ha_config: &HighAvailabilityConfig,
+    workers: &mut WorkerMap,
+) -> Result<LeaseGuard, Error> {
+    let lease_file = ha_config.lease_file.clone().ok_or_else(|| {
+        Error::config(ConfigError::invalid_high_availability(
+            "`lease_file` must be set if `enabled` is set to true".to_string(),
+        ))
+    })?;
+
+    let instance_id = ha_config
+        .instance_id
+        .clone()
+        .unwrap_or_else(lease::default_instance_id);
+
+    let lease = FileLease::new(lease_file);
+    let leadership = Leadership::new(instance_id.clone());
+
+    info!(
+        "running in high-availability mode as instance `{instance_id}`, starting as {}",
+        leadership.role()
+    );
+
+    telemetry!(
+        lease_leadership,
+        &instance_id,
+        leadership.is_leader(),
+        false
+    );
+
+    workers.set_leadership(leadership);
+
+    Ok(LeaseGuard::new(Box::new(lease), instance_id))
+}
+
+/// Spawn a background task which renews the lease while this instance holds it,
+/// and tries to acquire it otherwise.
+///
+/// The instance only becomes leader once the startup is over, with the first
+/// renewal following it. The workers are then spawned by the command worker from
+/// the scans kept while on standby, so that the lease keeps being renewed in the
+/// meantime. If it loses the lease, or cannot renew it before it lapses, the
+/// command worker stops all the workers and scans the chains again, to be ready
+/// to take over. The lease is released when the task stops.
+pub fn spawn_lease_worker(
+    ha_config: &HighAvailabilityConfig,
+    leadership: Leadership,
+    lease: LeaseGuard,
+    started: Arc<AtomicBool>,
+    cmd_tx: Sender<SupervisorCmd>,
+) -> TaskHandle {
+    let (lease_duration, renew_interval) = (ha_config.lease_duration, ha_config.renew_interval);
+
+    let mut last_renewal = None;
+
+    spawn_background_task(
+        error_span!("worker.lease", instance = %lease.holder()),
+        Some(renew_interval),
+        move || -> Result<Next, TaskError<Infallible>> {
+            let attempted_at = Instant::now();
+
+            let leader = match lease.lease().try_acquire(lease.holder(), lease_duration) {
+                Ok(true) => {
+                    last_renewal = Some(attempted_at);
+                    true
+                }
+                Ok(false) => false,
+                Err(e) => {
+                    warn!("failed to renew the lease: {e}");
+
+                    // Step down before the lease lapses, as another
+                    // instance may take it over as soon as it does
+                    last_renewal
+                        .is_some_and(|renewal| renewal.elapsed() + renew_interval < lease_duration)
+                }
+            };
+
+            // Stay on standby while starting up, even when holding the lease
+            let leader = leader && started.load(Ordering::SeqCst);
+
+            if !leadership.set_leader(leader) {
+                return Ok(Next::Continue);
+            }
+
+            telemetry!(lease_leadership, lease.holder(), leader, true);
+
+            let cmd = if leader {
+                info!("acquired the lease, taking over");
+                SupervisorCmd::TakeOver
+            } else {
+                warn!("lost the lease, standing by");
+                last_renewal = None;
+                SupervisorCmd::StandBy
+            };
+
+            if cmd_tx.send(cmd).is_err() {
+                error!("failed to notify the supervisor of the change of leadership");
+            }
 
             Ok(Next::Continue)
         },
     )
 }
 
+/// Spawn the workers from the scans kept while this instance was on standby.
+fn take_over<Chain: ChainHandle>(
+    config: &RwArc<Config>,
+    registry: &SharedRegistry<Chain>,
+    workers: &RwArc<WorkerMap>,
+) {
+    let config = config.acquire_read();
+    let mut registry = registry.write();
+    let mut workers = workers.acquire_write();
+
+    let scans = workers.take_warm_scans();
+    let mut spawn_context = spawn_context(&config, &mut registry, &mut workers);
+
+    // Skip the chains which were removed from the configuration in the meantime
+    for scan in scans {
+        if config.has_chain(&scan.chain_id) {
+            spawn_context.spawn_workers_for_chain(scan);
+        }
+    }
+}
+
+/// Stop all the workers, and scan the chains again
+/// to be ready to take over once the lease lapses.
+fn stand_by<Chain: ChainHandle>(
+    options: &SupervisorOptions,
+    config: &RwArc<Config>,
+    registry: &SharedRegistry<Chain>,
+    client_state_filter: &RwArc<FilterPolicy>,
+    workers: &RwArc<WorkerMap>,
+) {
+    // The lease may have been acquired again in the meantime
+    if workers.acquire_read().leadership().is_leader() {
+        return;
+    }
+
+    workers.acquire_write().shutdown_all();
+
+    let config = config.acquire_read();
+
+    if !should_scan(&config, options) {
+        return;
+    }
+
+    let mut registry = registry.write();
+
+    let scan = chain_scanner(
+        &config,
+        &mut registry,
+        &mut client_state_filter.acquire_write(),
+        if options.force_full_scan {
+            ScanMode::Full
+        } else {
+            ScanMode::Auto
+        },
+    )
+    .scan_chains();
+
+    spawn_context(&config, &mut registry, &mut workers.acquire_write()).spawn_workers(scan);
+}
+
 /// Spawn a background task which verifies if there are idle workers and removes them if.
 pub fn spawn_cleanup_worker(workers: Arc<RwLock<WorkerMap>>) -> TaskHandle {
     spawn_background_task(
@@ -667,6 +1033,50 @@ fn health_check<Chain: ChainHandle>(config: &Config, registry: &mut Registry<Cha
     }
 }
 
+/// Set up the replays of the events emitted by the chains before the supervisor started,
+/// either from the height given in the options, or from the last height handled
+/// before Hermes stopped if catching up is enabled.
+fn init_replays(
+    config: &Config,
+    options: &SupervisorOptions,
+    checkpoints: &Checkpoints,
+) -> Result<HashMap<ChainId, Replay>, Error> {
+    let mut replays = HashMap::new();
+
+    if let Some(from) = &options.replay_from {
+        replays.insert(from.chain_id.clone(), replay::replay(config, from)?);
+    }
+
+    if !config.catch_up.enabled {
+        return Ok(replays);
+    }
+
+    for chain_config in &config.chains {
+        let chain_id = chain_config.id();
+
+        if replays.contains_key(chain_id) {
+            continue;
+        }
+
+        let Some(last_height) = checkpoints.height(chain_id) else {
+            continue;
+        };
+
+        match replay::catch_up(config, chain_id, last_height, config.catch_up.max_lookback) {
+            Ok(Some(replay)) => {
+                replays.insert(chain_id.clone(), replay);
+            }
+            Ok(None) => {}
+            Err(e) => warn!(
+                chain = %chain_id,
+                "failed to catch up on the events since height {last_height}: {e}"
+            ),
+        }
+    }
+
+    Ok(replays)
+}
+
 /// Subscribe to the events emitted by the chains the supervisor is connected to.
 #[instrument(name = "supervisor.init_subscriptions", level = "error", skip_all)]
 fn init_subscriptions<Chain: ChainHandle>(
@@ -725,17 +1135,24 @@ fn dump_state<Chain: ChainHandle>(
 /// as a [`SupervisorState`].
 fn state<Chain: ChainHandle>(registry: &Registry<Chain>, workers: &WorkerMap) -> SupervisorState {
     let chains = registry.chains().map(|c| c.id()).collect_vec();
-    SupervisorState::new(chains, workers.handles())
+    SupervisorState::new(
+        chains,
+        workers.handles(),
+        workers.rate_limiter().deferred(),
+        workers.leadership().state(),
+        workers.channel_shards(),
+    )
 }
 
 fn handle_rest_requests<Chain: ChainHandle>(
     config: &Config,
     registry: &Registry<Chain>,
     workers: &WorkerMap,
+    cmd_tx: &Sender<SupervisorCmd>,
     rest_rx: &rest::Receiver,
 ) {
     if let Some(cmd) = rest::process_incoming_requests(config, rest_rx) {
-        handle_rest_cmd(registry, workers, cmd);
+        handle_rest_cmd(registry, workers, cmd_tx, cmd);
     }
 }
 
@@ -743,6 +1160,7 @@ fn handle_rest_requests<Chain: ChainHandle>(
 fn handle_rest_cmd<Chain: ChainHandle>(
     registry: &Registry<Chain>,
     workers: &WorkerMap,
+    cmd_tx: &Sender<SupervisorCmd>,
     cmd: rest::Command,
 ) {
     match cmd {
@@ -773,6 +1191,61 @@ fn handle_rest_cmd<Chain: ChainHandle>(
                 .send(Ok(()))
                 .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
         }
+
+        rest::Command::ReloadConfig(reply) => {
+            info!("reloading configuration after REST request");
+
+            let (tx, rx) = crossbeam_channel::bounded(1);
+
+            if cmd_tx.send(SupervisorCmd::ReloadConfig(tx)).is_err() {
+                error!("failed to forward configuration reload request to the supervisor");
+                return;
+            }
+
+            // The reload is performed by the command worker, which needs to acquire
+            // the locks currently held by this worker, so wait for its outcome on
+            // a separate thread.
+            std::thread::spawn(move || {
+                let result = rx
+                    .recv()
+                    .map_err(|e| rest::RestApiError::ChannelRecv(e.to_string()))
+                    .and_then(|r| r.map_err(|e| rest::RestApiError::ConfigReload(e.to_string())));
+
+                reply
+                    .send(result)
+                    .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+            });
+        }
+
+        rest::Command::GetClients(reply) => {
+            reply
+                .send(Ok(workers.client_expiries().list()))
+                .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+        }
+
+        rest::Command::Replay(from, reply) => {
+            info!(chain = %from.chain_id, "replaying events from height {} after REST request", from.height);
+
+            let (tx, rx) = crossbeam_channel::bounded(1);
+
+            if cmd_tx.send(SupervisorCmd::Replay(from, tx)).is_err() {
+                error!("failed to forward replay request to the supervisor");
+                return;
+            }
+
+            // The batch worker of the chain is restarted by the command worker,
+            // so wait for the outcome on a separate thread, as for reloads.
+            std::thread::spawn(move || {
+                let result = rx
+                    .recv()
+                    .map_err(|e| rest::RestApiError::ChannelRecv(e.to_string()))
+                    .and_then(|r| r.map_err(|e| rest::RestApiError::Replay(e.to_string())));
+
+                reply
+                    .send(result)
+                    .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+            });
+        }
     }
 }
 
@@ -790,6 +1263,51 @@ fn clear_pending_packets(workers: &WorkerMap, chain_id: &ChainId) -> Result<(),
     Ok(())
 }
 
+/// Replay the events of a chain from a past height, by restarting its batch worker
+/// with a new subscription, which queues up the live events during the replay.
+#[instrument(
+    name = "supervisor.replay_events",
+    level = "error",
+    skip_all,
+    fields(chain = %from.chain_id)
+)]
+fn replay_events<Chain: ChainHandle>(
+    from: &ReplayFrom,
+    config: &RwArc<Config>,
+    registry: &SharedRegistry<Chain>,
+    client_state_filter: &RwArc<FilterPolicy>,
+    workers: &RwArc<WorkerMap>,
+    batch_tasks: &mut HashMap<ChainId, TaskHandle>,
+) -> Result<ReplayRange, Error> {
+    let chain = registry
+        .get_or_spawn(&from.chain_id)
+        .map_err(Error::spawn)?;
+
+    // Subscribe before setting the replay up, so that no block is missed
+    // between the last replayed block and the first live one
+    let subscription = chain.subscribe().map_err(Error::relayer)?;
+    let replay = replay::replay(&config.acquire_read(), from)?;
+    let range = ReplayRange::new(&replay);
+
+    if let Some(task) = batch_tasks.remove(&from.chain_id) {
+        task.shutdown_and_wait();
+    }
+
+    let task = spawn_batch_worker(
+        config.clone(),
+        registry.clone(),
+        client_state_filter.clone(),
+        workers.clone(),
+        chain,
+        subscription,
+        Some(replay),
+    );
+
+    batch_tasks.insert(from.chain_id.clone(), task);
+
+    Ok(range)
+}
+
 /// Process a batch of events received from a chain.
 #[instrument(
     name = "supervisor.process_batch",
@@ -817,7 +1335,7 @@ fn process_batch<Chain: ChainHandle>(
     }
 
     // Forward the IBC events to the appropriate workers
-    for (object, events_with_heights) in collected.per_object.into_iter() {
+    for (mut object, events_with_heights) in collected.per_object.into_iter() {
         if events_with_heights.is_empty() {
             // Event batch is empty, nothing to do
             continue;
@@ -833,6 +1351,19 @@ fn process_batch<Chain: ChainHandle>(
             continue;
         };
 
+        let hop_chains = match multihop_chains(registry, &src_chain, &mut object) {
+            Ok(hop_chains) => hop_chains,
+            Err(e) => {
+                warn!(
+                    "skipping events for '{}': failed to resolve the multi-hop channel: {}",
+                    object.short_name(),
+                    e
+                );
+
+                continue;
+            }
+        };
+
         let Ok(dst_chain) = registry.get_or_spawn(object.dst_chain_id()) else {
             trace!(
                 "skipping events for '{}': destination chain '{}' is not registered",
@@ -867,7 +1398,12 @@ fn process_batch<Chain: ChainHandle>(
             ));
         }
 
-        let worker = workers.get_or_spawn(object, src_chain, dst_chain, config);
+        // No worker is spawned while this instance is on standby,
+        // or for the objects which belong to another shard
+        let Some(worker) = workers.get_or_spawn(object, src_chain, dst_chain, hop_chains, config)
+        else {
+            continue;
+        };
 
         worker.send_events(
             batch.height,
@@ -880,6 +1416,55 @@ fn process_batch<Chain: ChainHandle>(
     Ok(())
 }
 
+/// The handles of the intermediate chains of the channel of the given packet or channel
+/// object, if it is a multi-hop channel (ICS 033). In that case, the destination chain of
+/// the object, which is first set to the chain of the client of the channel, ie. the first
+/// intermediate chain, is set to the chain at the other end of the channel.
+fn multihop_chains<Chain: ChainHandle>(
+    registry: &mut Registry<Chain>,
+    src_chain: &Chain,
+    object: &mut Object,
+) -> Result<Vec<Chain>, Error> {
+    let (port_id, channel_id, dst_chain_id) = match object {
+        Object::Packet(packet) => (
+            &packet.src_port_id,
+            &packet.src_channel_id,
+            &mut packet.dst_chain_id,
+        ),
+        Object::Channel(channel) => (
+            &channel.src_port_id,
+            &channel.src_channel_id,
+            &mut channel.dst_chain_id,
+        ),
+        _ => return Ok(Vec::new()),
+    };
+
+    let (channel, _) = src_chain
+        .query_channel(
+            QueryChannelRequest {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(Error::relayer)?;
+
+    if !channel.is_multihop() {
+        return Ok(Vec::new());
+    }
+
+    let (hop_chains, counterparty_chain_id) =
+        multihop::hop_chains(src_chain, channel.connection_hops(), |chain_id| {
+            registry.get_or_spawn(chain_id).ok()
+        })
+        .map_err(Error::multihop)?;
+
+    *dst_chain_id = counterparty_chain_id;
+
+    Ok(hop_chains)
+}
+
 /// This method parses a list of IbcEvent and record the following three metrics if there is
 /// the corresponding event:
 /// * send_packet_events: The number of SendPacket events received
@@ -957,10 +1542,18 @@ fn handle_batch<Chain: ChainHandle>(
 
     match batch.deref() {
         Ok(batch) => {
-            if let Err(e) =
-                process_batch(config, registry, client_state_filter, workers, chain, batch)
-            {
-                error!("error during batch processing: {}", e);
+            match process_batch(config, registry, client_state_filter, workers, chain, batch) {
+                // The events handled on standby are not relayed,
+                // so they must be caught up on after a restart
+                Ok(()) if workers.leadership().is_leader() => {
+                    workers
+                        .checkpoints()
+                        .record(&chain_id, batch.height.revision_height());
+                }
+                Ok(()) => {}
+                Err(e) => {
+                    error!("error during batch processing: {}", e);
+                }
             }
         }
         Err(EventError(EventErrorDetail::SubscriptionCancelled(_), _)) => {
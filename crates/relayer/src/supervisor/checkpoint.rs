@@ -0,0 +1,204 @@
+//! The last block height whose events the supervisor handled on each chain,
+//! kept in a state file so that after a restart Hermes catches up on the events
+//! emitted while it was down, by replaying them from that height.
+//!
+//! The height of a block is recorded once its events are dispatched to the workers,
+//! not once the workers relayed them. The events still queued in the workers when
+//! Hermes crashes are thus not replayed after the restart, and are only relayed
+//! by packet clearing, eg. with `clear_on_start`.
+//!
+//! The heights are written to the file at most every [`FLUSH_INTERVAL`], and when
+//! the supervisor stops, so that recording them does not slow the event handling
+//! down. After a crash, the events of the last few blocks may thus be handled twice.
+
+use alloc::collections::BTreeMap;
+use alloc::sync::Arc;
+use core::time::Duration;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use flex_error::{define_error, TraceError};
+use tracing::warn;
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+/// The name of the state file, in the `data_dir` of the relayer.
+pub const CHECKPOINTS_FILE: &str = "heights.json";
+
+/// How often the recorded heights are written to the state file.
+pub const FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+define_error! {
+    Error {
+        Io
+            { path: PathBuf }
+            [ TraceError<io::Error> ]
+            |e| { format!("I/O error on the state file {}", e.path.display()) },
+
+        Decode
+            { path: PathBuf }
+            [ TraceError<serde_json::Error> ]
+            |e| { format!("failed to decode the state file {}", e.path.display()) },
+
+        Encode
+            [ TraceError<serde_json::Error> ]
+            |_| { "failed to encode the last handled heights" },
+    }
+}
+
+/// The last block height handled on each chain.
+///
+/// Recording the heights is a no-op unless the state file was opened.
+/// Cloning yields a handle to the same heights.
+#[derive(Clone, Debug, Default)]
+pub struct Checkpoints {
+    state: Option<Arc<Mutex<CheckpointState>>>,
+}
+
+#[derive(Debug)]
+struct CheckpointState {
+    path: PathBuf,
+    heights: BTreeMap<ChainId, u64>,
+    last_flush: Instant,
+    dirty: bool,
+}
+
+impl CheckpointState {
+    fn flush(&mut self) -> Result<(), Error> {
+        let contents = serde_json::to_vec_pretty(&self.heights).map_err(Error::encode)?;
+
+        // Replace the file atomically, so that it is never read half-written
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        fs::write(&tmp_path, contents).map_err(|e| Error::io(tmp_path.clone(), e))?;
+        fs::rename(&tmp_path, &self.path).map_err(|e| Error::io(self.path.clone(), e))?;
+
+        self.last_flush = Instant::now();
+        self.dirty = false;
+
+        Ok(())
+    }
+}
+
+impl Drop for CheckpointState {
+    fn drop(&mut self) {
+        if self.dirty {
+            if let Err(e) = self.flush() {
+                warn!("failed to record the last handled heights: {e}");
+            }
+        }
+    }
+}
+
+impl Checkpoints {
+    /// Load the heights recorded in the given state file, if it exists,
+    /// and record the next ones in it.
+    pub fn open(path: PathBuf) -> Result<Self, Error> {
+        let heights = match fs::read(&path) {
+            Ok(contents) => {
+                serde_json::from_slice(&contents).map_err(|e| Error::decode(path.clone(), e))?
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(Error::io(path, e)),
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| Error::io(parent.to_path_buf(), e))?;
+        }
+
+        Ok(Self {
+            state: Some(Arc::new(Mutex::new(CheckpointState {
+                path,
+                heights,
+                last_flush: Instant::now(),
+                dirty: false,
+            }))),
+        })
+    }
+
+    /// The last height handled on the given chain, if any.
+    pub fn height(&self, chain_id: &ChainId) -> Option<u64> {
+        let state = self.state.as_ref()?;
+        let state = state.lock().unwrap();
+
+        state.heights.get(chain_id).copied()
+    }
+
+    /// Record that the events of the given chain were handled up to the given height,
+    /// writing the heights to the state file if they were not written for a while.
+    pub fn record(&self, chain_id: &ChainId, height: u64) {
+        let Some(state) = &self.state else {
+            return;
+        };
+
+        let mut state = state.lock().unwrap();
+
+        let last = state.heights.entry(chain_id.clone()).or_default();
+
+        if height <= *last {
+            return;
+        }
+
+        *last = height;
+        state.dirty = true;
+
+        if state.last_flush.elapsed() >= FLUSH_INTERVAL {
+            if let Err(e) = state.flush() {
+                warn!("failed to record the last handled heights: {e}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heights_are_kept_across_restarts() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CHECKPOINTS_FILE);
+        let chain_id = ChainId::from_string("ibc-0");
+
+        let checkpoints = Checkpoints::open(path.clone()).unwrap();
+        assert_eq!(checkpoints.height(&chain_id), None);
+
+        checkpoints.record(&chain_id, 10);
+        checkpoints.record(&chain_id, 12);
+        assert_eq!(checkpoints.height(&chain_id), Some(12));
+
+        // The heights are written when the last handle is dropped
+        drop(checkpoints);
+
+        let checkpoints = Checkpoints::open(path).unwrap();
+        assert_eq!(checkpoints.height(&chain_id), Some(12));
+        assert_eq!(checkpoints.height(&ChainId::from_string("ibc-1")), None);
+    }
+
+    #[test]
+    fn heights_only_move_forward() {
+        let dir = tempfile::tempdir().unwrap();
+        let checkpoints = Checkpoints::open(dir.path().join(CHECKPOINTS_FILE)).unwrap();
+        let chain_id = ChainId::from_string("ibc-0");
+
+        checkpoints.record(&chain_id, 12);
+        checkpoints.record(&chain_id, 10);
+
+        assert_eq!(checkpoints.height(&chain_id), Some(12));
+    }
+
+    #[test]
+    fn disabled_checkpoints() {
+        let checkpoints = Checkpoints::default();
+        let chain_id = ChainId::from_string("ibc-0");
+
+        checkpoints.record(&chain_id, 12);
+
+        assert_eq!(checkpoints.height(&chain_id), None);
+    }
+}
@@ -202,6 +202,15 @@ impl FilterPolicy {
                     Permission::Allow
                 }
             }
+            // Solo machine clients verify the signatures of a single key, not of a quorum,
+            // and the localhost client verifies the state of its own chain
+            None if matches!(
+                state,
+                AnyClientState::SoloMachine(_) | AnyClientState::Localhost(_)
+            ) =>
+            {
+                Permission::Allow
+            }
             None => {
                 trace!(
                     "client {} on chain {} does not have a trust threshold set",
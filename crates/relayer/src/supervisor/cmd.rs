@@ -1,8 +1,17 @@
 use crossbeam_channel::Sender;
 
 use super::dump_state::SupervisorState;
+use super::reload::ConfigUpdate;
+use super::replay::{ReplayFrom, ReplayRange};
+use super::Error;
 
 #[derive(Clone, Debug)]
 pub enum SupervisorCmd {
     DumpState(Sender<SupervisorState>),
+    ReloadConfig(Sender<Result<ConfigUpdate, Error>>),
+    Replay(ReplayFrom, Sender<Result<ReplayRange, Error>>),
+    /// Spawn the workers, once this instance acquired the lease
+    TakeOver,
+    /// Stop the workers and scan the chains, once this instance lost the lease
+    StandBy,
 }
@@ -7,7 +7,9 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use crate::{
+    link::rate_limit::DeferredPackets,
     object::{Object, ObjectType},
+    supervisor::{lease::LeadershipState, shard::ChannelShard},
     worker::{WorkerData, WorkerHandle, WorkerId},
 };
 
@@ -28,12 +30,24 @@ impl WorkerDesc {
 pub struct SupervisorState {
     pub chains: Vec<ChainId>,
     pub workers: BTreeMap<ObjectType, Vec<WorkerDesc>>,
+    /// The channels with packets deferred by the rate limits of their chain
+    #[serde(default)]
+    pub deferred_packets: Vec<DeferredPackets>,
+    /// The role of the instance, if it runs in high-availability mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leadership: Option<LeadershipState>,
+    /// The shards the channels are assigned to, if sharding is enabled
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel_shards: Vec<ChannelShard>,
 }
 
 impl SupervisorState {
     pub fn new<'a>(
         mut chains: Vec<ChainId>,
         workers: impl Iterator<Item = &'a WorkerHandle>,
+        deferred_packets: Vec<DeferredPackets>,
+        leadership: Option<LeadershipState>,
+        channel_shards: Vec<ChannelShard>,
     ) -> Self {
         chains.sort();
 
@@ -44,7 +58,13 @@ impl SupervisorState {
             .update(|(_, os)| os.sort_by_key(|desc| desc.object.short_name()))
             .collect::<BTreeMap<_, _>>();
 
-        Self { chains, workers }
+        Self {
+            chains,
+            workers,
+            deferred_packets,
+            leadership,
+            channel_shards,
+        }
     }
 
     pub fn print_info(&self) {
@@ -58,6 +78,13 @@ impl Display for SupervisorState {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         writeln!(f)?;
         writeln!(f, "* Chains: {}", self.chains.iter().join(", "))?;
+        if let Some(leadership) = &self.leadership {
+            writeln!(
+                f,
+                "* Instance: {} ({})",
+                leadership.instance_id, leadership.role
+            )?;
+        }
         for (tpe, objects) in &self.workers {
             writeln!(f, "* {tpe:?} workers:")?;
             for desc in objects {
@@ -65,13 +92,50 @@ impl Display for SupervisorState {
                 if let Some(WorkerData::Client {
                     misbehaviour,
                     refresh,
+                    upgrade,
                 }) = desc.data
                 {
-                    writeln!(f, "    | misbehaviour: {misbehaviour}, refresh: {refresh}")?;
+                    writeln!(
+                        f,
+                        "    | misbehaviour: {misbehaviour}, refresh: {refresh}, upgrade: {upgrade}"
+                    )?;
                 }
             }
         }
 
+        if !self.deferred_packets.is_empty() {
+            writeln!(f, "* Deferred packets:")?;
+            for deferred in &self.deferred_packets {
+                writeln!(
+                    f,
+                    "  - {}/{}/{}: {}",
+                    deferred.chain_id, deferred.port_id, deferred.channel_id, deferred.packets
+                )?;
+            }
+        }
+
+        if !self.channel_shards.is_empty() {
+            writeln!(f, "* Channel shards:")?;
+            for channel_shard in &self.channel_shards {
+                let shard = match channel_shard.shard {
+                    Some(shard) => format!("shard {shard}"),
+                    None => "explicit assignment".to_string(),
+                };
+
+                let relayed = if channel_shard.owned {
+                    "relayed by this instance"
+                } else {
+                    "relayed by another instance"
+                };
+
+                writeln!(
+                    f,
+                    "  - {}/{}/{}: {shard}, {relayed}",
+                    channel_shard.chain_id, channel_shard.port_id, channel_shard.channel_id
+                )?;
+            }
+        }
+
         Ok(())
     }
 }
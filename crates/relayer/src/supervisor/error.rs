@@ -3,8 +3,12 @@ use flex_error::define_error;
 use ibc_relayer_types::core::ics03_connection::connection::Counterparty;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, ConnectionId, PortId};
 
+use crate::config::Error as ConfigError;
 use crate::error::Error as RelayerError;
+use crate::event::source::Error as EventSourceError;
+use crate::multihop::MultihopError;
 use crate::spawn::SpawnError;
+use crate::supervisor::checkpoint::Error as CheckpointError;
 use crate::supervisor::scan::Error as ScanError;
 
 define_error! {
@@ -70,11 +74,53 @@ define_error! {
             [ ScanError ]
             |_| { "supervisor encountered an error when scanning chains" },
 
+        Multihop
+            [ MultihopError ]
+            |_| { "failed to resolve the intermediate chains of a multi-hop channel" },
+
         HandleSend
             |_| { "failed to send a command to the supervisor through a channel" },
 
         HandleRecv
             |_| { "failed to receive the result of a command from the supervisor through a channel" },
+
+        MissingConfigPath
+            |_| { "cannot reload the configuration as the path to the configuration file is unknown" },
+
+        Config
+            [ ConfigError ]
+            |_| { "failed to load the configuration" },
+
+        Checkpoint
+            [ CheckpointError ]
+            |_| { "failed to load the last handled heights" },
+
+        DataDirUnavailable
+            |_| { "the folder to keep the last handled heights in is not configured, \
+                and the home folder could not be determined" },
+
+        EventSource
+            [ EventSourceError ]
+            |_| { "failed to pull events from the chain" },
+
+        ReplayChainNotFound
+            { chain_id: ChainId }
+            |e| { format_args!("cannot replay the events of chain {}, which is not configured", e.chain_id) },
+
+        ReplayUnsupported
+            { chain_id: ChainId }
+            |e| { format_args!("replaying the events of chain {} is not supported", e.chain_id) },
+
+        InvalidReplayHeight
+            {
+                chain_id: ChainId,
+                height: u64,
+                latest: u64,
+            }
+            |e| {
+                format_args!("cannot replay the events of chain {} from height {}, which is not between 1 and the latest height {}",
+                    e.chain_id, e.height, e.latest)
+            },
     }
 }
 
@@ -0,0 +1,390 @@
+//! The lease which the instances of Hermes running in high-availability mode
+//! compete for, so that only one of them relays at any given time.
+//!
+//! The instance holding the lease is the leader, and renews it periodically.
+//! The other instances stand by, with their chains scanned but without any
+//! workers, and take the lease over once the leader let it lapse.
+//!
+//! The lease is kept behind the [`Lease`] trait, so that other backends than
+//! the [`FileLease`], eg. a key-value store, can be added.
+
+use alloc::sync::Arc;
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::time::Duration;
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use flex_error::{define_error, TraceError};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// How old the lock guarding the lease file has to be for it to be considered
+/// left behind by an instance which stopped while holding it.
+const STALE_LOCK_AGE: Duration = Duration::from_secs(10);
+
+define_error! {
+    Error {
+        Io
+            { path: PathBuf }
+            [ TraceError<io::Error> ]
+            |e| { format!("I/O error on the lease file {}", e.path.display()) },
+
+        Busy
+            { path: PathBuf }
+            |e| { format!("the lease file {} is being updated by another instance", e.path.display()) },
+
+        Encode
+            [ TraceError<serde_json::Error> ]
+            |_| { "failed to encode the lease" },
+    }
+}
+
+/// A lease, held by at most one instance at a time.
+pub trait Lease: Send + Sync {
+    /// Acquire the lease for the given holder, or renew it if the holder already
+    /// has it, until `duration` from now.
+    ///
+    /// Returns `false` if the lease is held by another instance.
+    fn try_acquire(&self, holder: &str, duration: Duration) -> Result<bool, Error>;
+
+    /// Let go of the lease if it is held by the given holder,
+    /// so that another instance can take it over right away.
+    fn release(&self, holder: &str) -> Result<(), Error>;
+}
+
+/// The holder of a lease, and until when it holds it.
+#[derive(Debug, Serialize, Deserialize)]
+struct LeaseRecord {
+    holder: String,
+    /// Milliseconds since the Unix epoch
+    expires_at: u128,
+}
+
+/// A lease kept in a file on storage shared by all the instances.
+///
+/// The file is only updated while holding a lock, which is a sibling file
+/// created exclusively, and it is replaced atomically so that it can never be
+/// read half-written. The expiry of the lease is compared against the local
+/// clock, which must thus be kept in sync across the hosts.
+#[derive(Clone, Debug)]
+pub struct FileLease {
+    path: PathBuf,
+}
+
+impl FileLease {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    fn sibling(&self, extension: &str) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(".");
+        path.push(extension);
+        path.into()
+    }
+
+    fn lock(&self) -> Result<LockGuard, Error> {
+        let path = self.sibling("lock");
+
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(_) => Ok(LockGuard { path }),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                let stale = fs::metadata(&path)
+                    .and_then(|metadata| metadata.modified())
+                    .ok()
+                    .and_then(|modified| modified.elapsed().ok())
+                    .is_some_and(|age| age > STALE_LOCK_AGE);
+
+                if stale {
+                    warn!("removing the stale lock {}", path.display());
+                    let _ = fs::remove_file(&path);
+                }
+
+                Err(Error::busy(self.path.clone()))
+            }
+            Err(e) => Err(Error::io(path, e)),
+        }
+    }
+
+    fn read(&self) -> Result<Option<LeaseRecord>, Error> {
+        let contents = match fs::read(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(Error::io(self.path.clone(), e)),
+        };
+
+        match serde_json::from_slice(&contents) {
+            Ok(record) => Ok(Some(record)),
+            Err(e) => {
+                warn!(
+                    "ignoring the unreadable lease file {}: {e}",
+                    self.path.display()
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    fn write(&self, record: &LeaseRecord) -> Result<(), Error> {
+        let contents = serde_json::to_vec(record).map_err(Error::encode)?;
+
+        let tmp_path = self.sibling("tmp");
+        fs::write(&tmp_path, contents).map_err(|e| Error::io(tmp_path.clone(), e))?;
+        fs::rename(&tmp_path, &self.path).map_err(|e| Error::io(self.path.clone(), e))
+    }
+}
+
+impl Lease for FileLease {
+    fn try_acquire(&self, holder: &str, duration: Duration) -> Result<bool, Error> {
+        let _lock = self.lock()?;
+
+        let now = now_millis();
+
+        let held_by_another = self
+            .read()?
+            .is_some_and(|record| record.holder != holder && record.expires_at > now);
+
+        if held_by_another {
+            return Ok(false);
+        }
+
+        self.write(&LeaseRecord {
+            holder: holder.to_string(),
+            expires_at: now + duration.as_millis(),
+        })?;
+
+        Ok(true)
+    }
+
+    fn release(&self, holder: &str) -> Result<(), Error> {
+        let _lock = self.lock()?;
+
+        if self.read()?.is_some_and(|record| record.holder == holder) {
+            fs::remove_file(&self.path).map_err(|e| Error::io(self.path.clone(), e))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Removes the lock file when dropped.
+struct LockGuard {
+    path: PathBuf,
+}
+
+impl Drop for LockGuard {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Releases the lease when dropped, ie. when the task renewing it stops.
+pub struct LeaseGuard {
+    lease: Box<dyn Lease>,
+    holder: String,
+}
+
+impl LeaseGuard {
+    pub fn new(lease: Box<dyn Lease>, holder: String) -> Self {
+        Self { lease, holder }
+    }
+
+    pub fn lease(&self) -> &dyn Lease {
+        self.lease.as_ref()
+    }
+
+    pub fn holder(&self) -> &str {
+        &self.holder
+    }
+}
+
+impl Drop for LeaseGuard {
+    fn drop(&mut self) {
+        if let Err(e) = self.lease.release(&self.holder) {
+            warn!("failed to release the lease: {e}");
+        }
+    }
+}
+
+/// Whether an instance holds the lease.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Leader,
+    Standby,
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Role::Leader => write!(f, "leader"),
+            Role::Standby => write!(f, "standby"),
+        }
+    }
+}
+
+/// The role of an instance running in high-availability mode.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeadershipState {
+    pub instance_id: String,
+    pub role: Role,
+}
+
+/// Whether the instance currently holds the lease, and may thus spawn workers.
+///
+/// Without high-availability mode, the instance is always the leader.
+/// Cloning yields a handle to the same state.
+#[derive(Clone, Debug)]
+pub struct Leadership {
+    instance_id: Option<String>,
+    leader: Arc<AtomicBool>,
+}
+
+impl Default for Leadership {
+    fn default() -> Self {
+        Self {
+            instance_id: None,
+            leader: Arc::new(AtomicBool::new(true)),
+        }
+    }
+}
+
+impl Leadership {
+    /// The leadership of an instance running in high-availability mode,
+    /// which stands by until it acquires the lease.
+    pub fn new(instance_id: String) -> Self {
+        Self {
+            instance_id: Some(instance_id),
+            leader: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn is_leader(&self) -> bool {
+        self.leader.load(Ordering::SeqCst)
+    }
+
+    /// Record whether the instance holds the lease,
+    /// returning whether its role changed.
+    pub fn set_leader(&self, leader: bool) -> bool {
+        self.leader.swap(leader, Ordering::SeqCst) != leader
+    }
+
+    pub fn role(&self) -> Role {
+        if self.is_leader() {
+            Role::Leader
+        } else {
+            Role::Standby
+        }
+    }
+
+    /// The role of the instance, if it runs in high-availability mode.
+    pub fn state(&self) -> Option<LeadershipState> {
+        self.instance_id
+            .as_ref()
+            .map(|instance_id| LeadershipState {
+                instance_id: instance_id.clone(),
+                role: self.role(),
+            })
+    }
+}
+
+/// An identifier for this instance, unique across hosts with high probability.
+pub fn default_instance_id() -> String {
+    format!("hermes-{}-{}", std::process::id(), now_millis())
+}
+
+fn now_millis() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::path::Path;
+
+    fn exists(path: &Path) -> bool {
+        path.try_exists().unwrap()
+    }
+
+    #[test]
+    fn lease_is_held_by_one_instance_at_a_time() {
+        let dir = tempfile::tempdir().unwrap();
+        let lease = FileLease::new(dir.path().join("hermes.lease"));
+        let duration = Duration::from_secs(60);
+
+        assert!(lease.try_acquire("a", duration).unwrap());
+        assert!(!lease.try_acquire("b", duration).unwrap());
+
+        // The holder renews the lease
+        assert!(lease.try_acquire("a", duration).unwrap());
+        assert!(!lease.try_acquire("b", duration).unwrap());
+    }
+
+    #[test]
+    fn lapsed_lease_is_taken_over() {
+        let dir = tempfile::tempdir().unwrap();
+        let lease = FileLease::new(dir.path().join("hermes.lease"));
+
+        assert!(lease.try_acquire("a", Duration::ZERO).unwrap());
+        assert!(lease.try_acquire("b", Duration::from_secs(60)).unwrap());
+        assert!(!lease.try_acquire("a", Duration::from_secs(60)).unwrap());
+    }
+
+    #[test]
+    fn released_lease_is_taken_over() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hermes.lease");
+        let lease = FileLease::new(path.clone());
+        let duration = Duration::from_secs(60);
+
+        assert!(lease.try_acquire("a", duration).unwrap());
+
+        // Only the holder can release the lease
+        lease.release("b").unwrap();
+        assert!(exists(&path));
+
+        lease.release("a").unwrap();
+        assert!(!exists(&path));
+
+        assert!(lease.try_acquire("b", duration).unwrap());
+    }
+
+    #[test]
+    fn lease_is_busy_while_locked() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hermes.lease");
+        let lease = FileLease::new(path);
+
+        let lock = lease.lock().unwrap();
+        assert!(lease.try_acquire("a", Duration::from_secs(60)).is_err());
+
+        drop(lock);
+        assert!(lease.try_acquire("a", Duration::from_secs(60)).unwrap());
+    }
+
+    #[test]
+    fn leadership_changes() {
+        let leadership = Leadership::new("a".to_string());
+        assert_eq!(leadership.role(), Role::Standby);
+
+        assert!(leadership.set_leader(true));
+        assert!(!leadership.set_leader(true));
+        assert_eq!(
+            leadership.state(),
+            Some(LeadershipState {
+                instance_id: "a".to_string(),
+                role: Role::Leader
+            })
+        );
+
+        assert!(Leadership::default().is_leader());
+        assert_eq!(Leadership::default().state(), None);
+    }
+}
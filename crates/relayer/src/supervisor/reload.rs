@@ -0,0 +1,462 @@
+//! Reloading of the configuration of a running supervisor.
+//!
+//! The new configuration is compared against the one the supervisor is currently
+//! running with, and only the parts of the supervisor affected by the changes are
+//! restarted: chain runtimes are spawned or shut down for chains which were added,
+//! removed or updated, and only the workers relaying on these chains are restarted.
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use tracing::{error, error_span, info, warn};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::{
+    chain::handle::ChainHandle,
+    config::{self, ChainConfig, Config, Diagnostic},
+    object::Object,
+    registry::SharedRegistry,
+    telemetry,
+    util::{
+        diff::{gdiff, Change},
+        lock::{LockExt, RwArc},
+        task::TaskHandle,
+    },
+    worker::WorkerMap,
+};
+
+use super::{
+    chain_scanner, client_state_filter::FilterPolicy, scan::ChainsScan, scan::ScanMode,
+    should_scan, spawn_batch_worker, spawn_context, Error, SupervisorOptions,
+};
+
+/// The changes between the configuration the supervisor is running with
+/// and a newly loaded configuration.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigUpdate {
+    /// Chains which were added to the configuration.
+    pub added_chains: Vec<ChainId>,
+    /// Chains which were removed from the configuration.
+    pub removed_chains: Vec<ChainId>,
+    /// Chains whose configuration changed in a way which
+    /// requires restarting their runtime.
+    pub updated_chains: Vec<ChainId>,
+    /// Chains for which only the packet filter changed.
+    pub updated_packet_filters: Vec<ChainId>,
+    /// Whether the `[mode]` section changed.
+    pub mode_updated: bool,
+    /// Whether the `[sharding]` section changed.
+    #[serde(default)]
+    pub sharding_updated: bool,
+    /// Sections which changed but which can only be
+    /// applied by restarting Hermes.
+    pub ignored_sections: Vec<String>,
+}
+
+impl ConfigUpdate {
+    /// Compute the changes between the `prev` and `next` configurations.
+    pub fn new(prev: &Config, next: &Config) -> Self {
+        let mut update = Self::default();
+
+        let prev_chains = prev.chains_map();
+        let next_chains = next.chains_map();
+
+        for change in gdiff(&prev_chains, &next_chains, |a, b| a == b) {
+            match change {
+                Change::Added(chain_id) => update.added_chains.push((*chain_id).clone()),
+                Change::Removed(chain_id) => update.removed_chains.push((*chain_id).clone()),
+                Change::Updated(chain_id) => {
+                    let chain_id = *chain_id;
+
+                    if only_packet_filter_changed(prev_chains[chain_id], next_chains[chain_id]) {
+                        update.updated_packet_filters.push(chain_id.clone());
+                    } else {
+                        update.updated_chains.push(chain_id.clone());
+                    }
+                }
+            }
+        }
+
+        update.mode_updated = prev.mode != next.mode;
+        update.sharding_updated = prev.sharding != next.sharding;
+
+        let sections = [
+            ("global", prev.global != next.global),
+            ("rest", prev.rest != next.rest),
+            ("telemetry", prev.telemetry != next.telemetry),
+            ("tracing_server", prev.tracing_server != next.tracing_server),
+            (
+                "high_availability",
+                prev.high_availability != next.high_availability,
+            ),
+            ("catch_up", prev.catch_up != next.catch_up),
+        ];
+
+        update.ignored_sections = sections
+            .into_iter()
+            .filter(|(_, changed)| *changed)
+            .map(|(section, _)| section.to_string())
+            .collect();
+
+        update
+    }
+
+    /// Whether the new configuration is identical to the previous one.
+    pub fn is_empty(&self) -> bool {
+        self.added_chains.is_empty()
+            && self.removed_chains.is_empty()
+            && self.updated_chains.is_empty()
+            && self.updated_packet_filters.is_empty()
+            && !self.mode_updated
+            && !self.sharding_updated
+            && self.ignored_sections.is_empty()
+    }
+
+    /// The chains whose runtime has to be shut down.
+    fn chains_to_shutdown(&self) -> impl Iterator<Item = &ChainId> {
+        self.removed_chains.iter().chain(&self.updated_chains)
+    }
+
+    /// The chains whose runtime has to be (re)spawned.
+    fn chains_to_spawn(&self) -> impl Iterator<Item = &ChainId> {
+        self.added_chains.iter().chain(&self.updated_chains)
+    }
+}
+
+/// Whether the two chain configurations only differ by their packet filter.
+fn only_packet_filter_changed(prev: &ChainConfig, next: &ChainConfig) -> bool {
+    match (prev, next) {
+        (ChainConfig::CosmosSdk(prev), ChainConfig::CosmosSdk(next)) => {
+            let mut prev = prev.clone();
+            prev.packet_filter = next.packet_filter.clone();
+            &prev == next
+        }
+        (ChainConfig::SoloMachine(prev), ChainConfig::SoloMachine(next)) => {
+            let mut prev = prev.clone();
+            prev.packet_filter = next.packet_filter.clone();
+            &prev == next
+        }
+        _ => false,
+    }
+}
+
+/// Load and validate the configuration at the given path.
+fn load_config(path: &Path) -> Result<Config, Error> {
+    let config = config::load(path).map_err(Error::config)?;
+
+    match config.validate_config() {
+        Ok(()) => Ok(config),
+        Err(Diagnostic::Warning(e)) => {
+            warn!("the reloaded configuration is valid, with the following warning: {e}");
+            Ok(config)
+        }
+        Err(Diagnostic::Error(e)) => Err(Error::config(e)),
+    }
+}
+
+/// The objects whose workers have to be stopped in order to apply the given update.
+fn affected_objects(
+    update: &ConfigUpdate,
+    prev: &Config,
+    next: &Config,
+    workers: &WorkerMap,
+) -> Vec<Object> {
+    let objects = workers.handles().map(|handle| handle.object());
+
+    // Workers capture the mode configuration when they are spawned,
+    // so all of them have to be restarted when it changes.
+    // The same goes for the sharding, as the objects are assigned anew.
+    if update.mode_updated || update.sharding_updated {
+        return objects.cloned().collect();
+    }
+
+    objects
+        .filter(|object| {
+            if update.chains_to_shutdown().any(|id| object.for_chain(id)) {
+                return true;
+            }
+
+            let src_chain_id = object.src_chain_id();
+
+            if !update.updated_packet_filters.contains(src_chain_id) {
+                return false;
+            }
+
+            match object {
+                // Packet workers capture the fee filter of their channel
+                // and the ICS-20 filter of their chain when they are spawned
+                Object::Packet(p) => {
+                    let filters = |config: &Config| {
+                        config.find_chain(src_chain_id).map(|c| {
+                            let packet_filter = c.packet_filter();
+                            (packet_filter.min_fees.clone(), packet_filter.ics20.clone())
+                        })
+                    };
+
+                    filters(prev) != filters(next)
+                        || !next.packets_on_channel_allowed(
+                            src_chain_id,
+                            &p.src_port_id,
+                            &p.src_channel_id,
+                        )
+                }
+                Object::Channel(c) => !next.packets_on_channel_allowed(
+                    src_chain_id,
+                    &c.src_port_id,
+                    &c.src_channel_id,
+                ),
+                _ => false,
+            }
+        })
+        .cloned()
+        .collect()
+}
+
+/// Reload the configuration from the file at `config_path`, and apply the
+/// changes to the running supervisor.
+///
+/// This must not be called while holding any of the supervisor locks,
+/// as it needs to wait for the batch workers of updated chains to terminate.
+pub(super) fn reload_config<Chain: ChainHandle>(
+    config_path: &Path,
+    options: &SupervisorOptions,
+    config: &RwArc<Config>,
+    registry: &SharedRegistry<Chain>,
+    client_state_filter: &RwArc<FilterPolicy>,
+    workers: &RwArc<WorkerMap>,
+    batch_tasks: &mut BTreeMap<ChainId, TaskHandle>,
+) -> Result<ConfigUpdate, Error> {
+    let _span = error_span!("supervisor.reload_config").entered();
+
+    info!("reloading configuration from {}", config_path.display());
+
+    let next = load_config(config_path)?;
+    let prev = config.acquire_read().clone();
+
+    let update = ConfigUpdate::new(&prev, &next);
+
+    if update.is_empty() {
+        info!("configuration is unchanged, nothing to do");
+        return Ok(update);
+    }
+
+    for section in &update.ignored_sections {
+        warn!("changes to the `[{section}]` section will only be applied after restarting Hermes");
+    }
+
+    // Stop listening for events on the chains which are going away,
+    // before the runtime producing these events is shut down.
+    for chain_id in update.chains_to_shutdown() {
+        if let Some(task) = batch_tasks.remove(chain_id) {
+            task.shutdown_and_wait();
+        }
+    }
+
+    *config.acquire_write() = next.clone();
+    registry.write().set_config(next.clone());
+
+    let stopped = {
+        let mut workers = workers.acquire_write();
+        let objects = affected_objects(&update, &prev, &next, &workers);
+
+        for object in &objects {
+            info!("stopping worker: {}", object.short_name());
+            workers.shutdown_worker(object);
+        }
+
+        for chain_id in update.chains_to_shutdown() {
+            workers.forget_channel_shards(chain_id);
+        }
+
+        if update.sharding_updated {
+            for chain_config in &prev.chains {
+                workers.forget_channel_shards(chain_config.id());
+            }
+        }
+
+        objects
+    };
+
+    for chain_id in update.chains_to_shutdown() {
+        info!(chain = %chain_id, "shutting down chain runtime");
+        registry.shutdown(chain_id);
+    }
+
+    for chain_id in update.chains_to_spawn() {
+        let chain = match registry.get_or_spawn(chain_id) {
+            Ok(chain) => chain,
+            Err(e) => {
+                error!(chain = %chain_id, "failed to spawn chain runtime: {e}");
+                continue;
+            }
+        };
+
+        if next.telemetry.enabled {
+            if let Ok(_key) = chain.get_key() {
+                telemetry!(add_visible_fee_address, _key.account());
+            }
+        }
+
+        match chain.subscribe() {
+            Ok(subscription) => {
+                let task = spawn_batch_worker(
+                    config.clone(),
+                    registry.clone(),
+                    client_state_filter.clone(),
+                    workers.clone(),
+                    chain,
+                    subscription,
+                    None,
+                );
+
+                batch_tasks.insert(chain_id.clone(), task);
+            }
+            Err(e) => error!(chain = %chain_id, "failed to subscribe to events: {e}"),
+        }
+    }
+
+    // Scan the chains whose workers were stopped, as well as the new and updated
+    // chains, to respawn the workers which are still relevant under the new config.
+    // When the sharding changed, all the chains are scanned for the objects newly
+    // assigned to this instance, including on chains where it had no workers.
+    let to_scan: BTreeSet<&ChainId> = update
+        .chains_to_spawn()
+        .chain(&update.updated_packet_filters)
+        .chain(stopped.iter().map(|object| object.src_chain_id()))
+        .chain(
+            next.chains
+                .iter()
+                .filter(|_| update.sharding_updated)
+                .map(|chain_config| chain_config.id()),
+        )
+        .filter(|chain_id| next.has_chain(chain_id))
+        .collect();
+
+    if !to_scan.is_empty() && should_scan(&next, options) {
+        let scan_mode = if options.force_full_scan {
+            ScanMode::Full
+        } else {
+            ScanMode::Auto
+        };
+
+        let mut registry = registry.write();
+
+        let scan = {
+            let mut client_state_filter = client_state_filter.acquire_write();
+            let mut scanner =
+                chain_scanner(&next, &mut registry, &mut client_state_filter, scan_mode);
+
+            ChainsScan {
+                chains: to_scan
+                    .into_iter()
+                    .filter_map(|chain_id| next.find_chain(chain_id))
+                    .map(|chain_config| scanner.scan_chain(chain_config))
+                    .collect(),
+            }
+        };
+
+        spawn_context(&next, &mut registry, &mut workers.acquire_write()).spawn_workers(scan);
+    }
+
+    info!("configuration reloaded");
+
+    Ok(update)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::config::{filter::ChannelPolicy, load};
+
+    fn example_config() -> Config {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/config/fixtures/relayer_conf_example.toml"
+        );
+
+        load(path).expect("could not parse config")
+    }
+
+    #[test]
+    fn unchanged_config() {
+        let config = example_config();
+
+        let update = ConfigUpdate::new(&config, &config.clone());
+
+        assert!(update.is_empty());
+    }
+
+    #[test]
+    fn added_and_removed_chains() {
+        let prev = example_config();
+        let mut next = prev.clone();
+
+        let removed = next.chains.remove(0);
+
+        let mut added = removed.clone();
+        match &mut added {
+            ChainConfig::CosmosSdk(c) => c.id = ChainId::from_string("chain_C"),
+            _ => panic!("expected a CosmosSdk chain config"),
+        }
+        next.chains.push(added);
+
+        let update = ConfigUpdate::new(&prev, &next);
+
+        assert_eq!(update.added_chains, vec![ChainId::from_string("chain_C")]);
+        assert_eq!(update.removed_chains, vec![removed.id().clone()]);
+        assert!(update.updated_chains.is_empty());
+        assert!(!update.mode_updated);
+    }
+
+    #[test]
+    fn packet_filter_only_change() {
+        let prev = example_config();
+        let mut next = prev.clone();
+
+        match &mut next.chains[0] {
+            ChainConfig::CosmosSdk(c) => c.packet_filter.channel_policy = ChannelPolicy::AllowAll,
+            _ => panic!("expected a CosmosSdk chain config"),
+        }
+
+        let update = ConfigUpdate::new(&prev, &next);
+
+        assert_eq!(
+            update.updated_packet_filters,
+            vec![prev.chains[0].id().clone()]
+        );
+        assert!(update.updated_chains.is_empty());
+    }
+
+    #[test]
+    fn chain_and_mode_changes() {
+        let prev = example_config();
+        let mut next = prev.clone();
+
+        next.chains[1].set_key_name("other-key".to_string());
+        next.mode.packets.clear_interval += 1;
+        next.telemetry.port += 1;
+
+        let update = ConfigUpdate::new(&prev, &next);
+
+        assert_eq!(update.updated_chains, vec![prev.chains[1].id().clone()]);
+        assert!(update.mode_updated);
+        assert_eq!(update.ignored_sections, vec!["telemetry".to_string()]);
+    }
+
+    #[test]
+    fn sharding_change() {
+        let prev = example_config();
+        let mut next = prev.clone();
+
+        next.sharding.shard_count = 2;
+
+        let update = ConfigUpdate::new(&prev, &next);
+
+        assert!(update.sharding_updated);
+        assert!(!update.mode_updated);
+        assert!(update.ignored_sections.is_empty());
+    }
+}
@@ -0,0 +1,184 @@
+//! Replay of the events of past blocks through the supervisor, eg. to relay the
+//! events emitted while Hermes, or the full node it connects to, was down.
+//!
+//! The events are pulled from the RPC endpoint of the chain one block at a time
+//! by the batch worker of the chain, which only goes on with the live events
+//! once the replay is complete.
+
+use alloc::sync::Arc;
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use serde::{Deserialize, Serialize};
+use tendermint::block::Height as BlockHeight;
+use tendermint_rpc::{client::CompatMode, Client, HttpClient};
+use tokio::runtime::Runtime as TokioRuntime;
+use tracing::{info, warn};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::chain::cosmos::failover::build_rpc_client;
+use crate::config::{default, ChainConfig, Config, EventSourceMode};
+use crate::error::Error as RelayerError;
+use crate::event::source::rpc::Replay;
+use crate::util::compat_mode::compat_mode_from_version;
+
+use super::Error;
+
+/// The chain and height to replay the events from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReplayFrom {
+    pub chain_id: ChainId,
+    pub height: u64,
+}
+
+/// The heights whose events are being replayed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayRange {
+    pub chain_id: ChainId,
+    pub start: u64,
+    pub end: u64,
+}
+
+impl ReplayRange {
+    pub fn new(replay: &Replay) -> Self {
+        Self {
+            chain_id: replay.chain_id().clone(),
+            start: replay.start().value(),
+            end: replay.end().value(),
+        }
+    }
+}
+
+impl Display for ReplayRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "replaying the events of {} from height {} to {}",
+            self.chain_id, self.start, self.end
+        )
+    }
+}
+
+/// Set up the replay of the events of a chain, from the given height
+/// up to the latest height of the chain.
+pub fn replay(config: &Config, from: &ReplayFrom) -> Result<Replay, Error> {
+    let source = ReplaySource::connect(config, &from.chain_id)?;
+
+    let start = BlockHeight::try_from(from.height)
+        .ok()
+        .filter(|h| h.value() > 0);
+
+    match start {
+        Some(start) if start <= source.latest => source.replay(start),
+        _ => Err(Error::invalid_replay_height(
+            from.chain_id.clone(),
+            from.height,
+            source.latest.value(),
+        )),
+    }
+}
+
+/// Set up the replay of the events of a chain which were emitted after the given
+/// height, the last one handled before Hermes stopped, going back at most
+/// `max_lookback` blocks from the latest height of the chain.
+///
+/// Returns `None` if there are no events to catch up on.
+pub fn catch_up(
+    config: &Config,
+    chain_id: &ChainId,
+    last_height: u64,
+    max_lookback: u64,
+) -> Result<Option<Replay>, Error> {
+    let source = ReplaySource::connect(config, chain_id)?;
+    let latest = source.latest.value();
+
+    if last_height >= latest {
+        return Ok(None);
+    }
+
+    let oldest = latest.saturating_sub(max_lookback).saturating_add(1);
+    let start = (last_height + 1).max(oldest);
+
+    if start > last_height + 1 {
+        warn!(
+            chain = %chain_id,
+            "only catching up on the last {max_lookback} blocks, the events from height {} to {} \
+             are left to packet clearing",
+            last_height + 1,
+            start - 1
+        );
+    }
+
+    let start = BlockHeight::try_from(start)
+        .map_err(|_| Error::invalid_replay_height(chain_id.clone(), start, latest))?;
+
+    source.replay(start).map(Some)
+}
+
+/// The RPC endpoint of a chain to pull the events to replay from.
+struct ReplaySource {
+    chain_id: ChainId,
+    rpc_client: HttpClient,
+    max_retries: u32,
+    rt: Arc<TokioRuntime>,
+    latest: BlockHeight,
+}
+
+impl ReplaySource {
+    fn connect(config: &Config, chain_id: &ChainId) -> Result<Self, Error> {
+        let chain_config = config
+            .find_chain(chain_id)
+            .ok_or_else(|| Error::replay_chain_not_found(chain_id.clone()))?;
+
+        let ChainConfig::CosmosSdk(chain_config) = chain_config else {
+            return Err(Error::replay_unsupported(chain_id.clone()));
+        };
+
+        let max_retries = match chain_config.event_source {
+            EventSourceMode::Pull { max_retries, .. }
+            | EventSourceMode::Hybrid { max_retries, .. } => max_retries,
+            EventSourceMode::Push { .. } => default::max_retries(),
+        };
+
+        let rt = TokioRuntime::new()
+            .map(Arc::new)
+            .map_err(|e| Error::relayer(RelayerError::io(e)))?;
+
+        // The compatibility mode only matters once the node version is known
+        let mut rpc_client = build_rpc_client(&chain_config.rpc_addr, CompatMode::latest())
+            .map_err(Error::relayer)?;
+
+        let status = rt
+            .block_on(rpc_client.status())
+            .map_err(|e| Error::relayer(RelayerError::rpc(chain_config.rpc_addr.clone(), e)))?;
+
+        let compat_mode =
+            compat_mode_from_version(&chain_config.compat_mode, status.node_info.version)
+                .map_err(Error::relayer)?;
+
+        rpc_client.set_compat_mode(compat_mode.into());
+
+        Ok(Self {
+            chain_id: chain_id.clone(),
+            rpc_client,
+            max_retries,
+            rt,
+            latest: status.sync_info.latest_block_height,
+        })
+    }
+
+    fn replay(self, start: BlockHeight) -> Result<Replay, Error> {
+        let replay = Replay::new(
+            self.chain_id,
+            self.rpc_client,
+            start,
+            self.max_retries,
+            self.rt,
+        )
+        .map_err(Error::event_source)?;
+
+        info!("{}", ReplayRange::new(&replay));
+
+        Ok(replay)
+    }
+}
@@ -960,7 +960,8 @@ fn init_telemetry(
             chain_id,
             counterparty_chain_id,
             client,
-            config.mode.clients.misbehaviour && config.mode.clients.enabled
+            config.mode.clients.misbehaviour && config.mode.clients.enabled,
+            config.mode.clients.upgrade && config.mode.clients.enabled
         );
     }
 
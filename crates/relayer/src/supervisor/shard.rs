@@ -0,0 +1,225 @@
+//! Sharing of the relaying among several instances, each of which only spawns
+//! the workers for the objects assigned to its shard by the `[sharding]` section.
+//!
+//! Objects are assigned to shards by hashing their identifiers, so that every
+//! instance computes the same assignment without coordinating with the others.
+//! The packet and channel workers of a channel are assigned by the channel end
+//! they relay from, unless the channels are explicitly assigned to the instance.
+//! Every other object, clients in particular, is owned by exactly one shard,
+//! so that shared clients are only refreshed by a single instance.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+use crate::config::ShardingConfig;
+use crate::object::Object;
+
+/// Which shard a channel end is assigned to.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelShard {
+    pub chain_id: ChainId,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    /// The shard the channel is assigned to by hashing its identifiers,
+    /// or `None` if the channels are explicitly assigned to the instance
+    pub shard: Option<u64>,
+    /// Whether this instance relays on the channel
+    pub owned: bool,
+}
+
+impl ChannelShard {
+    fn new(
+        sharding: &ShardingConfig,
+        chain_id: &ChainId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Self {
+        let (shard, owned) = if sharding.channels.is_empty() {
+            let shard = shard_of(
+                &format!("{chain_id}/{port_id}/{channel_id}"),
+                sharding.shard_count,
+            );
+
+            (Some(shard), shard == sharding.shard_index)
+        } else {
+            let owned = sharding.channels.iter().any(|channel| {
+                &channel.chain_id == chain_id
+                    && &channel.port_id == port_id
+                    && &channel.channel_id == channel_id
+            });
+
+            (None, owned)
+        };
+
+        Self {
+            chain_id: chain_id.clone(),
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            shard,
+            owned,
+        }
+    }
+}
+
+/// The assignment of the channel end the worker for the given object relays from,
+/// for packet and channel workers.
+pub fn channel_shard(sharding: &ShardingConfig, object: &Object) -> Option<ChannelShard> {
+    match object {
+        Object::Packet(p) => Some(ChannelShard::new(
+            sharding,
+            &p.src_chain_id,
+            &p.src_port_id,
+            &p.src_channel_id,
+        )),
+        Object::Channel(c) => Some(ChannelShard::new(
+            sharding,
+            &c.src_chain_id,
+            &c.src_port_id,
+            &c.src_channel_id,
+        )),
+        _ => None,
+    }
+}
+
+/// Whether this instance spawns the worker for the given object.
+pub fn owns(sharding: &ShardingConfig, object: &Object) -> bool {
+    if !sharding.is_enabled() {
+        return true;
+    }
+
+    if let Some(channel_shard) = channel_shard(sharding, object) {
+        return channel_shard.owned;
+    }
+
+    match object {
+        // Wallet workers only query the balance of the wallet
+        Object::Wallet(_) => true,
+        _ => shard_of(&object.short_name(), sharding.shard_count) == sharding.shard_index,
+    }
+}
+
+/// The shard the object with the given key is assigned to.
+///
+/// The hash must not depend on the build or the platform,
+/// for all the instances to agree on the assignment.
+fn shard_of(key: &str, shard_count: u64) -> u64 {
+    let digest = Sha256::digest(key.as_bytes());
+
+    let mut prefix = [0; 8];
+    prefix.copy_from_slice(&digest[..8]);
+
+    u64::from_be_bytes(prefix) % shard_count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::config::ShardChannel;
+    use crate::object::{Client, Packet};
+
+    fn sharding(shard_count: u64, shard_index: u64) -> ShardingConfig {
+        ShardingConfig {
+            shard_count,
+            shard_index,
+            channels: Vec::new(),
+        }
+    }
+
+    fn packet(chain_id: &str, channel_id: &str) -> Object {
+        Object::Packet(Packet {
+            dst_chain_id: ChainId::from_string("ibc-1"),
+            src_chain_id: ChainId::from_string(chain_id),
+            src_channel_id: channel_id.parse().unwrap(),
+            src_port_id: PortId::transfer(),
+        })
+    }
+
+    fn client(client_id: &str) -> Object {
+        Object::Client(Client {
+            dst_chain_id: ChainId::from_string("ibc-0"),
+            dst_client_id: client_id.parse().unwrap(),
+            src_chain_id: ChainId::from_string("ibc-1"),
+        })
+    }
+
+    #[test]
+    fn every_object_is_owned_by_exactly_one_shard() {
+        let objects = (0..50)
+            .map(|i| packet("ibc-0", &format!("channel-{i}")))
+            .chain((0..50).map(|i| client(&format!("07-tendermint-{i}"))))
+            .collect::<Vec<_>>();
+
+        for object in &objects {
+            let owners = (0..4)
+                .filter(|index| owns(&sharding(4, *index), object))
+                .count();
+
+            assert_eq!(owners, 1, "{} has {owners} owners", object.short_name());
+        }
+
+        // All the shards get a share of the channels
+        for index in 0..4 {
+            assert!(objects
+                .iter()
+                .any(|object| owns(&sharding(4, index), object)));
+        }
+    }
+
+    #[test]
+    fn assignment_is_stable() {
+        // Instances running different versions of Hermes must agree on the assignment
+        let channel_shard = channel_shard(&sharding(4, 0), &packet("ibc-0", "channel-0"));
+
+        assert_eq!(channel_shard.unwrap().shard, Some(3));
+    }
+
+    #[test]
+    fn packet_and_channel_workers_share_a_shard() {
+        let channel = Object::Channel(crate::object::Channel {
+            dst_chain_id: ChainId::from_string("ibc-1"),
+            src_chain_id: ChainId::from_string("ibc-0"),
+            src_channel_id: "channel-7".parse().unwrap(),
+            src_port_id: PortId::transfer(),
+        });
+
+        let sharding = sharding(8, 0);
+
+        assert_eq!(
+            channel_shard(&sharding, &channel).unwrap().shard,
+            channel_shard(&sharding, &packet("ibc-0", "channel-7"))
+                .unwrap()
+                .shard
+        );
+    }
+
+    #[test]
+    fn explicitly_assigned_channels() {
+        let sharding = ShardingConfig {
+            channels: vec![ShardChannel {
+                chain_id: ChainId::from_string("ibc-0"),
+                port_id: PortId::transfer(),
+                channel_id: "channel-1".parse().unwrap(),
+            }],
+            ..sharding(1, 0)
+        };
+
+        assert!(owns(&sharding, &packet("ibc-0", "channel-1")));
+        assert!(!owns(&sharding, &packet("ibc-0", "channel-2")));
+        assert!(!owns(&sharding, &packet("ibc-2", "channel-1")));
+
+        // Clients are shared by hashing with the `shard_count`
+        assert!(owns(&sharding, &client("07-tendermint-0")));
+    }
+
+    #[test]
+    fn sharding_disabled() {
+        assert!(owns(
+            &ShardingConfig::default(),
+            &packet("ibc-0", "channel-0")
+        ));
+        assert!(owns(&ShardingConfig::default(), &client("07-tendermint-0")));
+    }
+}
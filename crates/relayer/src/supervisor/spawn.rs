@@ -1,14 +1,19 @@
-use tracing::{error, info};
+use tracing::{debug, error, info};
 
 use ibc_relayer_types::core::{
     ics03_connection::connection::IdentifiedConnectionEnd,
-    ics04_channel::channel::State as ChannelState,
+    ics04_channel::channel::{IdentifiedChannelEnd, State as ChannelState},
 };
 
 use crate::{
-    chain::{counterparty::connection_state_on_destination, handle::ChainHandle},
+    chain::{
+        counterparty::connection_state_on_destination,
+        handle::ChainHandle,
+        requests::{IncludeProof, QueryChannelRequest, QueryHeight},
+    },
     client_state::IdentifiedAnyClientState,
     config::Config,
+    multihop,
     object::{Channel, Client, Connection, Object, Packet, Wallet},
     registry::Registry,
     supervisor::error::Error as SupervisorError,
@@ -55,6 +60,12 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
     pub fn spawn_workers_for_chain(&mut self, scan: ChainScan) {
         let _span = tracing::error_span!("chain", chain = %scan.chain_id).entered();
 
+        if !self.workers.leadership().is_leader() {
+            debug!("on standby, keeping the scan to spawn the workers once leader");
+            self.workers.keep_warm(scan);
+            return;
+        }
+
         let chain = match self.registry.get_or_spawn(&scan.chain_id) {
             Ok(chain_handle) => chain_handle,
             Err(e) => {
@@ -224,11 +235,21 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
 
         let mode = &self.config.mode;
 
-        let counterparty_chain = self
+        let client_chain = self
             .registry
             .get_or_spawn(&client.client_state.chain_id())
             .map_err(SupervisorError::spawn)?;
 
+        // The counterparty of a multi-hop channel is on the chain at the end of its
+        // connection hops rather than on the chain of its client, ie. the first
+        // intermediate chain, which the scan looked for it on.
+        let (counterparty_chain, hop_chains, channel_scan) =
+            if channel_scan.channel.channel_end.is_multihop() {
+                self.resolve_multihop_channel(&chain, channel_scan)?
+            } else {
+                (client_chain.clone(), Vec::new(), channel_scan)
+            };
+
         let chan_state_src = channel_scan.channel.channel_end.state;
         let chan_state_dst = channel_scan
             .counterparty
@@ -251,7 +272,8 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
             && (chan_state_dst.is_open() || chan_state_dst.is_closed())
             && !is_channel_upgrading
         {
-            if mode.clients.enabled {
+            // The localhost client is updated by the chain itself, there is no need for a worker
+            if mode.clients.enabled && !client.client_id.is_localhost() {
                 // Spawn the client worker
                 let client_object = Object::Client(Client {
                     dst_client_id: client.client_id.clone(),
@@ -260,12 +282,7 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
                 });
 
                 self.workers
-                    .spawn(
-                        counterparty_chain.clone(),
-                        chain.clone(),
-                        &client_object,
-                        self.config,
-                    )
+                    .spawn(client_chain, chain.clone(), &client_object, self.config)
                     .then(|| info!("spawned client worker: {}", client_object.short_name()));
             }
 
@@ -295,9 +312,10 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
                     });
 
                     self.workers
-                        .spawn(
+                        .spawn_with_hops(
                             chain.clone(),
                             counterparty_chain.clone(),
+                            hop_chains,
                             &path_object,
                             self.config,
                         )
@@ -335,7 +353,13 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
                 });
 
                 self.workers
-                    .spawn(chain, counterparty_chain, &channel_object, self.config)
+                    .spawn_with_hops(
+                        chain,
+                        counterparty_chain,
+                        hop_chains,
+                        &channel_object,
+                        self.config,
+                    )
                     .then(|| info!("spawned channel worker: {}", channel_object.short_name()));
 
                 Ok(true)
@@ -351,9 +375,10 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
             });
 
             self.workers
-                .spawn(
+                .spawn_with_hops(
                     chain.clone(),
                     counterparty_chain.clone(),
+                    hop_chains.clone(),
                     &path_object,
                     self.config,
                 )
@@ -367,7 +392,13 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
             });
 
             self.workers
-                .spawn(chain, counterparty_chain, &channel_object, self.config)
+                .spawn_with_hops(
+                    chain,
+                    counterparty_chain,
+                    hop_chains,
+                    &channel_object,
+                    self.config,
+                )
                 .then(|| info!("spawned channel worker: {}", channel_object.short_name()));
 
             Ok(true)
@@ -375,4 +406,55 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
             Ok(false)
         }
     }
+
+    /// The counterparty chain and the intermediate chains of a multi-hop channel (ICS 033),
+    /// along with its scan in which the counterparty channel end is looked up on the
+    /// counterparty chain.
+    fn resolve_multihop_channel(
+        &mut self,
+        chain: &Chain,
+        channel_scan: ChannelScan,
+    ) -> Result<(Chain, Vec<Chain>, ChannelScan), Error> {
+        let registry = &mut *self.registry;
+
+        let (hop_chains, counterparty_chain_id) = multihop::hop_chains(
+            chain,
+            channel_scan.channel.channel_end.connection_hops(),
+            |chain_id| registry.get_or_spawn(chain_id).ok(),
+        )
+        .map_err(SupervisorError::multihop)?;
+
+        let counterparty_chain = registry
+            .get_or_spawn(&counterparty_chain_id)
+            .map_err(SupervisorError::spawn)?;
+
+        let channel_end = &channel_scan.channel.channel_end;
+
+        // Before the counterparty channel end is known, the channel is left to the
+        // channel worker, which looks it up on the counterparty chain.
+        let counterparty = channel_end
+            .counterparty()
+            .channel_id()
+            .and_then(|channel_id| {
+                let port_id = channel_end.counterparty().port_id();
+
+                counterparty_chain
+                    .query_channel(
+                        QueryChannelRequest {
+                            port_id: port_id.clone(),
+                            channel_id: channel_id.clone(),
+                            height: QueryHeight::Latest,
+                        },
+                        IncludeProof::No,
+                    )
+                    .ok()
+                    .map(|(end, _)| {
+                        IdentifiedChannelEnd::new(port_id.clone(), channel_id.clone(), end)
+                    })
+            });
+
+        let channel_scan = ChannelScan::new(channel_scan.channel, counterparty);
+
+        Ok((counterparty_chain, hop_chains, channel_scan))
+    }
 }
@@ -6,6 +6,7 @@ pub mod compat_mode;
 pub mod debug_section;
 pub mod diff;
 pub mod excluded_sequences;
+pub mod handshake;
 pub mod iter;
 pub mod lock;
 pub mod pretty;
@@ -13,5 +14,6 @@ pub mod profiling;
 pub mod queue;
 pub mod retry;
 pub mod seq_range;
+pub mod serde_base64;
 pub mod stream;
 pub mod task;
@@ -0,0 +1,103 @@
+//! Lookup of connection and channel handshakes which were started but never
+//! completed, eg. because `create connection` or `create channel` was interrupted,
+//! so that they can be resumed instead of starting a new handshake.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use crate::util::pretty::PrettyOption;
+
+/// The ends of a handshake left half-open on chains `a` and `b`,
+/// either of which may not have been created yet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HalfOpenHandshake<Id> {
+    pub a_id: Option<Id>,
+    pub b_id: Option<Id>,
+}
+
+impl<Id: Display> Display for HalfOpenHandshake<Id> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "{} <-> {}",
+            PrettyOption(&self.a_id),
+            PrettyOption(&self.b_id)
+        )
+    }
+}
+
+/// Pairs up the half-open ends found on chains `a` and `b` into handshakes.
+///
+/// Each end is given as its identifier along with the identifier of its counterparty
+/// end, if it is known yet. An end in `INIT` does not know its counterparty, which is
+/// then found among the ends of the other chain which point back to it.
+pub fn pair_half_open_ends<Id: Clone + PartialEq>(
+    a_ends: &[(Id, Option<Id>)],
+    b_ends: &[(Id, Option<Id>)],
+) -> Vec<HalfOpenHandshake<Id>> {
+    let mut handshakes = Vec::new();
+
+    for (a_id, a_counterparty_id) in a_ends {
+        let b_id = a_counterparty_id.clone().or_else(|| {
+            b_ends
+                .iter()
+                .find(|(_, b_counterparty_id)| b_counterparty_id.as_ref() == Some(a_id))
+                .map(|(b_id, _)| b_id.clone())
+        });
+
+        handshakes.push(HalfOpenHandshake {
+            a_id: Some(a_id.clone()),
+            b_id,
+        });
+    }
+
+    for (b_id, b_counterparty_id) in b_ends {
+        let paired = handshakes
+            .iter()
+            .any(|handshake| handshake.b_id.as_ref() == Some(b_id));
+
+        if !paired {
+            handshakes.push(HalfOpenHandshake {
+                a_id: b_counterparty_id.clone(),
+                b_id: Some(b_id.clone()),
+            });
+        }
+    }
+
+    handshakes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn handshake(a_id: Option<u64>, b_id: Option<u64>) -> HalfOpenHandshake<u64> {
+        HalfOpenHandshake { a_id, b_id }
+    }
+
+    #[test]
+    fn init_end_is_paired_with_the_try_end_pointing_back_to_it() {
+        // a: 1 in INIT, b: 7 in TRYOPEN
+        let handshakes = pair_half_open_ends(&[(1, None)], &[(7, Some(1))]);
+
+        assert_eq!(handshakes, [handshake(Some(1), Some(7))]);
+    }
+
+    #[test]
+    fn ends_whose_counterparty_is_not_half_open_are_kept() {
+        // a: 1 in INIT without a counterparty yet,
+        // b: 7 in TRYOPEN whose counterparty 2 on a is already OPEN
+        let handshakes = pair_half_open_ends(&[(1, None)], &[(7, Some(2))]);
+
+        assert_eq!(
+            handshakes,
+            [handshake(Some(1), None), handshake(Some(2), Some(7))]
+        );
+    }
+
+    #[test]
+    fn no_half_open_ends() {
+        let handshakes = pair_half_open_ends::<u64>(&[], &[]);
+
+        assert!(handshakes.is_empty());
+    }
+}
@@ -39,6 +39,10 @@ impl<T> Queue<T> {
         self.0.acquire_read().is_empty()
     }
 
+    pub fn any(&self, predicate: impl FnMut(&T) -> bool) -> bool {
+        self.0.acquire_read().iter().any(predicate)
+    }
+
     pub fn into_vec(self) -> VecDeque<T> {
         self.0.acquire_write().drain(..).collect()
     }
@@ -0,0 +1,15 @@
+//! Serialization of byte vectors as padded standard base64 strings,
+//! which is also how Go encodes byte slices in JSON.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+pub fn serialize<S: Serializer>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&STANDARD.encode(data))
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let data = String::deserialize(deserializer)?;
+    STANDARD.decode(data).map_err(D::Error::custom)
+}
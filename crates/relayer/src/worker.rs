@@ -3,14 +3,16 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 use ibc_relayer_types::core::ics04_channel::channel::Ordering;
 use serde::{Deserialize, Serialize};
 use std::sync::Mutex;
-use tracing::error;
+use tracing::{error, info, trace};
 
 use crate::foreign_client::ForeignClient;
-use crate::link::{Link, LinkParameters, Resubmit};
+use crate::link::{error::LinkError, rate_limit::RateLimiter, Link, LinkParameters, Resubmit};
+use crate::multihop::find_hop_chain;
+use crate::worker::client_expiry::ClientExpiries;
 use crate::{
     chain::handle::{ChainHandle, ChainHandlePair},
     config::Config,
-    object::Object,
+    object::{Object, Packet},
 };
 
 pub mod retry_strategy;
@@ -29,6 +31,7 @@ pub use map::WorkerMap;
 
 pub mod channel;
 pub mod client;
+pub mod client_expiry;
 pub mod connection;
 pub mod cross_chain_query;
 pub mod packet;
@@ -54,11 +57,18 @@ impl Display for WorkerId {
     }
 }
 
-pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
-    chains: ChainHandlePair<ChainA, ChainB>,
+/// Spawn the tasks of the worker for the given [`Object`].
+///
+/// The handles of the intermediate chains are only needed for the packet and
+/// channel workers of a multi-hop channel (ICS 033), and are empty otherwise.
+pub fn spawn_worker_tasks<Chain: ChainHandle>(
+    chains: ChainHandlePair<Chain, Chain>,
+    hop_chains: Vec<Chain>,
     id: WorkerId,
     object: Object,
     config: &Config,
+    rate_limiter: &RateLimiter,
+    client_expiries: &ClientExpiries,
 ) -> WorkerHandle {
     let mut task_handles = Vec::new();
 
@@ -66,7 +76,7 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
         Object::Client(client) => {
             let client = ForeignClient::restore(client.dst_client_id.clone(), chains.b, chains.a);
 
-            let (mut refresh, mut misbehaviour) = (false, false);
+            let (mut refresh, mut misbehaviour, mut upgrade) = (false, false, false);
 
             let refresh_task = client::spawn_refresh_client(client.clone());
             if let Some(refresh_task) = refresh_task {
@@ -74,6 +84,23 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
                 refresh = true;
             }
 
+            let expiry_task = client::spawn_client_expiry_task(
+                client.clone(),
+                config.mode.clients.expiry_warning,
+                client_expiries.clone(),
+            );
+            if let Some(expiry_task) = expiry_task {
+                task_handles.push(expiry_task);
+            }
+
+            if config.mode.clients.upgrade {
+                let upgrade_task = client::spawn_upgrade_client(client.clone());
+                if let Some(upgrade_task) = upgrade_task {
+                    task_handles.push(upgrade_task);
+                    upgrade = true;
+                }
+            }
+
             let cmd_tx = if config.mode.clients.misbehaviour {
                 let (cmd_tx, cmd_rx) = crossbeam_channel::unbounded();
                 let misbehavior_task = client::detect_misbehavior_task(cmd_rx, client);
@@ -90,6 +117,7 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
             let data = WorkerData::Client {
                 misbehaviour,
                 refresh,
+                upgrade,
             };
 
             (cmd_tx, Some(data))
@@ -104,7 +132,8 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
         }
         Object::Channel(channel) => {
             let (cmd_tx, cmd_rx) = crossbeam_channel::unbounded();
-            let channel_task = channel::spawn_channel_worker(channel.clone(), chains, cmd_rx);
+            let channel_task =
+                channel::spawn_channel_worker(channel.clone(), chains, hop_chains, cmd_rx);
             task_handles.push(channel_task);
 
             (Some(cmd_tx), None)
@@ -116,23 +145,53 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
                 .unwrap_or_default()
                 .to_vec();
 
+            // Whether packets are rate limited on either of the chains
+            let rate_limited = [chains.a.id(), chains.b.id()].iter().any(|chain_id| {
+                config
+                    .find_chain(chain_id)
+                    .is_some_and(|chain_config| chain_config.rate_limit().is_enabled())
+            });
+
             let packets_config = config.mode.packets;
-            let link_res = Link::new_from_opts(
-                chains.a.clone(),
-                chains.b,
-                LinkParameters {
-                    src_port_id: path.src_port_id.clone(),
-                    src_channel_id: path.src_channel_id.clone(),
-                    max_memo_size: packets_config.ics20_max_memo_size,
-                    max_receiver_size: packets_config.ics20_max_receiver_size,
-                    exclude_src_sequences,
-                },
-                packets_config.tx_confirmation,
-                packets_config.auto_register_counterparty_payee,
-            );
+            let link_parameters = LinkParameters {
+                src_port_id: path.src_port_id.clone(),
+                src_channel_id: path.src_channel_id.clone(),
+                max_memo_size: packets_config.ics20_max_memo_size,
+                max_receiver_size: packets_config.ics20_max_receiver_size,
+                exclude_src_sequences,
+            };
+
+            let link_res = if hop_chains.is_empty() {
+                Link::new_from_opts(
+                    chains.a.clone(),
+                    chains.b,
+                    link_parameters,
+                    packets_config.tx_confirmation,
+                    packets_config.auto_register_counterparty_payee,
+                )
+            } else {
+                let hop_chain = |chain_id: &_| find_hop_chain(&hop_chains, chain_id);
+
+                Link::new_multihop_from_opts(
+                    chains.a.clone(),
+                    chains.b,
+                    link_parameters,
+                    packets_config.tx_confirmation,
+                    packets_config.auto_register_counterparty_payee,
+                    hop_chain,
+                    hop_chain,
+                )
+            };
 
             match link_res {
-                Ok(link) => {
+                Ok(mut link) => {
+                    let restored_txs =
+                        if packets_config.tx_journal && packets_config.tx_confirmation {
+                            recover_from_journal(&mut link, config, path)
+                        } else {
+                            0
+                        };
+
                     let channel_ordering = link.a_to_b.channel().ordering;
                     let should_clear_on_start =
                         should_clear_on_start(&packets_config, channel_ordering);
@@ -167,8 +226,30 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
                         }
                     };
 
+                    if let Some(filter) = &fee_filter {
+                        link.lock().unwrap().a_to_b.set_fee_policy(filter.clone());
+                    }
+
+                    if rate_limited {
+                        link.lock()
+                            .unwrap()
+                            .a_to_b
+                            .set_rate_limiter(rate_limiter.clone());
+                    }
+
                     let resubmit = Resubmit::from_clear_interval(clear_interval);
 
+                    // Confirm or resubmit the transactions restored from the journal
+                    // before clearing packets, so that they are not relayed twice.
+                    if restored_txs > 0 {
+                        let link = link.lock().unwrap();
+
+                        for _ in 0..restored_txs {
+                            let summary = link.a_to_b.process_pending_txs(resubmit);
+                            trace!("produced relay summary: {:?}", summary);
+                        }
+                    }
+
                     let (clear_cmd_tx, clear_cmd_rx) = crossbeam_channel::unbounded();
                     let clear_task = packet::spawn_clear_cmd_worker(
                         cmd_rx,
@@ -245,6 +326,42 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
     WorkerHandle::new(id, object, data, cmd_tx, task_handles)
 }
 
+/// Enable the transaction journal of the given link, and restore the pending
+/// transactions recorded in it. Returns the number of restored transactions.
+///
+/// Failing to open the journal is not fatal, the link then relays without it.
+fn recover_from_journal<ChainA: ChainHandle, ChainB: ChainHandle>(
+    link: &mut Link<ChainA, ChainB>,
+    config: &Config,
+    path: &Packet,
+) -> usize {
+    let res = config
+        .global
+        .data_dir()
+        .ok_or_else(LinkError::data_dir_unavailable)
+        .and_then(|data_dir| link.a_to_b.recover_from_journal(&data_dir));
+
+    match res {
+        Ok(restored) => {
+            if restored > 0 {
+                info!(
+                    "restored {} pending transactions from the journal for packet path {}",
+                    restored,
+                    path.short_name()
+                );
+            }
+            restored
+        }
+        Err(e) => {
+            error!(
+                "failed to open the transaction journal for packet path {}, relaying without it: {}",
+                path.short_name(), e
+            );
+            0
+        }
+    }
+}
+
 fn should_clear_on_start(config: &crate::config::Packets, channel_ordering: Ordering) -> bool {
     if config.force_disable_clear_on_start {
         false
@@ -1,10 +1,12 @@
 use core::time::Duration;
 use crossbeam_channel::Receiver;
-use ibc_relayer_types::events::IbcEventType;
+use ibc_relayer_types::core::ics04_channel::channel::State;
+use ibc_relayer_types::events::{IbcEvent, IbcEventType};
 use tracing::{debug, error_span, warn};
 
 use crate::chain::requests::QueryHeight;
-use crate::channel::{channel_handshake_retry, Channel as RelayChannel};
+use crate::channel::{channel_handshake_retry, Channel as RelayChannel, ChannelError};
+use crate::multihop::find_hop_chain;
 use crate::util::retry::RetryResult;
 use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
 use crate::{
@@ -30,9 +32,60 @@ fn max_block_times<ChainA: ChainHandle, ChainB: ChainHandle>(
     a_block_time.max(b_block_time)
 }
 
-pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
+/// Restore the channel from its state on chain, through its intermediate
+/// chains if it is a multi-hop channel.
+fn restore_from_state<Chain: ChainHandle>(
+    chains: &ChainHandlePair<Chain, Chain>,
+    hop_chains: &[Chain],
+    channel: &Channel,
+) -> Result<(RelayChannel<Chain, Chain>, State), ChannelError> {
+    if hop_chains.is_empty() {
+        return RelayChannel::restore_from_state(
+            chains.a.clone(),
+            chains.b.clone(),
+            channel.clone(),
+            QueryHeight::Latest,
+        );
+    }
+
+    let hop_chain = |chain_id: &_| find_hop_chain(hop_chains, chain_id);
+
+    RelayChannel::restore_multihop_from_state(
+        chains.a.clone(),
+        chains.b.clone(),
+        channel.clone(),
+        QueryHeight::Latest,
+        hop_chain,
+        hop_chain,
+    )
+}
+
+/// Restore the channel from a handshake event, through its intermediate
+/// chains if it is a multi-hop channel.
+fn restore_from_event<Chain: ChainHandle>(
+    chains: &ChainHandlePair<Chain, Chain>,
+    hop_chains: &[Chain],
+    event: &IbcEvent,
+) -> Result<RelayChannel<Chain, Chain>, ChannelError> {
+    if hop_chains.is_empty() {
+        return RelayChannel::restore_from_event(chains.a.clone(), chains.b.clone(), event.clone());
+    }
+
+    let hop_chain = |chain_id: &_| find_hop_chain(hop_chains, chain_id);
+
+    RelayChannel::restore_multihop_from_event(
+        chains.a.clone(),
+        chains.b.clone(),
+        event.clone(),
+        hop_chain,
+        hop_chain,
+    )
+}
+
+pub fn spawn_channel_worker<Chain: ChainHandle>(
     channel: Channel,
-    chains: ChainHandlePair<ChainA, ChainB>,
+    chains: ChainHandlePair<Chain, Chain>,
+    hop_chains: Vec<Chain>,
     cmd_rx: Receiver<WorkerCmd>,
 ) -> TaskHandle {
     let mut complete_handshake_on_new_block = true;
@@ -61,12 +114,8 @@ pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
                                 | IbcEventType::UpgradeOpenChannel
                                 | IbcEventType::UpgradeTimeoutChannel => retry_with_index(
                                     channel_handshake_retry::default_strategy(max_block_times),
-                                    |index| match RelayChannel::restore_from_state(
-                                        chains.a.clone(),
-                                        chains.b.clone(),
-                                        channel.clone(),
-                                        QueryHeight::Latest,
-                                    ) {
+                                    |index| match restore_from_state(&chains, &hop_chains, &channel)
+                                    {
                                         Ok((mut handshake_channel, state)) => {
                                             handshake_channel.step_state(state, index)
                                         }
@@ -77,12 +126,8 @@ pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
 
                                 IbcEventType::UpgradeErrorChannel => retry_with_index(
                                     channel_handshake_retry::default_strategy(max_block_times),
-                                    |index| match RelayChannel::restore_from_state(
-                                        chains.a.clone(),
-                                        chains.b.clone(),
-                                        channel.clone(),
-                                        QueryHeight::Latest,
-                                    ) {
+                                    |index| match restore_from_state(&chains, &hop_chains, &channel)
+                                    {
                                         Ok((handshake_channel, _)) => {
                                             match handshake_channel
                                                 .build_chan_upgrade_cancel_and_send()
@@ -101,10 +146,10 @@ pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
 
                                 _ => retry_with_index(
                                     channel_handshake_retry::default_strategy(max_block_times),
-                                    |index| match RelayChannel::restore_from_event(
-                                        chains.a.clone(),
-                                        chains.b.clone(),
-                                        event_with_height.event.clone(),
+                                    |index| match restore_from_event(
+                                        &chains,
+                                        &hop_chains,
+                                        &event_with_height.event,
                                     ) {
                                         Ok(mut handshake_channel) => handshake_channel
                                             .step_event(&event_with_height.event, index),
@@ -128,12 +173,7 @@ pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
 
                         retry_with_index(
                             channel_handshake_retry::default_strategy(max_block_times),
-                            |index| match RelayChannel::restore_from_state(
-                                chains.a.clone(),
-                                chains.b.clone(),
-                                channel.clone(),
-                                QueryHeight::Latest,
-                            ) {
+                            |index| match restore_from_state(&chains, &hop_chains, &channel) {
                                 Ok((mut handshake_channel, state)) => {
                                     handshake_channel.step_state(state, index)
                                 }
@@ -3,24 +3,31 @@ use core::time::Duration;
 use crossbeam_channel::Receiver;
 use retry::delay::Fibonacci;
 use retry::retry_with_index;
-use tracing::{debug, debug_span, error_span, trace, warn};
+use tracing::{debug, debug_span, error, error_span, info, trace, warn};
 
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::Height;
 
+use crate::chain::requests::QueryUpgradedClientStateRequest;
+use crate::telemetry;
 use crate::util::retry::clamp_total;
 use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
 use crate::{
     chain::handle::ChainHandle,
-    foreign_client::{ForeignClient, MisbehaviourResults},
+    foreign_client::{ForeignClient, HasExpiredOrFrozenError, MisbehaviourResults},
 };
 
+use super::client_expiry::{ClientExpiries, ClientExpiry};
 use super::WorkerCmd;
 
 const REFRESH_CHECK_INTERVAL: Duration = Duration::from_secs(5); // 5 seconds
 const INITIAL_BACKOFF: Duration = Duration::from_secs(5); // 5 seconds
 const MAX_REFRESH_DELAY: Duration = Duration::from_secs(60 * 60); // 1 hour
 const MAX_REFRESH_TOTAL_DELAY: Duration = Duration::from_secs(60 * 60 * 24); // 1 day
+const UPGRADE_CHECK_INTERVAL: Duration = Duration::from_secs(10); // 10 seconds
+const MAX_UPGRADE_ATTEMPTS: u32 = 30; // 5 minutes at the check interval
+const EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(60); // 1 minute
 
 pub fn spawn_refresh_client<ChainA: ChainHandle, ChainB: ChainHandle>(
     mut client: ForeignClient<ChainA, ChainB>,
@@ -59,6 +66,298 @@ pub fn spawn_refresh_client<ChainA: ChainHandle, ChainB: ChainHandle>(
     ))
 }
 
+/// Watches the chain tracked by the client for an upgrade plan scheduled through
+/// governance, and upgrades the client once the chain halts at the upgrade height,
+/// provided the plan carries an upgraded client state.
+pub fn spawn_upgrade_client<ChainA: ChainHandle, ChainB: ChainHandle>(
+    client: ForeignClient<ChainA, ChainB>,
+) -> Option<TaskHandle> {
+    if client.is_expired_or_frozen() {
+        warn!(
+            client = %client.id,
+            "skipping upgrade client task on frozen client",
+        );
+
+        return None;
+    }
+
+    let mut upgrade = ScheduledUpgrade::default();
+
+    Some(spawn_background_task(
+        error_span!(
+            "worker.client.upgrade",
+            client = %client.id,
+            src_chain = %client.src_chain.id(),
+            dst_chain = %client.dst_chain.id(),
+        ),
+        Some(UPGRADE_CHECK_INTERVAL),
+        move || -> Result<Next, TaskError<Infallible>> {
+            upgrade.check(&client);
+
+            Ok(Next::Continue)
+        },
+    ))
+}
+
+/// The upgrade plan scheduled on the chain tracked by a client, if any,
+/// along with the state of the upgrade of the client.
+#[derive(Default)]
+struct ScheduledUpgrade {
+    /// The height at which the chain halts for the scheduled upgrade
+    height: Option<Height>,
+    /// The number of failed attempts at upgrading the client for the scheduled upgrade
+    attempts: u32,
+    /// The height of the last upgrade which was either performed or given up on
+    handled: Option<Height>,
+}
+
+impl ScheduledUpgrade {
+    fn check<ChainA: ChainHandle, ChainB: ChainHandle>(
+        &mut self,
+        client: &ForeignClient<ChainA, ChainB>,
+    ) {
+        let src_chain = client.src_chain();
+
+        let latest_height = match src_chain.query_latest_height() {
+            Ok(height) => height,
+            Err(e) => {
+                debug!(
+                    "failed to query the latest height of chain {}: {e}",
+                    src_chain.id()
+                );
+                return;
+            }
+        };
+
+        // Until the chain halts, follow the plan currently scheduled,
+        // as governance may cancel it or replace it with another one
+        if self.halted_for(latest_height).is_none() {
+            let plan = match src_chain.query_upgrade_plan() {
+                Ok(plan) => plan,
+                Err(e) => {
+                    debug!(
+                        "failed to query the upgrade plan of chain {}: {e}",
+                        src_chain.id()
+                    );
+                    return;
+                }
+            };
+
+            let height = plan
+                .filter(|plan| plan.height > 0)
+                .and_then(|plan| Height::new(src_chain.id().version(), plan.height as u64).ok());
+
+            if self.follow_plan(height) {
+                if let Some(height) = self.height {
+                    info!(
+                        "chain {} is scheduled to halt at height {height} for an upgrade",
+                        src_chain.id()
+                    );
+                }
+            }
+        }
+
+        let Some(upgrade_height) = self.halted_for(latest_height) else {
+            return;
+        };
+
+        // The upgraded client state is only available if the plan was scheduled
+        // with `MsgIBCSoftwareUpgrade`, or a legacy `UpgradeProposal`
+        if let Err(e) = src_chain
+            .query_upgraded_client_state(QueryUpgradedClientStateRequest { upgrade_height })
+        {
+            debug!(
+                "no upgraded client state found for the upgrade of chain {} at height {upgrade_height}: {e}",
+                src_chain.id()
+            );
+
+            self.fail(upgrade_height, "no upgraded client state was found");
+            return;
+        }
+
+        match client.upgrade(upgrade_height) {
+            Ok(events) => {
+                info!(
+                    "upgraded client after chain {} halted at height {upgrade_height}",
+                    src_chain.id()
+                );
+                debug!("client upgrade events: {events:?}");
+
+                telemetry!(
+                    client_upgrades_submitted,
+                    &src_chain.id(),
+                    &client.dst_chain().id(),
+                    client.id(),
+                    1
+                );
+
+                self.succeed(upgrade_height);
+            }
+            Err(e) => {
+                warn!("failed to upgrade client at height {upgrade_height}: {e}");
+
+                telemetry!(
+                    client_upgrades_failed,
+                    &src_chain.id(),
+                    &client.dst_chain().id(),
+                    client.id(),
+                    1
+                );
+
+                self.fail(upgrade_height, "the client upgrade kept failing");
+            }
+        }
+    }
+
+    /// Follows the upgrade scheduled at the given height, if any, unless it was already
+    /// handled. Returns whether the scheduled upgrade changed, which resets the attempts.
+    fn follow_plan(&mut self, height: Option<Height>) -> bool {
+        let height = height.filter(|height| self.handled.map_or(true, |handled| *height > handled));
+
+        if height == self.height {
+            return false;
+        }
+
+        self.height = height;
+        self.attempts = 0;
+
+        true
+    }
+
+    /// The height of the scheduled upgrade, if the chain at the given latest height halted for it.
+    fn halted_for(&self, latest_height: Height) -> Option<Height> {
+        self.height
+            .filter(|height| has_halted(latest_height, *height))
+    }
+
+    /// Records that the client was upgraded for the upgrade at the given height.
+    fn succeed(&mut self, upgrade_height: Height) {
+        self.handled = Some(upgrade_height);
+        self.height = None;
+    }
+
+    /// Records a failed attempt, and gives up on the upgrade after too many of them.
+    fn fail(&mut self, upgrade_height: Height, reason: &str) {
+        self.attempts += 1;
+
+        if self.attempts >= MAX_UPGRADE_ATTEMPTS {
+            error!(
+                "giving up on upgrading the client at height {upgrade_height} after {} attempts: {reason}",
+                self.attempts
+            );
+
+            self.handled = Some(upgrade_height);
+            self.height = None;
+        }
+    }
+}
+
+/// Whether a chain at the given latest height has halted for the upgrade at `upgrade_height`.
+///
+/// A chain halted for an upgrade reports an application height of one less than
+/// the upgrade height.
+fn has_halted(latest_height: Height, upgrade_height: Height) -> bool {
+    upgrade_height
+        .decrement()
+        .is_ok_and(|halt_height| latest_height >= halt_height)
+}
+
+/// Periodically computes how long until the trusting period of the client lapses,
+/// reports it through telemetry and the given `expiries`, and warns when the client
+/// expires within the `warning_threshold`. A zero threshold disables the warnings.
+///
+/// No task is spawned for frozen clients, nor for clients which never expire.
+pub fn spawn_client_expiry_task<ChainA: ChainHandle, ChainB: ChainHandle>(
+    client: ForeignClient<ChainA, ChainB>,
+    warning_threshold: Duration,
+    expiries: ClientExpiries,
+) -> Option<TaskHandle> {
+    match client.time_until_expiry() {
+        Ok(None) => return None,
+        Err(e) if e.is_frozen_error() => {
+            warn!(
+                client = %client.id,
+                "skipping client expiry task on frozen client",
+            );
+
+            return None;
+        }
+        _ => {}
+    }
+
+    let mut warning = false;
+
+    Some(spawn_background_task(
+        error_span!(
+            "worker.client.expiry",
+            client = %client.id,
+            src_chain = %client.src_chain.id(),
+            dst_chain = %client.dst_chain.id(),
+        ),
+        Some(EXPIRY_CHECK_INTERVAL),
+        move || -> Result<Next, TaskError<Infallible>> {
+            let time_until_expiry = match client.time_until_expiry() {
+                Ok(Some(time_until_expiry)) => time_until_expiry,
+                Ok(None) => return Ok(Next::Continue),
+                // A frozen client cannot expire anymore
+                Err(e) if e.is_frozen_error() => {
+                    expiries.remove(&client.dst_chain().id(), client.id());
+                    return Ok(Next::Continue);
+                }
+                Err(e) => {
+                    debug!("failed to compute the time until the client expires: {e}");
+                    return Ok(Next::Continue);
+                }
+            };
+
+            let (src_chain, dst_chain) = (client.src_chain().id(), client.dst_chain().id());
+            let seconds_until_expiry = time_until_expiry.as_secs();
+
+            telemetry!(
+                client_expiry_seconds,
+                &src_chain,
+                &dst_chain,
+                client.id(),
+                seconds_until_expiry
+            );
+
+            let was_warning = warning;
+            warning = !warning_threshold.is_zero() && time_until_expiry < warning_threshold;
+
+            if warning && !was_warning {
+                warn!(
+                    "client expires in {}, sooner than the warning threshold of {}",
+                    humantime::format_duration(Duration::from_secs(seconds_until_expiry)),
+                    humantime::format_duration(warning_threshold),
+                );
+
+                telemetry!(
+                    client_expiry_warnings,
+                    &src_chain,
+                    &dst_chain,
+                    client.id(),
+                    1
+                );
+            } else if was_warning && !warning {
+                info!(
+                    "client expires in {}, no longer within the warning threshold",
+                    humantime::format_duration(Duration::from_secs(seconds_until_expiry)),
+                );
+            }
+
+            expiries.set(ClientExpiry {
+                chain_id: dst_chain,
+                client_id: client.id().clone(),
+                counterparty_chain_id: src_chain,
+                seconds_until_expiry,
+                warning,
+            });
+
+            Ok(Next::Continue)
+        },
+    ))
+}
+
 pub fn detect_misbehavior_task<ChainA: ChainHandle, ChainB: ChainHandle>(
     receiver: Receiver<WorkerCmd>,
     client: ForeignClient<ChainB, ChainA>,
@@ -165,3 +464,83 @@ fn refresh_strategy() -> impl Iterator<Item = Duration> {
         MAX_REFRESH_TOTAL_DELAY,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn height(revision_height: u64) -> Height {
+        Height::new(1, revision_height).unwrap()
+    }
+
+    #[test]
+    fn chain_halts_one_block_before_the_upgrade_height() {
+        assert!(!has_halted(height(98), height(100)));
+        assert!(has_halted(height(99), height(100)));
+        assert!(has_halted(height(100), height(100)));
+    }
+
+    #[test]
+    fn cancelled_plan_is_dropped_before_the_halt() {
+        let mut upgrade = ScheduledUpgrade::default();
+
+        assert!(upgrade.follow_plan(Some(height(100))));
+        assert_eq!(upgrade.halted_for(height(50)), None);
+
+        // The plan is cancelled by governance
+        assert!(upgrade.follow_plan(None));
+        assert_eq!(upgrade.halted_for(height(99)), None);
+    }
+
+    #[test]
+    fn replaced_plan_resets_the_attempts() {
+        let mut upgrade = ScheduledUpgrade::default();
+
+        upgrade.follow_plan(Some(height(100)));
+        assert!(!upgrade.follow_plan(Some(height(100))));
+
+        upgrade.fail(height(100), "test");
+        assert_eq!(upgrade.attempts, 1);
+
+        // The plan is replaced by one at a later height
+        assert!(upgrade.follow_plan(Some(height(200))));
+        assert_eq!(upgrade.attempts, 0);
+        assert_eq!(upgrade.halted_for(height(99)), None);
+        assert_eq!(upgrade.halted_for(height(199)), Some(height(200)));
+    }
+
+    #[test]
+    fn handled_upgrade_is_not_attempted_again() {
+        let mut upgrade = ScheduledUpgrade::default();
+
+        upgrade.follow_plan(Some(height(100)));
+        upgrade.succeed(height(100));
+        assert_eq!(upgrade.halted_for(height(99)), None);
+
+        // The plan is still reported by the chain after the upgrade
+        assert!(!upgrade.follow_plan(Some(height(100))));
+        assert_eq!(upgrade.halted_for(height(120)), None);
+
+        // A later upgrade is followed
+        assert!(upgrade.follow_plan(Some(height(300))));
+        assert_eq!(upgrade.halted_for(height(299)), Some(height(300)));
+    }
+
+    #[test]
+    fn upgrade_is_given_up_after_too_many_attempts() {
+        let mut upgrade = ScheduledUpgrade::default();
+        upgrade.follow_plan(Some(height(100)));
+
+        for _ in 0..MAX_UPGRADE_ATTEMPTS - 1 {
+            upgrade.fail(height(100), "test");
+        }
+        assert_eq!(upgrade.halted_for(height(99)), Some(height(100)));
+
+        upgrade.fail(height(100), "test");
+        assert_eq!(upgrade.halted_for(height(99)), None);
+        assert_eq!(upgrade.handled, Some(height(100)));
+
+        // Giving up counts as handled
+        assert!(!upgrade.follow_plan(Some(height(100))));
+    }
+}
@@ -0,0 +1,65 @@
+//! Forecasts of when the clients lapse, computed by the client workers
+//! and shared with the supervisor, which exposes them through the REST API.
+
+use alloc::collections::BTreeMap;
+use alloc::sync::Arc;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+
+/// How long until the trusting period of a client lapses,
+/// as last computed by its worker.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientExpiry {
+    /// The chain hosting the client
+    pub chain_id: ChainId,
+    pub client_id: ClientId,
+    /// The chain tracked by the client
+    pub counterparty_chain_id: ChainId,
+    /// Seconds until the trusting period of the client lapses, zero if it already has
+    pub seconds_until_expiry: u64,
+    /// Whether the client expires sooner than the configured warning threshold
+    pub warning: bool,
+}
+
+/// The latest expiry forecasts of the clients which have a worker.
+///
+/// Cloning yields a handle to the same forecasts.
+#[derive(Clone, Debug, Default)]
+pub struct ClientExpiries {
+    state: Arc<Mutex<BTreeMap<(ChainId, ClientId), ClientExpiry>>>,
+}
+
+impl ClientExpiries {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the latest forecast for a client, returning the previous one, if any.
+    pub fn set(&self, expiry: ClientExpiry) -> Option<ClientExpiry> {
+        let key = (expiry.chain_id.clone(), expiry.client_id.clone());
+        self.state.lock().unwrap().insert(key, expiry)
+    }
+
+    /// Forget the forecast for a client, eg. once its worker has stopped.
+    pub fn remove(&self, chain_id: &ChainId, client_id: &ClientId) {
+        let key = (chain_id.clone(), client_id.clone());
+        self.state.lock().unwrap().remove(&key);
+    }
+
+    /// The forecasts of all the clients, soonest to expire first.
+    pub fn list(&self) -> Vec<ClientExpiry> {
+        let mut expiries = self
+            .state
+            .lock()
+            .unwrap()
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        expiries.sort_by_key(|expiry| expiry.seconds_until_expiry);
+        expiries
+    }
+}
@@ -22,7 +22,11 @@ use super::{WorkerCmd, WorkerId};
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum WorkerData {
-    Client { misbehaviour: bool, refresh: bool },
+    Client {
+        misbehaviour: bool,
+        refresh: bool,
+        upgrade: bool,
+    },
 }
 
 pub struct WorkerHandle {
@@ -2,17 +2,25 @@ use alloc::collections::btree_map::BTreeMap as HashMap;
 use core::mem;
 
 use ibc_relayer_types::core::ics02_client::events::NewBlock;
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::Height;
 use tracing::{debug, trace};
 
 use crate::{
     chain::handle::{ChainHandle, ChainHandlePair},
     config::Config,
+    link::rate_limit::RateLimiter,
     object::Object,
+    supervisor::{
+        checkpoint::Checkpoints,
+        lease::Leadership,
+        scan::ChainScan,
+        shard::{self, ChannelShard},
+    },
     telemetry,
 };
 
+use super::client_expiry::ClientExpiries;
 use super::{spawn_worker_tasks, WorkerHandle, WorkerId};
 
 /// Manage the lifecycle of [`WorkerHandle`]s associated with [`Object`]s.
@@ -20,6 +28,12 @@ use super::{spawn_worker_tasks, WorkerHandle, WorkerId};
 pub struct WorkerMap {
     workers: HashMap<Object, WorkerHandle>,
     latest_worker_id: WorkerId,
+    rate_limiter: RateLimiter,
+    client_expiries: ClientExpiries,
+    leadership: Leadership,
+    warm_scans: HashMap<ChainId, ChainScan>,
+    channel_shards: HashMap<(ChainId, PortId, ChannelId), ChannelShard>,
+    checkpoints: Checkpoints,
 }
 
 impl Default for WorkerMap {
@@ -27,6 +41,12 @@ impl Default for WorkerMap {
         Self {
             workers: HashMap::new(),
             latest_worker_id: WorkerId::new(0),
+            rate_limiter: RateLimiter::new(),
+            client_expiries: ClientExpiries::new(),
+            leadership: Leadership::default(),
+            warm_scans: HashMap::new(),
+            channel_shards: HashMap::new(),
+            checkpoints: Checkpoints::default(),
         }
     }
 }
@@ -39,6 +59,79 @@ impl WorkerMap {
         Self::default()
     }
 
+    /// The rate limiter shared by the packet workers.
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// The expiry forecasts reported by the client workers.
+    pub fn client_expiries(&self) -> &ClientExpiries {
+        &self.client_expiries
+    }
+
+    /// Whether this instance holds the high-availability lease,
+    /// without which no worker is spawned.
+    pub fn leadership(&self) -> &Leadership {
+        &self.leadership
+    }
+
+    /// Set the leadership of the instance, when running in high-availability mode.
+    pub fn set_leadership(&mut self, leadership: Leadership) {
+        self.leadership = leadership;
+    }
+
+    /// The last block height handled on each chain.
+    pub fn checkpoints(&self) -> &Checkpoints {
+        &self.checkpoints
+    }
+
+    /// Record the last block height handled on each chain in a state file,
+    /// to catch up on the events emitted while Hermes was down when restarting.
+    pub fn set_checkpoints(&mut self, checkpoints: Checkpoints) {
+        self.checkpoints = checkpoints;
+    }
+
+    /// Keep the scan of a chain for which no workers could be spawned because this
+    /// instance is on standby, to spawn them right away once it becomes the leader.
+    pub fn keep_warm(&mut self, scan: ChainScan) {
+        self.warm_scans.insert(scan.chain_id.clone(), scan);
+    }
+
+    /// Take the scans kept while this instance was on standby.
+    pub fn take_warm_scans(&mut self) -> Vec<ChainScan> {
+        mem::take(&mut self.warm_scans).into_values().collect()
+    }
+
+    /// The shards the channels seen so far are assigned to, when sharding is enabled.
+    pub fn channel_shards(&self) -> Vec<ChannelShard> {
+        self.channel_shards.values().cloned().collect()
+    }
+
+    /// Forget the shards of the channels of the given chain,
+    /// eg. once it was removed from the configuration.
+    pub fn forget_channel_shards(&mut self, chain_id: &ChainId) {
+        self.channel_shards
+            .retain(|(shard_chain_id, _, _), _| shard_chain_id != chain_id);
+    }
+
+    /// Whether this instance spawns the worker for the given [`Object`]: it must
+    /// hold the high-availability lease and the object must belong to its shard.
+    fn may_spawn(&mut self, object: &Object, config: &Config) -> bool {
+        if config.sharding.is_enabled() {
+            if let Some(channel_shard) = shard::channel_shard(&config.sharding, object) {
+                let key = (
+                    channel_shard.chain_id.clone(),
+                    channel_shard.port_id.clone(),
+                    channel_shard.channel_id.clone(),
+                );
+
+                self.channel_shards.insert(key, channel_shard);
+            }
+        }
+
+        self.leadership.is_leader() && shard::owns(&config.sharding, object)
+    }
+
     /// Returns `true` if there is a spawned [`WorkerHandle`] associated with the given [`Object`].
     pub fn contains(&self, object: &Object) -> bool {
         self.workers.contains_key(object)
@@ -65,6 +158,8 @@ impl WorkerMap {
                     "worker loop has ended"
                 );
 
+                self.forget_client_expiry(&object);
+
                 true
             }
             Some(handle) => {
@@ -116,33 +211,46 @@ impl WorkerMap {
     /// Get a handle to the worker in charge of handling events associated
     /// with the given [`Object`].
     ///
-    /// This function will spawn a new [`WorkerHandle`] if one does not exists already.
+    /// This function will spawn a new [`WorkerHandle`] if one does not exists already,
+    /// unless this instance is on standby or the object belongs to another shard,
+    /// in which case it returns `None`.
+    ///
+    /// The handles of the intermediate chains are only needed for the packet and
+    /// channel workers of a multi-hop channel (ICS 033), and are empty otherwise.
     pub fn get_or_spawn<Chain: ChainHandle>(
         &mut self,
         object: Object,
         src: Chain,
         dst: Chain,
+        hop_chains: Vec<Chain>,
         config: &Config,
-    ) -> &WorkerHandle {
-        if self.workers.contains_key(&object) {
+    ) -> Option<&WorkerHandle> {
+        if !self.may_spawn(&object, config) {
+            return None;
+        }
+
+        let worker = if self.workers.contains_key(&object) {
             if self.workers[&object].shutdown_stopped_tasks() {
                 self.remove_stopped(
                     self.workers[&object].id(),
                     self.workers[&object].object().clone(),
                 );
 
-                let worker = self.spawn_worker(src, dst, &object, config);
+                let worker = self.spawn_worker(src, dst, hop_chains, &object, config);
                 self.workers.entry(object).or_insert(worker)
             } else {
                 &self.workers[&object]
             }
         } else {
-            let worker = self.spawn_worker(src, dst, &object, config);
+            let worker = self.spawn_worker(src, dst, hop_chains, &object, config);
             self.workers.entry(object).or_insert(worker)
-        }
+        };
+
+        Some(worker)
     }
 
-    /// Spawn a new [`WorkerHandle`], only if one does not exists already.
+    /// Spawn a new [`WorkerHandle`], only if one does not exists already,
+    /// this instance is not on standby and the object belongs to its shard.
     ///
     /// Returns whether or not the worker was actually spawned.
     pub fn spawn<Chain: ChainHandle>(
@@ -152,8 +260,21 @@ impl WorkerMap {
         object: &Object,
         config: &Config,
     ) -> bool {
-        if !self.workers.contains_key(object) {
-            let worker = self.spawn_worker(src, dst, object, config);
+        self.spawn_with_hops(src, dst, Vec::new(), object, config)
+    }
+
+    /// Same as [`WorkerMap::spawn`], for the packet and channel workers of a multi-hop
+    /// channel (ICS 033), given the handles of its intermediate chains.
+    pub fn spawn_with_hops<Chain: ChainHandle>(
+        &mut self,
+        src: Chain,
+        dst: Chain,
+        hop_chains: Vec<Chain>,
+        object: &Object,
+        config: &Config,
+    ) -> bool {
+        if self.may_spawn(object, config) && !self.workers.contains_key(object) {
+            let worker = self.spawn_worker(src, dst, hop_chains, object, config);
             self.workers.entry(object.clone()).or_insert(worker);
             true
         } else {
@@ -166,6 +287,7 @@ impl WorkerMap {
         &mut self,
         src: Chain,
         dst: Chain,
+        hop_chains: Vec<Chain>,
         object: &Object,
         config: &Config,
     ) -> WorkerHandle {
@@ -173,9 +295,12 @@ impl WorkerMap {
 
         spawn_worker_tasks(
             ChainHandlePair { a: src, b: dst },
+            hop_chains,
             self.next_worker_id(),
             object.clone(),
             config,
+            &self.rate_limiter,
+            &self.client_expiries,
         )
     }
 
@@ -217,6 +342,25 @@ impl WorkerMap {
             handle.shutdown_and_wait();
         }
         // Drop handle automatically handles the waiting for tasks to terminate.
+
+        self.forget_client_expiry(object);
+    }
+
+    /// Drop the expiry forecast reported by the worker for a client, if any.
+    fn forget_client_expiry(&self, object: &Object) {
+        if let Object::Client(client) = object {
+            self.client_expiries
+                .remove(&client.dst_chain_id, &client.dst_client_id);
+        }
+    }
+
+    /// Shut down all the workers synchronously, eg. once this instance lost the lease.
+    pub fn shutdown_all(&mut self) {
+        let objects = self.workers.keys().cloned().collect::<Vec<_>>();
+
+        for object in &objects {
+            self.shutdown_worker(object);
+        }
     }
 
     /// Shut down all the workers, asynchronously.
@@ -3,20 +3,15 @@ use std::borrow::BorrowMut;
 use std::sync::{Arc, Mutex};
 
 use crossbeam_channel::{Receiver, Sender};
-use itertools::Itertools;
 use moka::sync::Cache;
-use tracing::{debug, error, error_span, info, trace, warn};
+use tracing::{error, error_span, info, trace, warn};
 
-use ibc_proto::ibc::apps::fee::v1::{IdentifiedPacketFees, QueryIncentivizedPacketRequest};
-use ibc_proto::ibc::core::channel::v1::PacketId;
 use ibc_relayer_types::applications::ics29_fee::events::IncentivizedPacket;
-use ibc_relayer_types::applications::transfer::{Amount, Coin, RawCoin};
 use ibc_relayer_types::core::ics04_channel::channel::Ordering;
-use ibc_relayer_types::core::ics04_channel::events::WriteAcknowledgement;
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
 use ibc_relayer_types::core::ics24_host::identifier::ChannelId;
 use ibc_relayer_types::core::ics24_host::identifier::PortId;
-use ibc_relayer_types::events::{IbcEvent, IbcEventType};
+use ibc_relayer_types::events::IbcEvent;
 use ibc_relayer_types::Height;
 
 use crate::chain::handle::ChainHandle;
@@ -360,8 +355,9 @@ fn handle_clear_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
 
 /// Receives incentivized worker commands and handles them accordingly.
 ///
-/// Given an `IbcEvent` command, filters the SendPacket and WriteAcknowledgment
-/// events using the FeesFilters and updates the schedule.
+/// Given an `IbcEvent` command, filters the SendPacket events using the
+/// fee policy and updates the schedule. WriteAcknowledgement events are
+/// filtered by the relay path, which queries the fees of their packets.
 ///
 /// The incentivized worker does not clear packet, so it only looks for
 /// `IbcEvent` commands.
@@ -385,103 +381,57 @@ fn handle_incentivized_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
                     .acquire_write()
                     .insert(packet.sequence, packet.clone());
             }
-            // It is not authorized to filter WriteAcknowledgement at the moment.
-            // This is because in order to filter them the worker would need to
-            // query the IncentivizedPacket events seen by the worker handling packets
-            // from ChainB to ChainA or it would need to query ChainA for incentivized
-            // packets.
-            // In addition if the WriteAcknowledgment are not relayed, no fees will be paid.
-            //IbcEvent::WriteAcknowledgement(ack) => get_incentivized_for_write_acknowledgement(link, ack, event.height.revision_height(), incentivized_ack_cache.clone()),
         }
-        filter_batch(batch.borrow_mut(), incentivized_recv_cache, fee_filter);
+        filter_batch(
+            link,
+            batch.borrow_mut(),
+            incentivized_recv_cache,
+            fee_filter,
+        );
         handle_update_schedule(link, 0, path, batch)
     } else {
         Ok(())
     }
 }
 
-/// It is only possible to filter incentivized packets using the `recv_fee` as criteria.
-/// This method is a helper which can be used if filtering using `ack_fee` is added.
-/// It queries the chain for incentivized packet information regarding the `WriteAcknowledgement`,
-/// and stores this information in a Read/Write Cache.
-fn _get_incentivized_for_write_acknowledgement<ChainA: ChainHandle, ChainB: ChainHandle>(
-    link: &mut Link<ChainA, ChainB>,
-    ack: WriteAcknowledgement,
-    height: u64,
-    incentivized_ack_cache: RwArc<Cache<Sequence, IdentifiedPacketFees>>,
-) {
-    let dst_chain = link.a_to_b.dst_chain();
-
-    // Build PacketId required for the QueryIncentivizedPacketRequest
-    let packet_id = PacketId {
-        port_id: ack.packet.source_port.to_string(),
-        channel_id: ack.packet.source_channel.to_string(),
-        sequence: ack.packet.sequence.into(),
-    };
-
-    let request = QueryIncentivizedPacketRequest {
-        packet_id: Some(packet_id),
-        query_height: height,
-    };
-
-    match dst_chain.query_incentivized_packet(request) {
-        Ok(ev) => {
-            if let Some(identifier_packet_fees) = ev.incentivized_packet {
-                if let Some(packet) = &identifier_packet_fees.packet_id {
-                    incentivized_ack_cache
-                        .acquire_write()
-                        .insert(packet.sequence.into(), identifier_packet_fees);
-                }
-            }
-        }
-        // If the query failed it could mean that the packet is not incentivized.
-        // The error is logged as debug.
-        Err(e) => {
-            debug!("Query for incentivized packet failed: {e}");
-        }
-    }
-}
-
-/// Using the configured FeesFilter and observed/queried information for
-/// incentivized packets, determine if the SendPacket and WriteAcknowledgement events
-/// should be relayed or not.
-fn filter_batch(
+/// Using the configured FeesFilter and the observed incentivized packets,
+/// determine if the SendPacket events should be relayed or not.
+fn filter_batch<ChainA: ChainHandle, ChainB: ChainHandle>(
+    link: &Link<ChainA, ChainB>,
     batch: &mut EventBatch,
     incentivized_recv_cache: &RwArc<Cache<Sequence, IncentivizedPacket>>,
     fee_filter: &FeePolicy,
 ) {
     batch.events.retain(|e| match &e.event {
-        IbcEvent::SendPacket(packet) => incentivized_recv_cache
-            .acquire_read()
-            .get(&packet.packet.sequence)
-            .map_or(false, |incentivized_event| {
-                let grouped_amounts =
-                    retrieve_all_fees_from_incentivized_packet(incentivized_event);
-
-                fee_filter.should_relay(IbcEventType::SendPacket, &grouped_amounts)
-            }),
+        IbcEvent::SendPacket(send_packet) => {
+            let should_relay = incentivized_recv_cache
+                .acquire_read()
+                .get(&send_packet.packet.sequence)
+                .is_some_and(|incentivized_event| {
+                    fee_filter.should_relay(&incentivized_event.total_fee())
+                });
+
+            if !should_relay {
+                let packet = &send_packet.packet;
+
+                telemetry!(
+                    filtered_packets,
+                    &link.a_to_b.src_chain().id(),
+                    &link.a_to_b.dst_chain().id(),
+                    &packet.source_channel,
+                    &packet.destination_channel,
+                    &packet.source_port,
+                    &packet.destination_port,
+                    1
+                );
+            }
+
+            should_relay
+        }
         _ => true,
     });
 }
 
-/// Multiple fees with different denoms can be specified as rewards,
-/// in an `IncentivizedPacket`. This method extract all and groups all
-/// the fees with the same denom.
-fn retrieve_all_fees_from_incentivized_packet(
-    incentivized_packet: IncentivizedPacket,
-) -> Vec<RawCoin> {
-    incentivized_packet
-        .total_recv_fee
-        .iter()
-        .chunk_by(|a| &a.denom)
-        .into_iter()
-        .map(|(key, group)| {
-            let total_amount: Amount = group.map(|v| v.amount).sum::<Amount>();
-            Coin::new(key.to_owned(), total_amount)
-        })
-        .collect()
-}
-
 /// Whether or not to clear pending packets at this `step` for some height.
 /// If the relayer has been configured to clear packets on start and that has not
 /// occurred yet, then packets are cleared.
@@ -12,38 +12,43 @@ pub fn spawn_wallet_worker<Chain: ChainHandle>(chain: Chain) -> TaskHandle {
     let span = error_span!("wallet", chain = %chain.id());
 
     spawn_background_task(span, Some(Duration::from_secs(5)), move || {
-        let key = chain.get_key().map_err(|e| {
-            TaskError::Fatal(format!("failed to get key in use by the relayer: {e}"))
+        let keys = chain.get_keys().map_err(|e| {
+            TaskError::Fatal(format!("failed to get the keys in use by the relayer: {e}"))
         })?;
 
-        let balance = chain.query_balance(None, None).map_err(|e| {
-            TaskError::Ignore(format!("failed to query balance for the account: {e}"))
-        })?;
+        for (key_name, key) in keys {
+            let account = key.account();
 
-        match balance.amount.parse::<f64>() {
-            Ok(amount) => {
-                telemetry!(
-                    wallet_balance,
-                    &chain.id(),
-                    &key.account(),
-                    amount,
-                    &balance.denom,
-                );
-                trace!(%amount, denom = %balance.denom, account = %key.account(), "wallet balance");
-                telemetry!(
-                    update_period_fees,
-                    &chain.id(),
-                    &key.account(),
-                    &balance.denom
-                );
-            }
-            Err(e) => {
-                warn!(
-                    %balance.amount, denom = %balance.denom, account = %key.account(),
-                    "unable to parse the wallet balance into a f64, the balance will therefore not be reported to telemetry. Reason: {}", e
-                );
+            // Keep reporting the balance of the other keys of the pool if this one fails
+            let balance = match chain.query_balance(Some(key_name), None) {
+                Ok(balance) => balance,
+                Err(e) => {
+                    warn!(%account, "failed to query balance for the account: {e}");
+                    continue;
+                }
+            };
+
+            match balance.amount.parse::<f64>() {
+                Ok(amount) => {
+                    telemetry!(
+                        wallet_balance,
+                        &chain.id(),
+                        &account,
+                        amount,
+                        &balance.denom,
+                    );
+                    trace!(%amount, denom = %balance.denom, %account, "wallet balance");
+                    telemetry!(update_period_fees, &chain.id(), &account, &balance.denom);
+                }
+                Err(e) => {
+                    warn!(
+                        %balance.amount, denom = %balance.denom, %account,
+                        "unable to parse the wallet balance into a f64, the balance will therefore not be reported to telemetry. Reason: {}", e
+                    );
+                }
             }
         }
+
         Ok(Next::Continue)
     })
 }
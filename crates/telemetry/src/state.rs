@@ -98,6 +98,12 @@ pub struct TelemetryState {
     /// Number of workers per type
     workers: UpDownCounter<i64>,
 
+    /// Whether the instance holds the high-availability lease (1) or stands by (0)
+    lease_leader: ObservableGauge<u64>,
+
+    /// Number of times the instance took over or lost the high-availability lease
+    lease_leadership_changes: Counter<u64>,
+
     /// Number of client update messages submitted per client
     client_updates_submitted: Counter<u64>,
 
@@ -108,6 +114,18 @@ pub struct TelemetryState {
     /// Number of misbehaviours detected and submitted per client
     client_misbehaviours_submitted: Counter<u64>,
 
+    /// Number of client upgrades submitted per client at the upgrade height of their chain
+    client_upgrades_submitted: Counter<u64>,
+
+    /// Number of failed attempts at upgrading a client at the upgrade height of its chain
+    client_upgrades_failed: Counter<u64>,
+
+    /// Seconds until the trusting period of a client lapses
+    client_expiry_seconds: ObservableGauge<u64>,
+
+    /// Number of times a client was found to expire within the warning threshold
+    client_expiry_warnings: Counter<u64>,
+
     /// Number of confirmed receive packets per channel
     receive_packets_confirmed: Counter<u64>,
 
@@ -129,9 +147,18 @@ pub struct TelemetryState {
     /// How many IBC events did Hermes receive via the WebSocket subscription, per chain
     ws_events: Counter<u64>,
 
+    /// Number of times Hermes failed over to another full node, per chain and source
+    endpoint_failovers: Counter<u64>,
+
     /// Number of messages submitted to a specific chain
     messages_submitted: Counter<u64>,
 
+    /// Number of stuck transactions resubmitted with a bumped gas price, per chain and channel
+    fee_bumps: Counter<u64>,
+
+    /// Number of packets deferred because of the rate limits of a chain, per chain and channel
+    deferred_packets: ObservableGauge<u64>,
+
     /// The balance of each wallet Hermes uses per chain
     wallet_balance: ObservableGauge<f64>,
 
@@ -213,7 +240,7 @@ pub struct TelemetryState {
     /// The EIP-1559 base fee successfully queried
     dynamic_gas_queried_success_fees: ObservableGauge<f64>,
 
-    /// Number of ICS-20 packets filtered because the memo and/or the receiver fields were exceeding the configured limits
+    /// Number of packets filtered because the ICS-20 memo and/or receiver fields were exceeding the configured limits, or because their ICS-29 fees did not meet the configured minimum
     filtered_packets: Counter<u64>,
 }
 
@@ -250,6 +277,16 @@ impl TelemetryState {
                 .with_description("Number of workers")
                 .init(),
 
+            lease_leader: meter
+                .u64_observable_gauge("lease_leader")
+                .with_description("Whether the instance holds the high-availability lease (1) or stands by (0)")
+                .init(),
+
+            lease_leadership_changes: meter
+                .u64_counter("lease_leadership_changes")
+                .with_description("Number of times the instance took over or lost the high-availability lease")
+                .init(),
+
             client_updates_submitted: meter
                 .u64_counter("client_updates_submitted")
                 .with_description("Number of client update messages submitted")
@@ -265,6 +302,27 @@ impl TelemetryState {
                 .with_description("Number of misbehaviours detected and submitted")
                 .init(),
 
+            client_upgrades_submitted: meter
+                .u64_counter("client_upgrades_submitted")
+                .with_description("Number of client upgrades submitted at the upgrade height of the chain they track")
+                .init(),
+
+            client_upgrades_failed: meter
+                .u64_counter("client_upgrades_failed")
+                .with_description("Number of failed attempts at upgrading a client at the upgrade height of the chain it tracks")
+                .init(),
+
+            client_expiry_seconds: meter
+                .u64_observable_gauge("client_expiry_seconds")
+                .with_unit(Unit::new("seconds"))
+                .with_description("Seconds until the trusting period of the client lapses")
+                .init(),
+
+            client_expiry_warnings: meter
+                .u64_counter("client_expiry_warnings")
+                .with_description("Number of times a client was found to expire within the configured warning threshold")
+                .init(),
+
             receive_packets_confirmed: meter
                 .u64_counter("receive_packets_confirmed")
                 .with_description("Number of confirmed receive packets. Available if relayer runs with Tx confirmation enabled")
@@ -302,6 +360,21 @@ impl TelemetryState {
                 .with_description("How many IBC events did Hermes receive via the websocket subscription")
                 .init(),
 
+            endpoint_failovers: meter
+                .u64_counter("endpoint_failovers")
+                .with_description("Number of times Hermes failed over to another full node")
+                .init(),
+
+            fee_bumps: meter
+                .u64_counter("fee_bumps")
+                .with_description("Number of stuck transactions resubmitted with a bumped gas price")
+                .init(),
+
+            deferred_packets: meter
+                .u64_observable_gauge("deferred_packets")
+                .with_description("Number of packets deferred because of the rate limits of the chain they are relayed to")
+                .init(),
+
             messages_submitted: meter
                 .u64_counter("messages_submitted")
                 .with_description("Number of messages submitted to a specific chain")
@@ -421,7 +494,7 @@ impl TelemetryState {
 
             filtered_packets: meter
                 .u64_counter("filtered_packets")
-                .with_description("Number of ICS-20 packets filtered because the memo and/or the receiver fields were exceeding the configured limits")
+                .with_description("Number of packets filtered because the ICS-20 memo and/or receiver fields were exceeding the configured limits, because their ICS-29 fees did not meet the configured minimum, or because their ICS-20 content was rejected by the packet filter")
                 .init(),
         }
     }
@@ -509,6 +582,7 @@ impl TelemetryState {
         dst_chain: &ChainId,
         client: &ClientId,
         misbehaviour: bool,
+        upgrade: bool,
     ) {
         let cx = Context::current();
 
@@ -520,10 +594,16 @@ impl TelemetryState {
 
         self.client_updates_submitted.add(&cx, 0, labels);
         self.client_updates_skipped.add(&cx, 0, labels);
+        self.client_expiry_warnings.add(&cx, 0, labels);
 
         if misbehaviour {
             self.client_misbehaviours_submitted.add(&cx, 0, labels);
         }
+
+        if upgrade {
+            self.client_upgrades_submitted.add(&cx, 0, labels);
+            self.client_upgrades_failed.add(&cx, 0, labels);
+        }
     }
 
     fn init_queries(&self, chain_id: &ChainId) {
@@ -555,6 +635,18 @@ impl TelemetryState {
         self.workers.add(&cx, count, labels);
     }
 
+    /// Record the role of the instance in high-availability mode,
+    /// counting a leadership change unless it is the initial role.
+    pub fn lease_leadership(&self, instance_id: &str, leader: bool, changed: bool) {
+        let cx = Context::current();
+        let labels = &[KeyValue::new("instance", instance_id.to_string())];
+
+        self.lease_leader.observe(&cx, u64::from(leader), labels);
+
+        self.lease_leadership_changes
+            .add(&cx, u64::from(changed), labels);
+    }
+
     /// Update the number of client updates per client
     pub fn client_updates_submitted(
         &self,
@@ -612,6 +704,82 @@ impl TelemetryState {
         self.client_misbehaviours_submitted.add(&cx, count, labels);
     }
 
+    /// Number of client upgrades submitted per client
+    pub fn client_upgrades_submitted(
+        &self,
+        src_chain: &ChainId,
+        dst_chain: &ChainId,
+        client: &ClientId,
+        count: u64,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("src_chain", src_chain.to_string()),
+            KeyValue::new("dst_chain", dst_chain.to_string()),
+            KeyValue::new("client", client.to_string()),
+        ];
+
+        self.client_upgrades_submitted.add(&cx, count, labels);
+    }
+
+    /// Number of failed client upgrade attempts per client
+    pub fn client_upgrades_failed(
+        &self,
+        src_chain: &ChainId,
+        dst_chain: &ChainId,
+        client: &ClientId,
+        count: u64,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("src_chain", src_chain.to_string()),
+            KeyValue::new("dst_chain", dst_chain.to_string()),
+            KeyValue::new("client", client.to_string()),
+        ];
+
+        self.client_upgrades_failed.add(&cx, count, labels);
+    }
+
+    /// Seconds until the trusting period of a client lapses
+    pub fn client_expiry_seconds(
+        &self,
+        src_chain: &ChainId,
+        dst_chain: &ChainId,
+        client: &ClientId,
+        seconds: u64,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("src_chain", src_chain.to_string()),
+            KeyValue::new("dst_chain", dst_chain.to_string()),
+            KeyValue::new("client", client.to_string()),
+        ];
+
+        self.client_expiry_seconds.observe(&cx, seconds, labels);
+    }
+
+    /// Number of expiry warnings per client
+    pub fn client_expiry_warnings(
+        &self,
+        src_chain: &ChainId,
+        dst_chain: &ChainId,
+        client: &ClientId,
+        count: u64,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("src_chain", src_chain.to_string()),
+            KeyValue::new("dst_chain", dst_chain.to_string()),
+            KeyValue::new("client", client.to_string()),
+        ];
+
+        self.client_expiry_warnings.add(&cx, count, labels);
+    }
+
     /// Number of receive packets relayed, per channel
     #[allow(clippy::too_many_arguments)]
     pub fn receive_packets_confirmed(
@@ -730,6 +898,67 @@ impl TelemetryState {
         self.ws_reconnect.add(&cx, 1, labels);
     }
 
+    /// Number of times Hermes failed over to another full node, per chain.
+    ///
+    /// The `source` is either `chain` for the node used for queries and transactions,
+    /// or `websocket`/`pull` for the node used by the event source.
+    pub fn endpoint_failover(&self, chain_id: &ChainId, source: &'static str) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("source", source),
+        ];
+
+        self.endpoint_failovers.add(&cx, 1, labels);
+    }
+
+    /// Number of stuck transactions resubmitted with a bumped gas price, per chain and channel.
+    ///
+    /// The `bumps` label is the number of times the gas price of the
+    /// resubmitted transaction was bumped since it was first submitted.
+    pub fn fee_bump(
+        &self,
+        chain_id: &ChainId,
+        counterparty_chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        bumps: u32,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+            KeyValue::new("bumps", bumps.to_string()),
+        ];
+
+        self.fee_bumps.add(&cx, 1, labels);
+    }
+
+    /// Number of packets to the given channel currently deferred by the rate limits of the chain
+    pub fn deferred_packets(
+        &self,
+        chain_id: &ChainId,
+        counterparty_chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        count: u64,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+        ];
+
+        self.deferred_packets.observe(&cx, count, labels);
+    }
+
     /// How many IBC events did Hermes receive via the WebSocket subscription, per chain
     pub fn ws_events(&self, chain_id: &ChainId, count: u64) {
         let cx = Context::current();
@@ -1209,7 +1438,7 @@ impl TelemetryState {
             .observe(&cx, amount, labels);
     }
 
-    /// Increment number of packets filtered because the memo field is too big
+    /// Increment number of packets filtered because of their ICS-20 fields or ICS-29 fees
     #[allow(clippy::too_many_arguments)]
     pub fn filtered_packets(
         &self,
@@ -1300,6 +1529,9 @@ impl AggregatorSelector for CustomAggregatorSelector {
             "backlog_oldest_sequence" => Some(Arc::new(last_value())),
             "backlog_latest_update_timestamp" => Some(Arc::new(last_value())),
             "backlog_size" => Some(Arc::new(last_value())),
+            "deferred_packets" => Some(Arc::new(last_value())),
+            "client_expiry_seconds" => Some(Arc::new(last_value())),
+            "lease_leader" => Some(Arc::new(last_value())),
             // Prometheus' supports only collector for histogram, sum, and last value aggregators.
             // https://docs.rs/opentelemetry-prometheus/0.10.0/src/opentelemetry_prometheus/lib.rs.html#411-418
             // TODO: Once quantile sketches are supported, replace histograms with that.
@@ -49,6 +49,7 @@ impl TestOverrides for Test {
                     // with external relayer commands.
                     chain_config.key_store_type = Store::Test;
                 }
+                ChainConfig::SoloMachine(_) => {}
             }
         }
     }
@@ -22,6 +22,8 @@ impl TestOverrides for CleanPacketWorkersTest {
                 enabled: false,
                 refresh: false,
                 misbehaviour: false,
+                upgrade: false,
+                expiry_warning: config::default::client_expiry_warning(),
             },
             connections: config::Connections { enabled: false },
             channels: config::Channels { enabled: false },
@@ -85,6 +87,8 @@ impl TestOverrides for CleanChannelWorkersTest {
                 enabled: false,
                 refresh: false,
                 misbehaviour: false,
+                upgrade: false,
+                expiry_warning: config::default::client_expiry_warning(),
             },
             connections: config::Connections { enabled: false },
             channels: config::Channels { enabled: true },
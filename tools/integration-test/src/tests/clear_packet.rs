@@ -335,6 +335,7 @@ impl TestOverrides for ClearPacketOverrideTest {
             match chain_config {
                 // Use a small clear interval in the chain configurations to override the global high interval
                 ChainConfig::CosmosSdk(chain_config) => chain_config.clear_interval = Some(10),
+                ChainConfig::SoloMachine(_) => {}
             }
         }
     }
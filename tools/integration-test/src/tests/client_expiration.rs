@@ -99,6 +99,8 @@ impl TestOverrides for ExpirationTestOverrides {
                 enabled: true,
                 refresh: true,
                 misbehaviour: true,
+                upgrade: false,
+                expiry_warning: config::default::client_expiry_warning(),
             },
             connections: config::Connections { enabled: true },
             channels: config::Channels { enabled: true },
@@ -116,6 +118,7 @@ impl TestOverrides for ExpirationTestOverrides {
                 ChainConfig::CosmosSdk(chain_config) => {
                     chain_config.trusting_period = Some(CLIENT_EXPIRY);
                 }
+                ChainConfig::SoloMachine(_) => {}
             }
         }
     }
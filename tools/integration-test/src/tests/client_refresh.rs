@@ -30,6 +30,7 @@ impl TestOverrides for ClientDefaultsTest {
             max_clock_drift: Some(Duration::from_secs(3)),
             trusting_period: Some(Duration::from_secs(60)),
             trust_threshold: Some(TrustThreshold::new(13, 23).unwrap()),
+            wasm_checksum: None,
         }
     }
 
@@ -38,6 +39,7 @@ impl TestOverrides for ClientDefaultsTest {
             max_clock_drift: Some(Duration::from_secs(6)),
             trusting_period: Some(Duration::from_secs(60)),
             trust_threshold: Some(TrustThreshold::TWO_THIRDS),
+            wasm_checksum: None,
         }
     }
 }
@@ -102,6 +104,7 @@ impl TestOverrides for ClientFailsTest {
             max_clock_drift: Some(Duration::from_secs(3)),
             trusting_period: Some(Duration::from_secs(60)),
             trust_threshold: Some(TrustThreshold::new(13, 23).unwrap()),
+            wasm_checksum: None,
         }
     }
 
@@ -110,6 +113,7 @@ impl TestOverrides for ClientFailsTest {
             max_clock_drift: Some(Duration::from_secs(6)),
             trusting_period: Some(Duration::from_secs(60)),
             trust_threshold: Some(TrustThreshold::TWO_THIRDS),
+            wasm_checksum: None,
         }
     }
 }
@@ -126,11 +130,15 @@ impl BinaryChainTest for ClientFailsTest {
             chains,
             |config| {
                 {
-                    let ChainConfig::CosmosSdk(config_chain_a) = &mut config.chains[0];
+                    let ChainConfig::CosmosSdk(config_chain_a) = &mut config.chains[0] else {
+                        unreachable!()
+                    };
                     config_chain_a.gas_multiplier = Some(GasMultiplier::unsafe_new(0.8));
                 }
 
-                let ChainConfig::CosmosSdk(config_chain_b) = &mut config.chains[1];
+                let ChainConfig::CosmosSdk(config_chain_b) = &mut config.chains[1] else {
+                    unreachable!()
+                };
                 config_chain_b.gas_multiplier = Some(GasMultiplier::unsafe_new(0.8));
             },
             config,
@@ -171,6 +171,7 @@ impl BinaryChainTest for ClientUpgradeTest {
                 assert_eq!(client_state.chain_id, upgraded_chain_id);
                 Ok(())
             }
+            _ => unreachable!("unexpected client state type"),
         }
     }
 }
@@ -224,6 +225,7 @@ impl BinaryChainTest for InvalidClientUpgradeTest {
                 assert_eq!(client_state.chain_id, chains.handle_a().id());
                 Ok(())
             }
+            _ => unreachable!("unexpected client state type"),
         }
     }
 }
@@ -322,6 +324,7 @@ impl BinaryChainTest for HeightTooHighClientUpgradeTest {
                 assert_eq!(client_state.chain_id, chains.handle_a().id());
                 Ok(())
             }
+            _ => unreachable!("unexpected client state type"),
         }
     }
 }
@@ -423,6 +426,7 @@ impl BinaryChainTest for HeightTooLowClientUpgradeTest {
                 assert_eq!(client_state.chain_id, chains.handle_a().id());
                 Ok(())
             }
+            _ => unreachable!("unexpected client state type"),
         }
     }
 }
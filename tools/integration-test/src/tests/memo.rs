@@ -38,6 +38,7 @@ impl TestOverrides for MemoTest {
                 ChainConfig::CosmosSdk(chain_config) => {
                     chain_config.memo_prefix = self.memo.clone();
                 }
+                ChainConfig::SoloMachine(_) => {}
             }
         }
     }
@@ -102,6 +103,7 @@ impl TestOverrides for MemoOverwriteTest {
                     chain_config.memo_prefix = self.memo.clone();
                     chain_config.memo_overwrite = Some(Memo::new(OVERWRITE_MEMO).unwrap())
                 }
+                ChainConfig::SoloMachine(_) => {}
             }
         }
     }
@@ -78,3 +78,6 @@ pub mod dynamic_gas_fee;
 
 #[cfg(any(doc, feature = "benchmark"))]
 pub mod benchmark;
+
+#[cfg(any(doc, feature = "multihop"))]
+pub mod multihop;
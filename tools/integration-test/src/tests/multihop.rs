@@ -0,0 +1,169 @@
+//! Tests the handshake of a multi-hop channel between chains A and C,
+//! whose connection hops go through chain B, and the relaying of an
+//! IBC transfer over that channel.
+
+use ibc_relayer::channel::Channel;
+use ibc_relayer::link::{Link, LinkParameters};
+use ibc_test_framework::prelude::*;
+use ibc_test_framework::types::topology::TopologyType;
+
+#[test]
+fn test_multihop_ibc_transfer() -> Result<(), Error> {
+    run_nary_connection_test(&MultihopIbcTransferTest)
+}
+
+pub struct MultihopIbcTransferTest;
+
+impl TestOverrides for MultihopIbcTransferTest {
+    fn modify_relayer_config(&self, config: &mut Config) {
+        config.mode.clients.misbehaviour = false;
+    }
+
+    fn topology(&self) -> Option<TopologyType> {
+        Some(TopologyType::Linear)
+    }
+
+    fn should_spawn_supervisor(&self) -> bool {
+        false
+    }
+}
+
+impl NaryConnectionTest<3> for MultihopIbcTransferTest {
+    fn run<Handle: ChainHandle>(
+        &self,
+        _config: &TestConfig,
+        relayer: RelayerDriver,
+        chains: NaryConnectedChains<Handle, 3>,
+        connections: NaryConnectedConnections<Handle, 3>,
+    ) -> Result<(), Error> {
+        let node_a = chains.full_node_at::<0>()?;
+        let node_c = chains.full_node_at::<2>()?;
+
+        let handle_a = chains.chain_handle_at::<0>()?.value().clone();
+        let handle_c = chains.chain_handle_at::<2>()?.value().clone();
+
+        let connection_a_to_b = connections.connection_at::<0, 1>()?;
+        let connection_b_to_c = connections.connection_at::<1, 2>()?;
+
+        let connection_hops = [
+            connection_a_to_b.connection_id_a.value().clone(),
+            connection_b_to_c.connection_id_a.value().clone(),
+        ];
+
+        let hop_chain = |chain_id: &ChainId| {
+            chains
+                .chain_handles()
+                .iter()
+                .find(|handle| &handle.id() == chain_id)
+                .cloned()
+        };
+
+        info!(
+            "Opening a multi-hop channel from chain {} to chain {} over connections {:?}",
+            node_a.chain_id(),
+            node_c.chain_id(),
+            connection_hops
+        );
+
+        let channel = Channel::new_multihop(
+            handle_a.clone(),
+            handle_c.clone(),
+            &connection_hops,
+            Ordering::Unordered,
+            PortId::transfer(),
+            PortId::transfer(),
+            None,
+            hop_chain,
+            hop_chain,
+        )?;
+
+        let channel_id_a = channel.a_side.channel_id().cloned().unwrap();
+        let channel_id_c = channel.b_side.channel_id().cloned().unwrap();
+
+        let port = PortId::transfer();
+        let port_a = DualTagged::new(&port);
+        let port_c = DualTagged::new(&port);
+
+        let denom_a = node_a.denom();
+        let wallet_a = node_a.wallets().user1().cloned();
+        let wallet_c = node_c.wallets().user1().cloned();
+
+        let balance_a = node_a
+            .chain_driver()
+            .query_balance(&wallet_a.address(), &denom_a)?;
+
+        let amount = 5000u64;
+
+        info!(
+            "Sending IBC transfer from chain {} to chain {} with amount of {} {}",
+            node_a.chain_id(),
+            node_c.chain_id(),
+            amount,
+            denom_a
+        );
+
+        node_a.chain_driver().ibc_transfer_token(
+            &port_a,
+            &DualTagged::new(&channel_id_a),
+            &wallet_a.as_ref(),
+            &wallet_c.address(),
+            &denom_a.with_amount(amount).as_ref(),
+        )?;
+
+        let packet_config = relayer.config.mode.packets;
+
+        let link_a_to_c = Link::new_multihop_from_opts(
+            handle_a.clone(),
+            handle_c.clone(),
+            LinkParameters {
+                src_port_id: port.clone(),
+                src_channel_id: channel_id_a.clone(),
+                max_memo_size: packet_config.ics20_max_memo_size,
+                max_receiver_size: packet_config.ics20_max_receiver_size,
+                exclude_src_sequences: vec![],
+            },
+            true,
+            false,
+            hop_chain,
+            hop_chain,
+        )?;
+
+        link_a_to_c.relay_recv_packet_and_timeout_messages(vec![])?;
+
+        let denom_a_to_c = derive_ibc_denom(&port_c, &DualTagged::new(&channel_id_c), &denom_a)?;
+
+        info!(
+            "Waiting for user on chain C to receive IBC transferred amount of {} {}",
+            amount, denom_a_to_c
+        );
+
+        node_c.chain_driver().assert_eventual_wallet_amount(
+            &wallet_c.address(),
+            &denom_a_to_c.with_amount(amount).as_ref(),
+        )?;
+
+        let link_c_to_a = Link::new_multihop_from_opts(
+            handle_c,
+            handle_a,
+            LinkParameters {
+                src_port_id: port.clone(),
+                src_channel_id: channel_id_c,
+                max_memo_size: packet_config.ics20_max_memo_size,
+                max_receiver_size: packet_config.ics20_max_receiver_size,
+                exclude_src_sequences: vec![],
+            },
+            true,
+            false,
+            hop_chain,
+            hop_chain,
+        )?;
+
+        link_c_to_a.relay_ack_packet_messages(vec![])?;
+
+        node_a
+            .chain_driver()
+            .assert_eventual_wallet_amount(&wallet_a.address(), &(balance_a - amount).as_ref())?;
+
+        Ok(())
+    }
+}
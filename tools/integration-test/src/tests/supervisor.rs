@@ -34,6 +34,8 @@ impl TestOverrides for SupervisorTest {
                 enabled: true,
                 refresh: true,
                 misbehaviour: true,
+                upgrade: false,
+                expiry_warning: config::default::client_expiry_warning(),
             },
             connections: config::Connections { enabled: true },
             channels: config::Channels { enabled: true },
@@ -174,6 +176,8 @@ impl TestOverrides for SupervisorScanTest {
                 enabled: false, // disable client workers, otherwise we have to scan
                 refresh: true,
                 misbehaviour: true,
+                upgrade: false,
+                expiry_warning: config::default::client_expiry_warning(),
             },
             connections: config::Connections { enabled: true },
             channels: config::Channels { enabled: true },
@@ -38,6 +38,7 @@ impl TestOverrides for SequentialCommitTest {
                 chain_config_a.max_msg_num = MaxMsgNum::new(MESSAGES_PER_BATCH).unwrap();
                 chain_config_a.sequential_batch_tx = true;
             }
+            ChainConfig::SoloMachine(_) => {}
         };
 
         match &mut config.chains[1] {
@@ -45,6 +46,7 @@ impl TestOverrides for SequentialCommitTest {
                 chain_config_b.max_msg_num = MaxMsgNum::new(MESSAGES_PER_BATCH).unwrap();
                 chain_config_b.sequential_batch_tx = false;
             }
+            ChainConfig::SoloMachine(_) => {}
         };
     }
 
@@ -142,7 +142,7 @@ impl<'a, Chain: Send> TaggedChainDriverExt<Chain> for MonoTagged<Chain, &'a Chai
             .block_on(simple_send_tx(
                 rpc_client.as_ref().into_value(),
                 &self.value().tx_config,
-                &wallet.value().key,
+                &wallet.value().key.clone().into(),
                 messages,
             ))
             .map_err(Error::relayer)
@@ -35,6 +35,7 @@
 //!                 ChainConfig::CosmosSdk(chain_config) => {
 //!                     chain_config.memo_prefix = self.memo.clone();
 //!                 },
+//!                 ChainConfig::SoloMachine(_) => {}
 //!             }
 //!         }
 //!     }
@@ -21,6 +21,7 @@
 */
 
 use crossbeam_channel as channel;
+use ibc_proto::cosmos::upgrade::v1beta1::Plan;
 use ibc_proto::ibc::core::channel::v1::{QueryUpgradeErrorRequest, QueryUpgradeRequest};
 use ibc_relayer::chain::cosmos::version::Specs;
 use ibc_relayer_types::core::ics04_channel::upgrade::{ErrorReceipt, Upgrade};
@@ -120,6 +121,10 @@ where
         self.value().get_key()
     }
 
+    fn get_keys(&self) -> Result<Vec<(String, AnySigningKeyPair)>, Error> {
+        self.value().get_keys()
+    }
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error> {
         self.value().add_key(key_name, key)
     }
@@ -456,4 +461,8 @@ where
         self.value()
             .query_upgrade_error(request, height, include_proof)
     }
+
+    fn query_upgrade_plan(&self) -> Result<Option<Plan>, Error> {
+        self.value().query_upgrade_plan()
+    }
 }
@@ -62,6 +62,8 @@ impl RelayerDriver {
             SupervisorOptions {
                 health_check: false,
                 force_full_scan: false,
+                config_path: None,
+                replay_from: None,
             },
         )
         .map_err(Error::supervisor)
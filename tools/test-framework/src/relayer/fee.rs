@@ -66,7 +66,7 @@ pub async fn ibc_token_transfer_with_fee<SrcChain, DstChain>(
     let events = simple_send_tx(
         rpc_client.value(),
         tx_config.value(),
-        &sender.value().key,
+        &sender.value().key.clone().into(),
         messages,
     )
     .await?;
@@ -104,7 +104,7 @@ pub async fn pay_packet_fee<Chain, Counterparty>(
     let events = simple_send_tx(
         rpc_client.value(),
         tx_config.value(),
-        &payer.value().key,
+        &payer.value().key.clone().into(),
         vec![message],
     )
     .await
@@ -143,7 +143,7 @@ pub async fn register_counterparty_payee<Chain, Counterparty>(
     simple_send_tx(
         rpc_client.value(),
         tx_config.value(),
-        &wallet.value().key,
+        &wallet.value().key.clone().into(),
         messages,
     )
     .await?;
@@ -177,7 +177,7 @@ pub async fn register_payee<Chain, Counterparty>(
     simple_send_tx(
         rpc_client.value(),
         tx_config.value(),
-        &wallet.value().key,
+        &wallet.value().key.clone().into(),
         messages,
     )
     .await?;
@@ -106,7 +106,7 @@ pub async fn ibc_token_transfer<SrcChain, DstChain>(
     let events = simple_send_tx(
         rpc_client.into_value(),
         tx_config.value(),
-        &sender.value().key,
+        &sender.value().key.clone().into(),
         vec![message],
     )
     .await?;
@@ -150,7 +150,7 @@ pub async fn batched_ibc_token_transfer<SrcChain, DstChain>(
     batched_send_tx(
         rpc_client.value(),
         tx_config.value(),
-        &sender.value().key,
+        &sender.value().key.clone().into(),
         messages,
     )
     .await?;
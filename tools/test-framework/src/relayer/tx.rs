@@ -8,6 +8,7 @@ use ibc_relayer::chain::cosmos::gas::calculate_fee;
 use ibc_relayer::chain::cosmos::types::config::TxConfig;
 use ibc_relayer::chain::cosmos::types::gas::GasConfig;
 use ibc_relayer::config::dynamic_gas::DynamicGasPrice;
+use ibc_relayer::config::fee_bump::FeeBump;
 use ibc_relayer::config::{AddressType, GasPrice};
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tendermint_rpc::Url;
@@ -52,6 +53,8 @@ pub fn gas_config_for_test(native_token: String, chain_type: ChainType) -> GasCo
         max_fee,
         fee_granter,
         dynamic_gas_price,
+        fee_bump: FeeBump::disabled(),
+        fee_bumps: 0,
     }
 }
 
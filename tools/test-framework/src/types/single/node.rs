@@ -173,6 +173,8 @@ impl FullNode {
             key_name: self.wallets.relayer.id.0.clone(),
             key_store_type: Store::Test,
             key_store_folder: Some(hermes_keystore_dir.into()),
+            key_store_passphrase: Default::default(),
+            remote_signer: None,
             store_prefix: "ibc".to_string(),
             default_gas: None,
             max_gas: Some(3000000),
@@ -193,11 +195,18 @@ impl FullNode {
             gas_price,
             packet_filter: Default::default(),
             address_type: chain_type.address_type(),
+            key_pool: Default::default(),
+            fee_bump: Default::default(),
+            fee_priority: Default::default(),
+            rate_limit: Default::default(),
             memo_prefix: Default::default(),
             memo_overwrite: None,
             proof_specs: Default::default(),
             extension_options: Default::default(),
+            backup_endpoints: Default::default(),
             sequential_batch_tx: false,
+            light_block_cache: false,
+            light_block_cache_folder: None,
             compat_mode,
             clear_interval: None,
             excluded_sequences: ExcludedSequences::new(BTreeMap::new()),